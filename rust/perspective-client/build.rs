@@ -56,6 +56,7 @@ fn prost_build() -> Result<()> {
             .type_attribute("ViewOnUpdateResp", "#[derive(ts_rs::TS)]")
             .field_attribute("ViewOnUpdateResp.delta", "#[ts(as = \"Vec::<u8>\")]")
             .field_attribute("ViewOnUpdateResp.delta", "#[serde(with = \"serde_bytes\")]")
+            .field_attribute("ViewOnUpdateResp.diff", "#[serde(skip)] #[ts(skip)]")
             .type_attribute("ColumnType", "#[derive(ts_rs::TS)]")
             .type_attribute(
                 "JoinType",
@@ -65,8 +66,13 @@ fn prost_build() -> Result<()> {
                 "ListFlatten",
                 "#[derive(serde::Deserialize, ts_rs::TS)] #[serde(rename_all = \"snake_case\")]",
             )
+            .type_attribute(
+                "OnBadRows",
+                "#[derive(serde::Deserialize, ts_rs::TS)] #[serde(rename_all = \"snake_case\")]",
+            )
             .field_attribute("ViewToArrowResp.arrow", "#[serde(skip)]")
             .field_attribute("from_arrow", "#[serde(skip)]")
+            .field_attribute("CompressedData.data", "#[serde(skip)]")
             .type_attribute(".", "#[derive(serde::Serialize)]")
             .type_attribute("ViewPort", "#[derive(serde::Deserialize)]")
             .type_attribute("ViewDimensionsResp", "#[derive(serde::Deserialize)]")
@@ -76,6 +82,14 @@ fn prost_build() -> Result<()> {
                 "#[derive(serde::Deserialize)]  #[serde(rename_all = \"snake_case\")]",
             )
             .type_attribute("ExprValidationError", "#[derive(serde::Deserialize)]")
+            .type_attribute("ColumnTypeParams", "#[derive(serde::Deserialize, ts_rs::TS)]")
+            .type_attribute("StructFlatten", "#[derive(serde::Deserialize, ts_rs::TS)]")
+            .field_attribute("StructFlatten.max_depth", "#[serde(default)] #[ts(optional)]")
+            .field_attribute("StructFlatten.separator", "#[serde(default)] #[ts(optional)]")
+            .type_attribute(
+                "TimeUnit",
+                "#[derive(serde::Deserialize, ts_rs::TS)] #[serde(rename_all = \"snake_case\")]",
+            )
             .compile_protos(&[proto_file], &[include_path])
             .unwrap();
 
@@ -0,0 +1,210 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! [`Batch`], for [`Client::batch`], which sends several requests to the
+//! server in a single round trip.
+
+use std::collections::HashMap;
+
+use prost::bytes::Bytes;
+
+use crate::client::Client;
+use crate::config::{ViewConfig, ViewConfigUpdate};
+use crate::proto::request::ClientReq;
+use crate::proto::response::ClientResp;
+use crate::proto::*;
+use crate::table::{Schema, Table};
+use crate::utils::{ClientError, ClientResult, randid};
+use crate::view::{View, ViewWindow};
+
+/// A pending response to a request added to a [`Batch`], which can be read
+/// from the [`BatchResponse`] once [`Client::batch`] resolves.
+#[must_use]
+pub struct BatchItem<T> {
+    msg_id: u32,
+    parse: fn(ClientResp) -> ClientResult<T>,
+}
+
+impl<T> std::fmt::Debug for BatchItem<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchItem")
+            .field("msg_id", &self.msg_id)
+            .finish()
+    }
+}
+
+/// A builder for the requests sent by [`Client::batch`].
+///
+/// Requests are handled by the server in the order they are added, so a
+/// [`View`] returned by [`Batch::view`] may be used by later requests in
+/// the same [`Batch`].
+pub struct Batch {
+    client: Client,
+    pub(crate) requests: Vec<Request>,
+}
+
+impl Batch {
+    pub(crate) fn new(client: &Client) -> Self {
+        Batch {
+            client: client.clone(),
+            requests: vec![],
+        }
+    }
+
+    fn push<T>(&mut self, msg: Request, parse: fn(ClientResp) -> ClientResult<T>) -> BatchItem<T> {
+        let msg_id = msg.msg_id;
+        self.requests.push(msg);
+        BatchItem { msg_id, parse }
+    }
+
+    /// Batch a [`Table::schema`] request.
+    pub fn table_schema(&mut self, table: &Table) -> BatchItem<Schema> {
+        let msg = table.client_message(ClientReq::TableSchemaReq(TableSchemaReq {}));
+        self.push(msg, |resp| match resp {
+            ClientResp::TableSchemaResp(TableSchemaResp {
+                schema: Some(schema),
+            }) => schema
+                .schema
+                .into_iter()
+                .map(|x| Ok((x.name, ColumnType::try_from(x.r#type)?)))
+                .collect(),
+            resp => Err(resp.into()),
+        })
+    }
+
+    /// Batch a [`Table::size`] request.
+    pub fn table_size(&mut self, table: &Table) -> BatchItem<usize> {
+        let msg = table.client_message(ClientReq::TableSizeReq(TableSizeReq {}));
+        self.push(msg, |resp| match resp {
+            ClientResp::TableSizeResp(TableSizeResp { size }) => Ok(size as usize),
+            resp => Err(resp.into()),
+        })
+    }
+
+    /// Batch a [`Table::view`] request. The returned [`View`] may be used by
+    /// later requests in this [`Batch`], and after the batch resolves if the
+    /// returned [`BatchItem`] is `Ok`.
+    pub fn view(
+        &mut self,
+        table: &Table,
+        config: Option<ViewConfigUpdate>,
+    ) -> (View, BatchItem<()>) {
+        let view_id = randid();
+        let msg = table.client_message(ClientReq::TableMakeViewReq(TableMakeViewReq {
+            view_id: view_id.clone(),
+            config: config.map(|x| x.into()),
+        }));
+
+        let item = self.push(msg, |resp| match resp {
+            ClientResp::TableMakeViewResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        });
+
+        (View::new(view_id, self.client.clone()), item)
+    }
+
+    /// Batch a [`View::dimensions`] request.
+    pub fn view_dimensions(&mut self, view: &View) -> BatchItem<ViewDimensionsResp> {
+        let msg = view.client_message(ClientReq::ViewDimensionsReq(ViewDimensionsReq {}));
+        self.push(msg, |resp| match resp {
+            ClientResp::ViewDimensionsResp(resp) => Ok(resp),
+            resp => Err(resp.into()),
+        })
+    }
+
+    /// Batch a [`View::schema`] request.
+    pub fn view_schema(&mut self, view: &View) -> BatchItem<HashMap<String, ColumnType>> {
+        let msg = view.client_message(ClientReq::ViewSchemaReq(ViewSchemaReq {}));
+        self.push(msg, |resp| match resp {
+            ClientResp::ViewSchemaResp(ViewSchemaResp { schema, .. }) => schema
+                .into_iter()
+                .map(|(x, y)| Ok((x, ColumnType::try_from(y)?)))
+                .collect(),
+            resp => Err(resp.into()),
+        })
+    }
+
+    /// Batch a [`View::get_config`] request.
+    pub fn view_config(&mut self, view: &View) -> BatchItem<ViewConfig> {
+        let msg = view.client_message(ClientReq::ViewGetConfigReq(ViewGetConfigReq {}));
+        self.push(msg, |resp| match resp {
+            ClientResp::ViewGetConfigResp(ViewGetConfigResp {
+                config: Some(config),
+            }) => Ok(config.into()),
+            resp => Err(resp.into()),
+        })
+    }
+
+    /// Batch a [`View::to_arrow`] request.
+    pub fn view_to_arrow(&mut self, view: &View, window: ViewWindow) -> BatchItem<Bytes> {
+        let msg = view.client_message(ClientReq::ViewToArrowReq(ViewToArrowReq {
+            viewport: Some(window.clone().into()),
+            compression: window.compression,
+        }));
+
+        self.push(msg, |resp| match resp {
+            ClientResp::ViewToArrowResp(ViewToArrowResp { arrow }) => Ok(arrow.into()),
+            resp => Err(resp.into()),
+        })
+    }
+}
+
+/// The responses to a [`Batch`], returned by [`Client::batch`].
+#[derive(Debug)]
+pub struct BatchResponse {
+    responses: HashMap<u32, Response>,
+}
+
+impl BatchResponse {
+    pub(crate) fn new(responses: Vec<Response>) -> Self {
+        BatchResponse {
+            responses: responses.into_iter().map(|x| (x.msg_id, x)).collect(),
+        }
+    }
+
+    /// The response to a batched request, or the error the server returned
+    /// for it.
+    pub fn get<T>(&mut self, item: BatchItem<T>) -> ClientResult<T> {
+        match self.responses.remove(&item.msg_id) {
+            Some(Response {
+                client_resp: Some(resp),
+                ..
+            }) => (item.parse)(resp),
+            _ => Err(ClientError::ResponseAborted),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_matches_by_msg_id() {
+        let item = BatchItem {
+            msg_id: 2,
+            parse: |resp| match resp {
+                ClientResp::TableSizeResp(TableSizeResp { size }) => Ok(size),
+                resp => Err(resp.into()),
+            },
+        };
+
+        let resp = |msg_id, size| Response {
+            msg_id,
+            entity_id: "table".to_owned(),
+            client_resp: Some(ClientResp::TableSizeResp(TableSizeResp { size })),
+        };
+
+        let mut responses = BatchResponse::new(vec![resp(1, 10), resp(2, 20)]);
+        assert_eq!(responses.get(item).unwrap(), 20);
+    }
+}
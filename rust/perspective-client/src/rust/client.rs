@@ -14,21 +14,28 @@ use std::collections::HashMap;
 use std::error::Error;
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_lock::{Mutex, RwLock};
 use futures::Future;
-use futures::future::{BoxFuture, LocalBoxFuture, join_all};
+use futures::future::{BoxFuture, Either, LocalBoxFuture, join_all, select};
+use futures_timer::Delay;
 use prost::Message;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use crate::batch::{Batch, BatchResponse};
+use crate::config::Filter;
 use crate::proto::request::ClientReq;
 use crate::proto::response::ClientResp;
 use crate::proto::{
-    ColumnType, GetFeaturesReq, GetFeaturesResp, GetHostedTablesReq, GetHostedTablesResp,
-    HostedTable, JoinType, MakeJoinTableReq, MakeTableReq, RemoveHostedTablesUpdateReq, Request,
-    Response, ServerError, ServerSystemInfoReq,
+    BatchReq, BatchResp, ColumnType, GetFeaturesReq, GetFeaturesResp, GetHostedTablesReq,
+    GetHostedTablesResp, HostedTable, JoinType, MakeJoinTableReq, MakeTableReq, MakeTableResp,
+    RemoveHostedTablesUpdateReq, Request, Response, ServerError, ServerSystemInfoReq,
+    TableMakeViewReq, UnsubscribeReq,
 };
+use crate::replay::SessionState;
+use crate::subscription::{BufferPolicy, Subscription};
 use crate::table::{JoinOptions, Table, TableInitOptions, TableOptions};
 use crate::table_data::{TableData, UpdateData};
 use crate::table_ref::TableRef;
@@ -124,6 +131,12 @@ impl Features {
     }
 }
 
+impl From<GetFeaturesResp> for Features {
+    fn from(value: GetFeaturesResp) -> Self {
+        Features(Arc::new(value))
+    }
+}
+
 impl Deref for Features {
     type Target = GetFeaturesResp;
 
@@ -141,6 +154,28 @@ impl GetFeaturesResp {
             .map(|x| x.as_str())
     }
 
+    /// Checks that `filter`'s operator is advertised in `filter_ops` for a
+    /// column of type `col_type`, returning
+    /// [`ClientError::UnsupportedFilterOp`] otherwise. A `col_type` the server
+    /// declares no operators for is not checked, like `aggregates`.
+    pub fn validate_filter(&self, filter: &Filter, col_type: ColumnType) -> ClientResult<()> {
+        let Some(supported) = self.filter_ops.get(&(col_type as u32)) else {
+            return Ok(());
+        };
+
+        let supported = supported.options.as_slice();
+        if supported.iter().any(|x| x == filter.op()) {
+            Ok(())
+        } else {
+            Err(ClientError::UnsupportedFilterOp {
+                column: filter.column().to_owned(),
+                column_type: col_type,
+                op: filter.op().to_owned(),
+                supported: supported.to_vec(),
+            })
+        }
+    }
+
     /// The window aggregates this server supports for a `col_type` SOURCE
     /// column, in the server's declared (menu) order.
     pub fn get_window_aggregates(
@@ -242,6 +277,28 @@ impl ReconnectCallback {
     }
 }
 
+/// Cancels a [`Client::oneshot`] request whose future is dropped before its
+/// response arrives, then sends its `cleanup` request (if any). As `Drop`
+/// can't `.await`, these are deferred like a dropped [`Subscription`].
+struct PendingRequest<'a> {
+    client: &'a Client,
+    msg_id: u32,
+    cleanup: Option<Request>,
+    done: bool,
+}
+
+impl Drop for PendingRequest<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            let msg = self.client.unsubscribe_request(self.msg_id);
+            self.client.defer_unsubscribe(self.msg_id, msg);
+            if let Some(cleanup) = self.cleanup.take() {
+                self.client.defer_unsubscribe(cleanup.msg_id, cleanup);
+            }
+        }
+    }
+}
+
 /// An instance of a [`Client`] is a connection to a single
 /// `perspective_server::Server`, whether locally in-memory or remote over some
 /// transport like a WebSocket.
@@ -254,6 +311,23 @@ pub struct Client {
     subscriptions_errors: Subscriptions<OnErrorCallback>,
     subscriptions_once: Subscriptions<OnceCallback>,
     subscriptions: Subscriptions<BoxFn<Response, BoxFuture<'static, Result<(), ClientError>>>>,
+
+    /// Subscriptions whose [`Subscription`] was dropped (and requests whose
+    /// future was dropped), with the request which removes each, sent before
+    /// the next request.
+    dropped_subscriptions: Arc<std::sync::Mutex<Vec<(u32, Request)>>>,
+
+    /// Deadline for requests from every handle to this connection, see
+    /// [`Client::set_timeout`].
+    default_timeout: Arc<std::sync::Mutex<Option<Duration>>>,
+
+    /// Deadline for requests made via this handle, overriding
+    /// `default_timeout`, see [`Client::with_timeout`].
+    timeout: Option<Duration>,
+
+    /// Server-side state to replay after a reconnect, or `None` unless
+    /// enabled by [`Client::set_resilient`].
+    session_state: Arc<std::sync::Mutex<Option<SessionState>>>,
 }
 
 impl PartialEq for Client {
@@ -293,6 +367,10 @@ impl Client {
             subscriptions: Subscriptions::default(),
             subscriptions_errors: Arc::default(),
             subscriptions_once: Arc::default(),
+            dropped_subscriptions: Arc::default(),
+            default_timeout: Arc::default(),
+            timeout: None,
+            session_state: Arc::default(),
         })
     }
 
@@ -313,6 +391,291 @@ impl Client {
         self.name.as_str()
     }
 
+    /// Set the default deadline for requests made by this [`Client`], and by
+    /// every [`Table`] and [`crate::View`] created from it. A request which
+    /// has no response within `timeout` fails with [`ClientError::Timeout`]
+    /// and its response is ignored, though the server still completes it.
+    /// `None` (the default) waits indefinitely.
+    ///
+    /// This only applies to requests which await a response; subscription
+    /// callbacks such as [`crate::View::on_update`] are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use perspective_client::Client;
+    /// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// client.set_timeout(Some(Duration::from_secs(30)));
+    /// # Ok(()) }
+    /// ```
+    pub fn set_timeout(&self, timeout: Option<Duration>) {
+        *self
+            .default_timeout
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = timeout;
+    }
+
+    /// Create a handle to this [`Client`]'s connection whose requests use
+    /// `timeout` rather than the default deadline set by
+    /// [`Client::set_timeout`]. [`Table`]s and [`crate::View`]s created from
+    /// the returned handle inherit `timeout`, see also [`Table::with_timeout`]
+    /// and [`crate::View::with_timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use perspective_client::Client;
+    /// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let tables = client
+    ///     .with_timeout(Duration::from_millis(500))
+    ///     .get_hosted_table_names()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Client {
+        Client {
+            timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+
+    /// Enable (or disable) resilient mode, in which this [`Client`] tracks
+    /// the [`Table`]s it opens, the [`crate::View`]s it creates and its
+    /// active subscriptions (e.g. [`crate::View::on_update`],
+    /// [`Table::on_delete`] and [`Client::on_hosted_tables_update`]).
+    ///
+    /// When the `reconnect` callback passed to [`Client::handle_error`]
+    /// succeeds, the views and subscriptions are recreated on the new server
+    /// session, so existing [`Table`] and [`crate::View`] handles, and
+    /// subscription ids, keep working. A [`Table`] the new session does not
+    /// host is recreated if this [`Client`] created it, by replaying the
+    /// requests which created and modified it (which are kept in memory for
+    /// as long as the [`Table`] exists). Replay fails for any other missing
+    /// [`Table`] and its views.
+    ///
+    /// Only state created after this is enabled is tracked. A
+    /// [`crate::View`]'s expansion state (e.g. [`crate::View::expand`]) is
+    /// not replayed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::Client;
+    /// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// client.set_resilient(true);
+    /// let table = client.open_table("superstore".to_owned()).await?;
+    /// let view = table.view(None).await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_resilient(&self, enabled: bool) {
+        let mut state = self.session_state();
+        match (enabled, state.is_some()) {
+            (true, false) => *state = Some(SessionState::default()),
+            (false, true) => *state = None,
+            _ => {},
+        }
+    }
+
+    fn session_state(&self) -> std::sync::MutexGuard<'_, Option<SessionState>> {
+        self.session_state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The id the current server session knows subscription `id` by, which
+    /// differs from the id returned to the caller after a replay.
+    fn current_id(&self, id: u32) -> u32 {
+        self.session_state()
+            .as_ref()
+            .map(|state| state.current_id(id))
+            .unwrap_or(id)
+    }
+
+    pub(crate) fn track_table(&self, name: &str) {
+        if let Some(state) = self.session_state().as_mut() {
+            state.tables.insert(name.to_owned());
+        }
+    }
+
+    fn track_created_table(&self, name: &str, msg: &Request) {
+        if let Some(state) = self.session_state().as_mut() {
+            state.track_created(name, msg);
+        }
+    }
+
+    pub(crate) fn track_view(&self, view_id: &str, msg: &Request) {
+        if let Some(state) = self.session_state().as_mut() {
+            state.views.insert(view_id.to_owned(), msg.clone());
+        }
+    }
+
+    pub(crate) fn untrack_view(&self, view_id: &str) {
+        if let Some(state) = self.session_state().as_mut() {
+            state.untrack_view(view_id);
+        }
+    }
+
+    /// Recreate the tables, views and subscriptions tracked in resilient
+    /// mode (see [`Client::set_resilient`]) on a new server session.
+    async fn replay_session(&self) -> ClientResult<()> {
+        let Some(state) = self.session_state().clone() else {
+            return Ok(());
+        };
+
+        let hosted = self.get_hosted_table_names().await?;
+        let mut errors = vec![];
+        let mut restored = vec![];
+        for table in state.tables.iter() {
+            let result = if hosted.contains(table) {
+                Ok(())
+            } else if let Some(requests) = state.created.get(table) {
+                self.replay_table(table, requests).await
+            } else {
+                Err(ClientError::Unknown(
+                    "not found, and was not created by this client".to_owned(),
+                ))
+            };
+
+            match result {
+                Ok(()) => restored.push(table.clone()),
+                Err(e) => {
+                    errors.push(format!("Table \"{table}\": {e}"));
+                    if let Some(state) = self.session_state().as_mut() {
+                        state.tables.shift_remove(table);
+                        state.created.shift_remove(table);
+                    }
+                },
+            }
+        }
+
+        for (view_id, msg) in state.views.iter() {
+            let msg = Request {
+                msg_id: self.gen_id(),
+                ..msg.clone()
+            };
+
+            let result = if restored.contains(&msg.entity_id) {
+                self.oneshot(&msg).await
+            } else {
+                Err(ClientError::Unknown(format!(
+                    "Table \"{}\" not found",
+                    msg.entity_id
+                )))
+            };
+
+            let error = match result {
+                Ok(ClientResp::TableMakeViewResp(_)) => {
+                    restored.push(view_id.clone());
+                    continue;
+                },
+                Ok(resp) => ClientError::from(resp),
+                Err(e) => e,
+            };
+
+            errors.push(format!("View \"{view_id}\": {error}"));
+            if let Some(state) = self.session_state().as_mut() {
+                state.views.shift_remove(view_id);
+            }
+        }
+
+        for (id, msg) in state.subscriptions {
+            let new_msg = Request {
+                msg_id: self.gen_id(),
+                ..msg.clone()
+            };
+
+            let is_restored = msg.entity_id.is_empty() || restored.contains(&msg.entity_id);
+            if !is_restored || !self.move_callback(msg.msg_id, new_msg.msg_id).await {
+                if let Some(state) = self.session_state().as_mut() {
+                    state.untrack_subscription(id);
+                }
+
+                continue;
+            }
+
+            if let Some(state) = self.session_state().as_mut() {
+                state.remap(id, new_msg.clone());
+            }
+
+            tracing::debug!("SEND {}", new_msg);
+            if let Err(e) = (self.send)(&new_msg).await {
+                errors.push(e.to_string());
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ClientError::SessionReplay(errors))
+        }
+    }
+
+    /// Recreate table `name` by resending the requests which created, then
+    /// modified, it. The creation request is resent untracked, after which
+    /// the remaining requests are tracked again as they are resent.
+    async fn replay_table(&self, name: &str, requests: &[Request]) -> ClientResult<()> {
+        let Some((create, requests)) = requests.split_first() else {
+            return Ok(());
+        };
+
+        let create = Request {
+            msg_id: self.gen_id(),
+            ..create.clone()
+        };
+
+        if let Some(state) = self.session_state().as_mut() {
+            state.created.shift_remove(name);
+        }
+
+        match self.oneshot(&create).await? {
+            ClientResp::MakeTableResp(_) | ClientResp::MakeJoinTableResp(_) => {
+                self.track_created_table(name, &create)
+            },
+            resp => return Err(resp.into()),
+        }
+
+        for msg in requests {
+            let msg = Request {
+                msg_id: self.gen_id(),
+                ..msg.clone()
+            };
+
+            if let resp @ ClientResp::ServerError(_) = self.oneshot(&msg).await? {
+                return Err(resp.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Re-key the callback for subscription `from` as `to`, returning `false`
+    /// if there is no such callback (e.g. a fired [`Table::on_delete`]).
+    async fn move_callback(&self, from: u32, to: u32) -> bool {
+        let mut subscriptions = self.subscriptions.write().await;
+        if let Some(callback) = subscriptions.remove(&from) {
+            subscriptions.insert(to, callback);
+            return true;
+        }
+
+        drop(subscriptions);
+        let mut subscriptions_once = self.subscriptions_once.write().await;
+        if let Some(callback) = subscriptions_once.remove(&from) {
+            subscriptions_once.insert(to, callback);
+            return true;
+        }
+
+        false
+    }
+
+    fn get_timeout(&self) -> Option<Duration> {
+        self.timeout.or_else(|| {
+            *self
+                .default_timeout
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+        })
+    }
+
     /// Handle a message from the external message queue.
     /// [`Client::handle_response`] is part of the low-level message-handling
     /// API necessary to implement new transports for a [`Client`]
@@ -360,10 +723,14 @@ impl Client {
         let tasks = join_all(subs.values().map(|callback| {
             callback(
                 message.clone(),
-                reconnect.clone().map(move |f| {
+                reconnect.clone().map(|f| {
+                    let client = self.clone();
                     ReconnectCallback(Arc::new(move || {
-                        clone!(f);
-                        Box::pin(async move { Ok(f().await?) }) as LocalBoxFuture<'static, _>
+                        clone!(f, client);
+                        Box::pin(async move {
+                            f().await?;
+                            Ok(client.replay_session().await?)
+                        }) as LocalBoxFuture<'static, _>
                     }))
                 }),
             )
@@ -384,15 +751,30 @@ impl Client {
             client_resp: Some(ClientResp::ServerError(ServerError {
                 message: format!("{message}"),
                 status_code: 2,
+                row_errors: vec![],
             })),
         };
 
-        self.subscriptions.write().await.clear();
+        // In resilient mode, subscriptions are kept for replay.
+        let tracked = self.session_state().as_ref().map(|state| {
+            state
+                .subscriptions
+                .values()
+                .map(|msg| msg.msg_id)
+                .collect::<Vec<_>>()
+        });
+
+        let is_untracked = |msg_id: &u32| tracked.as_ref().is_none_or(|x| !x.contains(msg_id));
+        self.subscriptions
+            .write()
+            .await
+            .retain(|msg_id, _| !is_untracked(msg_id));
+
         let callbacks_once = self
             .subscriptions_once
             .write()
             .await
-            .drain()
+            .extract_if(|msg_id, _| is_untracked(msg_id))
             .collect::<Vec<_>>();
 
         callbacks_once
@@ -416,6 +798,32 @@ impl Client {
         Ok(id)
     }
 
+    /// Checks a [`Filter`] against the filter operators the connected server
+    /// advertises for `column_type`, before sending it in a
+    /// [`crate::config::ViewConfig`]. This reports an unsupported or
+    /// misspelled operator as a descriptive
+    /// [`ClientError::UnsupportedFilterOp`], rather than a server error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::Client;
+    /// # use perspective_client::config::*;
+    /// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let filter = Filter::new("Sales", FilterOp::Gt, Scalar::Float(100.0));
+    /// client.validate_filter(&filter, ColumnType::Float).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn validate_filter(
+        &self,
+        filter: &Filter,
+        column_type: ColumnType,
+    ) -> ClientResult<()> {
+        self.get_features()
+            .await?
+            .validate_filter(filter, column_type)
+    }
+
     /// Generate a message ID unique to this client.
     pub(crate) fn gen_id(&self) -> u32 {
         self.id_gen.next()
@@ -426,13 +834,51 @@ impl Client {
             .subscriptions
             .write()
             .await
-            .remove(&update_id)
+            .remove(&self.current_id(update_id))
             .ok_or(ClientError::Unknown("remove_update".to_string()))?;
 
         drop(callback);
         Ok(())
     }
 
+    /// Schedule the removal of a dropped [`Subscription`], as `Drop` can't
+    /// `.await` it. The removal is sent before the next request.
+    pub(crate) fn defer_unsubscribe(&self, update_id: u32, msg: Request) {
+        self.dropped_subscriptions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((update_id, msg));
+    }
+
+    /// Send the removal of every dropped [`Subscription`].
+    async fn remove_dropped_subscriptions(&self) -> ClientResult<()> {
+        let dropped = std::mem::take(&mut *self.dropped_subscriptions.lock()?);
+        let mut dropped = dropped.into_iter();
+        while let Some((update_id, msg)) = dropped.next() {
+            let current_id = self.current_id(update_id);
+
+            // This may run from within a subscription callback (which holds a
+            // read lock on `subscriptions`), so retry later rather than wait.
+            let Some(mut subscriptions) = self.subscriptions.try_write() else {
+                self.defer_unsubscribe(update_id, msg);
+                continue;
+            };
+
+            subscriptions.remove(&current_id);
+            drop(subscriptions);
+            self.subscriptions_once.write().await.remove(&current_id);
+            if let Err(e) = self.subscribe_once(&msg, Box::new(|_| Ok(()))).await {
+                // Retried by the next request.
+                let mut retry = self.dropped_subscriptions.lock()?;
+                retry.push((update_id, msg));
+                retry.extend(dropped);
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Register a callback which is expected to respond exactly once.
     pub(crate) async fn subscribe_once(
         &self,
@@ -445,7 +891,7 @@ impl Client {
             .insert(msg.msg_id, on_update);
 
         tracing::debug!("SEND {}", msg);
-        if let Err(e) = (self.send)(msg).await {
+        if let Err(e) = self.send_request(msg).await {
             self.subscriptions_once.write().await.remove(&msg.msg_id);
             Err(ClientError::Unknown(e.to_string()))
         } else {
@@ -453,6 +899,17 @@ impl Client {
         }
     }
 
+    /// Send `msg`, first recording it for replay in resilient mode (see
+    /// [`Client::set_resilient`]).
+    async fn send_request(&self, msg: &Request) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let tracked = self
+            .session_state()
+            .as_mut()
+            .and_then(|state| state.track(msg));
+
+        (self.send)(tracked.as_ref().unwrap_or(msg)).await
+    }
+
     pub(crate) async fn subscribe<T, U>(&self, msg: &Request, on_update: T) -> ClientResult<()>
     where
         T: Fn(Response) -> U + Send + Sync + 'static,
@@ -464,7 +921,7 @@ impl Client {
             .insert(msg.msg_id, Box::new(move |x| Box::pin(on_update(x))));
 
         tracing::debug!("SEND {}", msg);
-        if let Err(e) = (self.send)(msg).await {
+        if let Err(e) = self.send_request(msg).await {
             self.subscriptions.write().await.remove(&msg.msg_id);
             Err(ClientError::Unknown(e.to_string()))
         } else {
@@ -474,16 +931,83 @@ impl Client {
 
     /// Send a `ClientReq` and await both the successful completion of the
     /// `send`, _and_ the `ClientResp` which is returned.
+    ///
+    /// If the request times out (see [`Client::set_timeout`]), or the
+    /// returned future is dropped first, the request is cancelled - its
+    /// response is ignored and an `UnsubscribeReq` tells the server to drop
+    /// any subscription registered under it, and to abandon the request if
+    /// it is still running and expensive (e.g. creating a [`crate::View`]).
     pub(crate) async fn oneshot(&self, req: &Request) -> ClientResult<ClientResp> {
+        self.oneshot_with_cleanup(req, None).await
+    }
+
+    /// Like [`Client::oneshot`], but also sends `cleanup` if `req` is
+    /// cancelled, to undo whatever part of `req` the server completed.
+    pub(crate) async fn oneshot_with_cleanup(
+        &self,
+        req: &Request,
+        cleanup: Option<Request>,
+    ) -> ClientResult<ClientResp> {
+        self.remove_dropped_subscriptions().await?;
         let (sender, receiver) = futures::channel::oneshot::channel::<ClientResp>();
         let on_update = Box::new(move |res: Response| {
-            sender.send(res.client_resp.unwrap()).map_err(|x| x.into())
+            // The receiver is gone if this request was cancelled, in which
+            // case the response is discarded.
+            let _ = sender.send(res.client_resp.unwrap());
+            Ok(())
         });
 
-        self.subscribe_once(req, on_update).await?;
-        receiver
-            .await
-            .map_err(|_| ClientError::Unknown(format!("Internal error for req {req}")))
+        let mut pending = PendingRequest {
+            client: self,
+            msg_id: req.msg_id,
+            cleanup,
+            done: false,
+        };
+
+        if let Err(e) = self.subscribe_once(req, on_update).await {
+            pending.done = true;
+            return Err(e);
+        }
+
+        let resp = match self.get_timeout() {
+            None => receiver.await,
+            Some(timeout) => match select(receiver, Delay::new(timeout)).await {
+                Either::Left((resp, _)) => resp,
+                Either::Right(_) => {
+                    pending.done = true;
+                    let cleanup = pending.cleanup.take();
+                    if let Err(e) = self.cancel(req.msg_id, cleanup).await {
+                        tracing::warn!("Failed to cancel timed out request: {}", e);
+                    }
+
+                    return Err(ClientError::Timeout(timeout));
+                },
+            },
+        };
+
+        pending.done = true;
+        resp.map_err(|_| ClientError::Unknown(format!("Internal error for req {req}")))
+    }
+
+    /// Forget the pending request `msg_id`, tell the server to cancel it and
+    /// drop its subscriptions, then send `cleanup` (if any).
+    async fn cancel(&self, msg_id: u32, cleanup: Option<Request>) -> ClientResult<()> {
+        self.subscriptions_once.write().await.remove(&msg_id);
+        let msg = self.unsubscribe_request(msg_id);
+        self.subscribe_once(&msg, Box::new(|_| Ok(()))).await?;
+        if let Some(cleanup) = cleanup {
+            self.subscribe_once(&cleanup, Box::new(|_| Ok(()))).await?;
+        }
+
+        Ok(())
+    }
+
+    fn unsubscribe_request(&self, msg_id: u32) -> Request {
+        Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::UnsubscribeReq(UnsubscribeReq { id: msg_id })),
+        }
     }
 
     pub(crate) async fn get_features(&self) -> ClientResult<Features> {
@@ -509,6 +1033,66 @@ impl Client {
         Ok(features)
     }
 
+    /// Send several requests in a single round trip. `batch` adds requests to
+    /// a [`Batch`], each returning a [`crate::BatchItem`] which can be read
+    /// from the [`BatchResponse`] once this resolves. The server handles the
+    /// requests in order, so a [`crate::View`] created by [`Batch::view`] may
+    /// be used by later requests in the same batch.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::{Client, ViewWindow};
+    /// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let table = client.open_table("superstore".to_owned()).await?;
+    /// let ((view, created, schema, arrow), mut resp) = client
+    ///     .batch(|b| {
+    ///         let (view, created) = b.view(&table, None);
+    ///         let schema = b.view_schema(&view);
+    ///         let arrow = b.view_to_arrow(&view, ViewWindow::default());
+    ///         (view, created, schema, arrow)
+    ///     })
+    ///     .await?;
+    ///
+    /// resp.get(created)?;
+    /// let schema = resp.get(schema)?;
+    /// let arrow = resp.get(arrow)?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn batch<T, R>(&self, batch: T) -> ClientResult<(R, BatchResponse)>
+    where
+        T: FnOnce(&mut Batch) -> R,
+    {
+        let mut builder = Batch::new(self);
+        let output = batch(&mut builder);
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::BatchReq(BatchReq {
+                requests: builder.requests.clone(),
+            })),
+        };
+
+        let responses = match self.oneshot(&msg).await? {
+            ClientResp::BatchResp(BatchResp { responses }) => responses,
+            resp => return Err(resp.into()),
+        };
+
+        for req in builder.requests.iter() {
+            if let Some(ClientReq::TableMakeViewReq(TableMakeViewReq { view_id, .. })) =
+                &req.client_req
+                && responses.iter().any(|resp| {
+                    resp.msg_id == req.msg_id
+                        && matches!(resp.client_resp, Some(ClientResp::TableMakeViewResp(_)))
+                })
+            {
+                self.track_view(view_id, req);
+            }
+        }
+
+        Ok((output, BatchResponse::new(responses)))
+    }
+
     /// Creates a new [`Table`] from either a _schema_ or _data_.
     ///
     /// The [`Client::table`] factory function can be initialized with either a
@@ -519,6 +1103,8 @@ impl Client {
     /// - JSON row-oriented
     /// - JSON column-oriented
     /// - NDJSON
+    /// - Any of the above, gzip- or zstd-compressed (see
+    ///   [`UpdateData::Compressed`])
     ///
     /// When instantiated with _data_, the schema is inferred from this data.
     /// While this is convenient, inferrence is sometimes imperfect e.g.
@@ -592,6 +1178,39 @@ impl Client {
             table.view_update_token = Some(on_update_token);
             Ok(table)
         } else {
+            #[cfg(feature = "arrow")]
+            if let TableData::Update(UpdateData::RecordBatches(batches)) = input {
+                let mut chunks = UpdateData::record_batch_chunks(batches);
+                let Some(first) = chunks.next() else {
+                    return Err(ClientError::Internal(
+                        "Can't create a `Table` from an empty `RecordBatch` list".to_owned(),
+                    ));
+                };
+
+                let (num_rows, first) = first?;
+                let table = self
+                    .crate_table_inner(first.into(), options.into(), entity_id)
+                    .await?;
+
+                // The remaining chunks are loaded as updates, so a failure in
+                // one of them must not leave a partially loaded `Table` behind.
+                let options = crate::UpdateOptions::default();
+                return match table.update_chunks(chunks, num_rows, &options).await {
+                    Ok(errors) => {
+                        let mut row_errors = table.row_errors();
+                        row_errors.extend(errors);
+                        Ok(table.with_row_errors(row_errors))
+                    },
+                    Err(err) => {
+                        table
+                            .delete(crate::DeleteOptions::default())
+                            .await
+                            .unwrap_or_log();
+                        Err(err)
+                    },
+                };
+            }
+
             self.crate_table_inner(input, options.into(), entity_id)
                 .await
         }
@@ -607,14 +1226,17 @@ impl Client {
             msg_id: self.gen_id(),
             entity_id: entity_id.clone(),
             client_req: Some(ClientReq::MakeTableReq(MakeTableReq {
-                data: Some(input.into()),
+                data: Some(input.try_into()?),
                 options: Some(options.clone().try_into()?),
             })),
         };
 
         let client = self.clone();
         match self.oneshot(&msg).await? {
-            ClientResp::MakeTableResp(_) => Ok(Table::new(entity_id, client, options)),
+            ClientResp::MakeTableResp(MakeTableResp { row_errors }) => {
+                self.track_created_table(&entity_id, &msg);
+                Ok(Table::new(entity_id, client, options).with_row_errors(row_errors))
+            },
             resp => Err(resp.into()),
         }
     }
@@ -654,12 +1276,20 @@ impl Client {
 
         let client = self.clone();
         match self.oneshot(&msg).await? {
-            ClientResp::MakeJoinTableResp(_) => Ok(Table::new(entity_id, client, TableOptions {
-                index: Some(on.to_owned()),
-                limit: None,
-                page_to_disk: None,
-                list_flatten: None,
-            })),
+            ClientResp::MakeJoinTableResp(_) => {
+                self.track_created_table(&entity_id, &msg);
+                Ok(Table::new(entity_id, client, TableOptions {
+                    index: Some(on.to_owned()),
+                    limit: None,
+                    page_to_disk: None,
+                    list_flatten: None,
+                    struct_flatten: None,
+                    preserve_arrow_types: None,
+                    csv: None,
+                    schema_hints: None,
+                    on_bad_rows: None,
+                }))
+            },
             resp => Err(resp.into()),
         }
     }
@@ -705,6 +1335,11 @@ impl Client {
                 limit: info.limit,
                 page_to_disk: None,
                 list_flatten: None,
+                struct_flatten: None,
+                preserve_arrow_types: None,
+                csv: None,
+                schema_hints: None,
+                on_bad_rows: None,
             };
 
             let client = self.clone();
@@ -781,6 +1416,57 @@ impl Client {
         Ok(msg.msg_id)
     }
 
+    /// A [`Subscription`] which yields whenever the hosted tables on the
+    /// [`Server`] change, like [`Client::on_hosted_tables_update`] but
+    /// unsubscribed automatically when dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use perspective_client::{BufferPolicy, Client};
+    /// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut updates = client.hosted_tables_updates(BufferPolicy::Latest).await?;
+    /// while let Some(()) = updates.next().await.transpose()? {
+    ///     println!("{:?}", client.get_hosted_table_names().await?);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn hosted_tables_updates(
+        &self,
+        buffer: BufferPolicy,
+    ) -> ClientResult<Subscription<()>> {
+        let msg = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::GetHostedTablesReq(GetHostedTablesReq {
+                subscribe: true,
+            })),
+        };
+
+        let remove = Request {
+            msg_id: self.gen_id(),
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::RemoveHostedTablesUpdateReq(
+                RemoveHostedTablesUpdateReq { id: msg.msg_id },
+            )),
+        };
+
+        let (subscription, sender) = Subscription::new(self, msg.msg_id, remove, buffer);
+        let sender = Arc::new(sender);
+        let callback = move |resp: Response| {
+            match resp.client_resp {
+                Some(ClientResp::GetHostedTablesResp(_)) | None => sender.send(()),
+                resp => sender.fail(resp.into()),
+            };
+
+            async { Ok(()) }
+        };
+
+        self.subscribe(&msg, callback).await?;
+        Ok(subscription)
+    }
+
     /// Remove a callback previously registered via
     /// `Client::on_hosted_tables_update`.
     pub async fn remove_hosted_tables_update(&self, update_id: u32) -> ClientResult<()> {
@@ -846,3 +1532,45 @@ impl Client {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn test_timeout_cancels_request() {
+        let sent: Arc<Mutex<Vec<Request>>> = Arc::default();
+        let client = Client::new_with_callback(None, {
+            let sent = sent.clone();
+            move |bytes: Vec<u8>| {
+                sent.lock()
+                    .unwrap()
+                    .push(Request::decode(bytes.as_slice()).unwrap());
+
+                async { Ok(()) }
+            }
+        })
+        .unwrap();
+
+        let result = block_on(
+            client
+                .with_timeout(Duration::from_millis(10))
+                .get_hosted_table_names(),
+        );
+
+        assert!(matches!(result, Err(ClientError::Timeout(_))));
+        assert_eq!(client.subscriptions_once.try_read().unwrap().len(), 1);
+        let sent = sent.lock().unwrap();
+        assert!(matches!(
+            &sent[..],
+            [
+                req,
+                Request { client_req: Some(ClientReq::UnsubscribeReq(UnsubscribeReq { id })), .. },
+            ] if *id == req.msg_id
+        ));
+    }
+}
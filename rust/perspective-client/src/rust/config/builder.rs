@@ -0,0 +1,350 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! A fluent builder for [`ViewConfigUpdate`], see
+//! [`ViewConfigUpdate::builder`].
+
+use super::aggregates::Aggregate;
+use super::diagnostics::ConfigDiagnostic;
+use super::filters::{Filter, FilterOp, FilterReducer, FilterTerm, Scalar};
+use super::sort::{Sort, SortDir};
+use super::view_config::{GroupRollupMode, SplitRollupMode, ViewConfigUpdate};
+use super::windows::WindowSpec;
+use crate::utils::{ClientError, ClientResult};
+
+impl ViewConfigUpdate {
+    /// Create a [`ViewConfigBuilder`], a fluent alternative to constructing a
+    /// [`ViewConfigUpdate`] literal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use perspective_client::config::*;
+    /// let config = ViewConfigUpdate::builder()
+    ///     .group_by(["State"])
+    ///     .split_by(["Category"])
+    ///     .sort("Sales", SortDir::Desc)
+    ///     .filter("Quantity", FilterOp::Gt, 5)
+    ///     .aggregate("Profit", "median")
+    ///     .expression("Margin", "\"Profit\" / \"Sales\"")
+    ///     .window("Running Sales", WindowSpec::new("Sales", "sum"))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> ViewConfigBuilder {
+        ViewConfigBuilder::default()
+    }
+}
+
+/// A fluent builder for [`ViewConfigUpdate`], created with
+/// [`ViewConfigUpdate::builder`].
+///
+/// Each method consumes and returns the builder; list-valued fields (e.g.
+/// `group_by`, `sort`) accumulate across calls. [`ViewConfigBuilder::build`]
+/// checks the config's _structure_ only - column names and aggregates are
+/// not checked against any `Table`, for which see
+/// [`crate::Table::validate_view_config`].
+#[derive(Clone, Debug, Default)]
+pub struct ViewConfigBuilder {
+    config: ViewConfigUpdate,
+    diagnostics: Vec<ConfigDiagnostic>,
+}
+
+impl ViewConfigBuilder {
+    /// Append columns to `group_by`.
+    pub fn group_by<S: Into<String>>(mut self, columns: impl IntoIterator<Item = S>) -> Self {
+        self.config
+            .group_by
+            .get_or_insert_default()
+            .extend(columns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Append columns to `split_by`.
+    pub fn split_by<S: Into<String>>(mut self, columns: impl IntoIterator<Item = S>) -> Self {
+        self.config
+            .split_by
+            .get_or_insert_default()
+            .extend(columns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Append columns to `columns`.
+    pub fn columns<S: Into<String>>(mut self, columns: impl IntoIterator<Item = S>) -> Self {
+        self.config
+            .columns
+            .get_or_insert_default()
+            .extend(columns.into_iter().map(|x| Some(x.into())));
+        self
+    }
+
+    /// Append a [`Sort`] of `column` in direction `dir`.
+    pub fn sort(mut self, column: impl Into<String>, dir: SortDir) -> Self {
+        self.config
+            .sort
+            .get_or_insert_default()
+            .push(Sort(column.into(), dir));
+        self
+    }
+
+    /// Append a [`Filter`] comparing `column` to a single `term`. Unary
+    /// operators such as [`FilterOp::IsNull`] ignore `term`, so pass
+    /// [`Scalar::Null`].
+    pub fn filter(
+        mut self,
+        column: impl Into<String>,
+        op: impl Into<FilterOp>,
+        term: impl Into<Scalar>,
+    ) -> Self {
+        let filter = Filter::new(&column.into(), op, FilterTerm::Scalar(term.into()));
+        self.config.filter.get_or_insert_default().push(filter);
+        self
+    }
+
+    /// Append a [`Filter`] comparing `column` to a list of `terms`, for
+    /// operators such as [`FilterOp::In`].
+    pub fn filter_in<T: Into<Scalar>>(
+        mut self,
+        column: impl Into<String>,
+        op: impl Into<FilterOp>,
+        terms: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let term = FilterTerm::Array(terms.into_iter().map(Into::into).collect());
+        let filter = Filter::new(&column.into(), op, term);
+        self.config.filter.get_or_insert_default().push(filter);
+        self
+    }
+
+    /// Set how multiple filters combine, see [`FilterReducer`].
+    pub fn filter_op(mut self, filter_op: FilterReducer) -> Self {
+        self.config.filter_op = Some(filter_op);
+        self
+    }
+
+    /// Set the [`Aggregate`] for `column`, replacing any set previously.
+    pub fn aggregate(mut self, column: impl Into<String>, aggregate: impl Into<Aggregate>) -> Self {
+        self.config
+            .aggregates
+            .get_or_insert_default()
+            .insert(column.into(), aggregate.into());
+        self
+    }
+
+    /// Add an expression column named `alias`. An `alias` used by another
+    /// expression or window is reported by [`ViewConfigBuilder::build`].
+    pub fn expression(mut self, alias: impl Into<String>, expression: impl Into<String>) -> Self {
+        let alias = alias.into();
+        let expressions = self.config.expressions.get_or_insert_default();
+        if expressions.0.contains_key(&alias) {
+            self.diagnostics.push(ConfigDiagnostic::new(
+                format!("expressions[{alias:?}]"),
+                format!("Duplicate expression alias \"{alias}\""),
+            ));
+        }
+
+        expressions.0.insert(alias, expression.into());
+        self
+    }
+
+    /// Add a window column named `alias`. An `alias` used by another
+    /// expression or window is reported by [`ViewConfigBuilder::build`].
+    pub fn window(mut self, alias: impl Into<String>, spec: WindowSpec) -> Self {
+        let alias = alias.into();
+        let windows = self.config.windows.get_or_insert_default();
+        if windows.contains_key(&alias) {
+            self.diagnostics.push(ConfigDiagnostic::new(
+                format!("windows[{alias:?}]"),
+                format!("Duplicate window alias \"{alias}\""),
+            ));
+        }
+
+        windows.insert(alias, spec);
+        self
+    }
+
+    /// Set `group_by_depth`.
+    pub fn group_by_depth(mut self, depth: u32) -> Self {
+        self.config.group_by_depth = Some(depth);
+        self
+    }
+
+    /// Set the [`GroupRollupMode`].
+    pub fn group_rollup_mode(mut self, mode: GroupRollupMode) -> Self {
+        self.config.group_rollup_mode = Some(mode);
+        self
+    }
+
+    /// Set the [`SplitRollupMode`].
+    pub fn split_rollup_mode(mut self, mode: SplitRollupMode) -> Self {
+        self.config.split_rollup_mode = Some(mode);
+        self
+    }
+
+    /// Check the config's structure and return it, or a
+    /// [`ClientError::InvalidViewConfig`] listing every problem found:
+    /// aliases shared by two expressions, two windows, or an expression and
+    /// a window, and window specs whose `column` is empty or names another
+    /// window (a window's input must be a `Table` column or expression).
+    pub fn build(self) -> ClientResult<ViewConfigUpdate> {
+        let Self {
+            config,
+            mut diagnostics,
+        } = self;
+
+        if let Some(windows) = &config.windows {
+            let mut names = windows.keys().collect::<Vec<_>>();
+            names.sort();
+            for name in names {
+                let spec = &windows[name];
+                let path = format!("windows[{name:?}]");
+                if config
+                    .expressions
+                    .as_ref()
+                    .is_some_and(|x| x.0.contains_key(name))
+                {
+                    diagnostics.push(ConfigDiagnostic::new(
+                        path.clone(),
+                        format!("Window alias collides with expression \"{name}\""),
+                    ));
+                }
+
+                if spec.column.is_empty() {
+                    diagnostics.push(ConfigDiagnostic::new(
+                        format!("{path}.column"),
+                        "Window column is undefined",
+                    ));
+                } else if windows.contains_key(&spec.column) {
+                    diagnostics.push(ConfigDiagnostic::new(
+                        format!("{path}.column"),
+                        format!(
+                            "Window column \"{}\" is a window, expected a column or expression",
+                            spec.column
+                        ),
+                    ));
+                }
+
+                if let Some(order_by) = &spec.order_by
+                    && order_by.0.is_empty()
+                {
+                    diagnostics.push(ConfigDiagnostic::new(
+                        format!("{path}.order_by[0]"),
+                        "Window order_by column is undefined",
+                    ));
+                }
+            }
+        }
+
+        if diagnostics.is_empty() {
+            Ok(config)
+        } else {
+            Err(ClientError::InvalidViewConfig(diagnostics))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::WindowSort;
+
+    #[test]
+    fn test_builder_builds_config() {
+        let config = ViewConfigUpdate::builder()
+            .group_by(["a"])
+            .split_by(["b"])
+            .sort("x", SortDir::Desc)
+            .filter("y", FilterOp::Gt, 5)
+            .aggregate("z", "median")
+            .expression("e", "\"x\" + 1")
+            .window("w", WindowSpec::new("e", "sum"))
+            .build()
+            .unwrap();
+
+        assert_eq!(config.group_by, Some(vec!["a".to_owned()]));
+        assert_eq!(config.split_by, Some(vec!["b".to_owned()]));
+        assert_eq!(config.sort, Some(vec![Sort("x".to_owned(), SortDir::Desc)]));
+        assert_eq!(
+            config.filter,
+            Some(vec![Filter::new("y", FilterOp::Gt, Scalar::Float(5.0))])
+        );
+        assert_eq!(
+            config.aggregates.unwrap()["z"],
+            Aggregate::SingleAggregate("median".to_owned())
+        );
+        assert_eq!(config.expressions.unwrap().0["e"], "\"x\" + 1");
+        assert_eq!(config.windows.unwrap()["w"].column, "e");
+    }
+
+    #[test]
+    fn test_builder_accumulates_lists() {
+        let config = ViewConfigUpdate::builder()
+            .group_by(["a"])
+            .group_by(vec!["b".to_owned()])
+            .filter_in("c", FilterOp::In, ["x", "y"])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.group_by,
+            Some(vec!["a".to_owned(), "b".to_owned()])
+        );
+
+        assert_eq!(
+            config.filter,
+            Some(vec![Filter::new("c", FilterOp::In, ["x", "y"])])
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_duplicate_aliases() {
+        let result = ViewConfigUpdate::builder()
+            .expression("e", "1")
+            .expression("e", "2")
+            .window("e", WindowSpec::new("x", "sum"))
+            .window("w", WindowSpec::new("x", "sum"))
+            .window("w", WindowSpec::new("x", "mean"))
+            .build();
+
+        let Err(ClientError::InvalidViewConfig(diagnostics)) = result else {
+            panic!("Expected InvalidViewConfig, got {result:?}");
+        };
+
+        let paths = diagnostics.iter().map(|x| x.path.as_str()).collect::<Vec<_>>();
+        assert_eq!(paths, [
+            r#"expressions["e"]"#,
+            r#"windows["w"]"#,
+            r#"windows["e"]"#
+        ]);
+    }
+
+    #[test]
+    fn test_builder_rejects_undefined_window_column() {
+        let mut spec = WindowSpec::new("", "sum");
+        spec.order_by = Some(WindowSort(String::new(), Default::default()));
+        let result = ViewConfigUpdate::builder()
+            .window("a", spec)
+            .window("b", WindowSpec::new("c", "sum"))
+            .window("c", WindowSpec::new("x", "sum"))
+            .build();
+
+        let Err(ClientError::InvalidViewConfig(diagnostics)) = result else {
+            panic!("Expected InvalidViewConfig, got {result:?}");
+        };
+
+        let paths = diagnostics.iter().map(|x| x.path.as_str()).collect::<Vec<_>>();
+        assert_eq!(paths, [
+            r#"windows["a"].column"#,
+            r#"windows["a"].order_by[0]"#,
+            r#"windows["b"].column"#
+        ]);
+    }
+}
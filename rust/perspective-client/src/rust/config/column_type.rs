@@ -14,7 +14,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use crate::ClientError;
-use crate::proto::ColumnType;
+use crate::proto::{ColumnType, ColumnTypeParams, TimeUnit};
 
 impl Display for ColumnType {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
@@ -25,6 +25,8 @@ impl Display for ColumnType {
             Self::Boolean => "boolean",
             Self::Date => "date",
             Self::Datetime => "datetime",
+            Self::Decimal => "decimal",
+            Self::List => "list",
         })
     }
 }
@@ -53,6 +55,10 @@ impl TryFrom<&str> for ColumnType {
             Ok(Self::Date)
         } else if val == "datetime" {
             Ok(Self::Datetime)
+        } else if val == "decimal" {
+            Ok(Self::Decimal)
+        } else if val == "list" {
+            Ok(Self::List)
         } else {
             Err(ClientError::Internal(format!("Unknown type {val}")))
         }
@@ -68,7 +74,183 @@ impl ColumnType {
             ColumnType::Integer => "Integer",
             ColumnType::Float => "Float",
             ColumnType::Boolean => "Boolean",
+            ColumnType::Decimal => "Decimal",
+            ColumnType::List => "List",
         }
         .into()
     }
+
+    /// Parses a type name like [`ColumnType::try_from`], additionally
+    /// accepting the parameterized forms `"decimal(precision, scale)"`,
+    /// `"datetime(unit)"` or `"datetime(unit, timezone)"`, where `unit` is one
+    /// of `s`, `ms`, `us` or `ns` and `timezone` is an IANA name, and
+    /// `"list(element)"`, where `element` is one of `string`, `integer`,
+    /// `float` or `boolean`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use perspective_client::config::{ColumnType, ColumnTypeParams, TimeUnit};
+    /// let (ty, params) = ColumnType::parse_with_params("decimal(10, 2)").unwrap();
+    /// assert_eq!(ty, ColumnType::Decimal);
+    /// assert_eq!(params, Some(ColumnTypeParams::decimal(10, 2)));
+    ///
+    /// let (ty, params) = ColumnType::parse_with_params("datetime(ns, America/New_York)").unwrap();
+    /// assert_eq!(ty, ColumnType::Datetime);
+    /// assert_eq!(
+    ///     params,
+    ///     Some(ColumnTypeParams::datetime(
+    ///         TimeUnit::Nanosecond,
+    ///         Some("America/New_York".to_owned())
+    ///     ))
+    /// );
+    ///
+    /// let (ty, params) = ColumnType::parse_with_params("list(integer)").unwrap();
+    /// assert_eq!(ty, ColumnType::List);
+    /// assert_eq!(params, Some(ColumnTypeParams::list(ColumnType::Integer)));
+    /// ```
+    pub fn parse_with_params(val: &str) -> Result<(Self, Option<ColumnTypeParams>), ClientError> {
+        let unknown = || ClientError::Internal(format!("Unknown type {val}"));
+        if let Some(args) = val
+            .strip_prefix("datetime(")
+            .and_then(|x| x.strip_suffix(')'))
+        {
+            let (unit, timezone) = match args.split_once(',') {
+                Some((unit, timezone)) => (unit, Some(timezone.trim().to_owned())),
+                None => (args, None),
+            };
+
+            let unit = TimeUnit::from_abbreviation(unit.trim()).ok_or_else(unknown)?;
+            return Ok((
+                Self::Datetime,
+                Some(ColumnTypeParams::datetime(unit, timezone)),
+            ));
+        }
+
+        if let Some(element) = val.strip_prefix("list(").and_then(|x| x.strip_suffix(')')) {
+            let element = ColumnType::try_from(element.trim())?;
+            return match element {
+                ColumnType::String
+                | ColumnType::Integer
+                | ColumnType::Float
+                | ColumnType::Boolean => Ok((Self::List, Some(ColumnTypeParams::list(element)))),
+                _ => Err(unknown()),
+            };
+        }
+
+        let Some(args) = val
+            .strip_prefix("decimal(")
+            .and_then(|x| x.strip_suffix(')'))
+        else {
+            return Ok((val.try_into()?, None));
+        };
+
+        let parse = |x: &str| {
+            x.trim()
+                .parse::<u32>()
+                .map_err(|_| ClientError::Internal(format!("Unknown type {val}")))
+        };
+
+        match args.split_once(',') {
+            Some((precision, scale)) => Ok((
+                Self::Decimal,
+                Some(ColumnTypeParams::decimal(parse(precision)?, parse(scale)?)),
+            )),
+            None => Err(ClientError::Internal(format!("Unknown type {val}"))),
+        }
+    }
+
+    /// The Arrow [`arrow_schema::DataType`] which columns of this type are
+    /// serialized as by [`crate::View::to_arrow`] and
+    /// [`crate::View::to_record_batches`].
+    ///
+    /// [`ColumnType::Decimal`] columns serialize as a `Decimal128` with the
+    /// column's own precision and scale (see
+    /// [`ColumnType::to_arrow_data_type_with_params`]); without them, this
+    /// method assumes the maximum precision and a scale of `0`.
+    /// [`ColumnType::List`] columns likewise assume `string` elements.
+    #[cfg(feature = "arrow")]
+    pub fn to_arrow_data_type(&self) -> arrow_schema::DataType {
+        crate::utils::arrow::column_type_to_arrow(*self)
+    }
+
+    /// Like [`ColumnType::to_arrow_data_type`], but uses the precision and
+    /// scale in `params` for [`ColumnType::Decimal`] columns, and the unit and
+    /// timezone in `params` for [`ColumnType::Datetime`] columns, and the
+    /// element type in `params` for [`ColumnType::List`] columns.
+    #[cfg(feature = "arrow")]
+    pub fn to_arrow_data_type_with_params(
+        &self,
+        params: Option<&ColumnTypeParams>,
+    ) -> arrow_schema::DataType {
+        match (self, params) {
+            (ColumnType::Decimal, Some(params)) => crate::utils::arrow::decimal_data_type(params),
+            (ColumnType::Datetime, Some(params)) => {
+                crate::utils::arrow::timestamp_data_type(params)
+            },
+            (ColumnType::List, Some(params)) => crate::utils::arrow::list_data_type(params),
+            _ => self.to_arrow_data_type(),
+        }
+    }
+}
+
+impl TimeUnit {
+    /// The short name of this unit, e.g. `"ns"`, as accepted by
+    /// [`ColumnType::parse_with_params`].
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            TimeUnit::Second => "s",
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Microsecond => "us",
+            TimeUnit::Nanosecond => "ns",
+        }
+    }
+
+    /// The inverse of [`TimeUnit::abbreviation`].
+    pub fn from_abbreviation(val: &str) -> Option<Self> {
+        match val {
+            "s" => Some(TimeUnit::Second),
+            "ms" => Some(TimeUnit::Millisecond),
+            "us" => Some(TimeUnit::Microsecond),
+            "ns" => Some(TimeUnit::Nanosecond),
+            _ => None,
+        }
+    }
+}
+
+impl ColumnTypeParams {
+    /// The largest precision a [`ColumnType::Decimal`] column can have. Values
+    /// are stored by the engine as a scaled 64-bit integer, so this is the
+    /// number of decimal digits which always fit in an `i64`.
+    pub const MAX_DECIMAL_PRECISION: u32 = 18;
+
+    /// Parameters for a [`ColumnType::Decimal`] column with `precision` total
+    /// digits, `scale` of which are after the decimal point.
+    pub fn decimal(precision: u32, scale: u32) -> Self {
+        Self {
+            precision: Some(precision),
+            scale: Some(scale),
+            ..Self::default()
+        }
+    }
+
+    /// Parameters for a [`ColumnType::Datetime`] column stored in `unit`. The
+    /// IANA `timezone` is display metadata only; values are always UTC.
+    pub fn datetime(unit: TimeUnit, timezone: Option<String>) -> Self {
+        Self {
+            unit: Some(unit as i32),
+            timezone,
+            ..Self::default()
+        }
+    }
+
+    /// Parameters for a [`ColumnType::List`] column whose elements are
+    /// `element`, one of [`ColumnType::String`], [`ColumnType::Integer`],
+    /// [`ColumnType::Float`] or [`ColumnType::Boolean`].
+    pub fn list(element: ColumnType) -> Self {
+        Self {
+            element_type: Some(element as i32),
+            ..Self::default()
+        }
+    }
 }
@@ -0,0 +1,392 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Client-side validation of a [`ViewConfigUpdate`], see
+//! [`crate::Table::validate_view_config`].
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::aggregates::Aggregate;
+use super::view_config::ViewConfigUpdate;
+use crate::client::Features;
+use crate::proto::ColumnType;
+use crate::table::ExprValidationResult;
+
+/// A problem with a [`ViewConfigUpdate`], located by `path`, the field path
+/// within the config (e.g. `group_by[1]`, `aggregates["Sales"]` or
+/// `windows["ma"].column`).
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, TS)]
+pub struct ConfigDiagnostic {
+    pub path: String,
+    pub message: String,
+}
+
+impl ConfigDiagnostic {
+    pub(crate) fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ConfigDiagnostic {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(fmt, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Validates `config` against the `Table`'s `schema`, the result of
+/// validating its `expressions`, and the server's [`Features`].
+pub(crate) fn validate_view_config(
+    config: &ViewConfigUpdate,
+    schema: &HashMap<String, ColumnType>,
+    expressions: &ExprValidationResult,
+    features: &Features,
+) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = vec![];
+    let mut columns = schema.clone();
+
+    let mut expression_names = config
+        .expressions
+        .iter()
+        .flat_map(|x| x.0.keys())
+        .collect::<Vec<_>>();
+
+    expression_names.sort();
+    for name in expression_names {
+        let path = format!("expressions[{name:?}]");
+        if let Some(error) = expressions.errors.get(name) {
+            diagnostics.push(ConfigDiagnostic::new(path, error.error_message.clone()));
+        } else if schema.contains_key(name) {
+            diagnostics.push(ConfigDiagnostic::new(
+                path,
+                format!("Expression alias collides with column \"{name}\""),
+            ));
+        } else if let Some(column_type) = expressions.expression_schema.get(name) {
+            columns.insert(name.clone(), *column_type);
+        }
+    }
+
+    if let Some(windows) = &config.windows {
+        if !windows.is_empty() && !features.has_window_aggregates() {
+            diagnostics.push(ConfigDiagnostic::new(
+                "windows",
+                "This server does not support window columns",
+            ));
+        }
+
+        let mut window_names = windows.keys().collect::<Vec<_>>();
+        window_names.sort();
+        let mut window_columns = vec![];
+        for name in window_names {
+            let spec = &windows[name];
+            let path = format!("windows[{name:?}]");
+            if columns.contains_key(name) {
+                diagnostics.push(ConfigDiagnostic::new(
+                    path.clone(),
+                    format!("Window alias collides with column \"{name}\""),
+                ));
+            }
+
+            let Some(column_type) = columns.get(&spec.column).copied() else {
+                diagnostics.push(ConfigDiagnostic::new(
+                    format!("{path}.column"),
+                    format!("Unknown column \"{}\"", spec.column),
+                ));
+                continue;
+            };
+
+            let aggregates = features.get_window_aggregates(column_type);
+            match aggregates.iter().find(|x| x.name == spec.aggregate) {
+                Some(args) => {
+                    let result_type = args
+                        .result_type
+                        .and_then(|x| ColumnType::try_from(x).ok())
+                        .unwrap_or(column_type);
+
+                    window_columns.push((name.clone(), result_type));
+                },
+                None if features.has_window_aggregates() => {
+                    diagnostics.push(ConfigDiagnostic::new(
+                        format!("{path}.aggregate"),
+                        format!(
+                            "Window aggregate \"{}\" is not supported for {} column \"{}\"",
+                            spec.aggregate, column_type, spec.column
+                        ),
+                    ));
+                },
+                None => {},
+            }
+
+            for (idx, column) in spec.partition_by.iter().enumerate() {
+                if !columns.contains_key(column) {
+                    diagnostics.push(ConfigDiagnostic::new(
+                        format!("{path}.partition_by[{idx}]"),
+                        format!("Unknown column \"{column}\""),
+                    ));
+                }
+            }
+
+            if let Some(order_by) = &spec.order_by
+                && !columns.contains_key(&order_by.0)
+            {
+                diagnostics.push(ConfigDiagnostic::new(
+                    format!("{path}.order_by[0]"),
+                    format!("Unknown column \"{}\"", order_by.0),
+                ));
+            }
+        }
+
+        columns.extend(window_columns);
+    }
+
+    let unknown = |path: String, column: &str| {
+        ConfigDiagnostic::new(path, format!("Unknown column \"{column}\""))
+    };
+
+    if let Some(group_by) = &config.group_by {
+        if !group_by.is_empty() && !features.group_by {
+            diagnostics.push(ConfigDiagnostic::new(
+                "group_by",
+                "This server does not support group_by",
+            ));
+        }
+
+        for (idx, column) in group_by.iter().enumerate() {
+            if !columns.contains_key(column) {
+                diagnostics.push(unknown(format!("group_by[{idx}]"), column));
+            }
+        }
+    }
+
+    if let Some(split_by) = &config.split_by {
+        if !split_by.is_empty() && !features.split_by {
+            diagnostics.push(ConfigDiagnostic::new(
+                "split_by",
+                "This server does not support split_by",
+            ));
+        }
+
+        for (idx, column) in split_by.iter().enumerate() {
+            if !columns.contains_key(column) {
+                diagnostics.push(unknown(format!("split_by[{idx}]"), column));
+            }
+        }
+    }
+
+    for (idx, column) in config.columns.iter().flatten().enumerate() {
+        if let Some(column) = column
+            && !columns.contains_key(column)
+        {
+            diagnostics.push(unknown(format!("columns[{idx}]"), column));
+        }
+    }
+
+    if let Some(sort) = &config.sort {
+        if !sort.is_empty() && !features.sort {
+            diagnostics.push(ConfigDiagnostic::new(
+                "sort",
+                "This server does not support sort",
+            ));
+        }
+
+        for (idx, sort) in sort.iter().enumerate() {
+            if !columns.contains_key(&sort.0) {
+                diagnostics.push(unknown(format!("sort[{idx}][0]"), &sort.0));
+            }
+        }
+    }
+
+    for (idx, filter) in config.filter.iter().flatten().enumerate() {
+        match columns.get(filter.column()) {
+            None => diagnostics.push(unknown(format!("filter[{idx}][0]"), filter.column())),
+            Some(column_type) => {
+                if let Err(err) = features.validate_filter(filter, *column_type) {
+                    diagnostics.push(ConfigDiagnostic::new(
+                        format!("filter[{idx}][1]"),
+                        err.to_string(),
+                    ));
+                }
+            },
+        }
+    }
+
+    if let Some(aggregates) = &config.aggregates {
+        let mut names = aggregates.keys().collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            let path = format!("aggregates[{name:?}]");
+            let Some(column_type) = columns.get(name) else {
+                diagnostics.push(unknown(path, name));
+                continue;
+            };
+
+            let aggregate = match &aggregates[name] {
+                Aggregate::SingleAggregate(x) => x,
+                Aggregate::MultiAggregate(x, _) => x,
+            };
+
+            if let Some(supported) = features.aggregates.get(&(*column_type as u32))
+                && !supported.aggregates.iter().any(|x| &x.name == aggregate)
+            {
+                diagnostics.push(ConfigDiagnostic::new(
+                    path,
+                    format!(
+                        "Aggregate \"{aggregate}\" is not supported for {column_type} column \
+                         \"{name}\""
+                    ),
+                ));
+            }
+        }
+    }
+
+    if let Some(mode) = config.group_rollup_mode {
+        let supported = features.get_group_rollup_modes();
+        if !supported.is_empty() && !supported.contains(&mode) {
+            diagnostics.push(ConfigDiagnostic::new(
+                "group_rollup_mode",
+                format!("This server does not support group_rollup_mode \"{mode}\""),
+            ));
+        }
+    }
+
+    if let Some(mode) = config.split_rollup_mode
+        && !features.get_split_rollup_modes().contains(&mode)
+    {
+        diagnostics.push(ConfigDiagnostic::new(
+            "split_rollup_mode",
+            format!("This server does not support split_rollup_mode \"{mode}\""),
+        ));
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Filter, Scalar, Sort, SortDir, WindowSpec, Windows};
+    use crate::proto::GetFeaturesResp;
+    use crate::proto::get_features_resp::{AggregateArgs, AggregateOptions, ColumnTypeOptions};
+
+    fn features() -> Features {
+        GetFeaturesResp {
+            group_by: true,
+            split_by: true,
+            sort: true,
+            filter_ops: HashMap::from([(ColumnType::Float as u32, ColumnTypeOptions {
+                options: vec!["==".to_owned(), ">".to_owned()],
+            })]),
+            aggregates: HashMap::from([(ColumnType::Float as u32, AggregateOptions {
+                aggregates: vec![AggregateArgs {
+                    name: "sum".to_owned(),
+                    args: vec![],
+                }],
+            })]),
+            ..GetFeaturesResp::default()
+        }
+        .into()
+    }
+
+    fn schema() -> HashMap<String, ColumnType> {
+        HashMap::from([
+            ("Region".to_owned(), ColumnType::String),
+            ("Sales".to_owned(), ColumnType::Float),
+        ])
+    }
+
+    fn validate(config: &ViewConfigUpdate) -> Vec<String> {
+        let expressions = ExprValidationResult {
+            expression_schema: HashMap::default(),
+            errors: HashMap::default(),
+            expression_alias: HashMap::default(),
+        };
+
+        validate_view_config(config, &schema(), &expressions, &features())
+            .into_iter()
+            .map(|x| x.path)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config() {
+        let config = ViewConfigUpdate {
+            group_by: Some(vec!["Region".to_owned()]),
+            sort: Some(vec![Sort("Sales".to_owned(), SortDir::Desc)]),
+            filter: Some(vec![Filter::new("Sales", ">", Scalar::Float(1.0))]),
+            aggregates: Some(HashMap::from([("Sales".to_owned(), "sum".into())])),
+            ..ViewConfigUpdate::default()
+        };
+
+        assert!(validate(&config).is_empty());
+    }
+
+    #[test]
+    fn test_field_paths() {
+        let config = ViewConfigUpdate {
+            group_by: Some(vec!["Region".to_owned(), "Regoin".to_owned()]),
+            filter: Some(vec![Filter::new("Sales", "begins with", Scalar::Null)]),
+            aggregates: Some(HashMap::from([("Sales".to_owned(), "median".into())])),
+            split_rollup_mode: Some(crate::config::SplitRollupMode::Rollup),
+            ..ViewConfigUpdate::default()
+        };
+
+        assert_eq!(validate(&config), vec![
+            "group_by[1]",
+            "filter[0][1]",
+            "aggregates[\"Sales\"]",
+            "split_rollup_mode",
+        ]);
+    }
+
+    #[test]
+    fn test_undeclared_column_type_not_checked() {
+        // `features()` declares neither filter operators nor aggregates for
+        // `"string"` columns, so neither check can reject `Region`.
+        let config = ViewConfigUpdate {
+            filter: Some(vec![Filter::new("Region", "==", Scalar::String("A".to_owned()))]),
+            aggregates: Some(HashMap::from([("Region".to_owned(), "count".into())])),
+            ..ViewConfigUpdate::default()
+        };
+
+        assert!(validate(&config).is_empty());
+    }
+
+    #[test]
+    fn test_window_alias_collision() {
+        let spec = WindowSpec {
+            column: "Sales".to_owned(),
+            aggregate: "sum".to_owned(),
+            partition_by: vec!["Missing".to_owned()],
+            order_by: None,
+            frame: None,
+            offset: None,
+            alpha: None,
+        };
+
+        let config = ViewConfigUpdate {
+            windows: Some(Windows(HashMap::from([("Sales".to_owned(), spec)]))),
+            ..ViewConfigUpdate::default()
+        };
+
+        assert_eq!(validate(&config), vec![
+            "windows",
+            "windows[\"Sales\"]",
+            "windows[\"Sales\"].partition_by[0]",
+        ]);
+    }
+}
@@ -26,6 +26,12 @@ use crate::proto::scalar;
 pub enum Scalar {
     Float(f64),
     String(String),
+
+    /// The exact text of a `"decimal"` value, e.g. `"12.50"`. This serializes
+    /// as a JSON string, so a deserialized decimal is a [`Scalar::String`],
+    /// which the engine parses exactly when it is compared to a `"decimal"`
+    /// column.
+    Decimal(String),
     Bool(bool),
     // DateTime(i64),
     // Date(String),
@@ -40,11 +46,42 @@ impl From<&str> for Scalar {
     }
 }
 
+impl From<String> for Scalar {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<f64> for Scalar {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<i32> for Scalar {
+    fn from(value: i32) -> Self {
+        Self::Float(value as f64)
+    }
+}
+
+impl From<u32> for Scalar {
+    fn from(value: u32) -> Self {
+        Self::Float(value as f64)
+    }
+}
+
+impl From<bool> for Scalar {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
 impl Display for Scalar {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         match self {
             Self::Float(x) => write!(fmt, "{x}"),
             Self::String(x) => write!(fmt, "{x}"),
+            Self::Decimal(x) => write!(fmt, "{x}"),
             Self::Bool(x) => write!(fmt, "{x}"),
             Self::Null => write!(fmt, ""),
         }
@@ -58,6 +95,12 @@ pub enum FilterTerm {
     Scalar(#[serde(default)] Scalar),
 }
 
+impl From<Scalar> for FilterTerm {
+    fn from(value: Scalar) -> Self {
+        Self::Scalar(value)
+    }
+}
+
 impl<'a, T> From<T> for FilterTerm
 where
     T: AsRef<[&'a str]>,
@@ -97,16 +140,142 @@ impl FilterTerm {
     }
 }
 
+/// A filter operator, as advertised per [`proto::ColumnType`] by
+/// `Features::filter_ops`.
+///
+/// [`FilterOp::Other`] is an escape hatch for operators specific to a
+/// virtual server, which are passed through verbatim.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FilterOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    BeginsWith,
+    NotBeginsWith,
+    Contains,
+    NotContains,
+    EndsWith,
+    NotEndsWith,
+    Matches,
+    NotMatches,
+    In,
+    NotIn,
+    IsNull,
+    IsNotNull,
+    Other(String),
+}
+
+impl FilterOp {
+    /// The operator's name, as sent to the server.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Eq => "==",
+            Self::NotEq => "!=",
+            Self::Lt => "<",
+            Self::LtEq => "<=",
+            Self::Gt => ">",
+            Self::GtEq => ">=",
+            Self::BeginsWith => "begins with",
+            Self::NotBeginsWith => "not begins with",
+            Self::Contains => "contains",
+            Self::NotContains => "not contains",
+            Self::EndsWith => "ends with",
+            Self::NotEndsWith => "not ends with",
+            Self::Matches => "matches",
+            Self::NotMatches => "not matches",
+            Self::In => "in",
+            Self::NotIn => "not in",
+            Self::IsNull => "is null",
+            Self::IsNotNull => "is not null",
+            Self::Other(x) => x.as_str(),
+        }
+    }
+
+    /// Whether this operator takes no term, e.g. `is null`.
+    pub fn is_unary(&self) -> bool {
+        matches!(self, Self::IsNull | Self::IsNotNull)
+    }
+}
+
+impl Display for FilterOp {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(fmt, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for FilterOp {
+    fn from(value: &str) -> Self {
+        match value {
+            "==" => Self::Eq,
+            "!=" => Self::NotEq,
+            "<" => Self::Lt,
+            "<=" => Self::LtEq,
+            ">" => Self::Gt,
+            ">=" => Self::GtEq,
+            "begins with" => Self::BeginsWith,
+            "not begins with" => Self::NotBeginsWith,
+            "contains" => Self::Contains,
+            "not contains" => Self::NotContains,
+            "ends with" => Self::EndsWith,
+            "not ends with" => Self::NotEndsWith,
+            "matches" => Self::Matches,
+            "not matches" => Self::NotMatches,
+            "in" => Self::In,
+            "not in" => Self::NotIn,
+            "is null" => Self::IsNull,
+            "is not null" => Self::IsNotNull,
+            x => Self::Other(x.to_owned()),
+        }
+    }
+}
+
+impl From<&String> for FilterOp {
+    fn from(value: &String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<String> for FilterOp {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<&FilterOp> for FilterOp {
+    fn from(value: &FilterOp) -> Self {
+        value.clone()
+    }
+}
+
+impl std::str::FromStr for FilterOp {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
 #[derive(Clone, Deserialize, Debug, PartialEq, Serialize, TS)]
 #[serde()]
 pub struct Filter(String, String, #[serde(default)] FilterTerm);
 
 impl Filter {
-    pub fn new<T>(column: &str, op: &str, term: T) -> Self
+    /// Create a new [`Filter`] from a column name, operator and term, e.g.
+    /// `Filter::new("Sales", FilterOp::Gt, Scalar::Float(100.0))`. `op` may
+    /// also be a `&str`, which is parsed with [`FilterOp::from`].
+    pub fn new<T>(column: &str, op: impl Into<FilterOp>, term: T) -> Self
     where
         FilterTerm: From<T>,
     {
-        Filter(column.to_string(), op.to_string(), term.into())
+        Filter(column.to_string(), op.into().as_str().to_owned(), term.into())
+    }
+
+    /// This filter's operator as a [`FilterOp`].
+    pub fn filter_op(&self) -> FilterOp {
+        self.1.as_str().into()
     }
 
     pub fn column(&self) -> &str {
@@ -152,6 +321,9 @@ impl From<Scalar> for proto::Scalar {
             Scalar::String(x) => proto::Scalar {
                 scalar: Some(scalar::Scalar::String(x)),
             },
+            Scalar::Decimal(x) => proto::Scalar {
+                scalar: Some(scalar::Scalar::Decimal(x)),
+            },
             Scalar::Bool(x) => proto::Scalar {
                 scalar: Some(scalar::Scalar::Bool(x)),
             },
@@ -167,6 +339,7 @@ impl From<proto::Scalar> for Scalar {
         match value.scalar {
             Some(scalar::Scalar::Bool(x)) => Scalar::Bool(x),
             Some(scalar::Scalar::String(x)) => Scalar::String(x),
+            Some(scalar::Scalar::Decimal(x)) => Scalar::Decimal(x),
             Some(scalar::Scalar::Float(x)) => Scalar::Float(x),
             Some(scalar::Scalar::Null(_)) => Scalar::Null,
             None => Scalar::Null,
@@ -200,3 +373,35 @@ impl From<proto::view_config::Filter> for Filter {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_op_roundtrips_str() {
+        for op in [
+            FilterOp::Eq,
+            FilterOp::NotBeginsWith,
+            FilterOp::NotIn,
+            FilterOp::IsNotNull,
+        ] {
+            assert_eq!(FilterOp::from(op.as_str()), op);
+        }
+    }
+
+    #[test]
+    fn test_filter_op_other() {
+        let op = FilterOp::from("begin with");
+        assert_eq!(op, FilterOp::Other("begin with".to_owned()));
+        assert_eq!(op.as_str(), "begin with");
+    }
+
+    #[test]
+    fn test_filter_new_accepts_filter_op() {
+        let filter = Filter::new("x", FilterOp::GtEq, Scalar::Float(1.0));
+        assert_eq!(filter.op(), ">=");
+        assert_eq!(filter.filter_op(), FilterOp::GtEq);
+        assert_eq!(Filter::new("x", ">=", Scalar::Float(1.0)), filter);
+    }
+}
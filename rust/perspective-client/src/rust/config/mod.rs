@@ -20,7 +20,9 @@
 use crate::Table;
 
 mod aggregates;
+mod builder;
 mod column_type;
+mod diagnostics;
 pub mod expressions;
 mod filters;
 mod plugin;
@@ -29,6 +31,9 @@ mod view_config;
 pub mod windows;
 
 pub use aggregates::*;
+pub use builder::ViewConfigBuilder;
+pub use diagnostics::ConfigDiagnostic;
+pub(crate) use diagnostics::validate_view_config;
 pub use expressions::*;
 pub use filters::*;
 pub use plugin::*;
@@ -36,4 +41,4 @@ pub use sort::*;
 pub use view_config::*;
 pub use windows::*;
 
-pub use crate::proto::{ColumnType, SortOp};
+pub use crate::proto::{ColumnType, ColumnTypeParams, SortOp, TimeUnit};
@@ -99,6 +99,23 @@ pub struct WindowSpec {
     pub alpha: Option<f64>,
 }
 
+impl WindowSpec {
+    /// A [`WindowSpec`] applying `aggregate` to `column` over the whole
+    /// `Table` as one partition, in natural order and with the aggregate's
+    /// default frame.
+    pub fn new(column: impl Into<String>, aggregate: impl Into<WindowAggregate>) -> Self {
+        WindowSpec {
+            column: column.into(),
+            aggregate: aggregate.into(),
+            partition_by: vec![],
+            order_by: None,
+            frame: None,
+            offset: None,
+            alpha: None,
+        }
+    }
+}
+
 /// The serialized form of [`WindowSpec`].
 #[derive(Clone, Debug, Deserialize, Serialize, TS)]
 #[serde(deny_unknown_fields)]
@@ -34,11 +34,16 @@
     clippy::await_holding_refcell_ref
 )]
 
+mod batch;
 mod client;
+mod replay;
+mod rows;
 mod session;
+mod subscription;
 mod table;
 mod table_data;
 mod table_ref;
+mod transaction;
 mod view;
 pub mod virtual_server;
 
@@ -50,18 +55,24 @@ pub mod proto;
 
 pub mod utils;
 
+pub use crate::batch::{Batch, BatchItem, BatchResponse};
 pub use crate::client::{Client, ClientHandler, Features, ReconnectCallback, SystemInfo};
 use crate::proto::HostedTable;
-pub use crate::proto::JoinType;
+pub use crate::proto::{JoinType, OnBadRows, RowError};
+pub use crate::rows::PerspectiveRow;
+#[cfg(feature = "chrono")]
+pub use crate::rows::{EpochMillis, epoch_millis};
 pub use crate::session::{ProxySession, Session};
+pub use crate::subscription::{BufferPolicy, Subscription};
 pub use crate::table::{
-    DeleteOptions, ExprValidationResult, JoinOptions, Table, TableInitOptions, TableReadFormat,
-    UpdateOptions,
+    CsvOptions, DeleteOptions, ExprValidationResult, JoinOptions, Table, TableInitOptions,
+    TableReadFormat, UpdateOptions,
 };
 pub use crate::table_data::{TableData, UpdateData};
 pub use crate::table_ref::TableRef;
+pub use crate::transaction::TableTransaction;
 pub use crate::view::{
-    ColumnWindow, OnUpdateData, OnUpdateMode, OnUpdateOptions, View, ViewWindow,
+    ColumnWindow, OnUpdateData, OnUpdateDiff, OnUpdateMode, OnUpdateOptions, View, ViewWindow,
 };
 
 pub type ClientError = utils::ClientError;
@@ -0,0 +1,254 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Bookkeeping for [`crate::Client::set_resilient`], which replays a
+//! [`crate::Client`]'s server-side state after it reconnects.
+
+use std::collections::HashMap;
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::proto::request::ClientReq;
+use crate::proto::{
+    GetHostedTablesReq, RemoveHostedTablesUpdateReq, Request, TableRemoveDeleteReq, UnsubscribeReq,
+    ViewRemoveDeleteReq, ViewRemoveOnUpdateReq,
+};
+
+/// The tables, views and subscriptions a [`crate::Client`] has created,
+/// which must be recreated on a new server session.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SessionState {
+    /// Names of the tables this client has opened.
+    pub tables: IndexSet<String>,
+
+    /// The requests which created, then modified, each table this client
+    /// created, by table name, in the order they were sent.
+    pub created: IndexMap<String, Vec<Request>>,
+
+    /// The `TableMakeViewReq` which created each view, by view name.
+    pub views: IndexMap<String, Request>,
+
+    /// The request which created each subscription, by the subscription id
+    /// returned to the caller. After a replay, the request's `msg_id` is
+    /// the id the new session knows it by.
+    pub subscriptions: IndexMap<u32, Request>,
+
+    /// Subscription ids returned to the caller, mapped to the `msg_id` of
+    /// the subscription's replayed request.
+    ids: HashMap<u32, u32>,
+}
+
+impl SessionState {
+    /// The id the current session knows subscription `id` by.
+    pub fn current_id(&self, id: u32) -> u32 {
+        self.ids.get(&id).copied().unwrap_or(id)
+    }
+
+    /// Record that subscription `id` was replayed as `msg`.
+    pub fn remap(&mut self, id: u32, msg: Request) {
+        self.ids.insert(id, msg.msg_id);
+        self.subscriptions.insert(id, msg);
+    }
+
+    /// Record that `msg` created table `name`, so it can be recreated if
+    /// the new session does not host it.
+    pub fn track_created(&mut self, name: &str, msg: &Request) {
+        self.created.insert(name.to_owned(), vec![msg.clone()]);
+    }
+
+    /// Forget subscription `id`, e.g. because its callback is gone.
+    pub fn untrack_subscription(&mut self, id: u32) {
+        self.ids.remove(&id);
+        self.subscriptions.shift_remove(&id);
+    }
+
+    /// Record a request on its way to the server, returning it with any
+    /// subscription id it refers to replaced by the current session's.
+    pub fn track(&mut self, msg: &Request) -> Option<Request> {
+        match &msg.client_req {
+            Some(
+                ClientReq::ViewOnUpdateReq(_)
+                | ClientReq::ViewOnDeleteReq(_)
+                | ClientReq::TableOnDeleteReq(_)
+                | ClientReq::GetHostedTablesReq(GetHostedTablesReq { subscribe: true }),
+            ) => {
+                self.subscriptions.insert(msg.msg_id, msg.clone());
+                None
+            },
+            Some(ClientReq::ViewDeleteReq(_)) => {
+                self.views.shift_remove(&msg.entity_id);
+                self.untrack_entity(&msg.entity_id);
+                None
+            },
+            Some(ClientReq::TableDeleteReq(_)) => {
+                self.tables.shift_remove(&msg.entity_id);
+                self.created.shift_remove(&msg.entity_id);
+                self.untrack_entity(&msg.entity_id);
+                None
+            },
+            Some(
+                ClientReq::TableUpdateReq(_)
+                | ClientReq::TableRemoveReq(_)
+                | ClientReq::TableReplaceReq(_)
+                | ClientReq::TableRemoveWhereReq(_)
+                | ClientReq::TableAddColumnsReq(_)
+                | ClientReq::TableDropColumnsReq(_)
+                | ClientReq::TableCastColumnReq(_)
+                | ClientReq::TableTransactionReq(_),
+            ) => {
+                if let Some(requests) = self.created.get_mut(&msg.entity_id) {
+                    requests.push(msg.clone());
+                }
+
+                None
+            },
+            Some(
+                ClientReq::ViewRemoveOnUpdateReq(ViewRemoveOnUpdateReq { id })
+                | ClientReq::ViewRemoveDeleteReq(ViewRemoveDeleteReq { id })
+                | ClientReq::TableRemoveDeleteReq(TableRemoveDeleteReq { id })
+                | ClientReq::RemoveHostedTablesUpdateReq(RemoveHostedTablesUpdateReq { id })
+                | ClientReq::UnsubscribeReq(UnsubscribeReq { id }),
+            ) => {
+                let current = self.current_id(*id);
+                self.untrack_subscription(*id);
+                (current != *id).then(|| {
+                    let mut msg = msg.clone();
+                    if let Some(
+                        ClientReq::ViewRemoveOnUpdateReq(ViewRemoveOnUpdateReq { id })
+                        | ClientReq::ViewRemoveDeleteReq(ViewRemoveDeleteReq { id })
+                        | ClientReq::TableRemoveDeleteReq(TableRemoveDeleteReq { id })
+                        | ClientReq::RemoveHostedTablesUpdateReq(RemoveHostedTablesUpdateReq {
+                            id,
+                        })
+                        | ClientReq::UnsubscribeReq(UnsubscribeReq { id }),
+                    ) = &mut msg.client_req
+                    {
+                        *id = current;
+                    }
+
+                    msg
+                })
+            },
+            _ => None,
+        }
+    }
+
+    /// Forget view `view_id`, deleted by the server rather than the client.
+    pub fn untrack_view(&mut self, view_id: &str) {
+        self.views.shift_remove(view_id);
+        self.untrack_entity(view_id);
+    }
+
+    fn untrack_entity(&mut self, entity_id: &str) {
+        let ids = self
+            .subscriptions
+            .iter()
+            .filter(|(_, msg)| msg.entity_id == entity_id)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        for id in ids {
+            self.untrack_subscription(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::{
+        MakeTableReq, TableDeleteReq, TableUpdateReq, ViewDeleteReq, ViewOnUpdateReq,
+    };
+
+    fn request(msg_id: u32, entity_id: &str, req: ClientReq) -> Request {
+        Request {
+            msg_id,
+            entity_id: entity_id.to_owned(),
+            client_req: Some(req),
+        }
+    }
+
+    #[test]
+    fn test_remaps_unsubscribe_after_replay() {
+        let mut state = SessionState::default();
+        let on_update = request(1, "view", ClientReq::ViewOnUpdateReq(ViewOnUpdateReq {
+            mode: None,
+        }));
+
+        state.track(&on_update);
+        state.remap(1, Request {
+            msg_id: 7,
+            ..on_update
+        });
+
+        let remove = request(
+            8,
+            "view",
+            ClientReq::ViewRemoveOnUpdateReq(ViewRemoveOnUpdateReq { id: 1 }),
+        );
+
+        let remapped = state.track(&remove).unwrap();
+        assert_eq!(
+            remapped.client_req,
+            Some(ClientReq::ViewRemoveOnUpdateReq(ViewRemoveOnUpdateReq {
+                id: 7
+            }))
+        );
+
+        assert!(state.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn test_view_delete_untracks_subscriptions() {
+        let mut state = SessionState::default();
+        state.track(&request(
+            1,
+            "view",
+            ClientReq::ViewOnUpdateReq(ViewOnUpdateReq { mode: None }),
+        ));
+
+        state.track(&request(2, "view", ClientReq::ViewDeleteReq(ViewDeleteReq {})));
+        assert!(state.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn test_tracks_updates_to_created_tables() {
+        let mut state = SessionState::default();
+        let create = request(
+            1,
+            "created",
+            ClientReq::MakeTableReq(MakeTableReq::default()),
+        );
+
+        let update = |msg_id, entity_id| {
+            request(
+                msg_id,
+                entity_id,
+                ClientReq::TableUpdateReq(TableUpdateReq::default()),
+            )
+        };
+
+        state.track_created("created", &create);
+        state.track(&update(2, "created"));
+        state.track(&update(3, "opened"));
+        assert_eq!(state.created["created"], vec![create, update(2, "created")]);
+        assert!(!state.created.contains_key("opened"));
+
+        state.track(&request(
+            4,
+            "created",
+            ClientReq::TableDeleteReq(TableDeleteReq::default()),
+        ));
+
+        assert!(state.created.is_empty());
+    }
+}
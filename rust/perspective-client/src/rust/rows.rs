@@ -0,0 +1,226 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Typed row ingestion and export via `serde`, see [`PerspectiveRow`].
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "derive")]
+pub use perspective_derive::PerspectiveRow;
+
+use crate::proto::ColumnType;
+use crate::table::TableInitOptions;
+use crate::table_data::TableData;
+#[cfg(doc)]
+use crate::{Client, Table, View};
+
+/// A Rust struct which maps to a row of a [`Table`], serialized to and from
+/// JSON via `serde`.
+///
+/// With the `derive` feature this trait can be derived with
+/// `#[derive(PerspectiveRow)]`, which generates [`PerspectiveRow::schema`]
+/// from the struct's field types and [`PerspectiveRow::index`] from the field
+/// marked `#[perspective(index)]`.
+///
+/// `chrono` fields should be annotated with
+/// `#[serde(with = "perspective_client::epoch_millis")]` (with the `chrono`
+/// feature), as Perspective serializes these types as epoch milliseconds.
+///
+/// # Examples
+///
+/// ```ignore
+/// # use perspective_client::*;
+/// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+/// #[derive(serde::Serialize, serde::Deserialize, PerspectiveRow)]
+/// struct Trade {
+///     #[perspective(index)]
+///     id: i32,
+///     symbol: String,
+///     price: Option<f64>,
+/// }
+///
+/// let table = client
+///     .table(Trade::table_data(), Trade::table_init_options())
+///     .await?;
+///
+/// table.update_rows(&[Trade { id: 1, symbol: "AAPL".into(), price: None }]).await?;
+/// let view = table.view(None).await?;
+/// let trades: Vec<Trade> = view.to_rows(ViewWindow::default()).await?;
+/// # Ok(()) }
+/// ```
+pub trait PerspectiveRow: Serialize + DeserializeOwned {
+    /// The column names and [`ColumnType`]s of this row type, in field order.
+    fn schema() -> Vec<(String, ColumnType)>;
+
+    /// The name of the column to use as the [`TableInitOptions::index`], if
+    /// any.
+    fn index() -> Option<&'static str> {
+        None
+    }
+
+    /// A [`TableData::Schema`] for an empty [`Table`] of this row type, for
+    /// use with [`Client::table`].
+    fn table_data() -> TableData {
+        TableData::Schema(Self::schema())
+    }
+
+    /// [`TableInitOptions`] with `index` set from [`PerspectiveRow::index`].
+    fn table_init_options() -> TableInitOptions {
+        TableInitOptions {
+            index: Self::index().map(|x| x.to_owned()),
+            ..TableInitOptions::default()
+        }
+    }
+}
+
+/// The `chrono` types which [`epoch_millis`] can (de)serialize, including
+/// their [`Option`]s.
+#[cfg(feature = "chrono")]
+pub trait EpochMillis: Sized {
+    fn to_epoch_millis(&self) -> Option<i64>;
+    fn from_epoch_millis(millis: Option<i64>) -> Option<Self>;
+}
+
+#[cfg(feature = "chrono")]
+impl EpochMillis for chrono::NaiveDate {
+    fn to_epoch_millis(&self) -> Option<i64> {
+        Some(self.and_time(chrono::NaiveTime::MIN).and_utc().timestamp_millis())
+    }
+
+    fn from_epoch_millis(millis: Option<i64>) -> Option<Self> {
+        chrono::DateTime::from_timestamp_millis(millis?).map(|x| x.date_naive())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl EpochMillis for chrono::NaiveDateTime {
+    fn to_epoch_millis(&self) -> Option<i64> {
+        Some(self.and_utc().timestamp_millis())
+    }
+
+    fn from_epoch_millis(millis: Option<i64>) -> Option<Self> {
+        chrono::DateTime::from_timestamp_millis(millis?).map(|x| x.naive_utc())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl EpochMillis for chrono::DateTime<chrono::Utc> {
+    fn to_epoch_millis(&self) -> Option<i64> {
+        Some(self.timestamp_millis())
+    }
+
+    fn from_epoch_millis(millis: Option<i64>) -> Option<Self> {
+        chrono::DateTime::from_timestamp_millis(millis?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<T: EpochMillis> EpochMillis for Option<T> {
+    fn to_epoch_millis(&self) -> Option<i64> {
+        self.as_ref().and_then(|x| x.to_epoch_millis())
+    }
+
+    fn from_epoch_millis(millis: Option<i64>) -> Option<Self> {
+        Some(T::from_epoch_millis(millis))
+    }
+}
+
+/// `serde` `with` module for the `chrono::NaiveDate`, `NaiveDateTime` and
+/// `DateTime<Utc>` fields (and their [`Option`]s) of a [`PerspectiveRow`],
+/// which (de)serializes them as epoch milliseconds.
+///
+/// ```ignore
+/// #[derive(serde::Serialize, serde::Deserialize, PerspectiveRow)]
+/// struct Event {
+///     #[serde(with = "perspective_client::epoch_millis")]
+///     timestamp: chrono::DateTime<chrono::Utc>,
+/// }
+/// ```
+#[cfg(feature = "chrono")]
+pub mod epoch_millis {
+    use serde::Serialize;
+
+    use super::EpochMillis;
+
+    pub fn serialize<T: EpochMillis, S: serde::Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.to_epoch_millis().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: EpochMillis, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let millis = <Option<f64> as serde::Deserialize>::deserialize(deserializer)?;
+        T::from_epoch_millis(millis.map(|x| x as i64))
+            .ok_or_else(|| serde::de::Error::custom("invalid or missing epoch milliseconds"))
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, PerspectiveRow)]
+    #[perspective(crate = "crate")]
+    struct Trade {
+        #[perspective(index)]
+        id: i32,
+        symbol: String,
+        #[serde(rename = "Price")]
+        price: Option<f64>,
+        #[perspective(type = "float")]
+        volume: u8,
+        #[serde(skip)]
+        _internal: bool,
+    }
+
+    #[derive(Serialize, Deserialize, PerspectiveRow)]
+    #[perspective(crate = "crate")]
+    #[serde(bound(serialize = "", deserialize = ""))]
+    struct Quote {
+        #[serde(rename(serialize = "Bid", deserialize = "Bid"))]
+        bid: f64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ask: Option<f64>,
+    }
+
+    #[test]
+    fn test_derived_schema() {
+        assert_eq!(Trade::schema(), vec![
+            ("id".to_owned(), ColumnType::Integer),
+            ("symbol".to_owned(), ColumnType::String),
+            ("Price".to_owned(), ColumnType::Float),
+            ("volume".to_owned(), ColumnType::Float),
+        ]);
+    }
+
+    #[test]
+    fn test_derived_schema_nested_serde_attrs() {
+        assert_eq!(Quote::schema(), vec![
+            ("Bid".to_owned(), ColumnType::Float),
+            ("ask".to_owned(), ColumnType::Float),
+        ]);
+
+        assert_eq!(Quote::index(), None);
+    }
+
+    #[test]
+    fn test_derived_index() {
+        assert_eq!(Trade::index(), Some("id"));
+        assert_eq!(Trade::table_init_options().index.as_deref(), Some("id"));
+    }
+}
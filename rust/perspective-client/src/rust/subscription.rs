@@ -0,0 +1,287 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! [`Subscription`], a [`Stream`] alternative to the callback-and-id pairs
+//! like [`crate::View::on_update`] and [`crate::View::remove_update`].
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use futures::Stream;
+
+use crate::client::Client;
+use crate::proto::Request;
+use crate::utils::{ClientError, ClientResult};
+
+/// How a [`Subscription`] buffers events which arrive faster than they are
+/// polled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BufferPolicy {
+    /// Keep only the most recent unread event, discarding older ones. Suits
+    /// consumers which re-query the [`crate::View`] on update and don't
+    /// read the event's `delta`.
+    Latest,
+
+    /// Queue up to this many unread events, discarding the oldest when full.
+    Bounded(usize),
+
+    /// Queue up to this many unread events. One more ends the stream with a
+    /// [`ClientError::SubscriptionOverflow`].
+    Error(usize),
+}
+
+struct Buffer<T> {
+    queue: VecDeque<ClientResult<T>>,
+    policy: BufferPolicy,
+    waker: Option<Waker>,
+
+    /// No more events will be queued, so the stream ends once `queue` drains.
+    ended: bool,
+
+    /// The server-side subscription is gone, so dropping the [`Subscription`]
+    /// need not remove it.
+    unsubscribed: bool,
+
+    /// The [`Subscription`] was dropped, so events are discarded.
+    dropped: bool,
+}
+
+fn lock<T>(buffer: &Mutex<Buffer<T>>) -> MutexGuard<'_, Buffer<T>> {
+    buffer.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl<T> Buffer<T> {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// The sending half of a [`Subscription`], owned by the callback registered
+/// with the [`Client`]. Dropping it (e.g. when the callback is removed, or
+/// after a once-callback fires) ends the stream.
+pub(crate) struct SubscriptionSender<T>(Arc<Mutex<Buffer<T>>>);
+
+impl<T> SubscriptionSender<T> {
+    /// Buffer `event` according to the [`BufferPolicy`].
+    pub(crate) fn send(&self, event: T) {
+        let mut buffer = lock(&self.0);
+        if buffer.ended || buffer.dropped {
+            return;
+        }
+
+        match buffer.policy {
+            BufferPolicy::Latest => buffer.queue.clear(),
+            BufferPolicy::Bounded(size) if buffer.queue.len() >= size.max(1) => {
+                buffer.queue.pop_front();
+            },
+            BufferPolicy::Error(size) if buffer.queue.len() >= size.max(1) => {
+                buffer.queue.push_back(Err(ClientError::SubscriptionOverflow(size)));
+                buffer.ended = true;
+                buffer.wake();
+                return;
+            },
+            _ => {},
+        }
+
+        buffer.queue.push_back(Ok(event));
+        buffer.wake();
+    }
+
+    /// End the stream with `error` after any buffered events.
+    pub(crate) fn fail(&self, error: ClientError) {
+        let mut buffer = lock(&self.0);
+        if !buffer.ended {
+            buffer.queue.push_back(Err(error));
+            buffer.ended = true;
+            buffer.wake();
+        }
+    }
+}
+
+impl<T> Drop for SubscriptionSender<T> {
+    fn drop(&mut self) {
+        let mut buffer = lock(&self.0);
+        buffer.ended = true;
+        buffer.unsubscribed = true;
+        buffer.wake();
+    }
+}
+
+/// A [`Stream`] of events pushed by the server, returned by methods like
+/// [`crate::View::updates`].
+///
+/// Dropping a [`Subscription`] unsubscribes it. As this can't `.await` the
+/// server's response, the unsubscribe request is sent before the [`Client`]'s
+/// next request; events which arrive in the meantime are discarded.
+pub struct Subscription<T> {
+    buffer: Arc<Mutex<Buffer<T>>>,
+    client: Client,
+    id: u32,
+    unsubscribe: Request,
+}
+
+impl<T> Subscription<T> {
+    /// Create a [`Subscription`] and its [`SubscriptionSender`], for the
+    /// request with `msg_id` `id`. `unsubscribe` is the request which
+    /// removes it on drop.
+    pub(crate) fn new(
+        client: &Client,
+        id: u32,
+        unsubscribe: Request,
+        policy: BufferPolicy,
+    ) -> (Self, SubscriptionSender<T>) {
+        let buffer = Arc::new(Mutex::new(Buffer {
+            queue: VecDeque::new(),
+            policy,
+            waker: None,
+            ended: false,
+            unsubscribed: false,
+            dropped: false,
+        }));
+
+        let sender = SubscriptionSender(buffer.clone());
+        let subscription = Subscription {
+            buffer,
+            client: client.clone(),
+            id,
+            unsubscribe,
+        };
+
+        (subscription, sender)
+    }
+
+    /// The subscription id, as would be returned by the equivalent callback
+    /// method (e.g. [`crate::View::on_update`]).
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl<T> Stream for Subscription<T> {
+    type Item = ClientResult<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut buffer = lock(&self.buffer);
+        if let Some(event) = buffer.queue.pop_front() {
+            Poll::Ready(Some(event))
+        } else if buffer.ended {
+            Poll::Ready(None)
+        } else {
+            buffer.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        let mut buffer = lock(&self.buffer);
+        buffer.dropped = true;
+        buffer.queue.clear();
+        let unsubscribed = buffer.unsubscribed;
+        drop(buffer);
+        if !unsubscribed {
+            self.client
+                .defer_unsubscribe(self.id, self.unsubscribe.clone());
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Subscription<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscription").field("id", &self.id).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::{FutureExt, StreamExt};
+
+    use super::*;
+
+    fn subscription(policy: BufferPolicy) -> (Subscription<u32>, SubscriptionSender<u32>) {
+        let client = Client::new_with_callback(None, |_| async { Ok(()) }).unwrap();
+        Subscription::new(&client, 1, Request::default(), policy)
+    }
+
+    #[test]
+    fn test_latest_coalesces() {
+        let (mut sub, sender) = subscription(BufferPolicy::Latest);
+        sender.send(1);
+        sender.send(2);
+        drop(sender);
+        assert_eq!(block_on(sub.next()).unwrap().unwrap(), 2);
+        assert!(block_on(sub.next()).is_none());
+    }
+
+    #[test]
+    fn test_bounded_discards_oldest() {
+        let (sub, sender) = subscription(BufferPolicy::Bounded(2));
+        for x in 0..4 {
+            sender.send(x);
+        }
+
+        drop(sender);
+        let events = block_on(sub.map(Result::unwrap).collect::<Vec<_>>());
+        assert_eq!(events, [2, 3]);
+    }
+
+    #[test]
+    fn test_error_ends_stream_on_overflow() {
+        let (sub, sender) = subscription(BufferPolicy::Error(2));
+        for x in 0..4 {
+            sender.send(x);
+        }
+
+        let events = block_on(sub.collect::<Vec<_>>());
+        assert_eq!(events.len(), 3);
+        assert!(matches!(
+            events[2],
+            Err(ClientError::SubscriptionOverflow(2))
+        ));
+    }
+
+    #[test]
+    fn test_drop_unsubscribes_before_next_request() {
+        let (sent, received) = std::sync::mpsc::channel();
+        let client = Client::new_with_callback(None, move |msg| {
+            let sent = sent.clone();
+            async move {
+                sent.send(msg)?;
+                Ok(())
+            }
+        })
+        .unwrap();
+
+        let unsubscribe = Request {
+            msg_id: 7,
+            ..Request::default()
+        };
+
+        let (sub, _sender) =
+            Subscription::<u32>::new(&client, 1, unsubscribe, BufferPolicy::Latest);
+
+        drop(sub);
+        assert!(received.try_recv().is_err());
+
+        // The next request sends the removal first, then waits for its own
+        // response, which never comes.
+        assert!(client.get_hosted_table_names().now_or_never().is_none());
+        let msg = received.try_recv().unwrap();
+        assert_eq!(<Request as prost::Message>::decode(&*msg).unwrap().msg_id, 7);
+    }
+}
@@ -12,19 +12,26 @@
 
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 use crate::assert_table_api;
 use crate::client::{Client, Features};
-use crate::config::{Expressions, ViewConfigUpdate};
+use crate::config::{
+    ConfigDiagnostic, Expressions, Filter, FilterReducer, ViewConfigUpdate, validate_view_config,
+};
 use crate::proto::make_table_req::MakeTableOptions;
 use crate::proto::make_table_req::make_table_options::MakeTableType;
 use crate::proto::request::ClientReq;
 use crate::proto::response::ClientResp;
 use crate::proto::*;
+use crate::rows::PerspectiveRow;
+use crate::subscription::{BufferPolicy, Subscription};
 use crate::table_data::UpdateData;
+use crate::transaction::TableTransaction;
 use crate::utils::*;
 use crate::view::View;
 
@@ -32,8 +39,8 @@ pub type Schema = HashMap<String, ColumnType>;
 
 /// The format to interpret data preovided to [`Client::table`].
 ///
-/// When serialized, these values are `"csv"`, `"json"`, `"columns"`, `"arrow"`
-/// and `"ndjson"`.
+/// When serialized, these values are `"csv"`, `"json"`, `"columns"`, `"arrow"`,
+/// `"ndjson"` and `"parquet"`.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, TS)]
 pub enum TableReadFormat {
     #[serde(rename = "csv")]
@@ -50,6 +57,9 @@ pub enum TableReadFormat {
 
     #[serde(rename = "ndjson")]
     Ndjson,
+
+    #[serde(rename = "parquet")]
+    Parquet,
 }
 
 impl TableReadFormat {
@@ -60,6 +70,7 @@ impl TableReadFormat {
             Some("columns") => Some(TableReadFormat::ColumnsString),
             Some("arrow") => Some(TableReadFormat::Arrow),
             Some("ndjson") => Some(TableReadFormat::Ndjson),
+            Some("parquet") => Some(TableReadFormat::Parquet),
             None => None,
             Some(x) => return Err(format!("Unknown format \"{x}\"")),
         })
@@ -104,10 +115,134 @@ pub struct TableInitOptions {
     /// default) and `cartesian` expand a row into one row per list element,
     /// and are incompatible with `index`, as the rows of an expansion
     /// repeat their index. `stringify` encodes each list as a JSON array in
-    /// a single string column instead.
+    /// a single string column instead, and `preserve` keeps lists of scalars
+    /// as a [`crate::config::ColumnType::List`] column.
     #[serde(default)]
     #[ts(optional)]
     pub list_flatten: Option<crate::proto::ListFlatten>,
+
+    /// How Arrow `Struct` columns and JSON `Object` values are ingested.
+    /// Nested fields are expanded into one column per leaf, named by joining
+    /// the path with `separator` (default `"."`, e.g. `parent.child`).
+    /// Objects nested deeper than `max_depth` are kept as JSON text in a
+    /// single string column instead. The same mapping is reused by
+    /// [`Table::update`].
+    #[serde(default)]
+    #[ts(optional)]
+    pub struct_flatten: Option<crate::proto::StructFlatten>,
+
+    /// Record the Arrow type of each column of an Arrow (or Parquet) input,
+    /// and cast [`crate::View::to_arrow`] output back to it, e.g. so an `Int8`
+    /// or `LargeUtf8` column is not exported as `Int32` or a `Utf8`
+    /// dictionary. Columns whose aggregate does not pick one of its input's
+    /// values, such as `mean` or `count`, keep their Perspective type. A
+    /// `Decimal128` column which fits [`ColumnTypeParams::MAX_DECIMAL_PRECISION`]
+    /// digits is loaded as an exact `"decimal"` column, rather than `"float"`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub preserve_arrow_types: Option<bool>,
+
+    /// How a [`TableReadFormat::Csv`] input is parsed. These options are
+    /// also used by later CSV calls to [`Table::update`], unless it is
+    /// given its own.
+    #[serde(default)]
+    #[ts(optional)]
+    pub csv: Option<CsvOptions>,
+
+    /// Pin the type of the named columns instead of inferring it, while every
+    /// other column is still inferred from the data. Values which cannot be
+    /// coerced to their hinted type are handled by `on_bad_rows`, and
+    /// reported by [`Table::row_errors`]. Only applies to the data
+    /// [`Client::table`] is created with.
+    #[serde(default)]
+    #[ts(optional)]
+    pub schema_hints: Option<HashMap<String, ColumnType>>,
+
+    /// What to do with a row holding a value which can't be coerced to the
+    /// type of its column: load the value as `null`, skip the row, or fail
+    /// with [`crate::ClientError::IngestionError`]. This applies to
+    /// `schema_hints` (and [`CsvOptions::column_types`]), and to every
+    /// column of later calls to [`Table::update`], unless they are given
+    /// their own. When unset, hinted values are loaded as `null` and update
+    /// data is not coerced.
+    #[serde(default)]
+    #[ts(optional)]
+    pub on_bad_rows: Option<crate::proto::OnBadRows>,
+}
+
+/// How CSV input to [`Client::table`] and [`Table::update`] is parsed.
+/// Unset fields keep the defaults: a header row names the columns, and every
+/// type is inferred.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, TS)]
+pub struct CsvOptions {
+    /// The field delimiter, e.g. `'\t'` for TSV or `'|'`. Defaults to `','`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub delimiter: Option<char>,
+
+    /// The quote character. Defaults to `'"'`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub quote: Option<char>,
+
+    /// The escape character for quotes and delimiters. By default, quotes
+    /// can only be escaped by doubling them.
+    #[serde(default)]
+    #[ts(optional)]
+    pub escape: Option<char>,
+
+    /// The column names of a CSV without a header row, whose first row is
+    /// then read as data.
+    #[serde(default)]
+    #[ts(optional)]
+    pub column_names: Option<Vec<String>>,
+
+    /// Values read as null in a column of any type, e.g. `["NA", "-"]`,
+    /// replacing the default set (which includes `""`, `"NA"` and
+    /// `"null"`).
+    #[serde(default)]
+    #[ts(optional)]
+    pub null_values: Option<Vec<String>>,
+
+    /// The type of a column, instead of the inferred one. Only applies to
+    /// [`Client::table`], as an update is always read as the [`Table`]'s
+    /// schema. [`ColumnType::Decimal`] and [`ColumnType::List`] are not
+    /// supported.
+    #[serde(default)]
+    #[ts(optional)]
+    pub column_types: Option<HashMap<String, ColumnType>>,
+
+    /// A `strptime` format for `date` columns, e.g. `"%d/%m/%Y"`, tried
+    /// before the built-in formats.
+    #[serde(default)]
+    #[ts(optional)]
+    pub date_format: Option<String>,
+
+    /// A `strptime` format for `datetime` columns, e.g.
+    /// `"%d/%m/%Y %H:%M"`, tried before the built-in formats.
+    #[serde(default)]
+    #[ts(optional)]
+    pub datetime_format: Option<String>,
+}
+
+impl From<CsvOptions> for crate::proto::CsvOptions {
+    fn from(value: CsvOptions) -> Self {
+        crate::proto::CsvOptions {
+            delimiter: value.delimiter.map(String::from),
+            quote: value.quote.map(String::from),
+            escape: value.escape.map(String::from),
+            column_names: value.column_names.unwrap_or_default(),
+            null_values: value.null_values.unwrap_or_default(),
+            column_types: value
+                .column_types
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, ty)| (name, ty as i32))
+                .collect(),
+            date_format: value.date_format,
+            datetime_format: value.datetime_format,
+        }
+    }
 }
 
 impl TableInitOptions {
@@ -122,9 +257,24 @@ impl TryFrom<TableOptions> for MakeTableOptions {
     fn try_from(value: TableOptions) -> Result<Self, Self::Error> {
         let page_to_disk = value.page_to_disk;
         let list_flatten = value.list_flatten.map(|x| x as i32);
+        let struct_flatten = value.struct_flatten.clone();
+        let preserve_arrow_types = value.preserve_arrow_types;
+        let csv = value.csv.clone().map(Into::into);
+        let schema_hints = value
+            .schema_hints
+            .iter()
+            .flatten()
+            .map(|(name, dtype)| (name.clone(), *dtype as i32))
+            .collect();
+        let on_bad_rows = value.on_bad_rows.map(|x| x as i32);
         Ok(MakeTableOptions {
             page_to_disk,
             list_flatten,
+            struct_flatten,
+            preserve_arrow_types,
+            csv,
+            schema_hints,
+            on_bad_rows,
             make_table_type: match value {
                 TableOptions {
                     index: Some(_),
@@ -149,6 +299,11 @@ pub(crate) struct TableOptions {
     pub limit: Option<u32>,
     pub page_to_disk: Option<bool>,
     pub list_flatten: Option<crate::proto::ListFlatten>,
+    pub struct_flatten: Option<crate::proto::StructFlatten>,
+    pub preserve_arrow_types: Option<bool>,
+    pub csv: Option<CsvOptions>,
+    pub schema_hints: Option<HashMap<String, ColumnType>>,
+    pub on_bad_rows: Option<crate::proto::OnBadRows>,
 }
 
 impl From<TableInitOptions> for TableOptions {
@@ -158,6 +313,11 @@ impl From<TableInitOptions> for TableOptions {
             limit: value.limit,
             page_to_disk: value.page_to_disk,
             list_flatten: value.list_flatten,
+            struct_flatten: value.struct_flatten,
+            preserve_arrow_types: value.preserve_arrow_types,
+            csv: value.csv,
+            schema_hints: value.schema_hints,
+            on_bad_rows: value.on_bad_rows,
         }
     }
 }
@@ -189,6 +349,18 @@ pub struct DeleteOptions {
 pub struct UpdateOptions {
     pub port_id: Option<u32>,
     pub format: Option<TableReadFormat>,
+
+    /// How a [`TableReadFormat::Csv`] update is parsed, instead of the
+    /// [`TableInitOptions::csv`] this [`Table`] was created with.
+    #[serde(default)]
+    #[ts(optional)]
+    pub csv: Option<CsvOptions>,
+
+    /// Overrides the [`TableInitOptions::on_bad_rows`] this [`Table`] was
+    /// created with, for this update.
+    #[serde(default)]
+    #[ts(optional)]
+    pub on_bad_rows: Option<crate::proto::OnBadRows>,
 }
 
 /// Result of a call to [`Table::validate_expressions`], containing a schema
@@ -218,6 +390,7 @@ pub struct Table {
     name: String,
     client: Client,
     options: TableOptions,
+    row_errors: Arc<Vec<RowError>>,
 
     /// If this table is constructed from a View, the view's on_update callback
     /// is wired into this table. So, we store the token to clean it up properly
@@ -227,6 +400,14 @@ pub struct Table {
 
 assert_table_api!(Table);
 
+#[cfg(feature = "arrow")]
+fn offset_row_errors(row_errors: Vec<RowError>, offset: usize) -> impl Iterator<Item = RowError> {
+    row_errors.into_iter().map(move |error| RowError {
+        row: error.row + offset as u64,
+        ..error
+    })
+}
+
 impl PartialEq for Table {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name && self.client == other.client
@@ -235,15 +416,24 @@ impl PartialEq for Table {
 
 impl Table {
     pub(crate) fn new(name: String, client: Client, options: TableOptions) -> Self {
+        client.track_table(&name);
         Table {
             name,
             client,
             options,
+            row_errors: Arc::default(),
             view_update_token: None,
         }
     }
 
-    fn client_message(&self, req: ClientReq) -> Request {
+    pub(crate) fn with_row_errors(self, row_errors: Vec<RowError>) -> Self {
+        Table {
+            row_errors: Arc::new(row_errors),
+            ..self
+        }
+    }
+
+    pub(crate) fn client_message(&self, req: ClientReq) -> Request {
         Request {
             msg_id: self.client.gen_id(),
             entity_id: self.name.clone(),
@@ -256,6 +446,27 @@ impl Table {
         self.client.clone()
     }
 
+    /// Create a handle to this [`Table`] whose requests fail with
+    /// [`crate::ClientError::Timeout`] after `timeout`, overriding the
+    /// default set by [`Client::set_timeout`]. [`View`]s created from the
+    /// returned handle inherit `timeout`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use perspective_client::Table;
+    /// # async fn run(table: Table) -> Result<(), Box<dyn std::error::Error>> {
+    /// let size = table.with_timeout(Duration::from_secs(5)).size().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Table {
+        Table {
+            client: self.client.with_timeout(timeout),
+            ..self.clone()
+        }
+    }
+
     /// Get a metadata dictionary of the `perspective_server::Server`'s
     /// features, which is (currently) implementation specific, but there is
     /// only one implementation.
@@ -284,6 +495,16 @@ impl Table {
         self.options.index.as_ref().map(|index| index.to_owned())
     }
 
+    /// Returns the values which could not be coerced to the type of their
+    /// column by the [`Client::table`] call which created this table, and
+    /// were loaded as `null` or skipped per its
+    /// [`TableInitOptions::on_bad_rows`] policy. Rows are counted from the
+    /// start of the data. [`Table::update_with_errors`] returns the errors of
+    /// an update instead.
+    pub fn row_errors(&self) -> Vec<RowError> {
+        self.row_errors.as_ref().clone()
+    }
+
     /// Returns the user-specified row limit for this table.
     pub fn get_limit(&self) -> Option<u32> {
         self.options.limit.as_ref().map(|limit| *limit)
@@ -394,6 +615,8 @@ impl Table {
     ///   JavaScript)
     /// - `"string"` - A [`String`] data type (encoded internally as a
     ///   _dictionary_)
+    /// - `"decimal"` - An exact fixed-point decimal, whose precision and scale
+    ///   are returned by [`Table::schema_params`]
     ///
     /// Note that all [`Table`] columns are _nullable_, regardless of the data
     /// type.
@@ -412,6 +635,35 @@ impl Table {
         }
     }
 
+    /// Returns the [`ColumnTypeParams`] of this [`Table`]'s parameterized
+    /// columns (e.g. the precision and scale of a `"decimal"` column), keyed
+    /// by column name. Columns without parameters are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::Table;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let table: Table = todo!();
+    /// let params = table.schema_params().await?;
+    /// let scale = params.get("price").and_then(|x| x.scale);
+    /// # Ok(()) }
+    /// ```
+    pub async fn schema_params(&self) -> ClientResult<HashMap<String, ColumnTypeParams>> {
+        let msg = self.client_message(ClientReq::TableSchemaReq(TableSchemaReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableSchemaResp(TableSchemaResp { schema }) => Ok(schema
+                .map(|x| {
+                    x.schema
+                        .into_iter()
+                        .filter_map(|x| Some((x.name, x.params?)))
+                        .collect()
+                })
+                .unwrap_or_default()),
+            resp => Err(resp.into()),
+        }
+    }
+
     /// Create a unique channel ID on this [`Table`], which allows
     /// `View::on_update` callback calls to be associated with the
     /// `Table::update` which caused them.
@@ -445,6 +697,42 @@ impl Table {
         Ok(msg.msg_id)
     }
 
+    /// A [`Subscription`] which yields once when this [`Table`] is deleted,
+    /// then ends. Like [`Table::on_delete`] but unsubscribed automatically
+    /// when dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use perspective_client::{BufferPolicy, Table};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let table: Table = todo!();
+    /// let mut deleted = table.deletions(BufferPolicy::Latest).await?;
+    /// deleted.next().await.transpose()?;
+    /// println!("Table deleted");
+    /// # Ok(()) }
+    /// ```
+    pub async fn deletions(&self, buffer: BufferPolicy) -> ClientResult<Subscription<()>> {
+        let msg = self.client_message(ClientReq::TableOnDeleteReq(TableOnDeleteReq {}));
+        let remove = self.client_message(ClientReq::TableRemoveDeleteReq(TableRemoveDeleteReq {
+            id: msg.msg_id,
+        }));
+
+        let (subscription, sender) = Subscription::new(&self.client, msg.msg_id, remove, buffer);
+        let callback = move |resp: Response| {
+            match resp.client_resp {
+                Some(ClientResp::TableOnDeleteResp(_)) => sender.send(()),
+                resp => sender.fail(resp.into()),
+            };
+
+            Ok(())
+        };
+
+        self.client.subscribe_once(&msg, Box::new(callback)).await?;
+        Ok(subscription)
+    }
+
     /// Removes a listener with a given ID, as returned by a previous call to
     /// [`Table::on_delete`].
     pub async fn remove_delete(&self, callback_id: u32) -> ClientResult<()> {
@@ -479,7 +767,7 @@ impl Table {
     /// ```
     pub async fn remove(&self, input: UpdateData) -> ClientResult<()> {
         let msg = self.client_message(ClientReq::TableRemoveReq(TableRemoveReq {
-            data: Some(input.into()),
+            data: Some(input.try_into()?),
         }));
 
         match self.client.oneshot(&msg).await? {
@@ -488,6 +776,151 @@ impl Table {
         }
     }
 
+    /// Removes all the rows in the [`Table`] which match `filter`, evaluated
+    /// by the engine against the [`Table`]'s current rows. Unlike
+    /// [`Table::remove`], this does not require an `index` and works on
+    /// unindexed [`Table`]s too. [`View`]s derived from this [`Table`] are
+    /// updated, and their [`View::on_update`] callbacks fired, as they would
+    /// be for [`Table::remove`].
+    ///
+    /// # Arguments
+    ///
+    /// - `filter` - The [`Filter`] terms a row must match to be removed, as in
+    ///   [`ViewConfigUpdate::filter`]. This must not be empty; use
+    ///   [`Table::clear`] to remove every row.
+    /// - `filter_op` - How multiple `filter` terms combine.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::Table;
+    /// # use perspective_client::config::{Filter, FilterOp, FilterReducer, Scalar};
+    /// # async fn run(table: Table) -> Result<(), Box<dyn std::error::Error>> {
+    /// let closed = Filter::new("status", FilterOp::Eq, Scalar::String("closed".into()));
+    /// table.remove_where(vec![closed], FilterReducer::And).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn remove_where(
+        &self,
+        filter: Vec<Filter>,
+        filter_op: FilterReducer,
+    ) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::TableRemoveWhereReq(TableRemoveWhereReq {
+            filter: filter.into_iter().map(|x| x.into()).collect(),
+            filter_op: view_config::FilterReducer::from(filter_op) as i32,
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableRemoveWhereResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    /// Add columns to this [`Table`], in order. Existing rows have `null`
+    /// values for the new columns. Existing [`View`]s are unaffected, and do
+    /// not include the new columns.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::Table;
+    /// # use perspective_client::config::ColumnType;
+    /// # async fn run(table: Table) -> Result<(), Box<dyn std::error::Error>> {
+    /// table
+    ///     .add_columns(vec![("region".to_owned(), ColumnType::String)])
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn add_columns(&self, columns: Vec<(String, ColumnType)>) -> ClientResult<()> {
+        let msg = self.client_message(ClientReq::TableAddColumnsReq(TableAddColumnsReq {
+            columns: columns
+                .into_iter()
+                .map(|(name, ty)| table_add_columns_req::Column {
+                    name,
+                    r#type: ty as i32,
+                })
+                .collect(),
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableAddColumnsResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    /// Drop columns from this [`Table`]. [`View`]s which read a dropped
+    /// column (e.g. in `columns`, `group_by`, `filter` or an expression) no
+    /// longer validate, and are deleted, firing their [`View::on_delete`]
+    /// callbacks. The index column cannot be dropped.
+    ///
+    /// # Returns
+    ///
+    /// The names of the deleted [`View`]s.
+    pub async fn drop_columns(&self, columns: Vec<String>) -> ClientResult<Vec<String>> {
+        let msg = self.client_message(ClientReq::TableDropColumnsReq(TableDropColumnsReq {
+            columns,
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableDropColumnsResp(TableDropColumnsResp { deleted_views }) => {
+                for view_id in &deleted_views {
+                    self.client.untrack_view(view_id);
+                }
+
+                Ok(deleted_views)
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
+    /// Change the type of `column`, converting each of its values. If any
+    /// value can't be converted exactly (e.g. the string `"abc"` to
+    /// [`ColumnType::Float`], or `1.5` to [`ColumnType::Integer`]), this
+    /// returns an error and the [`Table`] and its [`View`]s are unchanged. As
+    /// with [`Table::drop_columns`], [`View`]s which read `column` are
+    /// deleted, firing their [`View::on_delete`] callbacks.
+    ///
+    /// # Returns
+    ///
+    /// The names of the deleted [`View`]s.
+    pub async fn cast_column(&self, column: &str, ty: ColumnType) -> ClientResult<Vec<String>> {
+        let msg = self.client_message(ClientReq::TableCastColumnReq(TableCastColumnReq {
+            column: column.to_owned(),
+            r#type: ty as i32,
+        }));
+
+        match self.client.oneshot(&msg).await? {
+            ClientResp::TableCastColumnResp(TableCastColumnResp { deleted_views }) => {
+                for view_id in &deleted_views {
+                    self.client.untrack_view(view_id);
+                }
+
+                Ok(deleted_views)
+            },
+            resp => Err(resp.into()),
+        }
+    }
+
+    /// Create a [`TableTransaction`], which queues [`Table::update`],
+    /// [`Table::remove`] and [`Table::clear`] calls to apply together as a
+    /// single step when committed, such that derived [`View`]s never observe
+    /// a partially-applied set of changes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::{Table, UpdateData, UpdateOptions};
+    /// # async fn run(table: Table) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut tx = table.transaction();
+    /// tx.clear();
+    /// tx.update(UpdateData::Csv("x,y\n1,2".into()), UpdateOptions::default())?;
+    /// tx.commit(UpdateOptions::default()).await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn transaction(&self) -> TableTransaction {
+        TableTransaction::new(self)
+    }
+
     /// Replace all rows in this [`Table`] with the input data, coerced to this
     /// [`Table`]'s existing [`Schema`], notifying any derived [`View`] and
     /// [`View::on_update`] callbacks.
@@ -511,7 +944,7 @@ impl Table {
     /// ```
     pub async fn replace(&self, input: UpdateData) -> ClientResult<()> {
         let msg = self.client_message(ClientReq::TableReplaceReq(TableReplaceReq {
-            data: Some(input.into()),
+            data: Some(input.try_into()?),
         }));
 
         match self.client.oneshot(&msg).await? {
@@ -547,18 +980,120 @@ impl Table {
     /// table.update(data, opts).await?;
     /// # Ok(()) }
     /// ```
+    ///
+    /// [`UpdateData::RecordBatches`] input is sent as a sequence of bounded
+    /// update requests, each of which triggers [`View::on_update`]. If one of
+    /// these fails, the chunks before it remain applied.
     pub async fn update(&self, input: UpdateData, options: UpdateOptions) -> ClientResult<()> {
+        self.update_with_errors(input, options).await?;
+        Ok(())
+    }
+
+    /// As [`Table::update`], but returns the values of `input` which could
+    /// not be coerced to the type of their column, and were loaded as `null`
+    /// or skipped per the `on_bad_rows` policy. Rows are counted from the
+    /// start of `input`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::{OnBadRows, Table, UpdateData, UpdateOptions};
+    /// # async fn run(table: Table) -> Result<(), Box<dyn std::error::Error>> {
+    /// let data = UpdateData::Csv("x,y\n1,2".into());
+    /// let opts = UpdateOptions {
+    ///     on_bad_rows: Some(OnBadRows::Null),
+    ///     ..UpdateOptions::default()
+    /// };
+    ///
+    /// let row_errors = table.update_with_errors(data, opts).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn update_with_errors(
+        &self,
+        input: UpdateData,
+        options: UpdateOptions,
+    ) -> ClientResult<Vec<RowError>> {
+        #[cfg(feature = "arrow")]
+        if let UpdateData::RecordBatches(batches) = input {
+            let chunks = UpdateData::record_batch_chunks(batches);
+            return self.update_chunks(chunks, 0, &options).await;
+        }
+
+        self.update_inner(input, &options).await
+    }
+
+    /// Sends each of `chunks` as an update request, counting the rows of
+    /// their errors from `offset` rather than from the start of each chunk.
+    #[cfg(feature = "arrow")]
+    pub(crate) async fn update_chunks(
+        &self,
+        chunks: impl Iterator<Item = ClientResult<(usize, UpdateData)>>,
+        mut offset: usize,
+        options: &UpdateOptions,
+    ) -> ClientResult<Vec<RowError>> {
+        let mut row_errors = vec![];
+        for chunk in chunks {
+            let (num_rows, chunk) = chunk?;
+            match self.update_inner(chunk, options).await {
+                Ok(errors) => row_errors.extend(offset_row_errors(errors, offset)),
+                Err(ClientError::IngestionError {
+                    message,
+                    row_errors,
+                }) => {
+                    return Err(ClientError::IngestionError {
+                        message,
+                        row_errors: offset_row_errors(row_errors, offset).collect(),
+                    });
+                },
+                Err(err) => return Err(err),
+            }
+
+            offset += num_rows;
+        }
+
+        Ok(row_errors)
+    }
+
+    async fn update_inner(
+        &self,
+        input: UpdateData,
+        options: &UpdateOptions,
+    ) -> ClientResult<Vec<RowError>> {
         let msg = self.client_message(ClientReq::TableUpdateReq(TableUpdateReq {
-            data: Some(input.into()),
+            data: Some(input.try_into()?),
             port_id: options.port_id.unwrap_or(0),
+            csv: options.csv.clone().map(Into::into),
+            on_bad_rows: options.on_bad_rows.map(|x| x as i32),
         }));
 
         match self.client.oneshot(&msg).await? {
-            ClientResp::TableUpdateResp(_) => Ok(()),
+            ClientResp::TableUpdateResp(TableUpdateResp { row_errors }) => Ok(row_errors),
             resp => Err(resp.into()),
         }
     }
 
+    /// Updates the rows of this table from a slice of [`PerspectiveRow`]
+    /// structs, serialized via `serde` as JSON rows. See [`Table::update`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// # use perspective_client::*;
+    /// # async fn run(table: Table) -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(serde::Serialize, serde::Deserialize, PerspectiveRow)]
+    /// struct Row {
+    ///     x: i32,
+    /// }
+    ///
+    /// table.update_rows(&[Row { x: 1 }, Row { x: 2 }]).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn update_rows<T: PerspectiveRow>(&self, rows: &[T]) -> ClientResult<()> {
+        let json = serde_json::to_string(rows)?;
+        self.update(UpdateData::JsonRows(json), UpdateOptions::default())
+            .await
+    }
+
     /// Validates the given expressions.
     pub async fn validate_expressions(
         &self,
@@ -582,6 +1117,50 @@ impl Table {
         }
     }
 
+    /// Validates a [`ViewConfigUpdate`] against this [`Table`]'s schema, its
+    /// `expressions` and the server's [`Features`], without creating a
+    /// [`View`].
+    ///
+    /// Returns one [`ConfigDiagnostic`] per problem found (an empty [`Vec`]
+    /// if `config` is valid), each naming the field path it applies to, e.g.
+    /// an unknown column in `group_by[1]`, an unsupported aggregate in
+    /// `aggregates["Sales"]` or a window alias which collides with a column
+    /// in `windows["Sales"]`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::Table;
+    /// # use perspective_client::config::*;
+    /// # async fn run(table: Table) -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ViewConfigUpdate {
+    ///     group_by: Some(vec!["Regoin".into()]),
+    ///     ..ViewConfigUpdate::default()
+    /// };
+    ///
+    /// for diagnostic in table.validate_view_config(&config).await? {
+    ///     println!("{}", diagnostic);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn validate_view_config(
+        &self,
+        config: &ViewConfigUpdate,
+    ) -> ClientResult<Vec<ConfigDiagnostic>> {
+        let schema = self.schema().await?;
+        let expressions = self
+            .validate_expressions(config.expressions.clone().unwrap_or_default())
+            .await?;
+
+        let features = self.client.get_features().await?;
+        Ok(validate_view_config(
+            config,
+            &schema,
+            &expressions,
+            &features,
+        ))
+    }
+
     /// Create a new [`View`] from this table with a specified
     /// [`ViewConfigUpdate`].
     ///
@@ -621,10 +1200,23 @@ impl Table {
             .into(),
         };
 
-        match self.client.oneshot(&msg).await? {
+        // The server may have created the view before the request is
+        // cancelled (on timeout, or if this future is dropped), so delete it.
+        let cleanup = Request {
+            msg_id: self.client.gen_id(),
+            entity_id: view_name.clone(),
+            client_req: Some(ClientReq::ViewDeleteReq(ViewDeleteReq {})),
+        };
+
+        match self
+            .client
+            .oneshot_with_cleanup(&msg, Some(cleanup))
+            .await?
+        {
             ClientResp::TableMakeViewResp(TableMakeViewResp { view_id })
                 if view_id == view_name =>
             {
+                self.client.track_view(&view_name, &msg);
                 Ok(View::new(view_name, self.client.clone()))
             },
             resp => Err(resp.into()),
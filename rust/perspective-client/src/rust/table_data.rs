@@ -14,6 +14,10 @@ use prost::bytes::Bytes;
 
 use crate::proto;
 use crate::proto::*;
+use crate::utils::ClientError;
+#[cfg(feature = "arrow")]
+use crate::utils::ClientResult;
+use crate::table::TableReadFormat;
 use crate::view::View;
 #[cfg(doc)]
 use crate::{Client, Table};
@@ -23,6 +27,11 @@ use crate::{Client, Table};
 #[derive(Debug)]
 pub enum TableData {
     Schema(Vec<(String, ColumnType)>),
+
+    /// A [`TableData::Schema`] whose parameterized columns (e.g.
+    /// [`ColumnType::Decimal`] or [`ColumnType::List`]) carry their
+    /// [`ColumnTypeParams`].
+    SchemaWithParams(Vec<(String, ColumnType, Option<ColumnTypeParams>)>),
     Update(UpdateData),
     View(View),
 }
@@ -36,6 +45,70 @@ pub enum UpdateData {
     JsonRows(String),
     JsonColumns(String),
     Ndjson(String),
+
+    /// A Parquet file, which is decoded to Arrow by the client before it is
+    /// sent to the server.
+    #[cfg(feature = "parquet")]
+    Parquet(Bytes),
+
+    /// A gzip- or zstd-compressed payload in `format`, which is decompressed
+    /// by the server. The codec is detected from the payload's magic bytes,
+    /// see [`UpdateData::is_compressed`].
+    Compressed {
+        format: TableReadFormat,
+        data: Bytes,
+    },
+
+    /// Arrow [`arrow_array::RecordBatch`]es, which are serialized and sent to
+    /// the server in chunks of at most [`UpdateData::RECORD_BATCH_CHUNK_ROWS`]
+    /// rows by [`Client::table`] and [`Table::update`].
+    #[cfg(feature = "arrow")]
+    RecordBatches(Vec<arrow_array::RecordBatch>),
+}
+
+impl UpdateData {
+    /// Whether `data` starts with the magic bytes of a gzip or zstd stream,
+    /// and so must be sent as [`UpdateData::Compressed`].
+    pub fn is_compressed(data: &[u8]) -> bool {
+        data.starts_with(&[0x1f, 0x8b]) || data.starts_with(&[0x28, 0xb5, 0x2f, 0xfd])
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl UpdateData {
+    /// The maximum number of rows sent per request when ingesting
+    /// [`UpdateData::RecordBatches`].
+    pub const RECORD_BATCH_CHUNK_ROWS: usize = 65_536;
+
+    /// Serializes `batches` into a sequence of [`UpdateData::Arrow`] payloads
+    /// of at most [`UpdateData::RECORD_BATCH_CHUNK_ROWS`] rows each, paired
+    /// with their row count. Chunks are encoded lazily, so only one is held in
+    /// memory at a time.
+    pub(crate) fn record_batch_chunks(
+        batches: Vec<arrow_array::RecordBatch>,
+    ) -> impl Iterator<Item = ClientResult<(usize, UpdateData)>> {
+        crate::utils::arrow::chunk_record_batches(batches, Self::RECORD_BATCH_CHUNK_ROWS)
+            .into_iter()
+            .map(|chunk| {
+                let num_rows = chunk.iter().map(|batch| batch.num_rows()).sum();
+                let ipc = crate::utils::arrow::write_arrow_ipc(&chunk)?;
+                Ok((num_rows, UpdateData::Arrow(ipc.into())))
+            })
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<Vec<arrow_array::RecordBatch>> for UpdateData {
+    fn from(value: Vec<arrow_array::RecordBatch>) -> Self {
+        UpdateData::RecordBatches(value)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl FromIterator<arrow_array::RecordBatch> for UpdateData {
+    fn from_iter<T: IntoIterator<Item = arrow_array::RecordBatch>>(iter: T) -> Self {
+        UpdateData::RecordBatches(iter.into_iter().collect())
+    }
 }
 
 impl From<UpdateData> for TableData {
@@ -44,10 +117,12 @@ impl From<UpdateData> for TableData {
     }
 }
 
-impl From<TableData> for proto::MakeTableData {
-    fn from(value: TableData) -> Self {
+impl TryFrom<TableData> for proto::MakeTableData {
+    type Error = ClientError;
+
+    fn try_from(value: TableData) -> Result<Self, Self::Error> {
         let data = match value {
-            TableData::Update(x) => return x.into(),
+            TableData::Update(x) => return x.try_into(),
             TableData::View(view) => make_table_data::Data::FromView(view.name),
             TableData::Schema(x) => make_table_data::Data::FromSchema(proto::Schema {
                 schema: x
@@ -55,25 +130,64 @@ impl From<TableData> for proto::MakeTableData {
                     .map(|(name, r#type)| schema::KeyTypePair {
                         name,
                         r#type: r#type as i32,
+                        params: None,
+                    })
+                    .collect(),
+            }),
+            TableData::SchemaWithParams(x) => make_table_data::Data::FromSchema(proto::Schema {
+                schema: x
+                    .into_iter()
+                    .map(|(name, r#type, params)| schema::KeyTypePair {
+                        name,
+                        r#type: r#type as i32,
+                        params,
                     })
                     .collect(),
             }),
         };
 
-        MakeTableData { data: Some(data) }
+        Ok(MakeTableData { data: Some(data) })
     }
 }
 
-impl From<UpdateData> for proto::MakeTableData {
-    fn from(value: UpdateData) -> Self {
+impl TryFrom<UpdateData> for proto::MakeTableData {
+    type Error = ClientError;
+
+    fn try_from(value: UpdateData) -> Result<Self, Self::Error> {
         let data = match value {
             UpdateData::Csv(x) => make_table_data::Data::FromCsv(x),
             UpdateData::Arrow(x) => make_table_data::Data::FromArrow(x.into()),
             UpdateData::JsonRows(x) => make_table_data::Data::FromRows(x),
             UpdateData::JsonColumns(x) => make_table_data::Data::FromCols(x),
             UpdateData::Ndjson(x) => make_table_data::Data::FromNdjson(x),
+            #[cfg(feature = "arrow")]
+            UpdateData::RecordBatches(x) => {
+                make_table_data::Data::FromArrow(crate::utils::arrow::write_arrow_ipc(&x)?)
+            },
+            #[cfg(feature = "parquet")]
+            UpdateData::Parquet(x) => {
+                make_table_data::Data::FromArrow(crate::utils::parquet::parquet_to_arrow_ipc(x)?)
+            },
+            UpdateData::Compressed { format, data } => {
+                use make_table_data::compressed_data::Format;
+                let format = match format {
+                    TableReadFormat::Csv => Format::Csv,
+                    TableReadFormat::Arrow => Format::Arrow,
+                    TableReadFormat::JsonString => Format::Rows,
+                    TableReadFormat::ColumnsString => Format::Cols,
+                    TableReadFormat::Ndjson => Format::Ndjson,
+                    TableReadFormat::Parquet => Err(ClientError::Unknown(
+                        "Parquet input cannot be compressed".to_owned(),
+                    ))?,
+                };
+
+                make_table_data::Data::FromCompressed(make_table_data::CompressedData {
+                    format: format as i32,
+                    data: data.into(),
+                })
+            },
         };
 
-        MakeTableData { data: Some(data) }
+        Ok(MakeTableData { data: Some(data) })
     }
 }
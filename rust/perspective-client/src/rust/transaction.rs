@@ -0,0 +1,134 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! [`TableTransaction`], for [`Table::transaction`].
+
+use crate::proto::request::ClientReq;
+use crate::proto::response::ClientResp;
+use crate::proto::table_transaction_req::{Clear, Op, op};
+use crate::proto::{MakeTableData, TableTransactionReq};
+use crate::table::{Table, UpdateOptions};
+use crate::table_data::UpdateData;
+use crate::utils::ClientResult;
+
+/// A set of [`Table::update`], [`Table::remove`] and [`Table::clear`] calls
+/// which are applied together by [`TableTransaction::commit`], created by
+/// [`Table::transaction`].
+///
+/// Calls are queued locally until committed. The server applies them in
+/// order as a single engine step, so [`crate::View`]s never reflect part of
+/// a transaction, and [`crate::View::on_update`] callbacks fire once for the
+/// whole transaction. If any call fails, none are applied. Dropping a
+/// [`TableTransaction`] without committing it discards its calls.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use perspective_client::{Table, UpdateData, UpdateOptions};
+/// # async fn run(table: Table) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut tx = table.transaction();
+/// tx.remove(UpdateData::JsonRows(r#"[{"id": 1}]"#.into()))?;
+/// tx.update(
+///     UpdateData::JsonRows(r#"[{"id": 2, "x": 3}]"#.into()),
+///     UpdateOptions::default(),
+/// )?;
+/// tx.commit(UpdateOptions::default()).await?;
+/// # Ok(()) }
+/// ```
+#[must_use = "a `TableTransaction` is discarded unless committed"]
+pub struct TableTransaction {
+    table: Table,
+    ops: Vec<Op>,
+}
+
+impl TableTransaction {
+    pub(crate) fn new(table: &Table) -> Self {
+        TableTransaction {
+            table: table.clone(),
+            ops: vec![],
+        }
+    }
+
+    /// Queue a [`Table::update`] with `input`. The `csv` and `on_bad_rows`
+    /// of `options` apply to this call only, while its `port_id` is ignored
+    /// in favor of the one passed to [`TableTransaction::commit`].
+    pub fn update(&mut self, input: UpdateData, options: UpdateOptions) -> ClientResult<()> {
+        let data: MakeTableData = input.try_into()?;
+        self.ops.push(Op {
+            op: Some(op::Op::Update(data)),
+            csv: options.csv.map(Into::into),
+            on_bad_rows: options.on_bad_rows.map(|x| x as i32),
+        });
+
+        Ok(())
+    }
+
+    /// Queue a [`Table::remove`] of the index values in `input`.
+    pub fn remove(&mut self, input: UpdateData) -> ClientResult<()> {
+        let data: MakeTableData = input.try_into()?;
+        self.ops.push(Op {
+            op: Some(op::Op::Remove(data)),
+            ..Op::default()
+        });
+
+        Ok(())
+    }
+
+    /// Queue a [`Table::clear`], which removes all rows including those
+    /// added by calls queued before it.
+    pub fn clear(&mut self) {
+        self.ops.push(Op {
+            op: Some(op::Op::Clear(Clear {})),
+            ..Op::default()
+        });
+    }
+
+    /// The number of calls queued in this [`TableTransaction`].
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Whether no calls are queued in this [`TableTransaction`].
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Apply the queued calls as a single step. `options.port_id` is the
+    /// port every call is applied on, reported by
+    /// [`crate::View::on_update`].
+    pub async fn commit(self, options: UpdateOptions) -> ClientResult<()> {
+        let msg = self
+            .table
+            .client_message(ClientReq::TableTransactionReq(TableTransactionReq {
+                ops: self.ops,
+                port_id: options.port_id.unwrap_or(0),
+            }));
+
+        match self.table.get_client().oneshot(&msg).await? {
+            ClientResp::TableTransactionResp(_) => Ok(()),
+            resp => Err(resp.into()),
+        }
+    }
+
+    /// Discard the queued calls, equivalent to dropping this
+    /// [`TableTransaction`].
+    pub fn rollback(self) {}
+}
+
+impl std::fmt::Debug for TableTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableTransaction")
+            .field("table", &self.table.get_name())
+            .field("ops", &self.ops.len())
+            .finish()
+    }
+}
@@ -0,0 +1,489 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Conversions between the Arrow IPC payloads emitted by the engine and
+//! Perspective's canonical Arrow column types, shared by [`crate::View`] and
+//! the [`crate::virtual_server`] data model.
+
+use std::sync::Arc;
+
+use arrow_array::builder::StringDictionaryBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int32Type;
+use arrow_array::{
+    Array, ArrayRef, Date32Array, Date64Array, Decimal128Array, Float16Array, Float32Array,
+    Float64Array, Int8Array, Int16Array, Int32Array, Int64Array, LargeStringArray, RecordBatch,
+    StringArray, Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray,
+    Time64NanosecondArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+    TimestampNanosecondArray, TimestampSecondArray, UInt8Array, UInt16Array, UInt32Array,
+    UInt64Array,
+};
+use arrow_ipc::reader::{FileReader, StreamReader};
+use arrow_schema::{ArrowError, DataType, Field, SchemaRef, TimeUnit};
+
+use crate::proto::ColumnTypeParams;
+#[cfg(feature = "arrow")]
+use crate::proto::ColumnType;
+
+/// The field metadata key the engine sets on a column of a [`crate::Table`]
+/// created with `preserve_arrow_types`, which it has already cast back to the
+/// Arrow type it was read from.
+#[cfg(feature = "arrow")]
+pub(crate) const PRESERVED_TYPE_KEY: &str = "perspective.preserved_type";
+
+/// The canonical Arrow type of a string column, a `Utf8` dictionary.
+pub(crate) fn dict_data_type() -> DataType {
+    DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+}
+
+/// The Arrow type of a `DECIMAL` column with the given [`ColumnTypeParams`].
+pub(crate) fn decimal_data_type(params: &ColumnTypeParams) -> DataType {
+    DataType::Decimal128(
+        params
+            .precision
+            .unwrap_or(ColumnTypeParams::MAX_DECIMAL_PRECISION) as u8,
+        params.scale.unwrap_or(0) as i8,
+    )
+}
+
+/// Whether the engine can store an Arrow `Decimal128(precision, scale)`
+/// column exactly as a `DECIMAL`, the same rule as its Arrow loader. Other
+/// decimals are coerced to `Float64`, as the engine loads them.
+pub(crate) fn is_exact_decimal(precision: u8, scale: i8) -> bool {
+    precision > 0
+        && precision as u32 <= ColumnTypeParams::MAX_DECIMAL_PRECISION
+        && scale >= 0
+        && scale as u8 <= precision
+}
+
+/// The canonical Arrow [`DataType`] for each Perspective [`ColumnType`],
+/// matching the engine's `to_arrow` output.
+#[cfg(feature = "arrow")]
+pub(crate) fn column_type_to_arrow(column_type: ColumnType) -> DataType {
+    match column_type {
+        ColumnType::String => dict_data_type(),
+        ColumnType::Date => DataType::Date32,
+        ColumnType::Datetime => DataType::Timestamp(TimeUnit::Millisecond, None),
+        ColumnType::Integer => DataType::Int32,
+        ColumnType::Float => DataType::Float64,
+        ColumnType::Boolean => DataType::Boolean,
+        ColumnType::Decimal => decimal_data_type(&ColumnTypeParams::default()),
+        ColumnType::List => list_data_type(&ColumnTypeParams::default()),
+    }
+}
+
+/// Decodes an Arrow IPC payload in either the file (`ARROW1` magic) or
+/// streaming format.
+pub(crate) fn read_arrow_ipc(ipc: &[u8]) -> Result<(SchemaRef, Vec<RecordBatch>), ArrowError> {
+    let cursor = std::io::Cursor::new(ipc);
+    if ipc.len() >= 6 && &ipc[0..6] == "ARROW1".as_bytes() {
+        let reader = FileReader::try_new(cursor, None)?;
+        let schema = reader.schema();
+        Ok((schema, reader.collect::<Result<Vec<_>, _>>()?))
+    } else {
+        let reader = StreamReader::try_new(cursor, None)?;
+        let schema = reader.schema();
+        Ok((schema, reader.collect::<Result<Vec<_>, _>>()?))
+    }
+}
+
+/// Coerces every column of `batch` with [`coerce_column`], so that each
+/// column has its canonical Perspective type (see [`column_type_to_arrow`]),
+/// except for columns marked with [`PRESERVED_TYPE_KEY`].
+#[cfg(feature = "arrow")]
+pub(crate) fn coerce_batch(batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+    let schema = batch.schema();
+    let (fields, arrays): (Vec<_>, Vec<_>) = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(col_idx, field)| match field.data_type() {
+            _ if field.metadata().contains_key(PRESERVED_TYPE_KEY) => {
+                Ok((field.as_ref().clone(), batch.column(col_idx).clone()))
+            },
+            // `LIST` columns are already in their canonical type.
+            DataType::List(item) if is_list_element(item.data_type()) => {
+                Ok((field.as_ref().clone(), batch.column(col_idx).clone()))
+            },
+            _ => coerce_column(field.name(), field, batch.column(col_idx)),
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+
+    let options = arrow_array::RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    RecordBatch::try_new_with_options(Arc::new(arrow_schema::Schema::new(fields)), arrays, &options)
+}
+
+/// Encodes `batches` as a single Arrow IPC stream, using the schema of the
+/// first batch.
+#[cfg(feature = "arrow")]
+pub(crate) fn write_arrow_ipc(batches: &[RecordBatch]) -> Result<Vec<u8>, ArrowError> {
+    let schema = batches
+        .first()
+        .ok_or_else(|| ArrowError::InvalidArgumentError("No `RecordBatch` to write".to_owned()))?
+        .schema();
+
+    let mut buffer = vec![];
+    let mut writer = arrow_ipc::writer::StreamWriter::try_new(&mut buffer, &schema)?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+
+    writer.finish()?;
+    drop(writer);
+    Ok(buffer)
+}
+
+/// Splits `batches` into groups of at most `max_rows` total rows, slicing
+/// batches which straddle a group boundary (this is zero-copy).
+#[cfg(feature = "arrow")]
+pub(crate) fn chunk_record_batches(
+    batches: Vec<RecordBatch>,
+    max_rows: usize,
+) -> Vec<Vec<RecordBatch>> {
+    let mut chunks = vec![];
+    let mut chunk = vec![];
+    let mut chunk_rows = 0;
+    for batch in batches {
+        if batch.num_rows() == 0 {
+            chunk.push(batch);
+            continue;
+        }
+
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            let len = (max_rows - chunk_rows).min(batch.num_rows() - offset);
+            chunk.push(batch.slice(offset, len));
+            offset += len;
+            chunk_rows += len;
+            if chunk_rows == max_rows {
+                chunks.push(std::mem::take(&mut chunk));
+                chunk_rows = 0;
+            }
+        }
+    }
+
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+
+    chunks
+}
+
+/// The Arrow type of a `DATETIME` column with the given [`ColumnTypeParams`].
+#[cfg(feature = "arrow")]
+pub(crate) fn timestamp_data_type(params: &ColumnTypeParams) -> DataType {
+    let unit = match params.unit() {
+        crate::proto::TimeUnit::Second => TimeUnit::Second,
+        crate::proto::TimeUnit::Millisecond => TimeUnit::Millisecond,
+        crate::proto::TimeUnit::Microsecond => TimeUnit::Microsecond,
+        crate::proto::TimeUnit::Nanosecond => TimeUnit::Nanosecond,
+    };
+
+    DataType::Timestamp(unit, params.timezone.as_deref().map(Arc::from))
+}
+
+/// The Arrow type of a `LIST` column with the given [`ColumnTypeParams`],
+/// a `List` of the element type, defaulting to `Utf8`.
+#[cfg(feature = "arrow")]
+pub(crate) fn list_data_type(params: &ColumnTypeParams) -> DataType {
+    let element = match params
+        .element_type
+        .and_then(|x| ColumnType::try_from(x).ok())
+    {
+        Some(ColumnType::Integer) => DataType::Int64,
+        Some(ColumnType::Float) => DataType::Float64,
+        Some(ColumnType::Boolean) => DataType::Boolean,
+        _ => DataType::Utf8,
+    };
+
+    DataType::List(Arc::new(Field::new("item", element, true)))
+}
+
+/// Whether the engine can store an Arrow list with `element` values as a
+/// `LIST` column (when list flattening is `preserve`).
+#[cfg(feature = "arrow")]
+pub(crate) fn is_list_element(element: &DataType) -> bool {
+    matches!(
+        element,
+        DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::Float32
+            | DataType::Float64
+    )
+}
+
+/// The value of the (non-null) cell `row_idx` of a timestamp array of any
+/// unit, in epoch milliseconds, the unit of Perspective's JSON output. Finer
+/// units are floored, so pre-epoch instants round to the earlier millisecond.
+pub(crate) fn timestamp_to_millis(array: &dyn Array, row_idx: usize) -> Option<i64> {
+    let DataType::Timestamp(unit, _) = array.data_type() else {
+        return None;
+    };
+
+    let array = array.as_any();
+    Some(match unit {
+        TimeUnit::Second => array.downcast_ref::<TimestampSecondArray>()?.value(row_idx) * 1_000,
+        TimeUnit::Millisecond => array
+            .downcast_ref::<TimestampMillisecondArray>()?
+            .value(row_idx),
+        TimeUnit::Microsecond => array
+            .downcast_ref::<TimestampMicrosecondArray>()?
+            .value(row_idx)
+            .div_euclid(1_000),
+        TimeUnit::Nanosecond => array
+            .downcast_ref::<TimestampNanosecondArray>()?
+            .value(row_idx)
+            .div_euclid(1_000_000),
+    })
+}
+
+/// Coerces an Arrow column to Perspective-compatible types, optionally
+/// renaming.
+pub(crate) fn coerce_column(
+    name: &str,
+    field: &Field,
+    array: &ArrayRef,
+) -> Result<(Field, ArrayRef), ArrowError> {
+    match field.data_type() {
+        DataType::Boolean | DataType::Float64 | DataType::Int32 | DataType::Date32 => Ok((
+            Field::new(name, field.data_type().clone(), true),
+            array.clone(),
+        )),
+        DataType::Dictionary(key, value) => {
+            if key.as_ref() == &DataType::Int32 && value.as_ref() == &DataType::Utf8 {
+                return Ok((Field::new(name, dict_data_type(), true), array.clone()));
+            }
+
+            let dict = array.as_any_dictionary_opt().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Column '{}' is not a dictionary array",
+                    name
+                ))
+            })?;
+
+            let values = arrow_select::take::take(dict.values(), dict.keys(), None)?;
+            let field = Field::new(name, values.data_type().clone(), true);
+            coerce_column(name, &field, &values)
+        },
+        DataType::Utf8 => {
+            let arr = array.as_any().downcast_ref::<StringArray>().unwrap();
+            let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+            for i in 0..arr.len() {
+                if arr.is_null(i) {
+                    builder.append_null();
+                } else {
+                    builder.append_value(arr.value(i));
+                }
+            }
+            Ok((
+                Field::new(name, dict_data_type(), true),
+                Arc::new(builder.finish()) as ArrayRef,
+            ))
+        },
+        // Timestamps keep their native unit and timezone, which the engine
+        // stores as-is.
+        DataType::Timestamp(..) => Ok((
+            Field::new(name, field.data_type().clone(), true),
+            array.clone(),
+        )),
+        DataType::Int8 => {
+            let arr = array.as_any().downcast_ref::<Int8Array>().unwrap();
+            let result: Int32Array = arr.iter().map(|v| v.map(|v| v as i32)).collect();
+            Ok((
+                Field::new(name, DataType::Int32, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Int16 => {
+            let arr = array.as_any().downcast_ref::<Int16Array>().unwrap();
+            let result: Int32Array = arr.iter().map(|v| v.map(|v| v as i32)).collect();
+            Ok((
+                Field::new(name, DataType::Int32, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::UInt8 => {
+            let arr = array.as_any().downcast_ref::<UInt8Array>().unwrap();
+            let result: Int32Array = arr.iter().map(|v| v.map(|v| v as i32)).collect();
+            Ok((
+                Field::new(name, DataType::Int32, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::UInt16 => {
+            let arr = array.as_any().downcast_ref::<UInt16Array>().unwrap();
+            let result: Int32Array = arr.iter().map(|v| v.map(|v| v as i32)).collect();
+            Ok((
+                Field::new(name, DataType::Int32, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::UInt32 => {
+            let arr = array.as_any().downcast_ref::<UInt32Array>().unwrap();
+            let result: Int64Array = arr.iter().map(|v| v.map(|v| v as i64)).collect();
+            let result: Float64Array = result.iter().map(|v| v.map(|v| v as f64)).collect();
+            Ok((
+                Field::new(name, DataType::Float64, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Int64 => {
+            let arr = array.as_any().downcast_ref::<Int64Array>().unwrap();
+            let result: Float64Array = arr.iter().map(|v| v.map(|v| v as f64)).collect();
+            Ok((
+                Field::new(name, DataType::Float64, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::UInt64 => {
+            let arr = array.as_any().downcast_ref::<UInt64Array>().unwrap();
+            let result: Float64Array = arr.iter().map(|v| v.map(|v| v as f64)).collect();
+            Ok((
+                Field::new(name, DataType::Float64, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Float32 => {
+            let arr = array.as_any().downcast_ref::<Float32Array>().unwrap();
+            let result: Float64Array = arr.iter().map(|v| v.map(|v| v as f64)).collect();
+            Ok((
+                Field::new(name, DataType::Float64, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Float16 => {
+            let arr = array.as_any().downcast_ref::<Float16Array>().unwrap();
+            let result: Float64Array = arr.iter().map(|v| v.map(|v| v.to_f64())).collect();
+            Ok((
+                Field::new(name, DataType::Float64, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Decimal128(precision, scale) if is_exact_decimal(*precision, *scale) => Ok((
+            Field::new(name, field.data_type().clone(), true),
+            array.clone(),
+        )),
+        DataType::Decimal128(_, scale) => {
+            let scale = *scale;
+            let arr = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
+            let divisor = 10_f64.powi(scale as i32);
+            let result: Float64Array = arr.iter().map(|v| v.map(|v| v as f64 / divisor)).collect();
+            Ok((
+                Field::new(name, DataType::Float64, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Date64 => {
+            let arr = array.as_any().downcast_ref::<Date64Array>().unwrap();
+            let result: Date32Array = arr
+                .iter()
+                .map(|v| v.map(|v| (v / 86_400_000) as i32))
+                .collect();
+            Ok((
+                Field::new(name, DataType::Date32, true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Time32(TimeUnit::Second) => {
+            let arr = array.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+            let result: TimestampMillisecondArray =
+                arr.iter().map(|v| v.map(|v| v as i64 * 1_000)).collect();
+            Ok((
+                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Time32(TimeUnit::Millisecond) => {
+            let arr = array
+                .as_any()
+                .downcast_ref::<Time32MillisecondArray>()
+                .unwrap();
+            let result: TimestampMillisecondArray =
+                arr.iter().map(|v| v.map(|v| v as i64)).collect();
+            Ok((
+                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Time64(TimeUnit::Microsecond) => {
+            let arr = array
+                .as_any()
+                .downcast_ref::<Time64MicrosecondArray>()
+                .unwrap();
+            let result: TimestampMillisecondArray =
+                arr.iter().map(|v| v.map(|v| v / 1_000)).collect();
+            Ok((
+                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            let arr = array
+                .as_any()
+                .downcast_ref::<Time64NanosecondArray>()
+                .unwrap();
+            let result: TimestampMillisecondArray =
+                arr.iter().map(|v| v.map(|v| v / 1_000_000)).collect();
+            Ok((
+                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
+                Arc::new(result) as ArrayRef,
+            ))
+        },
+        DataType::LargeUtf8 => {
+            let arr = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+            for i in 0..arr.len() {
+                if arr.is_null(i) {
+                    builder.append_null();
+                } else {
+                    builder.append_value(arr.value(i));
+                }
+            }
+            Ok((
+                Field::new(name, dict_data_type(), true),
+                Arc::new(builder.finish()) as ArrayRef,
+            ))
+        },
+        dt => {
+            tracing::warn!(
+                "Coercing unknown Arrow type {} to Dictionary for column '{}'",
+                dt,
+                name
+            );
+            let num_rows = array.len();
+            let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+            for i in 0..num_rows {
+                if array.is_null(i) {
+                    builder.append_null();
+                } else {
+                    let scalar_arr = array.slice(i, 1);
+                    builder.append_value(format!("{:?}", scalar_arr));
+                }
+            }
+            Ok((
+                Field::new(name, dict_data_type(), true),
+                Arc::new(builder.finish()) as ArrayRef,
+            ))
+        },
+    }
+}
+
@@ -61,6 +61,8 @@ impl std::fmt::Display for Request {
                 client_req:
                     Some(ClientReq::TableUpdateReq(TableUpdateReq {
                         port_id,
+                        ref csv,
+                        on_bad_rows,
                         data:
                             Some(MakeTableData {
                                 data: Some(ref data),
@@ -70,6 +72,8 @@ impl std::fmt::Display for Request {
             } => Request {
                 client_req: Some(ClientReq::TableUpdateReq(TableUpdateReq {
                     port_id,
+                    csv: csv.clone(),
+                    on_bad_rows,
                     data: Some(MakeTableData {
                         data: Some(replace(data.clone())),
                     }),
@@ -145,6 +149,7 @@ impl std::fmt::Display for Response {
                 client_resp: Some(ClientResp::ViewOnUpdateResp(ViewOnUpdateResp {
                     delta: x.delta.as_ref().map(|_| vec![]),
                     port_id: x.port_id,
+                    diff: None,
                 })),
                 ..msg.clone()
             },
@@ -12,8 +12,11 @@
 
 //! Utility functions that are common to the `perspective` crates.
 
+pub(crate) mod arrow;
 mod clone;
 mod logging;
+#[cfg(feature = "parquet")]
+pub(crate) mod parquet;
 mod rand_sequence;
 
 #[cfg(feature = "talc-allocator")]
@@ -31,6 +34,7 @@ use rand::rngs::StdRng;
 pub(crate) use talc_allocator::get_used;
 use thiserror::*;
 
+use crate::config::ConfigDiagnostic;
 use crate::proto;
 use crate::utils::rand_sequence::RandomSequence;
 
@@ -45,6 +49,14 @@ pub enum ClientError {
     #[error("Transport error: {0}")]
     TransportError(String),
 
+    /// A load rejected by [`proto::OnBadRows::Fail`], with every value which
+    /// could not be coerced to the type of its column.
+    #[error("Ingestion error: {message}")]
+    IngestionError {
+        message: String,
+        row_errors: Vec<proto::RowError>,
+    },
+
     #[error("Client not yet initialized")]
     NotInitialized,
 
@@ -83,6 +95,39 @@ pub enum ClientError {
 
     #[error("{0}")]
     TimeError(#[from] SystemTimeError),
+
+    #[error("Arrow error: {0}")]
+    ArrowError(Arc<arrow_schema::ArrowError>),
+
+    #[error("JSON error: {0}")]
+    JsonError(Arc<serde_json::Error>),
+
+    #[cfg(feature = "parquet")]
+    #[error("Parquet error: {0}")]
+    ParquetError(Arc<::parquet::errors::ParquetError>),
+
+    #[error(
+        "Filter operator \"{op}\" is not supported for {column_type} column \"{column}\", expected one of [{}]",
+        .supported.join(", ")
+    )]
+    UnsupportedFilterOp {
+        column: String,
+        column_type: proto::ColumnType,
+        op: String,
+        supported: Vec<String>,
+    },
+
+    #[error("Invalid view config: {}", .0.iter().map(|x| x.to_string()).collect::<Vec<_>>().join("; "))]
+    InvalidViewConfig(Vec<ConfigDiagnostic>),
+
+    #[error("Subscription buffer overflowed with {0} unread events")]
+    SubscriptionOverflow(usize),
+
+    #[error("Request timed out after {0:?}")]
+    Timeout(std::time::Duration),
+
+    #[error("Failed to restore session after reconnect: {}", .0.join("; "))]
+    SessionReplay(Vec<String>),
 }
 
 pub type ClientResult<T> = Result<T, ClientError>;
@@ -93,6 +138,25 @@ impl From<Box<dyn std::error::Error + Send + Sync>> for ClientError {
     }
 }
 
+impl From<arrow_schema::ArrowError> for ClientError {
+    fn from(value: arrow_schema::ArrowError) -> Self {
+        ClientError::ArrowError(Arc::new(value))
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<::parquet::errors::ParquetError> for ClientError {
+    fn from(value: ::parquet::errors::ParquetError) -> Self {
+        ClientError::ParquetError(Arc::new(value))
+    }
+}
+
+impl From<serde_json::Error> for ClientError {
+    fn from(value: serde_json::Error) -> Self {
+        ClientError::JsonError(Arc::new(value))
+    }
+}
+
 impl<'a, A> From<std::sync::PoisonError<std::sync::MutexGuard<'a, A>>> for ClientError {
     fn from(_: std::sync::PoisonError<std::sync::MutexGuard<'a, A>>) -> Self {
         ClientError::Internal("Lock Error".to_owned())
@@ -106,6 +170,10 @@ impl From<Option<proto::response::ClientResp>> for ClientError {
                 proto::StatusCode::ServerError => ClientError::Internal(x.message),
                 proto::StatusCode::ViewNotFound => ClientError::ViewNotFound,
                 proto::StatusCode::TransportError => ClientError::TransportError(x.message),
+                proto::StatusCode::IngestionError => ClientError::IngestionError {
+                    message: x.message,
+                    row_errors: x.row_errors,
+                },
             },
             Some(x) => ClientError::ResponseFailed(Box::new(x)),
             None => ClientError::ResponseAborted,
@@ -120,6 +188,10 @@ impl From<proto::response::ClientResp> for ClientError {
                 proto::StatusCode::ServerError => ClientError::Internal(x.message),
                 proto::StatusCode::ViewNotFound => ClientError::ViewNotFound,
                 proto::StatusCode::TransportError => ClientError::TransportError(x.message),
+                proto::StatusCode::IngestionError => ClientError::IngestionError {
+                    message: x.message,
+                    row_errors: x.row_errors,
+                },
             },
             x => ClientError::ResponseFailed(Box::new(x)),
         }
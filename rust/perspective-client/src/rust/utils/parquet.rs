@@ -0,0 +1,67 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Conversions between Parquet files and the Arrow IPC payloads accepted and
+//! emitted by the engine, which has no Parquet reader or writer of its own.
+
+use std::str::FromStr;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use prost::bytes::Bytes;
+
+use super::arrow::write_arrow_ipc;
+
+/// Decodes a Parquet file into an Arrow IPC stream. A file with no rows
+/// decodes to a single empty batch, so it still carries the file's schema.
+pub(crate) fn parquet_to_arrow_ipc(parquet: Bytes) -> Result<Vec<u8>, ParquetError> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(parquet)?;
+    let schema = builder.schema().clone();
+    let mut batches = builder.build()?.collect::<Result<Vec<RecordBatch>, _>>()?;
+    if batches.is_empty() {
+        batches.push(RecordBatch::new_empty(schema));
+    }
+
+    Ok(write_arrow_ipc(&batches)?)
+}
+
+/// Encodes `batches` as a Parquet file. `compression` is a Parquet codec
+/// name, e.g. `"snappy"` (the default), `"gzip(6)"` or `"uncompressed"`, or
+/// natively `"zstd(3)"` or `"lz4"`.
+pub(crate) fn write_parquet(
+    schema: SchemaRef,
+    batches: &[RecordBatch],
+    compression: Option<&str>,
+) -> Result<Vec<u8>, ParquetError> {
+    let compression = compression
+        .map(Compression::from_str)
+        .transpose()?
+        .unwrap_or(Compression::SNAPPY);
+
+    let props = WriterProperties::builder()
+        .set_compression(compression)
+        .build();
+
+    let mut buffer = vec![];
+    let mut writer = ArrowWriter::try_new(&mut buffer, schema, Some(props))?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+
+    writer.close()?;
+    Ok(buffer)
+}
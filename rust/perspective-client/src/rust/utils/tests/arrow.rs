@@ -0,0 +1,119 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::Arc;
+
+use arrow_array::{
+    Array, ArrayRef, Decimal128Array, Float64Array, Int64Array, RecordBatch, StringArray,
+};
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::utils::arrow::*;
+
+fn test_batch() -> RecordBatch {
+    let schema = Schema::new(vec![
+        Field::new("x", DataType::Int64, true),
+        Field::new("y", DataType::Utf8, true),
+    ]);
+
+    RecordBatch::try_new(Arc::new(schema), vec![
+        Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+        Arc::new(StringArray::from(vec![Some("a"), Some("b"), None])) as ArrayRef,
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_read_arrow_ipc_stream() {
+    let batch = test_batch();
+    let mut buffer = vec![];
+    let mut writer = StreamWriter::try_new(&mut buffer, &batch.schema()).unwrap();
+    writer.write(&batch).unwrap();
+    writer.finish().unwrap();
+    drop(writer);
+    let (schema, batches) = read_arrow_ipc(&buffer).unwrap();
+    assert_eq!(schema.fields().len(), 2);
+    assert_eq!(batches, vec![batch]);
+}
+
+#[test]
+fn test_coerce_column_int64_to_float64() {
+    let batch = test_batch();
+    let (field, array) = coerce_column("x", batch.schema().field(0), batch.column(0)).unwrap();
+    assert_eq!(field.data_type(), &DataType::Float64);
+    assert_eq!(array.len(), 3);
+    assert!(array.is_null(1));
+}
+
+#[test]
+fn test_coerce_column_utf8_to_dictionary() {
+    let batch = test_batch();
+    let (field, array) = coerce_column("y", batch.schema().field(1), batch.column(1)).unwrap();
+    assert_eq!(field.data_type(), &dict_data_type());
+    assert!(array.is_null(2));
+}
+
+#[test]
+fn test_coerce_column_decimal128() {
+    let exact = Decimal128Array::from(vec![Some(1250), None])
+        .with_precision_and_scale(18, 2)
+        .unwrap();
+    let field = Field::new("x", exact.data_type().clone(), true);
+    let (field, _) = coerce_column("x", &field, &(Arc::new(exact) as ArrayRef)).unwrap();
+    assert_eq!(field.data_type(), &DataType::Decimal128(18, 2));
+
+    let wide = Decimal128Array::from(vec![Some(1250), None])
+        .with_precision_and_scale(38, 2)
+        .unwrap();
+    let field = Field::new("x", wide.data_type().clone(), true);
+    let (field, array) = coerce_column("x", &field, &(Arc::new(wide) as ArrayRef)).unwrap();
+    assert_eq!(field.data_type(), &DataType::Float64);
+    let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(array.value(0), 12.5);
+    assert!(array.is_null(1));
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn test_coerce_batch() {
+    use crate::proto::ColumnType;
+
+    let batch = coerce_batch(&test_batch()).unwrap();
+    assert_eq!(batch.num_rows(), 3);
+    assert_eq!(
+        batch.schema().field(1).data_type(),
+        &column_type_to_arrow(ColumnType::String)
+    );
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn test_chunk_record_batches() {
+    let chunks = chunk_record_batches(vec![test_batch(), test_batch()], 4);
+    let rows = chunks
+        .iter()
+        .map(|chunk| chunk.iter().map(|x| x.num_rows()).sum::<usize>())
+        .collect::<Vec<_>>();
+
+    assert_eq!(rows, vec![4, 2]);
+    assert_eq!(chunks[0].len(), 2);
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn test_write_arrow_ipc_roundtrip() {
+    let batch = test_batch();
+    let ipc = write_arrow_ipc(&[batch.slice(1, 2)]).unwrap();
+    let (_, batches) = read_arrow_ipc(&ipc).unwrap();
+    assert_eq!(batches, vec![batch.slice(1, 2)]);
+}
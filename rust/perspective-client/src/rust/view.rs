@@ -14,9 +14,11 @@ use std::collections::HashMap;
 use std::ops::Deref;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
-use futures::Future;
+use futures::{Future, Stream};
 use prost::bytes::Bytes;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
@@ -26,6 +28,7 @@ use crate::client::Client;
 use crate::proto::request::ClientReq;
 use crate::proto::response::ClientResp;
 use crate::proto::*;
+use crate::subscription::{BufferPolicy, Subscription};
 #[cfg(doc)]
 use crate::table::Table;
 pub use crate::utils::*;
@@ -42,21 +45,44 @@ pub struct OnUpdateOptions {
 /// Apache Arrow to the callback provided to [`View::on_update`]. This allows
 /// incremental updates if your callbakc can read this format, but should be
 /// disabled otherwise.
-#[derive(Default, Debug, Deserialize, TS)]
+///
+/// `None` mode provides only the `port_id`, for callbacks which just need to
+/// know that the [`View`] changed.
+///
+/// `Diff` mode provides an [`OnUpdateDiff`] of the rows added, updated and
+/// removed by the update, see [`OnUpdateData::diff`].
+#[derive(Clone, Copy, Default, Debug, Deserialize, PartialEq, TS)]
 pub enum OnUpdateMode {
     #[default]
     #[serde(rename = "row")]
     Row,
+
+    #[serde(rename = "none")]
+    None,
+
+    #[serde(rename = "diff")]
+    Diff,
 }
 
 impl FromStr for OnUpdateMode {
     type Err = ClientError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "row" {
-            Ok(OnUpdateMode::Row)
-        } else {
-            Err(ClientError::Option)
+        match s {
+            "row" => Ok(OnUpdateMode::Row),
+            "none" => Ok(OnUpdateMode::None),
+            "diff" => Ok(OnUpdateMode::Diff),
+            _ => Err(ClientError::Option),
+        }
+    }
+}
+
+impl From<OnUpdateMode> for Mode {
+    fn from(value: OnUpdateMode) -> Self {
+        match value {
+            OnUpdateMode::Row => Mode::Row,
+            OnUpdateMode::None => Mode::None,
+            OnUpdateMode::Diff => Mode::Diff,
         }
     }
 }
@@ -105,7 +131,9 @@ pub struct ViewWindow {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub formatted: Option<bool>,
 
-    /// Only impacts [`View::to_arrow`]
+    /// Only impacts [`View::to_arrow`] and `View::to_parquet`, where it names
+    /// the Parquet codec (e.g. `"snappy"`, `"gzip(6)"` or `"uncompressed"`;
+    /// `"zstd(3)"` and `"lz4"` are not available in WebAssembly).
     #[ts(optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compression: Option<String>,
@@ -156,6 +184,60 @@ impl Deref for OnUpdateData {
     }
 }
 
+impl OnUpdateData {
+    /// The rows added, updated and removed by this update, if the callback
+    /// was registered with [`OnUpdateMode::Diff`].
+    pub fn diff(&self) -> Option<OnUpdateDiff> {
+        self.0.diff.clone().map(OnUpdateDiff::from)
+    }
+}
+
+/// The rows added, updated and removed by an update, provided to the
+/// callback argument to [`View::on_update`] with the "diff" mode.
+///
+/// Each of `added`, `updated` and `removed` is an Apache Arrow of the
+/// [`View`]'s columns, with the key of each row at the same position in the
+/// corresponding `_keys` list - the `index` (or row id) of the row.
+/// `removed` rows have the values they had before the update. Only a
+/// [`View`] without a `group_by` or `split_by` supports this mode.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, TS)]
+pub struct OnUpdateDiff {
+    #[serde(with = "serde_bytes")]
+    #[ts(as = "Vec::<u8>")]
+    pub added: Vec<u8>,
+
+    #[serde(with = "serde_bytes")]
+    #[ts(as = "Vec::<u8>")]
+    pub updated: Vec<u8>,
+
+    #[serde(with = "serde_bytes")]
+    #[ts(as = "Vec::<u8>")]
+    pub removed: Vec<u8>,
+
+    pub added_keys: Vec<Vec<crate::config::Scalar>>,
+    pub updated_keys: Vec<Vec<crate::config::Scalar>>,
+    pub removed_keys: Vec<Vec<crate::config::Scalar>>,
+}
+
+impl From<ViewOnUpdateDiff> for OnUpdateDiff {
+    fn from(value: ViewOnUpdateDiff) -> Self {
+        let keys = |keys: Vec<view_on_update_diff::Key>| {
+            keys.into_iter()
+                .map(|key| key.values.into_iter().map(From::from).collect())
+                .collect()
+        };
+
+        OnUpdateDiff {
+            added: value.added,
+            updated: value.updated,
+            removed: value.removed,
+            added_keys: keys(value.added_keys),
+            updated_keys: keys(value.updated_keys),
+            removed_keys: keys(value.removed_keys),
+        }
+    }
+}
+
 /// The [`View`] struct is Perspective's query and serialization interface. It
 /// represents a query on the `Table`'s dataset and is always created from an
 /// existing `Table` instance via the [`Table::view`] method.
@@ -277,7 +359,27 @@ impl View {
         View { name, client }
     }
 
-    fn client_message(&self, req: ClientReq) -> Request {
+    /// Create a handle to this [`View`] whose requests fail with
+    /// [`crate::ClientError::Timeout`] after `timeout`, overriding the
+    /// default set by [`Client::set_timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use perspective_client::{View, ViewWindow};
+    /// # async fn run(view: View) -> Result<(), Box<dyn std::error::Error>> {
+    /// let arrow = view
+    ///     .with_timeout(Duration::from_secs(10))
+    ///     .to_arrow(ViewWindow::default())
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> View {
+        View::new(self.name.clone(), self.client.with_timeout(timeout))
+    }
+
+    pub(crate) fn client_message(&self, req: ClientReq) -> Request {
         crate::proto::Request {
             msg_id: self.client.gen_id(),
             entity_id: self.name.clone(),
@@ -383,7 +485,7 @@ impl View {
     pub async fn schema(&self) -> ClientResult<HashMap<String, ColumnType>> {
         let msg = self.client_message(ClientReq::ViewSchemaReq(ViewSchemaReq {}));
         match self.client.oneshot(&msg).await? {
-            ClientResp::ViewSchemaResp(ViewSchemaResp { schema }) => Ok(schema
+            ClientResp::ViewSchemaResp(ViewSchemaResp { schema, .. }) => Ok(schema
                 .into_iter()
                 .map(|(x, y)| (x, ColumnType::try_from(y).unwrap()))
                 .collect()),
@@ -391,6 +493,19 @@ impl View {
         }
     }
 
+    /// The [`ColumnTypeParams`] of this [`View`]'s parameterized columns,
+    /// keyed by column name, as in [`Table::schema_params`]. An aggregate
+    /// over a `"decimal"` column keeps its input's scale; `"sum"`-like
+    /// aggregates widen the precision to [`ColumnTypeParams::MAX_DECIMAL_PRECISION`].
+    /// Reading a [`View`] returns an error if such a sum overflows.
+    pub async fn schema_params(&self) -> ClientResult<HashMap<String, ColumnTypeParams>> {
+        let msg = self.client_message(ClientReq::ViewSchemaReq(ViewSchemaReq {}));
+        match self.client.oneshot(&msg).await? {
+            ClientResp::ViewSchemaResp(ViewSchemaResp { params, .. }) => Ok(params),
+            resp => Err(resp.into()),
+        }
+    }
+
     /// Serializes a [`View`] to the Apache Arrow data format.
     pub async fn to_arrow(&self, window: ViewWindow) -> ClientResult<Bytes> {
         let msg = self.client_message(ClientReq::ViewToArrowReq(ViewToArrowReq {
@@ -404,6 +519,185 @@ impl View {
         }
     }
 
+    /// Serializes a [`View`] to the Apache Arrow data format as a [`Stream`]
+    /// of pages of at most `chunk_rows` rows each, so that very large windows
+    /// never need to be held in memory as a single message.
+    ///
+    /// Each item of the stream is a complete Arrow IPC payload (schema and
+    /// record batches) for its page. Pages are requested one at a time, and
+    /// the next page is only requested once the previous one is consumed. The
+    /// last page holds the remaining rows, and a window with no rows yields no
+    /// pages at all.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use perspective_client::{View, ViewWindow};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let view: View = todo!();
+    /// let mut pages = Box::pin(view.to_arrow_stream(ViewWindow::default(), 100_000));
+    /// while let Some(arrow) = pages.next().await {
+    ///     let arrow = arrow?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn to_arrow_stream(
+        &self,
+        window: ViewWindow,
+        chunk_rows: u32,
+    ) -> impl Stream<Item = ClientResult<Bytes>> + use<> {
+        let view = self.clone();
+        let viewport: ViewPort = window.clone().into();
+        futures::stream::try_unfold(Some(viewport), move |viewport| {
+            let view = view.clone();
+            let compression = window.compression.clone();
+            async move {
+                let Some(viewport) = viewport else {
+                    return Ok(None);
+                };
+
+                let msg = view.client_message(ClientReq::ViewToArrowPageReq(ViewToArrowPageReq {
+                    viewport: Some(viewport.clone()),
+                    page_rows: chunk_rows,
+                    compression,
+                }));
+
+                match view.client.oneshot(&msg).await? {
+                    ClientResp::ViewToArrowPageResp(ViewToArrowPageResp { arrow, .. })
+                        if arrow.is_empty() =>
+                    {
+                        Ok(None)
+                    },
+                    ClientResp::ViewToArrowPageResp(ViewToArrowPageResp {
+                        arrow,
+                        next_start_row,
+                    }) => {
+                        let next = next_start_row.map(|start_row| ViewPort {
+                            start_row: Some(start_row),
+                            ..viewport
+                        });
+
+                        Ok(Some((arrow.into(), next)))
+                    },
+                    resp => Err(resp.into()),
+                }
+            }
+        })
+    }
+
+    /// Serializes a [`View`] to a [`Vec`] of Arrow [`arrow_array::RecordBatch`],
+    /// decoded on the client.
+    ///
+    /// Each column is typed according to [`ColumnType::to_arrow_data_type`],
+    /// e.g. `"string"` columns are `Dictionary(Int32, Utf8)` and `"datetime"`
+    /// columns are `Timestamp(Millisecond, None)`. For a [`View`] with a
+    /// `group_by`, the row path is emitted as one `__ROW_PATH_N__` column per
+    /// group-by level (unless [`ViewWindow::emit_legacy_row_path_names`] is
+    /// explicitly `true`), typed like the column it groups by.
+    ///
+    /// [`ViewWindow::compression`] is ignored, as the payload is decoded
+    /// immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::{View, ViewWindow};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let view: View = todo!();
+    /// let batches = view.to_record_batches(ViewWindow::default()).await?;
+    /// let num_rows: usize = batches.iter().map(|x| x.num_rows()).sum();
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "arrow")]
+    pub async fn to_record_batches(
+        &self,
+        window: ViewWindow,
+    ) -> ClientResult<Vec<arrow_array::RecordBatch>> {
+        let window = ViewWindow {
+            compression: None,
+            emit_legacy_row_path_names: Some(window.emit_legacy_row_path_names.unwrap_or(false)),
+            ..window
+        };
+
+        let arrow = self.to_arrow(window).await?;
+        let (_, batches) = crate::utils::arrow::read_arrow_ipc(&arrow)?;
+        Ok(batches
+            .iter()
+            .map(crate::utils::arrow::coerce_batch)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Serializes a [`View`] to the Apache Parquet file format. The Arrow
+    /// payload is converted on the client, with columns typed as in
+    /// [`View::to_record_batches`].
+    ///
+    /// [`ViewWindow::compression`] selects the Parquet codec, e.g.
+    /// `"snappy"` (the default), `"gzip(6)"`, `"zstd(3)"` or
+    /// `"uncompressed"`. The `zstd` and `lz4` codecs are not available when
+    /// compiled to WebAssembly.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use perspective_client::{View, ViewWindow};
+    /// # async fn run(view: View) -> Result<(), Box<dyn std::error::Error>> {
+    /// let window = ViewWindow {
+    ///     compression: Some("zstd(3)".into()),
+    ///     ..ViewWindow::default()
+    /// };
+    ///
+    /// std::fs::write("out.parquet", view.to_parquet(window).await?)?;
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "parquet")]
+    pub async fn to_parquet(&self, window: ViewWindow) -> ClientResult<Bytes> {
+        let compression = window.compression.clone();
+        let arrow = self
+            .to_arrow(ViewWindow {
+                compression: None,
+                emit_legacy_row_path_names: Some(
+                    window.emit_legacy_row_path_names.unwrap_or(false),
+                ),
+                ..window
+            })
+            .await?;
+
+        let (schema, batches) = crate::utils::arrow::read_arrow_ipc(&arrow)?;
+        let batches = batches
+            .iter()
+            .map(crate::utils::arrow::coerce_batch)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let schema = batches.first().map(|x| x.schema()).unwrap_or(schema);
+        let parquet =
+            crate::utils::parquet::write_parquet(schema, &batches, compression.as_deref())?;
+
+        Ok(parquet.into())
+    }
+
+    /// Deserializes the rows of this [`View`] into structs via `serde`, e.g. a
+    /// [`crate::PerspectiveRow`]. Columns not present in `T` are ignored,
+    /// unless `T` denies unknown fields.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// # use perspective_client::*;
+    /// # async fn run(view: View) -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(serde::Serialize, serde::Deserialize, PerspectiveRow)]
+    /// struct Row {
+    ///     x: i32,
+    /// }
+    ///
+    /// let rows: Vec<Row> = view.to_rows(ViewWindow::default()).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn to_rows<T: DeserializeOwned>(&self, window: ViewWindow) -> ClientResult<Vec<T>> {
+        let json = self.to_json_string(window).await?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
     /// Serializes this [`View`] to a string of JSON data. Useful if you want to
     /// save additional round trip serialize/deserialize cycles.    
     pub async fn to_columns_string(&self, window: ViewWindow) -> ClientResult<String> {
@@ -520,7 +814,9 @@ impl View {
     ///   parameter.
     /// - `options` - If this is provided as `OnUpdateOptions { mode:
     ///   Some(OnUpdateMode::Row) }`, then `delta` is an Arrow of the updated
-    ///   rows. Otherwise `delta` will be [`Option::None`].
+    ///   rows. With [`OnUpdateMode::Diff`], [`OnUpdateData::diff`] is the
+    ///   rows added, updated and removed. Otherwise `delta` will be
+    ///   [`Option::None`].
     pub async fn on_update<T, U>(&self, on_update: T, options: OnUpdateOptions) -> ClientResult<u32>
     where
         T: Fn(OnUpdateData) -> U + Send + Sync + 'static,
@@ -541,13 +837,61 @@ impl View {
         };
 
         let msg = self.client_message(ClientReq::ViewOnUpdateReq(ViewOnUpdateReq {
-            mode: options.mode.map(|OnUpdateMode::Row| Mode::Row as i32),
+            mode: options.mode.map(|x| Mode::from(x) as i32),
         }));
 
         self.client.subscribe(&msg, callback).await?;
         Ok(msg.msg_id)
     }
 
+    /// A [`Subscription`] to this [`View`]'s updates, like [`View::on_update`]
+    /// but unsubscribed automatically when dropped. `buffer` decides what
+    /// happens to updates which arrive faster than they are polled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use perspective_client::{BufferPolicy, OnUpdateOptions, View};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let view: View = todo!();
+    /// let mut updates = view
+    ///     .updates(OnUpdateOptions::default(), BufferPolicy::Bounded(64))
+    ///     .await?;
+    ///
+    /// while let Some(update) = updates.next().await.transpose()? {
+    ///     println!("Updated port {}", update.port_id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn updates(
+        &self,
+        options: OnUpdateOptions,
+        buffer: BufferPolicy,
+    ) -> ClientResult<Subscription<OnUpdateData>> {
+        let msg = self.client_message(ClientReq::ViewOnUpdateReq(ViewOnUpdateReq {
+            mode: options.mode.map(|x| Mode::from(x) as i32),
+        }));
+
+        let remove = self.client_message(ClientReq::ViewRemoveOnUpdateReq(ViewRemoveOnUpdateReq {
+            id: msg.msg_id,
+        }));
+
+        let (subscription, sender) = Subscription::new(&self.client, msg.msg_id, remove, buffer);
+        let sender = Arc::new(sender);
+        let callback = move |resp: Response| {
+            match resp.client_resp {
+                Some(ClientResp::ViewOnUpdateResp(resp)) => sender.send(OnUpdateData(resp)),
+                resp => sender.fail(resp.into()),
+            };
+
+            async { Ok(()) }
+        };
+
+        self.client.subscribe(&msg, callback).await?;
+        Ok(subscription)
+    }
+
     /// Unregister a previously registered update callback with this [`View`].
     ///
     /// # Arguments
@@ -14,26 +14,21 @@ use std::error::Error;
 use std::sync::Arc;
 
 use arrow_array::builder::{
-    BooleanBuilder, Float64Builder, Int32Builder, StringDictionaryBuilder,
+    BooleanBuilder, Decimal128Builder, Float64Builder, Int32Builder, StringDictionaryBuilder,
     TimestampMillisecondBuilder,
 };
-use arrow_array::cast::AsArray;
-use arrow_array::types::Int32Type;
+use arrow_array::types::{Decimal128Type, DecimalType, Int32Type};
 use arrow_array::{
-    Array, ArrayRef, BooleanArray, Date32Array, Date64Array, Decimal128Array, DictionaryArray,
-    Float16Array, Float32Array, Float64Array, Int8Array, Int16Array, Int32Array, Int64Array,
-    LargeStringArray, RecordBatch, RecordBatchOptions, StringArray, Time32MillisecondArray,
-    Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
-    TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt8Array,
-    UInt16Array, UInt32Array, UInt64Array,
+    Array, ArrayRef, BooleanArray, Date32Array, Decimal128Array, DictionaryArray, Float64Array,
+    Int32Array, Int64Array, RecordBatch, RecordBatchOptions, StringArray, Time64MicrosecondArray,
 };
-use arrow_ipc::reader::{FileReader, StreamReader};
 use arrow_ipc::writer::StreamWriter;
-use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use arrow_schema::{DECIMAL128_MAX_PRECISION, DataType, Field, Schema, TimeUnit};
 use indexmap::IndexMap;
 use serde::Serialize;
 
 use crate::config::{GroupRollupMode, Scalar, ViewConfig};
+use crate::utils::arrow::{coerce_column, dict_data_type, read_arrow_ipc, timestamp_to_millis};
 
 /// An Arrow column builder, used during the population phase of
 /// [`VirtualDataSlice`].
@@ -43,10 +38,10 @@ pub enum ColumnBuilder {
     Float(Float64Builder),
     Integer(Int32Builder),
     Datetime(TimestampMillisecondBuilder),
-}
 
-fn dict_data_type() -> DataType {
-    DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+    /// Unscaled values and their scale, which is fixed by the first non-null
+    /// [`VirtualDecimal`] written to the column.
+    Decimal(Decimal128Builder, Option<i8>),
 }
 
 /// Reads a cell from a canonical `Dictionary(Int32, Utf8)` column, or
@@ -88,9 +83,26 @@ pub enum VirtualDataCell {
     Float(Option<f64>),
     Integer(Option<i32>),
     Datetime(Option<i64>),
+    Decimal(Option<String>),
     RowPath(Vec<Scalar>),
 }
 
+/// A `DECIMAL` cell for [`VirtualDataSlice::set_col`], as an unscaled integer
+/// `value` and the number of digits of it after the decimal point, e.g.
+/// `12.50` is `VirtualDecimal { value: 1250, scale: 2 }`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VirtualDecimal {
+    pub value: i128,
+    pub scale: i8,
+}
+
+impl std::fmt::Display for VirtualDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = DECIMAL128_MAX_PRECISION;
+        f.write_str(&Decimal128Type::format_decimal(self.value, precision, self.scale))
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum RowPathStyle {
     /// Legacy: emit a single `__ROW_PATH__` sidecar (per-row nested
@@ -229,6 +241,35 @@ impl SetVirtualDataColumn for Option<i64> {
     }
 }
 
+impl SetVirtualDataColumn for Option<VirtualDecimal> {
+    fn write_to(self, col: &mut ColumnBuilder) -> Result<(), &'static str> {
+        if let ColumnBuilder::Decimal(builder, scale) = col {
+            match self {
+                Some(v) if *scale.get_or_insert(v.scale) == v.scale => {
+                    builder.append_value(v.value)
+                },
+                Some(_) => return Err("Mismatched decimal scale"),
+                None => builder.append_null(),
+            }
+            Ok(())
+        } else {
+            Err("Bad type")
+        }
+    }
+
+    fn new_builder() -> ColumnBuilder {
+        ColumnBuilder::Decimal(Decimal128Builder::new(), None)
+    }
+
+    fn to_scalar(self) -> Scalar {
+        if let Some(x) = self {
+            Scalar::Decimal(x.to_string())
+        } else {
+            Scalar::Null
+        }
+    }
+}
+
 impl SetVirtualDataColumn for Option<bool> {
     fn write_to(self, col: &mut ColumnBuilder) -> Result<(), &'static str> {
         if let ColumnBuilder::Boolean(builder) = col {
@@ -276,6 +317,7 @@ impl std::fmt::Debug for ColumnBuilder {
             ColumnBuilder::Float(_) => write!(f, "ColumnBuilder::Float(..)"),
             ColumnBuilder::Integer(_) => write!(f, "ColumnBuilder::Integer(..)"),
             ColumnBuilder::Datetime(_) => write!(f, "ColumnBuilder::Datetime(..)"),
+            ColumnBuilder::Decimal(..) => write!(f, "ColumnBuilder::Decimal(..)"),
         }
     }
 }
@@ -337,285 +379,20 @@ fn extract_scalar(array: &ArrayRef, row_idx: usize) -> Scalar {
             let arr = array.as_any().downcast_ref::<BooleanArray>().unwrap();
             Scalar::Bool(arr.value(row_idx))
         },
-        DataType::Timestamp(TimeUnit::Millisecond, _) => {
-            let arr = array
-                .as_any()
-                .downcast_ref::<TimestampMillisecondArray>()
-                .unwrap();
-            Scalar::Float(arr.value(row_idx) as f64)
-        },
+        DataType::Timestamp(..) => timestamp_to_millis(array.as_ref(), row_idx)
+            .map(|x| Scalar::Float(x as f64))
+            .unwrap_or(Scalar::Null),
         DataType::Date32 => {
             let arr = array.as_any().downcast_ref::<Date32Array>().unwrap();
             Scalar::Float(arr.value(row_idx) as f64 * 86_400_000.0)
         },
-        dt => {
-            tracing::error!("Non-canonical row path type {}", dt);
-            Scalar::Null
-        },
-    }
-}
-
-/// Coerces an Arrow column to Perspective-compatible types, optionally
-/// renaming.
-/// Manually converts a timestamp array of any unit to milliseconds.
-fn timestamp_to_millis(array: &ArrayRef, unit: &TimeUnit) -> ArrayRef {
-    let millis: TimestampMillisecondArray = match unit {
-        TimeUnit::Second => {
-            let arr = array
-                .as_any()
-                .downcast_ref::<TimestampSecondArray>()
-                .unwrap();
-            arr.iter().map(|v| v.map(|v| v * 1_000)).collect()
-        },
-        TimeUnit::Microsecond => {
-            let arr = array
-                .as_any()
-                .downcast_ref::<TimestampMicrosecondArray>()
-                .unwrap();
-            arr.iter().map(|v| v.map(|v| v / 1_000)).collect()
-        },
-        TimeUnit::Nanosecond => {
-            let arr = array
-                .as_any()
-                .downcast_ref::<TimestampNanosecondArray>()
-                .unwrap();
-            arr.iter().map(|v| v.map(|v| v / 1_000_000)).collect()
-        },
-        TimeUnit::Millisecond => {
-            let arr = array
-                .as_any()
-                .downcast_ref::<TimestampMillisecondArray>()
-                .unwrap();
-
-            return Arc::new(arr.clone().with_timezone_opt(None::<Arc<str>>)) as ArrayRef;
-        },
-    };
-    Arc::new(millis) as ArrayRef
-}
-
-fn coerce_column(
-    name: &str,
-    field: &Field,
-    array: &ArrayRef,
-) -> Result<(Field, ArrayRef), Box<dyn Error>> {
-    match field.data_type() {
-        DataType::Boolean | DataType::Float64 | DataType::Int32 | DataType::Date32 => Ok((
-            Field::new(name, field.data_type().clone(), true),
-            array.clone(),
-        )),
-        DataType::Dictionary(key, value) => {
-            if key.as_ref() == &DataType::Int32 && value.as_ref() == &DataType::Utf8 {
-                return Ok((Field::new(name, dict_data_type(), true), array.clone()));
-            }
-
-            let dict = array
-                .as_any_dictionary_opt()
-                .ok_or_else(|| format!("Column '{}' is not a dictionary array", name))?;
-
-            let values = arrow_select::take::take(dict.values(), dict.keys(), None)?;
-            let field = Field::new(name, values.data_type().clone(), true);
-            coerce_column(name, &field, &values)
-        },
-        DataType::Utf8 => {
-            let arr = array.as_any().downcast_ref::<StringArray>().unwrap();
-            let mut builder = StringDictionaryBuilder::<Int32Type>::new();
-            for i in 0..arr.len() {
-                if arr.is_null(i) {
-                    builder.append_null();
-                } else {
-                    builder.append_value(arr.value(i));
-                }
-            }
-            Ok((
-                Field::new(name, dict_data_type(), true),
-                Arc::new(builder.finish()) as ArrayRef,
-            ))
-        },
-        DataType::Timestamp(TimeUnit::Millisecond, None) => Ok((
-            Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
-            array.clone(),
-        )),
-        DataType::Int8 => {
-            let arr = array.as_any().downcast_ref::<Int8Array>().unwrap();
-            let result: Int32Array = arr.iter().map(|v| v.map(|v| v as i32)).collect();
-            Ok((
-                Field::new(name, DataType::Int32, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Int16 => {
-            let arr = array.as_any().downcast_ref::<Int16Array>().unwrap();
-            let result: Int32Array = arr.iter().map(|v| v.map(|v| v as i32)).collect();
-            Ok((
-                Field::new(name, DataType::Int32, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::UInt8 => {
-            let arr = array.as_any().downcast_ref::<UInt8Array>().unwrap();
-            let result: Int32Array = arr.iter().map(|v| v.map(|v| v as i32)).collect();
-            Ok((
-                Field::new(name, DataType::Int32, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::UInt16 => {
-            let arr = array.as_any().downcast_ref::<UInt16Array>().unwrap();
-            let result: Int32Array = arr.iter().map(|v| v.map(|v| v as i32)).collect();
-            Ok((
-                Field::new(name, DataType::Int32, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::UInt32 => {
-            let arr = array.as_any().downcast_ref::<UInt32Array>().unwrap();
-            let result: Int64Array = arr.iter().map(|v| v.map(|v| v as i64)).collect();
-            let result: Float64Array = result.iter().map(|v| v.map(|v| v as f64)).collect();
-            Ok((
-                Field::new(name, DataType::Float64, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Int64 => {
-            let arr = array.as_any().downcast_ref::<Int64Array>().unwrap();
-            let result: Float64Array = arr.iter().map(|v| v.map(|v| v as f64)).collect();
-            Ok((
-                Field::new(name, DataType::Float64, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::UInt64 => {
-            let arr = array.as_any().downcast_ref::<UInt64Array>().unwrap();
-            let result: Float64Array = arr.iter().map(|v| v.map(|v| v as f64)).collect();
-            Ok((
-                Field::new(name, DataType::Float64, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Float32 => {
-            let arr = array.as_any().downcast_ref::<Float32Array>().unwrap();
-            let result: Float64Array = arr.iter().map(|v| v.map(|v| v as f64)).collect();
-            Ok((
-                Field::new(name, DataType::Float64, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Float16 => {
-            let arr = array.as_any().downcast_ref::<Float16Array>().unwrap();
-            let result: Float64Array = arr.iter().map(|v| v.map(|v| v.to_f64())).collect();
-            Ok((
-                Field::new(name, DataType::Float64, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Decimal128(_, scale) => {
-            let scale = *scale;
+        DataType::Decimal128(..) => {
             let arr = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
-            let divisor = 10_f64.powi(scale as i32);
-            let result: Float64Array = arr.iter().map(|v| v.map(|v| v as f64 / divisor)).collect();
-            Ok((
-                Field::new(name, DataType::Float64, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Date64 => {
-            let arr = array.as_any().downcast_ref::<Date64Array>().unwrap();
-            let result: Date32Array = arr
-                .iter()
-                .map(|v| v.map(|v| (v / 86_400_000) as i32))
-                .collect();
-            Ok((
-                Field::new(name, DataType::Date32, true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Timestamp(unit, _) => {
-            let casted = timestamp_to_millis(array, unit);
-            Ok((
-                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
-                casted,
-            ))
-        },
-        DataType::Time32(TimeUnit::Second) => {
-            let arr = array.as_any().downcast_ref::<Time32SecondArray>().unwrap();
-            let result: TimestampMillisecondArray =
-                arr.iter().map(|v| v.map(|v| v as i64 * 1_000)).collect();
-            Ok((
-                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Time32(TimeUnit::Millisecond) => {
-            let arr = array
-                .as_any()
-                .downcast_ref::<Time32MillisecondArray>()
-                .unwrap();
-            let result: TimestampMillisecondArray =
-                arr.iter().map(|v| v.map(|v| v as i64)).collect();
-            Ok((
-                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Time64(TimeUnit::Microsecond) => {
-            let arr = array
-                .as_any()
-                .downcast_ref::<Time64MicrosecondArray>()
-                .unwrap();
-            let result: TimestampMillisecondArray =
-                arr.iter().map(|v| v.map(|v| v / 1_000)).collect();
-            Ok((
-                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::Time64(TimeUnit::Nanosecond) => {
-            let arr = array
-                .as_any()
-                .downcast_ref::<Time64NanosecondArray>()
-                .unwrap();
-            let result: TimestampMillisecondArray =
-                arr.iter().map(|v| v.map(|v| v / 1_000_000)).collect();
-            Ok((
-                Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
-                Arc::new(result) as ArrayRef,
-            ))
-        },
-        DataType::LargeUtf8 => {
-            let arr = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
-            let mut builder = StringDictionaryBuilder::<Int32Type>::new();
-            for i in 0..arr.len() {
-                if arr.is_null(i) {
-                    builder.append_null();
-                } else {
-                    builder.append_value(arr.value(i));
-                }
-            }
-            Ok((
-                Field::new(name, dict_data_type(), true),
-                Arc::new(builder.finish()) as ArrayRef,
-            ))
+            Scalar::Decimal(arr.value_as_string(row_idx))
         },
         dt => {
-            tracing::warn!(
-                "Coercing unknown Arrow type {} to Dictionary for column '{}'",
-                dt,
-                name
-            );
-            let num_rows = array.len();
-            let mut builder = StringDictionaryBuilder::<Int32Type>::new();
-            for i in 0..num_rows {
-                if array.is_null(i) {
-                    builder.append_null();
-                } else {
-                    let scalar_arr = array.slice(i, 1);
-                    builder.append_value(format!("{:?}", scalar_arr));
-                }
-            }
-            Ok((
-                Field::new(name, dict_data_type(), true),
-                Arc::new(builder.finish()) as ArrayRef,
-            ))
+            tracing::error!("Non-canonical row path type {}", dt);
+            Scalar::Null
         },
     }
 }
@@ -643,16 +420,7 @@ impl VirtualDataSlice {
     /// native `perspective-server`'s `to_arrow` output when
     /// `emit_legacy_row_path_names: false`.
     pub fn from_arrow_ipc(&mut self, ipc: &[u8]) -> Result<(), Box<dyn Error>> {
-        let cursor = std::io::Cursor::new(ipc);
-        let (ipc_schema, batches) = if &ipc[0..6] == "ARROW1".as_bytes() {
-            let reader = FileReader::try_new(cursor, None)?;
-            let schema = reader.schema();
-            (schema, reader.collect::<Result<Vec<_>, _>>()?)
-        } else {
-            let reader = StreamReader::try_new(cursor, None)?;
-            let schema = reader.schema();
-            (schema, reader.collect::<Result<Vec<_>, _>>()?)
-        };
+        let (ipc_schema, batches) = read_arrow_ipc(ipc)?;
 
         let batch = match batches.len() {
             0 => RecordBatch::new_empty(ipc_schema),
@@ -783,6 +551,17 @@ impl VirtualDataSlice {
                         Field::new(name, DataType::Timestamp(TimeUnit::Millisecond, None), true),
                         Arc::new(b.finish()),
                     ),
+                    ColumnBuilder::Decimal(b, scale) => {
+                        let array = b
+                            .finish()
+                            .with_precision_and_scale(DECIMAL128_MAX_PRECISION, scale.unwrap_or(0))
+                            .expect("Decimal scale should not exceed the maximum precision");
+
+                        (
+                            Field::new(name, array.data_type().clone(), true),
+                            Arc::new(array),
+                        )
+                    },
                 };
                 fields.push(field);
                 arrays.push(array);
@@ -863,6 +642,9 @@ impl VirtualDataSlice {
                             match rp[row_idx].get(level) {
                                 Some(Scalar::String(x)) => VirtualDataCell::String(Some(x.clone())),
                                 Some(Scalar::Float(x)) => VirtualDataCell::Float(Some(*x)),
+                                Some(Scalar::Decimal(x)) => {
+                                    VirtualDataCell::Decimal(Some(x.clone()))
+                                },
                                 Some(Scalar::Bool(x)) => VirtualDataCell::Boolean(Some(*x)),
                                 Some(Scalar::Null) | None => VirtualDataCell::String(None),
                             },
@@ -884,9 +666,8 @@ impl VirtualDataSlice {
                             },
                             DataType::Float64 => VirtualDataCell::Float(None),
                             DataType::Int32 => VirtualDataCell::Integer(None),
-                            DataType::Timestamp(TimeUnit::Millisecond, _) => {
-                                VirtualDataCell::Datetime(None)
-                            },
+                            DataType::Timestamp(..) => VirtualDataCell::Datetime(None),
+                            DataType::Decimal128(..) => VirtualDataCell::Decimal(None),
                             _ => continue,
                         }
                     } else {
@@ -922,26 +703,19 @@ impl VirtualDataSlice {
                                     .unwrap();
                                 VirtualDataCell::Float(Some(arr.value(row_idx) as f64))
                             },
-                            DataType::Timestamp(TimeUnit::Microsecond, _) => {
-                                let arr = col
-                                    .as_any()
-                                    .downcast_ref::<Time64MicrosecondArray>()
-                                    .unwrap();
-                                VirtualDataCell::Datetime(Some(arr.value(row_idx) * 1000))
-                            },
-                            DataType::Timestamp(TimeUnit::Millisecond, _) => {
-                                let arr = col
-                                    .as_any()
-                                    .downcast_ref::<TimestampMillisecondArray>()
-                                    .unwrap();
-                                VirtualDataCell::Datetime(Some(arr.value(row_idx)))
-                            },
+                            DataType::Timestamp(..) => VirtualDataCell::Datetime(
+                                timestamp_to_millis(col.as_ref(), row_idx),
+                            ),
                             DataType::Date32 => {
                                 let arr = col.as_any().downcast_ref::<Date32Array>().unwrap();
                                 VirtualDataCell::Datetime(Some(
                                     arr.value(row_idx) as i64 * 86_400_000,
                                 ))
                             },
+                            DataType::Decimal128(..) => {
+                                let arr = col.as_any().downcast_ref::<Decimal128Array>().unwrap();
+                                VirtualDataCell::Decimal(Some(arr.value_as_string(row_idx)))
+                            },
                             x => {
                                 tracing::error!("Unknown Arrow IPC type {}", x);
                                 continue;
@@ -1071,10 +845,21 @@ impl VirtualDataSlice {
                             .collect::<Vec<_>>(),
                     )?
                 },
-                DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                DataType::Timestamp(..) => serde_json::to_value(
+                    (0..num_rows)
+                        .map(|i| {
+                            if col.is_null(i) {
+                                None
+                            } else {
+                                timestamp_to_millis(col.as_ref(), i)
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                )?,
+                DataType::Time64(TimeUnit::Microsecond) => {
                     let arr = col
                         .as_any()
-                        .downcast_ref::<TimestampMillisecondArray>()
+                        .downcast_ref::<Time64MicrosecondArray>()
                         .unwrap();
                     serde_json::to_value(
                         (0..num_rows)
@@ -1082,38 +867,35 @@ impl VirtualDataSlice {
                                 if arr.is_null(i) {
                                     None
                                 } else {
-                                    Some(arr.value(i))
+                                    Some(arr.value(i) as f64)
                                 }
                             })
                             .collect::<Vec<_>>(),
                     )?
                 },
-                DataType::Time64(TimeUnit::Microsecond) => {
-                    let arr = col
-                        .as_any()
-                        .downcast_ref::<Time64MicrosecondArray>()
-                        .unwrap();
+                DataType::Date32 => {
+                    let arr = col.as_any().downcast_ref::<Date32Array>().unwrap();
                     serde_json::to_value(
                         (0..num_rows)
                             .map(|i| {
                                 if arr.is_null(i) {
                                     None
                                 } else {
-                                    Some(arr.value(i) as f64)
+                                    Some(arr.value(i) as i64 * 86_400_000)
                                 }
                             })
                             .collect::<Vec<_>>(),
                     )?
                 },
-                DataType::Date32 => {
-                    let arr = col.as_any().downcast_ref::<Date32Array>().unwrap();
+                DataType::Decimal128(..) => {
+                    let arr = col.as_any().downcast_ref::<Decimal128Array>().unwrap();
                     serde_json::to_value(
                         (0..num_rows)
                             .map(|i| {
                                 if arr.is_null(i) {
                                     None
                                 } else {
-                                    Some(arr.value(i) as i64 * 86_400_000)
+                                    Some(arr.value_as_string(i))
                                 }
                             })
                             .collect::<Vec<_>>(),
@@ -421,6 +421,7 @@ impl GenericSQLVirtualServerModel {
             Scalar::Bool(b) => Some(if *b { "TRUE" } else { "FALSE" }.to_string()),
             Scalar::Float(f) => Some(f.to_string()),
             Scalar::String(s) => Some(table_make_view::string_literal(s, backslash_escaped)),
+            Scalar::Decimal(d) => Some(d.clone()),
         }
     }
 }
@@ -22,7 +22,9 @@ mod generic_sql_model;
 mod handler;
 mod server;
 
-pub use data::{RowPathStyle, SetVirtualDataColumn, VirtualDataCell, VirtualDataSlice};
+pub use data::{
+    RowPathStyle, SetVirtualDataColumn, VirtualDataCell, VirtualDataSlice, VirtualDecimal,
+};
 pub use error::{ResultExt, VirtualServerError};
 pub use features::{AggSpec, Features, WindowAggSpec};
 pub use generic_sql_model::{
@@ -24,13 +24,13 @@ use crate::config::{ViewConfig, ViewConfigUpdate};
 use crate::proto::response::ClientResp;
 use crate::proto::table_validate_expr_resp::ExprValidationError;
 use crate::proto::{
-    ColumnType, GetFeaturesResp, GetHostedTablesResp, MakeTableResp, Request, Response,
+    BatchResp, ColumnType, GetFeaturesResp, GetHostedTablesResp, MakeTableResp, Request, Response,
     ServerError, TableMakePortResp, TableMakeViewResp, TableOnDeleteResp, TableRemoveDeleteResp,
-    TableSchemaResp, TableSizeResp, TableValidateExprResp, ViewColumnPathsResp, ViewDeleteResp,
-    ViewDimensionsResp, ViewExpressionSchemaResp, ViewGetConfigResp, ViewGetMinMaxResp,
-    ViewOnDeleteResp, ViewOnUpdateResp, ViewRemoveDeleteResp, ViewRemoveOnUpdateResp,
-    ViewSchemaResp, ViewToArrowResp, ViewToColumnsStringResp, ViewToCsvResp,
-    ViewToNdjsonStringResp, ViewToRowsStringResp,
+    TableSchemaResp, TableSizeResp, TableValidateExprResp, UnsubscribeResp, ViewColumnPathsResp,
+    ViewDeleteResp, ViewDimensionsResp, ViewExpressionSchemaResp, ViewGetConfigResp,
+    ViewGetMinMaxResp, ViewOnDeleteResp, ViewOnUpdateResp, ViewRemoveDeleteResp,
+    ViewRemoveOnUpdateResp, ViewSchemaResp, ViewToArrowPageResp, ViewToArrowResp,
+    ViewToColumnsStringResp, ViewToCsvResp, ViewToNdjsonStringResp, ViewToRowsStringResp,
 };
 
 macro_rules! respond {
@@ -94,7 +94,8 @@ impl<T: VirtualServerHandler> VirtualServer<T> {
                 tracing::error!("{}", err);
                 Ok(respond!(msg, ServerError {
                     message: err.to_string(),
-                    status_code: 0
+                    status_code: 0,
+                    row_errors: vec![]
                 }))
             },
         }
@@ -163,6 +164,7 @@ impl<T: VirtualServerHandler> VirtualServer<T> {
                             .map(|x| crate::proto::schema::KeyTypePair {
                                 name: x.0.to_string(),
                                 r#type: *x.1 as i32,
+                                params: None,
                             })
                             .collect()
                     })
@@ -243,7 +245,8 @@ impl<T: VirtualServerHandler> VirtualServer<T> {
                         .await?
                         .into_iter()
                         .map(|(x, y)| (x.to_string(), y as i32))
-                        .collect()
+                        .collect(),
+                    params: Default::default(),
                 })
             },
             ViewDimensionsReq(_) => {
@@ -335,6 +338,42 @@ impl<T: VirtualServerHandler> VirtualServer<T> {
 
                 respond!(msg, ViewToArrowResp { arrow })
             },
+            ViewToArrowPageReq(req) => {
+                let viewport = req.viewport.unwrap_or_default();
+                let num_rows = self.handler.view_size(msg.entity_id.as_str()).await?;
+                let start_row = viewport.start_row.unwrap_or_default();
+                let end_row = viewport.end_row.unwrap_or(num_rows).min(num_rows);
+                let page_end_row = if req.page_rows > 0 {
+                    end_row.min(start_row.saturating_add(req.page_rows))
+                } else {
+                    end_row
+                };
+
+                let viewport = crate::proto::ViewPort {
+                    start_row: Some(start_row),
+                    end_row: Some(page_end_row),
+                    ..viewport
+                };
+
+                let arrow = if start_row < page_end_row {
+                    let schema = self.get_cached_view_schema(&msg.entity_id, false).await?;
+                    let config = self.view_configs.get(&msg.entity_id).unwrap();
+                    let mut cols = self
+                        .handler
+                        .view_get_data(msg.entity_id.as_str(), config, &schema, &viewport)
+                        .await?;
+
+                    cols.render_to_arrow_ipc()
+                        .map_err(|e| VirtualServerError::Other(e.to_string()))?
+                } else {
+                    vec![]
+                };
+
+                respond!(msg, ViewToArrowPageResp {
+                    arrow,
+                    next_start_row: (page_end_row < end_row).then_some(page_end_row),
+                })
+            },
             ViewToCsvReq(view_to_csv_req) => {
                 let viewport = view_to_csv_req.viewport.unwrap();
                 let schema = self.get_cached_view_schema(&msg.entity_id, false).await?;
@@ -425,7 +464,7 @@ impl<T: VirtualServerHandler> VirtualServer<T> {
                 self.handler
                     .make_table(&msg.entity_id, req.data.as_ref().unwrap())
                     .await?;
-                respond!(msg, MakeTableResp {})
+                respond!(msg, MakeTableResp { row_errors: vec![] })
             },
             ViewGetMinMaxReq(req) => {
                 let config = self.view_configs.get(&msg.entity_id).unwrap();
@@ -446,7 +485,8 @@ impl<T: VirtualServerHandler> VirtualServer<T> {
             ViewOnUpdateReq(_) => {
                 respond!(msg, ViewOnUpdateResp {
                     delta: None,
-                    port_id: 0
+                    port_id: 0,
+                    diff: None
                 })
             },
             ViewOnDeleteReq(_) => {
@@ -461,6 +501,20 @@ impl<T: VirtualServerHandler> VirtualServer<T> {
             ViewRemoveDeleteReq(_) => {
                 respond!(msg, ViewRemoveDeleteResp {})
             },
+            UnsubscribeReq(_) => {
+                respond!(msg, UnsubscribeResp {})
+            },
+            BatchReq(req) => {
+                let mut responses = Vec::with_capacity(req.requests.len());
+                for sub_req in req.requests {
+                    let bytes = sub_req.encode_to_vec().into();
+                    let resp = Box::pin(self.handle_request(bytes)).await?;
+                    let resp = Response::decode(resp).map_err(VirtualServerError::DecodeError)?;
+                    responses.push(resp);
+                }
+
+                respond!(msg, BatchResp { responses })
+            },
             x => {
                 // Return an error response instead of empty bytes
                 return Err(VirtualServerError::Other(format!(
@@ -0,0 +1,297 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+//! Derive macros for the Perspective Rust client, re-exported by
+//! `perspective-client` with the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, LitStr, PathArguments, Type, parse_macro_input,
+};
+
+/// Derives `PerspectiveRow` for a struct with named fields, generating its
+/// Perspective schema from the field types.
+///
+/// Field types map to `ColumnType` as follows (`Option<T>` maps as `T`):
+///
+/// - `String`, `&str`, `char` → `string`
+/// - `bool` → `boolean`
+/// - `i8`, `i16`, `i32`, `u8`, `u16` → `integer`
+/// - `u32`, `i64`, `u64`, `isize`, `usize`, `f32`, `f64` → `float`
+/// - `chrono::NaiveDate` → `date`
+/// - `chrono::NaiveDateTime`, `chrono::DateTime<_>` → `datetime`
+///
+/// Column names follow the field's `#[serde(rename = "...")]`, if any. A
+/// `#[serde(rename(serialize = "...", deserialize = "..."))]` must use the same
+/// name for both, as rows are read back by the names they were loaded with.
+///
+/// # Attributes
+///
+/// - `#[perspective(index)]` on a field marks it as the `Table` index.
+/// - `#[perspective(type = "float")]` on a field overrides its `ColumnType`.
+/// - `#[perspective(skip)]` (or `#[serde(skip)]`) on a field omits it.
+/// - `#[perspective(crate = "perspective::client")]` on the struct overrides
+///   the path to `perspective_client`.
+#[proc_macro_derive(PerspectiveRow, attributes(perspective))]
+pub fn derive_perspective_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    index: bool,
+    skip: bool,
+    column_type: Option<LitStr>,
+    rename: Option<String>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut krate: syn::Path = syn::parse_quote!(::perspective_client);
+    for attr in &input.attrs {
+        if attr.path().is_ident("perspective") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    krate = meta.value()?.parse::<LitStr>()?.parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `perspective` attribute"))
+                }
+            })?;
+        } else if attr.path().is_ident("serde") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    Err(meta.error(
+                        "`PerspectiveRow` does not support `rename_all`, use `rename` on each \
+                         field instead",
+                    ))
+                } else {
+                    skip_meta_value(&meta)
+                }
+            })?;
+        }
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`PerspectiveRow` can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            data.fields.span(),
+            "`PerspectiveRow` requires a struct with named fields",
+        ));
+    };
+
+    let mut columns = vec![];
+    let mut index = None;
+    for field in &fields.named {
+        let attrs = parse_field_attrs(field)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let name = attrs.rename.unwrap_or_else(|| {
+            field
+                .ident
+                .as_ref()
+                .unwrap()
+                .to_string()
+                .trim_start_matches("r#")
+                .to_owned()
+        });
+
+        let column_type = match attrs.column_type {
+            Some(lit) => column_type_from_str(&lit)?,
+            None => column_type_from_type(&field.ty)?,
+        };
+
+        if attrs.index {
+            if index.is_some() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "only one field may be marked `#[perspective(index)]`",
+                ));
+            }
+
+            index = Some(name.clone());
+        }
+
+        columns.push(quote! {
+            (#name.to_owned(), #krate::proto::ColumnType::#column_type)
+        });
+    }
+
+    let index = match index {
+        Some(index) => quote! { ::std::option::Option::Some(#index) },
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::PerspectiveRow for #ident #ty_generics #where_clause {
+            fn schema() -> ::std::vec::Vec<(::std::string::String, #krate::proto::ColumnType)> {
+                ::std::vec![#(#columns),*]
+            }
+
+            fn index() -> ::std::option::Option<&'static str> {
+                #index
+            }
+        }
+    })
+}
+
+fn parse_field_attrs(field: &syn::Field) -> syn::Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+    for attr in &field.attrs {
+        if attr.path().is_ident("perspective") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("index") {
+                    attrs.index = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("type") {
+                    attrs.column_type = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `perspective` attribute"))
+                }
+            })?;
+        } else if attr.path().is_ident("serde") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") && meta.input.peek(syn::token::Paren) {
+                    // `rename(serialize = "...", deserialize = "...")`. Rows
+                    // are loaded by their serialized name and read back by
+                    // their deserialized one, so these must be the same.
+                    let field_name = field.ident.as_ref().map(|x| x.to_string());
+                    let mut serialize = field_name.clone();
+                    let mut deserialize = field_name;
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("serialize") {
+                            serialize = Some(meta.value()?.parse::<LitStr>()?.value());
+                            Ok(())
+                        } else if meta.path.is_ident("deserialize") {
+                            deserialize = Some(meta.value()?.parse::<LitStr>()?.value());
+                            Ok(())
+                        } else {
+                            skip_meta_value(&meta)
+                        }
+                    })?;
+
+                    if serialize != deserialize {
+                        return Err(meta.error(
+                            "`PerspectiveRow` requires the `serialize` and `deserialize` names \
+                             of `rename` to match",
+                        ));
+                    }
+
+                    attrs.rename = serialize;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    attrs.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                    attrs.skip = true;
+                    Ok(())
+                } else {
+                    skip_meta_value(&meta)
+                }
+            })?;
+        }
+    }
+
+    Ok(attrs)
+}
+
+/// Consumes the value of a `serde` attribute this macro doesn't interpret,
+/// e.g. `default` or `with = "..."`.
+fn skip_meta_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|m| skip_meta_value(&m))?;
+    }
+
+    Ok(())
+}
+
+fn column_type_from_str(lit: &LitStr) -> syn::Result<syn::Ident> {
+    let variant = match lit.value().as_str() {
+        "string" => "String",
+        "integer" => "Integer",
+        "float" => "Float",
+        "boolean" => "Boolean",
+        "date" => "Date",
+        "datetime" => "Datetime",
+        x => {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!("unknown Perspective type \"{}\"", x),
+            ));
+        },
+    };
+
+    Ok(syn::Ident::new(variant, lit.span()))
+}
+
+fn column_type_from_type(ty: &Type) -> syn::Result<syn::Ident> {
+    let variant = match ty {
+        Type::Reference(reference) => return column_type_from_type(&reference.elem),
+        Type::Group(group) => return column_type_from_type(&group.elem),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last().unwrap();
+            match segment.ident.to_string().as_str() {
+                "Option" => return column_type_from_type(single_type_argument(segment)?),
+                "String" | "str" | "char" => "String",
+                "bool" => "Boolean",
+                "i8" | "i16" | "i32" | "u8" | "u16" => "Integer",
+                "u32" | "i64" | "u64" | "isize" | "usize" | "f32" | "f64" => "Float",
+                "NaiveDate" => "Date",
+                "NaiveDateTime" | "DateTime" => "Datetime",
+                _ => "",
+            }
+        },
+        _ => "",
+    };
+
+    if variant.is_empty() {
+        Err(syn::Error::new(
+            ty.span(),
+            "unsupported field type for `PerspectiveRow`, annotate it with \
+             `#[perspective(type = \"...\")]` or `#[perspective(skip)]`",
+        ))
+    } else {
+        Ok(syn::Ident::new(variant, ty.span()))
+    }
+}
+
+fn single_type_argument(segment: &syn::PathSegment) -> syn::Result<&Type> {
+    if let PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(GenericArgument::Type(ty)) = args.args.first()
+    {
+        Ok(ty)
+    } else {
+        Err(syn::Error::new(segment.span(), "expected a type argument"))
+    }
+}
@@ -285,6 +285,25 @@ impl Client {
         Ok(())
     }
 
+    /// Enable (or disable) resilient mode, in which this [`Client`] tracks
+    /// the [`Table`]s, [`crate::view::View`]s and callbacks it creates, and
+    /// recreates them on the new server session after the `reconnect`
+    /// callback passed to [`Client::handle_error`] succeeds. Existing handles
+    /// and callback ids keep working across a server restart. Tables this
+    /// [`Client`] created are recreated with their updates; any other table
+    /// must be hosted by the new server under the same name.
+    ///
+    /// # JavaScript Examples
+    ///
+    /// ```javascript
+    /// client.set_resilient(true);
+    /// const table = await client.open_table("superstore");
+    /// ```
+    #[wasm_bindgen]
+    pub fn set_resilient(&self, enabled: bool) {
+        self.client.set_resilient(enabled);
+    }
+
     #[wasm_bindgen]
     pub async fn on_error(&self, callback: Function) -> ApiResult<u32> {
         let callback = JsReconnect::from(
@@ -102,6 +102,26 @@ impl Table {
         self.0.get_name().to_owned()
     }
 
+    /// Returns the values which could not be coerced to the type of their
+    /// column by the `client.table()` call which created this table, and were
+    /// loaded as `null` or skipped per the `on_bad_rows` option.
+    /// `update_with_errors()` returns the errors of an update instead.
+    ///
+    /// # JavaScript Examples
+    ///
+    /// ```javascript
+    /// const table = await client.table("x\n1\nabc", {
+    ///     schema_hints: { x: "integer" },
+    /// });
+    /// const errors = await table.row_errors(); // [{ row: 1, column: "x", ... }]
+    /// ```
+    #[wasm_bindgen(
+        unchecked_return_type = "{ row: number, column: string, value: string, message: string }[]"
+    )]
+    pub async fn row_errors(&self) -> ApiResult<JsValue> {
+        Ok(JsValue::from_serde_ext(&self.0.row_errors())?)
+    }
+
     /// Returns the user-specified row limit for this table.
     #[wasm_bindgen]
     pub async fn get_limit(&self) -> Option<u32> {
@@ -327,6 +347,38 @@ impl Table {
         })
     }
 
+    /// As [`Table::update`], but returns the values of `input` which could
+    /// not be coerced to the type of their column, in the same form as
+    /// [`Table::row_errors`].
+    ///
+    /// # JavaScript Examples
+    ///
+    /// ```javascript
+    /// const errors = await table.update_with_errors("x,y\n1,2", {
+    ///     on_bad_rows: "null",
+    /// });
+    /// ```
+    #[wasm_bindgen(
+        unchecked_return_type = "Promise<{ row: number, column: string, value: string, message: \
+                                 string }[]>"
+    )]
+    pub fn update_with_errors(
+        &self,
+        input: JsTableInitData,
+        options: Option<JsUpdateOptions>,
+    ) -> ApiFuture<JsValue> {
+        let table = self.clone();
+        ApiFuture::new(async move {
+            let options = options
+                .into_serde_ext::<Option<UpdateOptions>>()?
+                .unwrap_or_default();
+
+            let input = UpdateData::from_js_value(&input, options.format)?;
+            let row_errors = table.0.update_with_errors(input, options).await?;
+            Ok(JsValue::from_serde_ext(&row_errors)?)
+        })
+    }
+
     /// Create a new [`View`] from this table with a specified
     /// [`ViewConfigUpdate`].
     ///
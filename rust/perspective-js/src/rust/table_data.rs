@@ -12,31 +12,33 @@
 
 use extend::ext;
 use js_sys::{Array, ArrayBuffer, Function, JSON, Object, Reflect, Uint8Array};
-use perspective_client::config::ColumnType;
+use perspective_client::config::{ColumnType, ColumnTypeParams};
 use perspective_client::{TableData, TableReadFormat, UpdateData};
 use wasm_bindgen::convert::TryFromJsValue;
 use wasm_bindgen::intern;
 use wasm_bindgen::prelude::*;
 
 use crate::apierror;
-use crate::utils::{ApiError, ApiResult, JsValueSerdeExt};
+use crate::utils::{ApiError, ApiResult};
 pub use crate::view::*;
 
 #[ext]
-impl Vec<(String, ColumnType)> {
-    fn from_js_value(value: &JsValue) -> ApiResult<Vec<(String, ColumnType)>> {
-        Ok(Object::keys(value.unchecked_ref())
+impl Vec<(String, ColumnType, Option<ColumnTypeParams>)> {
+    fn from_js_value(
+        value: &JsValue,
+    ) -> ApiResult<Vec<(String, ColumnType, Option<ColumnTypeParams>)>> {
+        Object::keys(value.unchecked_ref())
             .iter()
-            .map(|x| -> Result<_, JsValue> {
+            .map(|x| -> ApiResult<_> {
                 let key = x.as_string().expect("Not string??");
                 let val = Reflect::get(value, &x)?
                     .as_string()
-                    .expect("Y no string?")
-                    .into_serde_ext()?;
+                    .expect("Y no string?");
 
-                Ok((key, val))
+                let (ty, params) = ColumnType::parse_with_params(&val)?;
+                Ok((key, ty, params))
             })
-            .collect::<Result<Vec<_>, _>>()?)
+            .collect()
     }
 }
 
@@ -70,7 +72,7 @@ pub(crate) impl TableData {
             };
 
             if all_strings() {
-                Ok(TableData::Schema(Vec::from_js_value(value)?))
+                Ok(TableData::SchemaWithParams(Vec::from_js_value(value)?))
             } else if all_arrays() {
                 let json = JSON::stringify(value)?
                     .as_string()
@@ -110,10 +112,21 @@ pub(crate) impl UpdateData {
                 Some(TableReadFormat::Ndjson) => Ok(Some(UpdateData::Ndjson(
                     value.as_string().expect("Ndjson???"),
                 ))),
+                Some(TableReadFormat::Parquet) => {
+                    Err(ApiError::new("Parquet input must be an ArrayBuffer or Uint8Array"))
+                },
             }
         } else if value.is_instance_of::<ArrayBuffer>() {
             let uint8array = Uint8Array::new(value);
             let slice = uint8array.to_vec();
+            if UpdateData::is_compressed(&slice) {
+                let format = format.unwrap_or(TableReadFormat::Arrow);
+                return Ok(Some(UpdateData::Compressed {
+                    format,
+                    data: slice.into(),
+                }));
+            }
+
             match format {
                 Some(TableReadFormat::Csv) => Ok(Some(UpdateData::Csv(String::from_utf8(slice)?))),
                 Some(TableReadFormat::JsonString) => {
@@ -125,10 +138,19 @@ pub(crate) impl UpdateData {
                 Some(TableReadFormat::Ndjson) => {
                     Ok(Some(UpdateData::Ndjson(String::from_utf8(slice)?)))
                 },
+                Some(TableReadFormat::Parquet) => Ok(Some(UpdateData::Parquet(slice.into()))),
                 None | Some(TableReadFormat::Arrow) => Ok(Some(UpdateData::Arrow(slice.into()))),
             }
         } else if let Some(uint8array) = value.dyn_ref::<Uint8Array>() {
             let slice = uint8array.to_vec();
+            if UpdateData::is_compressed(&slice) {
+                let format = format.unwrap_or(TableReadFormat::Arrow);
+                return Ok(Some(UpdateData::Compressed {
+                    format,
+                    data: slice.into(),
+                }));
+            }
+
             match format {
                 Some(TableReadFormat::Csv) => Ok(Some(UpdateData::Csv(String::from_utf8(slice)?))),
                 Some(TableReadFormat::JsonString) => {
@@ -140,6 +162,7 @@ pub(crate) impl UpdateData {
                 Some(TableReadFormat::Ndjson) => {
                     Ok(Some(UpdateData::Ndjson(String::from_utf8(slice)?)))
                 },
+                Some(TableReadFormat::Parquet) => Ok(Some(UpdateData::Parquet(slice.into()))),
                 None | Some(TableReadFormat::Arrow) => Ok(Some(UpdateData::Arrow(slice.into()))),
             }
         } else if value.is_instance_of::<Array>() {
@@ -152,7 +175,7 @@ pub(crate) impl UpdateData {
 
     fn from_js_value(value: &JsValue, format: Option<TableReadFormat>) -> ApiResult<UpdateData> {
         match TableData::from_js_value(value, format)? {
-            TableData::Schema(_) => Err(ApiError::new(
+            TableData::Schema(_) | TableData::SchemaWithParams(_) => Err(ApiError::new(
                 "Method cannot be called with `Schema` argument",
             )),
             TableData::Update(x) => Ok(x),
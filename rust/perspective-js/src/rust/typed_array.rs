@@ -49,6 +49,14 @@ impl From<TypedArrayWindow> for ViewWindow {
     }
 }
 
+/// The raw `i64` values of a timestamp column of unit `T`, with null slots
+/// zeroed.
+fn timestamp_values<T: ArrowPrimitiveType<Native = i64>>(col: &dyn arrow_array::Array) -> &[i64] {
+    let typed = col.as_primitive::<T>();
+    zero_invalid_slots(typed);
+    typed.values()
+}
+
 fn zero_invalid_slots<T: ArrowPrimitiveType>(arr: &PrimitiveArray<T>) {
     let Some(nulls) = arr.nulls() else { return };
     let ptr = arr.values().as_ptr() as *mut T::Native;
@@ -189,10 +197,24 @@ pub(crate) async fn decode_and_call(
                 js_values.set(col_idx as u32, arr.into());
                 js_dicts.set(col_idx as u32, JsValue::NULL);
             },
-            DataType::Timestamp(TimeUnit::Millisecond, _) => {
-                let typed = col.as_primitive::<TimestampMillisecondType>();
-                zero_invalid_slots(typed);
-                let vals: Box<[f64]> = typed.values().iter().map(|&v| v as f64).collect();
+            DataType::Timestamp(unit, _) => {
+                // Epoch milliseconds whatever the column's unit, fractional
+                // for finer units.
+                let (values, per_milli) = match unit {
+                    TimeUnit::Second => (timestamp_values::<TimestampSecondType>(col), 0.001),
+                    TimeUnit::Millisecond => {
+                        (timestamp_values::<TimestampMillisecondType>(col), 1.0)
+                    },
+                    TimeUnit::Microsecond => {
+                        (timestamp_values::<TimestampMicrosecondType>(col), 1_000.0)
+                    },
+                    TimeUnit::Nanosecond => (
+                        timestamp_values::<TimestampNanosecondType>(col),
+                        1_000_000.0,
+                    ),
+                };
+
+                let vals: Box<[f64]> = values.iter().map(|&v| v as f64 / per_milli).collect();
 
                 let arr = unsafe { js_sys::Float64Array::view(&vals) };
                 f64_storage.push(vals);
@@ -51,6 +51,7 @@ fn scalar_to_jsvalue(scalar: &perspective_client::config::Scalar) -> JsValue {
     match scalar {
         perspective_client::config::Scalar::Float(x) => JsValue::from_f64(*x),
         perspective_client::config::Scalar::String(x) => JsValue::from_str(x),
+        perspective_client::config::Scalar::Decimal(x) => JsValue::from_str(x),
         perspective_client::config::Scalar::Bool(x) => JsValue::from_bool(*x),
         perspective_client::config::Scalar::Null => JsValue::NULL,
     }
@@ -204,6 +205,19 @@ impl View {
             .unchecked_into())
     }
 
+    /// Serializes a [`View`] to the Apache Parquet file format. The
+    /// `compression` window field selects the Parquet codec, one of
+    /// `"snappy"` (the default), `"gzip"` (optionally with a level, e.g.
+    /// `"gzip(6)"`) or `"uncompressed"`.
+    #[wasm_bindgen]
+    pub async fn to_parquet(&self, window: Option<JsViewWindow>) -> ApiResult<ArrayBuffer> {
+        let window = window.into_serde_ext::<Option<ViewWindow>>()?;
+        let parquet = self.0.to_parquet(window.unwrap_or_default()).await?;
+        Ok(js_sys::Uint8Array::from(&parquet[..])
+            .buffer()
+            .unchecked_into())
+    }
+
     /// Serializes this [`View`] to a string of JSON data. Useful if you want to
     /// save additional round trip serialize/deserialize cycles.
     #[wasm_bindgen]
@@ -299,7 +313,10 @@ impl View {
     ///   parameter.
     /// - `options` - If this is provided as `OnUpdateOptions { mode:
     ///   Some(OnUpdateMode::Row) }`, then `delta` is an Arrow of the updated
-    ///   rows. Otherwise `delta` will be [`Option::None`].
+    ///   rows. With `{ mode: "diff" }`, `diff` holds Arrows of the `added`,
+    ///   `updated` and `removed` rows, with their `_keys`. With
+    ///   `{ mode: "none" }`, or otherwise, `delta` will be
+    ///   [`Option::None`].
     ///
     /// # JavaScript Examples
     ///
@@ -312,6 +329,13 @@ impl View {
     /// // `on_update` with row deltas
     /// view.on_update((updated) => console.log(updated.delta), { mode: "row" });
     /// ```
+    ///
+    /// ```javascript
+    /// // `on_update` with the added, updated and removed rows
+    /// view.on_update(({ diff }) => console.log(diff.removed_keys), {
+    ///     mode: "diff",
+    /// });
+    /// ```
     #[wasm_bindgen]
     pub fn on_update(
         &self,
@@ -320,6 +344,10 @@ impl View {
     ) -> ApiFuture<u32> {
         let poll_loop = LocalPollLoop::new(move |args: OnUpdateData| {
             let js_obj = JsValue::from_serde_ext(&*args)?;
+            if let Some(diff) = args.diff() {
+                js_sys::Reflect::set(&js_obj, &"diff".into(), &JsValue::from_serde_ext(&diff)?)?;
+            }
+
             on_update_js.call1(&JsValue::UNDEFINED, &js_obj)
         });
 
@@ -178,6 +178,102 @@ test.describe("Arrow", function () {
         });
     });
 
+    test.describe("preserve_arrow_types", function () {
+        function source() {
+            return arrow.tableToIPC(
+                arrow.tableFromArrays({
+                    s: arrow.vectorFromArray(
+                        ["a", "b", "a"],
+                        new arrow.LargeUtf8(),
+                    ),
+                    x: arrow.vectorFromArray([1, 2, 3], new arrow.Int8()),
+                    y: arrow.vectorFromArray(
+                        [1.5, 2.5, 3.5],
+                        new arrow.Float32(),
+                    ),
+                }),
+            );
+        }
+
+        async function arrow_types(view) {
+            const output = arrow.tableFromIPC(await view.to_arrow());
+            return Object.fromEntries(
+                output.schema.fields.map((f) => [f.name, f.type.toString()]),
+            );
+        }
+
+        test("Exports columns in their source Arrow types", async function () {
+            const table = await perspective.table(source(), {
+                preserve_arrow_types: true,
+            });
+
+            const view = await table.view();
+            expect(await arrow_types(view)).toEqual({
+                s: "LargeUtf8",
+                x: "Int8",
+                y: "Float32",
+            });
+
+            await view.delete();
+            await table.delete();
+        });
+
+        test("Exports strings as a dictionary without the option", async function () {
+            const table = await perspective.table(source());
+            const view = await table.view({ columns: ["s"] });
+            expect(await arrow_types(view)).toEqual({
+                s: "Dictionary<Int32, Utf8>",
+            });
+
+            await view.delete();
+            await table.delete();
+        });
+
+        test("Falls back for aggregates which do not pick a value", async function () {
+            const table = await perspective.table(source(), {
+                preserve_arrow_types: true,
+            });
+
+            const view = await table.view({
+                group_by: ["s"],
+                columns: ["x", "y"],
+                aggregates: { x: "mean", y: "max" },
+            });
+
+            expect(await arrow_types(view)).toEqual({
+                "s (Group by 1)": "LargeUtf8",
+                x: "Float64",
+                y: "Float32",
+            });
+
+            await view.delete();
+            await table.delete();
+        });
+
+        test("Keeps source Arrow types after an update", async function () {
+            const table = await perspective.table(source(), {
+                preserve_arrow_types: true,
+            });
+
+            await table.update([{ s: "c", x: 4, y: 4.5 }]);
+            const view = await table.view();
+            expect(await arrow_types(view)).toEqual({
+                s: "LargeUtf8",
+                x: "Int8",
+                y: "Float32",
+            });
+
+            expect(await view.to_columns()).toEqual({
+                s: ["a", "b", "a", "c"],
+                x: [1, 2, 3, 4],
+                y: [1.5, 2.5, 3.5, 4.5],
+            });
+
+            await view.delete();
+            await table.delete();
+        });
+    });
+
     test.describe("Malformed input", function () {
         test("Rejects an Arrow with a row count that exceeds 32 bits", async function () {
             const bytes = new Uint8Array(
@@ -1751,6 +1751,50 @@ class TestView(object):
         tbl.update(data)
         assert s.get() == 0
 
+    def test_view_on_update_mode_none(self, sentinel):
+        s = sentinel(False)
+
+        def callback(port_id):
+            s.set(True)
+
+        def row_callback(port_id, delta):
+            pass
+
+        data = [{"a": 1, "b": 2}, {"a": 3, "b": 4}]
+        tbl = Table(data)
+        view = tbl.view()
+        view.on_update(row_callback, mode="row")
+        view.on_update(callback, mode="none")
+        tbl.update(data)
+        assert s.get() is True
+
+    def test_view_on_update_mode_diff(self, sentinel):
+        s = sentinel(None)
+
+        def callback(port_id, diff):
+            s.set(diff)
+
+        tbl = Table({"a": [1, 2, 3], "b": ["x", "y", "z"]}, index="a")
+        view = tbl.view()
+        view.on_update(callback, mode="diff")
+        tbl.update({"a": [2, 4], "b": ["yy", "w"]})
+        diff = s.get()
+        assert diff["added_keys"] == [[4]]
+        assert diff["updated_keys"] == [[2]]
+        assert diff["removed_keys"] == []
+        assert Table(diff["added"]).view().to_columns() == {"a": [4], "b": ["w"]}
+        assert Table(diff["updated"]).view().to_columns() == {
+            "a": [2],
+            "b": ["yy"],
+        }
+
+        tbl.remove([3])
+        diff = s.get()
+        assert diff["added_keys"] == []
+        assert diff["updated_keys"] == []
+        assert diff["removed_keys"] == [[3]]
+        assert Table(diff["removed"]).view().to_columns() == {"a": [3], "b": ["z"]}
+
     # on_delete
 
     def test_view_on_delete(self, sentinel):
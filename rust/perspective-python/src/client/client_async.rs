@@ -16,7 +16,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use futures::FutureExt;
-use perspective_client::proto::ListFlatten;
+use perspective_client::proto::{ListFlatten, OnBadRows};
 use perspective_client::{
     Client, ColumnWindow, DeleteOptions, OnUpdateData, OnUpdateMode, OnUpdateOptions, Table,
     TableData, TableInitOptions, TableReadFormat, TableRef, UpdateData, UpdateOptions, View,
@@ -59,10 +59,21 @@ fn parse_list_flatten(value: Option<String>) -> PyResult<Option<ListFlatten>> {
         Some("zip") => Ok(Some(ListFlatten::Zip)),
         Some("cartesian") => Ok(Some(ListFlatten::Cartesian)),
         Some("stringify") => Ok(Some(ListFlatten::Stringify)),
+        Some("preserve") => Ok(Some(ListFlatten::Preserve)),
         Some(x) => Err(PyValueError::new_err(format!("Unknown `list_flatten`"))),
     }
 }
 
+fn parse_on_bad_rows(value: Option<String>) -> PyResult<Option<OnBadRows>> {
+    match value.as_deref() {
+        None => Ok(None),
+        Some("null") => Ok(Some(OnBadRows::Null)),
+        Some("skip") => Ok(Some(OnBadRows::Skip)),
+        Some("fail") => Ok(Some(OnBadRows::Fail)),
+        Some(x) => Err(PyValueError::new_err(format!("Unknown `on_bad_rows` {x}"))),
+    }
+}
+
 /// `perspective_server::Server`, whether locally in-memory or remote over some
 /// transport like a WebSocket.
 ///
@@ -189,7 +200,7 @@ impl AsyncClient {
     /// table = await client.table("x,y\n1,2\n3,4")
     /// ```
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature=(input, limit=None, index=None, name=None, format=None, page_to_disk=None, list_flatten=None))]
+    #[pyo3(signature=(input, limit=None, index=None, name=None, format=None, page_to_disk=None, list_flatten=None, struct_flatten=None, preserve_arrow_types=None, csv=None, schema_hints=None, on_bad_rows=None))]
     pub async fn table(
         &self,
         input: Py<PyAny>,
@@ -199,6 +210,11 @@ impl AsyncClient {
         format: Option<Py<PyString>>,
         page_to_disk: Option<bool>,
         list_flatten: Option<Py<PyString>>,
+        struct_flatten: Option<Py<PyDict>>,
+        preserve_arrow_types: Option<bool>,
+        csv: Option<Py<PyDict>>,
+        schema_hints: Option<Py<PyDict>>,
+        on_bad_rows: Option<Py<PyString>>,
     ) -> PyResult<AsyncTable> {
         let client = self.client.clone();
         let py_client = Python::with_gil(|_| self.clone());
@@ -207,6 +223,11 @@ impl AsyncClient {
                 name: name.map(|x| x.extract::<String>(py)).transpose()?,
                 page_to_disk,
                 list_flatten: parse_list_flatten(list_flatten.map(|x| x.to_string()))?,
+                struct_flatten: struct_flatten.map(|x| depythonize(x.bind(py))).transpose()?,
+                preserve_arrow_types,
+                csv: csv.map(|x| depythonize(x.bind(py))).transpose()?,
+                schema_hints: schema_hints.map(|x| depythonize(x.bind(py))).transpose()?,
+                on_bad_rows: parse_on_bad_rows(on_bad_rows.map(|x| x.to_string()))?,
                 ..TableInitOptions::default()
             };
 
@@ -422,6 +443,15 @@ impl AsyncTable {
         }
     }
 
+    /// Returns the values which could not be coerced to the type of their
+    /// column by the `table()` call which created this table, as a list of
+    /// `dict`s with `row`, `column`, `value` and `message` keys.
+    /// `update_with_errors()` returns the errors of an update instead.
+    pub fn row_errors(&self) -> PyResult<Py<PyAny>> {
+        let row_errors = self.table.row_errors();
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &row_errors)?.unbind()))
+    }
+
     /// Returns the user-specified row limit for this table.
     pub fn get_limit(&self) -> Option<u32> {
         self.table.get_limit()
@@ -589,13 +619,39 @@ impl AsyncTable {
     /// ```python
     /// await table.update("x,y\n1,2")
     /// ```
-    #[pyo3(signature=(input, port_id=None, format=None))]
+    #[pyo3(signature=(input, port_id=None, format=None, csv=None, on_bad_rows=None))]
     pub async fn update(
         &self,
         input: Py<PyAny>,
         port_id: Option<u32>,
         format: Option<String>,
+        csv: Option<Py<PyDict>>,
+        on_bad_rows: Option<String>,
     ) -> PyResult<()> {
+        self.update_with_errors(input, port_id, format, csv, on_bad_rows)
+            .await?;
+
+        Ok(())
+    }
+
+    /// As [`Table::update`], but returns the values of `input` which could
+    /// not be coerced to the type of their column, in the same form as
+    /// [`Table::row_errors`].
+    ///
+    /// # Python Examples
+    ///
+    /// ```python
+    /// errors = await table.update_with_errors("x,y\n1,2", on_bad_rows="null")
+    /// ```
+    #[pyo3(signature=(input, port_id=None, format=None, csv=None, on_bad_rows=None))]
+    pub async fn update_with_errors(
+        &self,
+        input: Py<PyAny>,
+        port_id: Option<u32>,
+        format: Option<String>,
+        csv: Option<Py<PyDict>>,
+        on_bad_rows: Option<String>,
+    ) -> PyResult<Py<PyAny>> {
         let input_data: Py<PyAny> = Python::with_gil(|py| {
             let input = input.into_bound(py);
             let data = if pyarrow::is_arrow_table(py, &input)? {
@@ -614,11 +670,18 @@ impl AsyncTable {
         let format = TableReadFormat::parse(format).map_err(PyPerspectiveError::new_err)?;
         let table_data =
             Python::with_gil(|py| UpdateData::from_py(input_data.into_bound(py), format))?;
-        let options = UpdateOptions { port_id, format };
-        AllowThreads(pin!(table.update(table_data, options)))
+        let csv = Python::with_gil(|py| csv.map(|x| depythonize(x.bind(py))).transpose())?;
+        let options = UpdateOptions {
+            port_id,
+            format,
+            csv,
+            on_bad_rows: parse_on_bad_rows(on_bad_rows)?,
+        };
+        let row_errors = AllowThreads(pin!(table.update_with_errors(table_data, options)))
             .await
             .into_pyerr()?;
-        Ok(())
+
+        Python::with_gil(|py| Ok(pythonize::pythonize(py, &row_errors)?.unbind()))
     }
 
     /// Validates the given expressions.
@@ -872,7 +935,10 @@ impl AsyncView {
     ///   parameter.
     /// - `options` - If this is provided as `OnUpdateOptions { mode:
     ///   Some(OnUpdateMode::Row) }`, then `delta` is an Arrow of the updated
-    ///   rows. Otherwise `delta` will be [`Option::None`].
+    ///   rows. With `mode="diff"`, the callback instead receives a `dict` of
+    ///   the `added`, `updated` and `removed` rows as Arrow, with their
+    ///   `added_keys`, `updated_keys` and `removed_keys`. With `mode="none"`,
+    ///   or otherwise, `delta` will be [`Option::None`].
     #[pyo3(signature=(callback, mode=None))]
     pub async fn on_update(&self, callback: Py<PyAny>, mode: Option<String>) -> PyResult<u32> {
         let callback = move |x: OnUpdateData| {
@@ -881,9 +947,13 @@ impl AsyncView {
                 let aggregate_errors: PyResult<()> = {
                     let callback = Python::with_gil(|py| Py::clone_ref(&callback, py));
                     Python::with_gil(|py| {
-                        match &x.delta {
-                            None => callback.call1(py, (x.port_id,))?,
-                            Some(delta) => {
+                        match (&x.delta, x.diff()) {
+                            (_, Some(diff)) => {
+                                let diff = pythonize::pythonize(py, &diff)?;
+                                callback.call1(py, (x.port_id, diff))?
+                            },
+                            (None, None) => callback.call1(py, (x.port_id,))?,
+                            (Some(delta), None) => {
                                 callback.call1(py, (x.port_id, PyBytes::new(py, delta)))?
                             },
                         };
@@ -948,6 +1018,16 @@ impl AsyncView {
         Ok(Python::with_gil(|py| PyBytes::new(py, &arrow).into()))
     }
 
+    /// Serializes a [`View`] to the Apache Parquet file format.
+    #[pyo3(signature=(**window))]
+    pub async fn to_parquet(&self, window: Option<Py<PyDict>>) -> PyResult<Py<PyBytes>> {
+        let window: ViewWindow = Python::with_gil(|py| window.map(|x| depythonize(x.bind(py))))
+            .transpose()?
+            .unwrap_or_default();
+        let parquet = self.view.to_parquet(window).await.into_pyerr()?;
+        Ok(Python::with_gil(|py| PyBytes::new(py, &parquet).into()))
+    }
+
     /// Serializes this [`View`] to CSV data in a standard format.
     #[pyo3(signature=(**window))]
     pub async fn to_csv(&self, window: Option<Py<PyDict>>) -> PyResult<String> {
@@ -56,6 +56,10 @@ pub(crate) fn scalar_to_py(py: Python<'_>, scalar: &Scalar) -> PyObject {
     match scalar {
         Scalar::Float(x) => x.into_pyobject(py).unwrap().into_any().unbind(),
         Scalar::String(x) => x.into_pyobject(py).unwrap().into_any().unbind(),
+        Scalar::Decimal(x) => PyModule::import(py, "decimal")
+            .and_then(|decimal| decimal.call_method1("Decimal", (x,)))
+            .map(|x| x.unbind())
+            .unwrap_or_else(|_| x.into_pyobject(py).unwrap().into_any().unbind()),
         Scalar::Bool(x) => x.into_pyobject(py).unwrap().to_owned().into_any().unbind(),
         Scalar::Null => py.None(),
     }
@@ -166,7 +170,7 @@ impl Client {
     /// table = client.table("x,y\n1,2\n3,4")
     /// ```
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, limit=None, index=None, name=None, format=None, page_to_disk=None, list_flatten=None))]
+    #[pyo3(signature = (input, limit=None, index=None, name=None, format=None, page_to_disk=None, list_flatten=None, struct_flatten=None, preserve_arrow_types=None, csv=None, schema_hints=None, on_bad_rows=None))]
     pub fn table(
         &self,
         py: Python<'_>,
@@ -177,6 +181,11 @@ impl Client {
         format: Option<Py<PyString>>,
         page_to_disk: Option<bool>,
         list_flatten: Option<Py<PyString>>,
+        struct_flatten: Option<Py<PyDict>>,
+        preserve_arrow_types: Option<bool>,
+        csv: Option<Py<PyDict>>,
+        schema_hints: Option<Py<PyDict>>,
+        on_bad_rows: Option<Py<PyString>>,
     ) -> PyResult<Table> {
         Ok(Table(
             self.0
@@ -188,6 +197,11 @@ impl Client {
                     format,
                     page_to_disk,
                     list_flatten,
+                    struct_flatten,
+                    preserve_arrow_types,
+                    csv,
+                    schema_hints,
+                    on_bad_rows,
                 )
                 .py_block_on(py)?,
         ))
@@ -341,6 +355,14 @@ impl Table {
         Client(self.0.get_client().py_block_on(py))
     }
 
+    /// Returns the values which could not be coerced to the type of their
+    /// column by the `table()` call which created this table, as a list of
+    /// `dict`s with `row`, `column`, `value` and `message` keys.
+    /// `update_with_errors()` returns the errors of an update instead.
+    pub fn row_errors(&self) -> PyResult<Py<PyAny>> {
+        self.0.row_errors()
+    }
+
     /// Returns the user-specified row limit for this table.
     pub fn get_limit(&self) -> Option<u32> {
         self.0.get_limit()
@@ -527,15 +549,37 @@ impl Table {
     /// - `options` - Options for this update step - see
     ///   [`perspective_client::UpdateOptions`].
     /// ```  
-    #[pyo3(signature = (input, port_id=None, format=None))]
+    #[pyo3(signature = (input, port_id=None, format=None, csv=None, on_bad_rows=None))]
     pub fn update(
         &self,
         py: Python<'_>,
         input: Py<PyAny>,
         port_id: Option<u32>,
         format: Option<String>,
+        csv: Option<Py<PyDict>>,
+        on_bad_rows: Option<String>,
     ) -> PyResult<()> {
-        self.0.update(input, port_id, format).py_block_on(py)
+        self.0
+            .update(input, port_id, format, csv, on_bad_rows)
+            .py_block_on(py)
+    }
+
+    /// As [`Table::update`], but returns the values of `input` which could
+    /// not be coerced to the type of their column, in the same form as
+    /// [`Table::row_errors`].
+    #[pyo3(signature = (input, port_id=None, format=None, csv=None, on_bad_rows=None))]
+    pub fn update_with_errors(
+        &self,
+        py: Python<'_>,
+        input: Py<PyAny>,
+        port_id: Option<u32>,
+        format: Option<String>,
+        csv: Option<Py<PyDict>>,
+        on_bad_rows: Option<String>,
+    ) -> PyResult<Py<PyAny>> {
+        self.0
+            .update_with_errors(input, port_id, format, csv, on_bad_rows)
+            .py_block_on(py)
     }
 }
 
@@ -678,6 +722,17 @@ impl View {
         self.0.to_arrow(window).py_block_on(py)
     }
 
+    /// Renders this [`View`] as an Apache Parquet file. The `compression`
+    /// window field selects the Parquet codec, e.g. `"zstd(3)"`.
+    ///
+    /// # Arguments
+    ///
+    /// - `window` - a [`ViewWindow`]
+    #[pyo3(signature = (**window))]
+    pub fn to_parquet(&self, py: Python<'_>, window: Option<Py<PyDict>>) -> PyResult<Py<PyBytes>> {
+        self.0.to_parquet(window).py_block_on(py)
+    }
+
     /// Delete this [`View`] and clean up all resources associated with it.
     /// [`View`] objects do not stop consuming resources or processing
     /// updates when they are garbage collected - you must call this method
@@ -797,7 +852,10 @@ impl View {
     ///   parameter.
     /// - `options` - If this is provided as `OnUpdateOptions { mode:
     ///   Some(OnUpdateMode::Row) }`, then `delta` is an Arrow of the updated
-    ///   rows. Otherwise `delta` will be [`Option::None`].
+    ///   rows. With `mode="diff"`, the callback instead receives a `dict` of
+    ///   the `added`, `updated` and `removed` rows as Arrow, with their
+    ///   `added_keys`, `updated_keys` and `removed_keys`. With `mode="none"`,
+    ///   or otherwise, `delta` will be [`Option::None`].
     #[pyo3(signature = (callback, mode=None))]
     pub fn on_update(
         &self,
@@ -10,7 +10,7 @@
 // ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use perspective_client::config::ColumnType;
+use perspective_client::config::{ColumnType, ColumnTypeParams};
 use perspective_client::{TableData, TableReadFormat, UpdateData};
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
@@ -19,12 +19,15 @@ use pyo3::types::{PyAny, PyAnyMethods, PyDict, PyList, PyString, PyType};
 use super::update_data::UpdateDataExt;
 use crate::py_err::ResultTClientErrorExt;
 
-fn psp_type_from_py_type(_py: Python<'_>, val: Bound<'_, PyAny>) -> PyResult<ColumnType> {
+fn psp_type_from_py_type(
+    _py: Python<'_>,
+    val: Bound<'_, PyAny>,
+) -> PyResult<(ColumnType, Option<ColumnTypeParams>)> {
     if let Ok(pystr) = val.downcast::<PyString>() {
-        ColumnType::try_from(pystr.to_string_lossy().as_ref()).into_pyerr()
+        ColumnType::parse_with_params(pystr.to_string_lossy().as_ref()).into_pyerr()
     } else if let Ok(val) = val.downcast::<PyType>() {
         let (module, typename) = (val.module()?, val.name()?);
-        match (
+        let ty = match (
             module.to_string_lossy().as_ref(),
             typename.to_string_lossy().as_ref(),
         ) {
@@ -35,7 +38,9 @@ fn psp_type_from_py_type(_py: Python<'_>, val: Bound<'_, PyAny>) -> PyResult<Col
             ("datetime", "date") => Ok(ColumnType::Date),
             ("datetime", "datetime") => Ok(ColumnType::Datetime),
             (modname, typename) => Err(PyTypeError::new_err(format!("{modname}.{typename}"))),
-        }
+        }?;
+
+        Ok((ty, None))
     } else {
         Err(PyTypeError::new_err(format!(
             "Unknown schema type {:?}",
@@ -57,10 +62,11 @@ fn from_dict(py: Python<'_>, pydict: &Bound<'_, PyDict>) -> Result<TableData, Py
     } else {
         let mut schema = vec![];
         for (key, val) in pydict.into_iter() {
-            schema.push((key.extract::<String>()?, psp_type_from_py_type(py, val)?));
+            let (ty, params) = psp_type_from_py_type(py, val)?;
+            schema.push((key.extract::<String>()?, ty, params));
         }
 
-        Ok(TableData::Schema(schema))
+        Ok(TableData::SchemaWithParams(schema))
     }
 }
 
@@ -24,6 +24,13 @@ fn from_arrow(
     // TODO need to explicitly qualify this b/c bug in
     // rust-analyzer - should be: just `pybytes.as_bytes()`.
     let vec = pyo3::prelude::PyBytesMethods::as_bytes(pybytes).to_vec();
+    if UpdateData::is_compressed(&vec) {
+        let format = format.unwrap_or(TableReadFormat::Arrow);
+        return Ok(Some(UpdateData::Compressed {
+            format,
+            data: vec.into(),
+        }));
+    }
 
     match format {
         Some(TableReadFormat::Csv) => Ok(Some(UpdateData::Csv(String::from_utf8(vec)?))),
@@ -34,6 +41,7 @@ fn from_arrow(
         Some(TableReadFormat::ColumnsString) => {
             Ok(Some(UpdateData::JsonColumns(String::from_utf8(vec)?)))
         },
+        Some(TableReadFormat::Parquet) => Ok(Some(UpdateData::Parquet(vec.into()))),
         None | Some(TableReadFormat::Arrow) => Ok(Some(UpdateData::Arrow(vec.into()))),
     }
 }
@@ -49,6 +57,7 @@ fn from_string(
         Some(TableReadFormat::Ndjson) => Ok(Some(UpdateData::Ndjson(string))),
         Some(TableReadFormat::ColumnsString) => Ok(Some(UpdateData::JsonColumns(string))),
         Some(TableReadFormat::Arrow) => Ok(Some(UpdateData::Arrow(string.into_bytes().into()))),
+        Some(TableReadFormat::Parquet) => Err(PyValueError::new_err("Parquet input must be bytes")),
     }
 }
 
@@ -47,6 +47,7 @@ function(psp_build_dep name cmake_file)
         set(ARROW_CSV ON)
         set(ARROW_LZ4 ON)
         set(ARROW_WITH_ZSTD ON)
+        set(ARROW_WITH_ZLIB ON)
         set(ARROW_WITH_LZ4 ON)
         set(ARROW_NO_EXPORT ON)
         set(ARROW_CXXFLAGS " -Wno-documentation ")
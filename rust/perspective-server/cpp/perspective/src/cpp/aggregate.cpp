@@ -36,10 +36,7 @@ t_aggregate::init() {
         case AGGTYPE_PCT_SUM_GRAND_TOTAL: {
             switch (m_icolumns[0]->get_dtype()) {
                 case DTYPE_INT64: {
-                    build_aggregate<t_aggimpl_sum<
-                        std::int64_t,
-                        std::int64_t,
-                        std::int64_t>>();
+                    build_aggregate<t_aggimpl_checked_sum>();
                 } break;
                 case DTYPE_INT32: {
                     build_aggregate<t_aggimpl_sum<
@@ -250,6 +250,9 @@ t_aggspec::agg_str() const {
         case AGGTYPE_GMV: {
             return "gmv";
         }
+        case AGGTYPE_UNION: {
+            return "union";
+        }
         default: {
             PSP_COMPLAIN_AND_ABORT("Unknown agg type");
             return "unknown";
@@ -390,7 +393,8 @@ t_aggspec::get_output_specs(const t_schema& schema) const {
         case AGGTYPE_WEIGHTED_MEAN: {
             return mk_col_name_type_vec(name(), DTYPE_F64PAIR);
         }
-        case AGGTYPE_JOIN: {
+        case AGGTYPE_JOIN:
+        case AGGTYPE_UNION: {
             return mk_col_name_type_vec(name(), DTYPE_STR);
         }
         case AGGTYPE_SCALED_DIV:
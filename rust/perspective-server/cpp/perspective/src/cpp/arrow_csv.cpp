@@ -16,6 +16,7 @@
 #include <arrow/util/value_parsing.h>
 #include <arrow/io/memory.h>
 #include <arrow/csv/reader.h>
+#include <arrow/compute/cast.h>
 
 template <class TimePoint>
 static inline arrow::TimestampType::c_type
@@ -586,10 +587,15 @@ std::vector<std::shared_ptr<arrow::TimestampParser>> DATE_READERS{
 
 std::optional<int64_t>
 parseAsArrowTimestamp(const std::string& input) {
+    return parseAsArrowTimestamp(input, arrow::TimeUnit::MILLI);
+}
+
+std::optional<int64_t>
+parseAsArrowTimestamp(const std::string& input, arrow::TimeUnit::type unit) {
     for (const auto& candidate : DATE_PARSERS) {
         int64_t datetime;
         if (candidate->operator()(
-                input.c_str(), input.size(), arrow::TimeUnit::MILLI, &datetime
+                input.c_str(), input.size(), unit, &datetime
             )) {
             return datetime;
         }
@@ -602,7 +608,8 @@ std::shared_ptr<::arrow::Table>
 csvToTable(
     const std::string_view& csv,
     bool is_update,
-    std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>& schema
+    std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>& schema,
+    const t_csv_options& options
 ) {
     const arrow::io::IOContext& io_context = arrow::io::default_io_context();
     auto input = std::make_shared<arrow::io::BufferReader>(csv);
@@ -614,15 +621,50 @@ csvToTable(
 #else
     read_options.use_threads = false;
 #endif
+    read_options.column_names = options.column_names;
     parse_options.newlines_in_values = true;
-
-    if (is_update) {
-        convert_options.column_types = std::move(schema);
-        convert_options.timestamp_parsers = DATE_READERS;
-    } else {
-        convert_options.timestamp_parsers = DATE_PARSERS;
+    parse_options.delimiter = options.delimiter;
+    parse_options.quote_char = options.quote;
+    if (options.escape.has_value()) {
+        parse_options.escaping = true;
+        parse_options.escape_char = *options.escape;
+    }
+
+    if (!options.null_values.empty()) {
+        convert_options.null_values = options.null_values;
+        convert_options.strings_can_be_null = true;
+    }
+
+    std::vector<std::shared_ptr<arrow::TimestampParser>> parsers;
+    if (options.datetime_format.has_value()) {
+        parsers.push_back(
+            arrow::TimestampParser::MakeStrptime(*options.datetime_format)
+        );
+    }
+
+    // Arrow only parses `date32`/`date64` columns as ISO dates, so with an
+    // explicit `date_format` these are read as timestamps and cast back.
+    std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>
+        date_columns;
+    if (options.date_format.has_value()) {
+        parsers.push_back(
+            arrow::TimestampParser::MakeStrptime(*options.date_format)
+        );
+
+        for (auto& [name, type] : schema) {
+            if (type->id() == arrow::Type::DATE32
+                || type->id() == arrow::Type::DATE64) {
+                date_columns[name] = type;
+                type = arrow::timestamp(arrow::TimeUnit::MILLI);
+            }
+        }
     }
 
+    const auto& defaults = is_update ? DATE_READERS : DATE_PARSERS;
+    parsers.insert(parsers.end(), defaults.begin(), defaults.end());
+    convert_options.timestamp_parsers = std::move(parsers);
+    convert_options.column_types = std::move(schema);
+
     auto maybe_reader = arrow::csv::TableReader::Make(
         io_context, input, read_options, parse_options, convert_options
     );
@@ -633,7 +675,30 @@ csvToTable(
     if (!maybe_table.ok()) {
         PSP_COMPLAIN_AND_ABORT(maybe_table.status().ToString());
     }
-    return *maybe_table;
+
+    std::shared_ptr<arrow::Table> table = *maybe_table;
+    for (const auto& [name, type] : date_columns) {
+        const int idx = table->schema()->GetFieldIndex(name);
+        if (idx == -1) {
+            continue;
+        }
+
+        auto maybe_cast = arrow::compute::Cast(table->column(idx), type);
+        if (!maybe_cast.ok()) {
+            PSP_COMPLAIN_AND_ABORT(maybe_cast.status().ToString());
+        }
+
+        auto maybe_set = table->SetColumn(
+            idx, arrow::field(name, type), (*maybe_cast).chunked_array()
+        );
+        if (!maybe_set.ok()) {
+            PSP_COMPLAIN_AND_ABORT(maybe_set.status().ToString());
+        }
+
+        table = *maybe_set;
+    }
+
+    return table;
 }
 
 } // namespace perspective::apachearrow
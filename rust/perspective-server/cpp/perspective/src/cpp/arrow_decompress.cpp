@@ -0,0 +1,179 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/base.h>
+#include <perspective/arrow_decompress.h>
+#include <arrow/io/compressed.h>
+#include <arrow/io/memory.h>
+#include <arrow/util/compression.h>
+#include <algorithm>
+#include <cstdint>
+#include <cstring>
+#include <optional>
+#include <sstream>
+
+namespace perspective {
+namespace apachearrow {
+
+    static constexpr std::int64_t READ_CHUNK_SIZE = 1 << 20;
+
+    static bool
+    has_magic(const std::string& data, const char* magic, std::size_t len) {
+        return data.size() >= len && std::memcmp(data.data(), magic, len) == 0;
+    }
+
+    /**
+     * @brief The decompressed size `data` declares in its header or trailer,
+     * if any: the zstd frame content size, or the gzip `ISIZE` trailer (the
+     * size modulo 2^32). This is only a hint, which may be absent or forged,
+     * so the size of the output is still checked as it is decompressed.
+     */
+    static std::optional<std::uint64_t>
+    declared_size(const std::string& data, arrow::Compression::type codec) {
+        const auto* bytes = reinterpret_cast<const std::uint8_t*>(data.data());
+        const auto read_le = [&](std::size_t offset, std::size_t len) {
+            std::uint64_t value = 0;
+            for (std::size_t i = 0; i < len; ++i) {
+                value |= std::uint64_t{bytes[offset + i]} << (8 * i);
+            }
+
+            return value;
+        };
+
+        if (codec == arrow::Compression::GZIP) {
+            // A gzip member is at least a 10 byte header and 8 byte trailer.
+            if (data.size() < 18) {
+                return std::nullopt;
+            }
+
+            return read_le(data.size() - 4, 4);
+        }
+
+        // zstd frame header: magic (4), descriptor (1), then an optional
+        // window descriptor (1), dictionary id (0-4) and content size (0-8).
+        if (data.size() < 5) {
+            return std::nullopt;
+        }
+
+        const auto descriptor = bytes[4];
+        const auto fcs_flag = descriptor >> 6;
+        const bool single_segment = ((descriptor >> 5) & 1) != 0;
+        static constexpr std::size_t DICT_ID_SIZES[] = {0, 1, 2, 4};
+        static constexpr std::size_t FCS_SIZES[] = {0, 2, 4, 8};
+        const auto fcs_size =
+            fcs_flag == 0 && single_segment ? 1 : FCS_SIZES[fcs_flag];
+
+        if (fcs_size == 0) {
+            return std::nullopt;
+        }
+
+        const auto offset =
+            5 + (single_segment ? 0 : 1) + DICT_ID_SIZES[descriptor & 3];
+
+        if (data.size() < offset + fcs_size) {
+            return std::nullopt;
+        }
+
+        // The 2 byte field is offset by 256, per RFC 8878.
+        return read_le(offset, fcs_size) + (fcs_size == 2 ? 256 : 0);
+    }
+
+    static std::optional<arrow::Compression::type>
+    detect_compression(const std::string& data) {
+        if (has_magic(data, "\x1f\x8b", 2)) {
+            return arrow::Compression::GZIP;
+        }
+
+        if (has_magic(data, "\x28\xb5\x2f\xfd", 4)) {
+            return arrow::Compression::ZSTD;
+        }
+
+        return std::nullopt;
+    }
+
+    static void
+    abort_too_large(std::uint64_t max_size) {
+        std::stringstream ss;
+        ss << "Compressed input exceeds the maximum decompressed size of "
+           << max_size << " bytes";
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    std::string
+    decompress(const std::string& data, std::uint64_t max_size) {
+        auto compression = detect_compression(data);
+        if (!compression.has_value()) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Compressed input is neither gzip nor zstd"
+            );
+        }
+
+        const auto declared = declared_size(data, *compression);
+        if (declared.has_value() && *declared > max_size) {
+            abort_too_large(max_size);
+        }
+
+        auto codec = arrow::util::Codec::Create(*compression);
+        if (!codec.ok()) {
+            std::stringstream ss;
+            ss << "Failed to create codec: " << codec.status().message();
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+
+        auto source = std::make_shared<arrow::io::BufferReader>(
+            std::string_view(data)
+        );
+
+        auto stream =
+            arrow::io::CompressedInputStream::Make(codec->get(), source);
+        if (!stream.ok()) {
+            std::stringstream ss;
+            ss << "Failed to decompress input: " << stream.status().message();
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+
+        // Reserve the declared size up front, but no more than deflate's
+        // maximum ratio of ~1032:1 allows, so a forged header can't make a
+        // small payload allocate up to `max_size`.
+        std::string out;
+        if (declared.has_value()) {
+            out.reserve(std::min<std::uint64_t>(*declared, data.size() * 1032));
+        }
+
+        while (true) {
+            auto chunk = (*stream)->Read(READ_CHUNK_SIZE);
+            if (!chunk.ok()) {
+                std::stringstream ss;
+                ss << "Failed to decompress input: "
+                   << chunk.status().message();
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+
+            if ((*chunk)->size() == 0) {
+                break;
+            }
+
+            if (out.size() + (*chunk)->size() > max_size) {
+                abort_too_large(max_size);
+            }
+
+            out.append(
+                reinterpret_cast<const char*>((*chunk)->data()),
+                (*chunk)->size()
+            );
+        }
+
+        return out;
+    }
+
+} // namespace apachearrow
+} // namespace perspective
@@ -15,15 +15,20 @@
 #include <arrow/array/array_binary.h>
 #include <arrow/array/array_nested.h>
 #include <arrow/array/array_primitive.h>
+#include <arrow/array/builder_binary.h>
+#include <arrow/array/builder_primitive.h>
+#include <arrow/compute/cast.h>
 #include <arrow/type.h>
 #include <arrow/type_fwd.h>
 #include <algorithm>
+#include <cerrno>
 #include <cstdint>
 #include <exception>
 #include <limits>
 #include <memory>
 #include <mutex>
 #include <perspective/arrow_loader.h>
+#include <perspective/list.h>
 #include "perspective/exception.h"
 #include <sstream>
 #include <type_traits>
@@ -146,7 +151,7 @@ ArrowLoader::ArrowLoader(ArrowLoader&&) noexcept = default;
 t_dtype
 convert_type(const std::string& src) {
     if (src == "dictionary" || src == "utf8" || src == "binary"
-        || src == "large_utf8") {
+        || src == "large_utf8" || src == "utf8_view") {
         return DTYPE_STR;
     }
     if (src == "bool") {
@@ -208,7 +213,10 @@ convert_type(const std::string& src) {
 
 void
 ArrowLoader::initialize(
-    const std::uint8_t* ptr, const uint32_t length, t_list_flatten mode
+    const std::uint8_t* ptr,
+    const uint32_t length,
+    t_list_flatten mode,
+    const t_struct_flatten& structs
 ) {
     if (std::memcmp("ARROW1", (const void*)ptr, 6) == 0) {
         load_file(ptr, length, m_table);
@@ -221,19 +229,73 @@ ArrowLoader::initialize(
         PSP_COMPLAIN_AND_ABORT(validation.ToString());
     }
 
+    m_mode = mode;
     if (!normalize_table_is_noop(*m_table, mode)) {
-        m_expanded = normalize_table_expands(*m_table, mode);
+        m_expanded = normalize_table_expands(*m_table, mode, structs);
         m_normalized = std::make_unique<t_normalized_table>(
-            normalize_table(m_table, mode)
+            normalize_table(m_table, mode, structs)
         );
     }
 
+    apply_schema_hints();
     for (const auto& field : fields()) {
-        m_names.push_back(field->name());
-        m_types.push_back(convert_type(field->type()->name()));
+        push_field(field);
     }
 }
 
+void
+ArrowLoader::push_field(const std::shared_ptr<arrow::Field>& field) {
+    m_names.push_back(field->name());
+    if (field->type()->id() == arrow::Type::TIMESTAMP) {
+        const auto& timestamp_type =
+            static_cast<const arrow::TimestampType&>(*field->type());
+        if (timestamp_type.unit() != arrow::TimeUnit::MILLI
+            || !timestamp_type.timezone().empty()) {
+            m_timestamp_params[field->name()] = t_timestamp_params{
+                from_arrow_time_unit(timestamp_type.unit()),
+                timestamp_type.timezone()
+            };
+        }
+    }
+
+    if (m_exact_decimals && field->type()->id() == arrow::Type::DECIMAL128) {
+        const auto& decimal_type =
+            static_cast<const arrow::Decimal128Type&>(*field->type());
+        if (is_exact_decimal(decimal_type.precision(), decimal_type.scale())) {
+            m_types.push_back(DTYPE_INT64);
+            m_decimal_params[field->name()] = t_decimal_params{
+                static_cast<std::uint32_t>(decimal_type.precision()),
+                static_cast<std::uint32_t>(decimal_type.scale())
+            };
+
+            return;
+        }
+    }
+
+    if (m_mode == LIST_FLATTEN_PRESERVE) {
+        const auto id = field->type()->id();
+        if (id == arrow::Type::LIST || id == arrow::Type::LARGE_LIST) {
+            const auto element = list_element_dtype(
+                *static_cast<const arrow::BaseListType&>(*field->type())
+                     .value_type()
+            );
+
+            if (element.has_value()) {
+                m_types.push_back(DTYPE_STR);
+                m_list_params[field->name()] = t_list_params{*element};
+                return;
+            }
+        }
+    }
+
+    // A hinted column's type is the hint's, not its source's.
+    if (m_schema_hints.count(field->name()) == 0) {
+        m_arrow_types[field->name()] = field->type();
+    }
+
+    m_types.push_back(convert_type(field->type()->name()));
+}
+
 const std::vector<std::shared_ptr<arrow::Field>>&
 ArrowLoader::fields() const {
     return m_normalized ? m_normalized->fields : m_table->schema()->fields();
@@ -244,13 +306,24 @@ ArrowLoader::init_csv(
     const std::string_view& csv,
     bool is_update,
     std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>&
-        psp_schema
+        psp_schema,
+    const t_csv_options& options
 ) {
-    m_table = deduplicate_table(csvToTable(csv, is_update, psp_schema));
+    // Hinted columns are read as text, and coerced by `apply_schema_hints`.
+    for (const auto& [name, _] : m_schema_hints) {
+        psp_schema[name] = arrow::utf8();
+    }
+
+    m_table =
+        deduplicate_table(csvToTable(csv, is_update, psp_schema, options));
+    apply_schema_hints();
     for (const auto& field : fields()) {
-        m_names.push_back(field->name());
-        m_types.push_back(convert_type(field->type()->name()));
+        push_field(field);
     }
+
+    // The unit of an inferred CSV timestamp is an artifact of which parser
+    // matched, not a property of the data.
+    m_timestamp_params.clear();
 }
 
 void
@@ -678,6 +751,17 @@ copy_array_impl(
                 dest->set_nth(offset + i, elem);
             }
         } break;
+        case arrow::StringViewType::type_id: {
+            std::shared_ptr<arrow::StringViewArray> scol =
+                std::static_pointer_cast<arrow::StringViewArray>(src);
+
+            std::string elem;
+
+            for (std::int64_t i = 0; i < len; ++i) {
+                elem.assign(scol->GetView(gather[i]));
+                dest->set_nth(offset + i, elem);
+            }
+        } break;
         case arrow::BinaryType::type_id:
         case arrow::StringType::type_id: {
             std::shared_ptr<arrow::StringArray> scol =
@@ -857,6 +941,351 @@ copy_array(
     copy_array_impl(dest, src, offset, len, t_identity_gather{});
 }
 
+/**
+ * Rescale a `Decimal128` at `from_scale` to an unscaled `params` decimal,
+ * rounding half away from zero when the scale shrinks.
+ */
+static std::optional<std::int64_t>
+rescale_decimal128(
+    arrow::Decimal128 value,
+    std::int32_t from_scale,
+    const t_decimal_params& params
+) {
+    const std::int32_t delta =
+        static_cast<std::int32_t>(params.scale) - from_scale;
+    if (delta < 0) {
+        value = value.ReduceScaleBy(-delta, true);
+    } else if (delta > 0) {
+        // Check before scaling up, so the multiply cannot overflow 128 bits.
+        const std::int32_t headroom =
+            static_cast<std::int32_t>(params.precision) - delta;
+        if (headroom <= 0 ? value != arrow::Decimal128(0)
+                          : !value.FitsInPrecision(headroom)) {
+            return std::nullopt;
+        }
+
+        value = value.IncreaseScaleBy(delta);
+    }
+
+    if (!value.FitsInPrecision(static_cast<std::int32_t>(params.precision))) {
+        return std::nullopt;
+    }
+
+    return static_cast<std::int64_t>(value.low_bits());
+}
+
+/**
+ * Scale an integer up to an unscaled `params` decimal.
+ */
+template <typename T>
+static std::optional<std::int64_t>
+integer_to_decimal(T value, const t_decimal_params& params) {
+    const auto bound = static_cast<std::uint64_t>(
+        decimal_pow10(params.precision - params.scale)
+    );
+
+    std::uint64_t magnitude;
+    if constexpr (std::is_signed_v<T>) {
+        magnitude = value < 0 ? ~static_cast<std::uint64_t>(value) + 1
+                              : static_cast<std::uint64_t>(value);
+    } else {
+        magnitude = static_cast<std::uint64_t>(value);
+    }
+
+    if (magnitude >= bound) {
+        return std::nullopt;
+    }
+
+    return static_cast<std::int64_t>(value) * decimal_pow10(params.scale);
+}
+
+/**
+ * Write the non-null rows of `src` into the `DECIMAL` column `dest` as
+ * unscaled values, aborting on the first value which does not fit `params`.
+ */
+template <typename GATHER, typename CONVERT>
+static void
+fill_decimal_rows(
+    const std::shared_ptr<t_column>& dest,
+    const std::shared_ptr<arrow::Array>& src,
+    const std::string& name,
+    const t_decimal_params& params,
+    const int64_t offset,
+    const int64_t len,
+    const GATHER& gather,
+    const CONVERT& convert
+) {
+    for (int64_t i = 0; i < len; ++i) {
+        if (gather.is_null(i) || src->IsNull(gather[i])) {
+            continue;
+        }
+
+        const auto unscaled = convert(gather[i]);
+        if (!unscaled.has_value()) {
+            auto scalar = src->GetScalar(gather[i]);
+            std::stringstream ss;
+            ss << "Value `" << (scalar.ok() ? (*scalar)->ToString() : "")
+               << "` does not fit DECIMAL(" << params.precision << ", "
+               << params.scale << ") column `" << name << "`\n";
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+
+        dest->set_nth<std::int64_t>(offset + i, *unscaled);
+    }
+}
+
+#define FILL_DECIMAL_INTEGER(ARROW_TYPE)                                       \
+    {                                                                          \
+        auto scol = std::static_pointer_cast<ARROW_TYPE>(src);                 \
+        fill_decimal_rows(                                                     \
+            dest, src, name, params, offset, len, gather, [&](int64_t j) {     \
+                return integer_to_decimal(scol->Value(j), params);             \
+            }                                                                  \
+        );                                                                     \
+    }
+
+/**
+ * Fill the `DECIMAL` column `dest` from any numeric or string array.
+ */
+template <typename GATHER>
+static void
+copy_decimal_array(
+    const std::shared_ptr<t_column>& dest,
+    const std::shared_ptr<arrow::Array>& src,
+    const std::string& name,
+    const t_decimal_params& params,
+    const int64_t offset,
+    const int64_t len,
+    const GATHER& gather
+) {
+    switch (src->type_id()) {
+        case arrow::Type::DECIMAL128: {
+            auto scol = std::static_pointer_cast<arrow::Decimal128Array>(src);
+            const auto scale =
+                static_cast<const arrow::Decimal128Type&>(*src->type()).scale();
+            fill_decimal_rows(
+                dest,
+                src,
+                name,
+                params,
+                offset,
+                len,
+                gather,
+                [&](int64_t j) {
+                    return rescale_decimal128(
+                        arrow::Decimal128(scol->GetValue(j)), scale, params
+                    );
+                }
+            );
+        } break;
+        case arrow::Type::INT8: {
+            FILL_DECIMAL_INTEGER(arrow::Int8Array);
+        } break;
+        case arrow::Type::UINT8: {
+            FILL_DECIMAL_INTEGER(arrow::UInt8Array);
+        } break;
+        case arrow::Type::INT16: {
+            FILL_DECIMAL_INTEGER(arrow::Int16Array);
+        } break;
+        case arrow::Type::UINT16: {
+            FILL_DECIMAL_INTEGER(arrow::UInt16Array);
+        } break;
+        case arrow::Type::INT32: {
+            FILL_DECIMAL_INTEGER(arrow::Int32Array);
+        } break;
+        case arrow::Type::UINT32: {
+            FILL_DECIMAL_INTEGER(arrow::UInt32Array);
+        } break;
+        case arrow::Type::INT64: {
+            FILL_DECIMAL_INTEGER(arrow::Int64Array);
+        } break;
+        case arrow::Type::UINT64: {
+            FILL_DECIMAL_INTEGER(arrow::UInt64Array);
+        } break;
+        case arrow::Type::FLOAT: {
+            auto scol = std::static_pointer_cast<arrow::FloatArray>(src);
+            fill_decimal_rows(
+                dest,
+                src,
+                name,
+                params,
+                offset,
+                len,
+                gather,
+                [&](int64_t j) {
+                    return double_to_decimal(scol->Value(j), params);
+                }
+            );
+        } break;
+        case arrow::Type::DOUBLE: {
+            auto scol = std::static_pointer_cast<arrow::DoubleArray>(src);
+            fill_decimal_rows(
+                dest,
+                src,
+                name,
+                params,
+                offset,
+                len,
+                gather,
+                [&](int64_t j) {
+                    return double_to_decimal(scol->Value(j), params);
+                }
+            );
+        } break;
+        case arrow::Type::STRING: {
+            auto scol = std::static_pointer_cast<arrow::StringArray>(src);
+            fill_decimal_rows(
+                dest,
+                src,
+                name,
+                params,
+                offset,
+                len,
+                gather,
+                [&](int64_t j) {
+                    return parse_decimal(scol->GetView(j), params);
+                }
+            );
+        } break;
+        case arrow::Type::LARGE_STRING: {
+            auto scol = std::static_pointer_cast<arrow::LargeStringArray>(src);
+            fill_decimal_rows(
+                dest,
+                src,
+                name,
+                params,
+                offset,
+                len,
+                gather,
+                [&](int64_t j) {
+                    return parse_decimal(scol->GetView(j), params);
+                }
+            );
+        } break;
+        default: {
+            std::stringstream ss;
+            ss << "Could not fill DECIMAL column `" << name
+               << "` from Arrow column of type `" << src->type()->ToString()
+               << "`\n";
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+    }
+}
+
+#undef FILL_DECIMAL_INTEGER
+
+/**
+ * Copy a `Timestamp` array of any unit into a `DTYPE_TIME` column stored in
+ * `unit`.
+ */
+template <typename GATHER>
+static void
+copy_timestamp_array(
+    const std::shared_ptr<t_column>& dest,
+    const std::shared_ptr<arrow::Array>& src,
+    t_timestamp_unit unit,
+    std::int64_t offset,
+    std::int64_t len,
+    const GATHER& gather
+) {
+    const auto& type = static_cast<const arrow::TimestampType&>(*src->type());
+    const auto from = from_arrow_time_unit(type.unit());
+    const std::int64_t* vals =
+        std::static_pointer_cast<arrow::TimestampArray>(src)->raw_values();
+
+    for (std::int64_t i = 0; i < len; ++i) {
+        dest->set_nth<std::int64_t>(
+            offset + i, convert_timestamp(vals[gather[i]], from, unit)
+        );
+    }
+}
+
+/**
+ * Fill the `LIST` column `dest` with the encoded text of each list of `src`,
+ * which is an Arrow `LIST` or `LARGE_LIST`, or a string column of JSON arrays
+ * as read from CSV.
+ */
+template <typename GATHER>
+static void
+copy_list_array(
+    const std::shared_ptr<t_column>& dest,
+    const std::shared_ptr<arrow::Array>& src,
+    const std::string& name,
+    const t_list_params& params,
+    std::int64_t offset,
+    std::int64_t len,
+    const GATHER& gather
+) {
+    const auto invalid = [&](std::int64_t j) {
+        auto scalar = src->GetScalar(j);
+        std::stringstream ss;
+        ss << "Value `" << (scalar.ok() ? (*scalar)->ToString() : "")
+           << "` is not a LIST(" << list_element_name(params.element)
+           << ") for column `" << name << "`\n";
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    };
+
+    if (src->null_count() == src->length()) {
+        return;
+    }
+
+    switch (src->type_id()) {
+        case arrow::Type::LIST:
+        case arrow::Type::LARGE_LIST: {
+            for (std::int64_t i = 0; i < len; ++i) {
+                if (gather.is_null(i) || src->IsNull(gather[i])) {
+                    continue;
+                }
+
+                const auto j = gather[i];
+                const auto values = src->type_id() == arrow::Type::LIST
+                    ? static_cast<const arrow::ListArray&>(*src).value_slice(j)
+                    : static_cast<const arrow::LargeListArray&>(*src)
+                          .value_slice(j);
+
+                const auto encoded = encode_arrow_list(*values, params);
+                if (!encoded.has_value()) {
+                    invalid(j);
+                }
+
+                dest->set_nth(offset + i, encoded->c_str());
+            }
+        } break;
+        case arrow::Type::STRING:
+        case arrow::Type::LARGE_STRING: {
+            for (std::int64_t i = 0; i < len; ++i) {
+                if (gather.is_null(i) || src->IsNull(gather[i])) {
+                    continue;
+                }
+
+                const auto j = gather[i];
+                const auto view = src->type_id() == arrow::Type::STRING
+                    ? static_cast<const arrow::StringArray&>(*src).GetView(j)
+                    : static_cast<const arrow::LargeStringArray&>(*src)
+                          .GetView(j);
+
+                const rapidjson::Value text(
+                    rapidjson::StringRef(view.data(), view.size())
+                );
+
+                const auto encoded = encode_list(text, params);
+                if (!encoded.has_value()) {
+                    invalid(j);
+                }
+
+                dest->set_nth(offset + i, encoded->c_str());
+            }
+        } break;
+        default: {
+            std::stringstream ss;
+            ss << "Could not fill LIST column `" << name
+               << "` from Arrow column of type `" << src->type()->ToString()
+               << "`\n";
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+    }
+}
+
 // Defines the full matrix of type interactions between arrow arrays and
 // schema-defined tables.
 #define FILL_COLUMN_ITER(ARRAY_TYPE)                                           \
@@ -958,6 +1387,9 @@ void fill_column_chunk(
     const std::shared_ptr<arrow::Array>& array,
     const std::string& name,
     t_dtype type,
+    const t_decimal_params* decimal,
+    t_timestamp_unit time_unit,
+    const t_list_params* list,
     std::int64_t offset,
     std::int64_t len,
     bool is_update,
@@ -993,6 +1425,30 @@ ArrowLoader::fill_column(
         return;
     }
 
+    const t_decimal_params* decimal = nullptr;
+    if (col->get_dtype() == DTYPE_INT64) {
+        const auto it = m_decimal_params.find(name);
+        if (it != m_decimal_params.end()) {
+            decimal = &it->second;
+        }
+    }
+
+    t_timestamp_unit time_unit = TIMESTAMP_UNIT_MILLISECOND;
+    if (col->get_dtype() == DTYPE_TIME) {
+        const auto it = m_timestamp_params.find(name);
+        if (it != m_timestamp_params.end()) {
+            time_unit = it->second.unit;
+        }
+    }
+
+    const t_list_params* list = nullptr;
+    if (col->get_dtype() == DTYPE_STR) {
+        const auto it = m_list_params.find(name);
+        if (it != m_list_params.end()) {
+            list = &it->second;
+        }
+    }
+
     if (indices != nullptr) {
         if (carray->num_chunks() == 0 || carray->chunk(0)->length() == 0) {
             for (std::size_t i = 0; i < indices->size(); ++i) {
@@ -1011,6 +1467,9 @@ ArrowLoader::fill_column(
             carray->chunk(0),
             name,
             type,
+            decimal,
+            time_unit,
+            list,
             0,
             static_cast<std::int64_t>(indices->size()),
             is_update,
@@ -1025,7 +1484,17 @@ ArrowLoader::fill_column(
         std::shared_ptr<arrow::Array> array = carray->chunk(i);
         int64_t len = array->length();
         fill_column_chunk(
-            col, array, name, type, offset, len, is_update, t_identity_gather{}
+            col,
+            array,
+            name,
+            type,
+            decimal,
+            time_unit,
+            list,
+            offset,
+            len,
+            is_update,
+            t_identity_gather{}
         );
 
         offset += len;
@@ -1039,6 +1508,9 @@ fill_column_chunk(
     const std::shared_ptr<arrow::Array>& array,
     const std::string& name,
     t_dtype type,
+    const t_decimal_params* decimal,
+    t_timestamp_unit time_unit,
+    const t_list_params* list,
     std::int64_t offset,
     std::int64_t len,
     bool is_update,
@@ -1052,7 +1524,29 @@ fill_column_chunk(
 
         // `type`: arrow array dtype converted to `t_dtype`
         // `column_dtype`: dtype of the `t_column`
-        if (type != column_dtype) {
+        if (decimal != nullptr) {
+            copy_decimal_array(col, array, name, *decimal, offset, len, gather);
+        } else if (list != nullptr) {
+            copy_list_array(col, array, name, *list, offset, len, gather);
+        } else if (column_dtype == DTYPE_TIME
+                   && time_unit != TIMESTAMP_UNIT_MILLISECOND
+                   && array->type_id() == arrow::Type::TIMESTAMP) {
+            copy_timestamp_array(col, array, time_unit, offset, len, gather);
+        } else if (array->type_id() == arrow::Type::DECIMAL128) {
+            // `type` may be the unscaled `DTYPE_INT64` of an exact decimal,
+            // so the only non-decimal column it converts into is a float.
+            if (column_dtype == DTYPE_FLOAT64) {
+                copy_array_impl(col, array, offset, len, gather);
+                fill_validity(col, array, offset, len, is_update, gather);
+                return;
+            }
+
+            std::stringstream ss;
+            ss << "Could not fill column `" << name << "` with "
+               << "t_dtype: `" << get_dtype_descr(column_dtype) << "`, "
+               << "array type: `" << array->type()->ToString() << "`\n";
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        } else if (type != column_dtype) {
             LOG_DEBUG(
                 "Type " << type << " != " << column_dtype << " for column "
                         << name << " - filling iteratively"
@@ -1148,6 +1642,370 @@ ArrowLoader::repeated_index(const std::string& index) const {
     return std::nullopt;
 }
 
+const t_decimal_map&
+ArrowLoader::decimal_params() const {
+    return m_decimal_params;
+}
+
+void
+ArrowLoader::set_exact_decimals(bool exact_decimals) {
+    m_exact_decimals = exact_decimals;
+}
+
+void
+ArrowLoader::set_decimal_params(const t_decimal_map& params) {
+    m_decimal_params = params;
+}
+
+const t_timestamp_map&
+ArrowLoader::timestamp_params() const {
+    return m_timestamp_params;
+}
+
+void
+ArrowLoader::set_timestamp_params(const t_timestamp_map& params) {
+    m_timestamp_params = params;
+}
+
+const t_list_map&
+ArrowLoader::list_params() const {
+    return m_list_params;
+}
+
+void
+ArrowLoader::set_list_params(const t_list_map& params) {
+    m_list_params = params;
+}
+
+const t_arrow_type_map&
+ArrowLoader::arrow_types() const {
+    return m_arrow_types;
+}
+
+void
+ArrowLoader::set_schema_hints(const t_schema_hints& hints) {
+    m_schema_hints = hints;
+}
+
+const t_row_errors&
+ArrowLoader::row_errors() const {
+    return m_row_errors;
+}
+
+/**
+ * The Arrow type a column hinted as `dtype` is converted to, which `push_field`
+ * reads back as `dtype`.
+ */
+static std::shared_ptr<arrow::DataType>
+hint_arrow_type(t_dtype dtype) {
+    switch (dtype) {
+        case DTYPE_STR:
+            return arrow::utf8();
+        case DTYPE_INT32:
+            return arrow::int32();
+        case DTYPE_FLOAT64:
+            return arrow::float64();
+        case DTYPE_BOOL:
+            return arrow::boolean();
+        case DTYPE_DATE:
+            return arrow::date32();
+        case DTYPE_TIME:
+            return arrow::timestamp(arrow::TimeUnit::MILLI);
+        default: {
+            std::stringstream ss;
+            ss << "Cannot hint a column as " << dtype_to_str(dtype);
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+            return nullptr;
+        }
+    }
+}
+
+/**
+ * The text of value `i` of `array`, i.e. a string's own contents, or the
+ * rendering of any other value.
+ */
+static std::string
+arrow_raw_text(const arrow::Array& array, std::int64_t i) {
+    switch (array.type_id()) {
+        case arrow::Type::STRING:
+            return std::string(
+                static_cast<const arrow::StringArray&>(array).GetView(i)
+            );
+        case arrow::Type::LARGE_STRING:
+            return std::string(
+                static_cast<const arrow::LargeStringArray&>(array).GetView(i)
+            );
+        case arrow::Type::STRING_VIEW:
+            return std::string(
+                static_cast<const arrow::StringViewArray&>(array).GetView(i)
+            );
+        default: {
+            const auto scalar = array.GetScalar(i);
+            return scalar.ok() ? (*scalar)->ToString() : std::string{};
+        }
+    }
+}
+
+/**
+ * Build `type` from the text of each value of `array`, parsed by `parse`,
+ * which is the path taken when Arrow can't cast `array` as a whole. Values
+ * whose text `parse` rejects are null, and recorded in `failures` by their
+ * index in the column.
+ */
+template <typename BUILDER, typename PARSE>
+static std::shared_ptr<arrow::Array>
+coerce_array(
+    const arrow::Array& array,
+    const std::shared_ptr<arrow::DataType>& type,
+    t_dtype dtype,
+    std::int64_t offset,
+    std::map<std::int64_t, std::string>& failures,
+    PARSE&& parse
+) {
+    BUILDER builder(type, arrow::default_memory_pool());
+    for (std::int64_t i = 0; i < array.length(); ++i) {
+        if (array.IsNull(i)) {
+            (void)builder.AppendNull();
+            continue;
+        }
+
+        auto text = arrow_raw_text(array, i);
+        if (text.empty() && dtype != DTYPE_STR) {
+            (void)builder.AppendNull();
+            continue;
+        }
+
+        const auto value = parse(text);
+        if (value.has_value()) {
+            (void)builder.Append(*value);
+        } else {
+            (void)builder.AppendNull();
+            failures.emplace(offset + i, std::move(text));
+        }
+    }
+
+    auto result = builder.Finish();
+    if (!result.ok()) {
+        PSP_COMPLAIN_AND_ABORT(result.status().ToString());
+    }
+
+    return *result;
+}
+
+static std::shared_ptr<arrow::Array>
+hint_array(
+    std::shared_ptr<arrow::Array> array,
+    t_dtype dtype,
+    const std::shared_ptr<arrow::DataType>& type,
+    std::int64_t offset,
+    std::map<std::int64_t, std::string>& failures
+) {
+    if (array->type_id() == arrow::Type::DICTIONARY) {
+        const auto& dictionary =
+            static_cast<const arrow::DictionaryType&>(*array->type());
+        auto decoded = arrow::compute::Cast(*array, dictionary.value_type());
+        if (decoded.ok()) {
+            array = *decoded;
+        }
+    }
+
+    auto cast = arrow::compute::Cast(
+        *array, type, arrow::compute::CastOptions::Safe()
+    );
+
+    if (cast.ok()) {
+        return *cast;
+    }
+
+    switch (dtype) {
+        case DTYPE_STR:
+            return coerce_array<arrow::StringBuilder>(
+                *array,
+                type,
+                dtype,
+                offset,
+                failures,
+                [](const std::string& text) {
+                    return std::optional<std::string>{text};
+                }
+            );
+        case DTYPE_INT32:
+            return coerce_array<arrow::Int32Builder>(
+                *array,
+                type,
+                dtype,
+                offset,
+                failures,
+                [](const std::string& text) -> std::optional<std::int32_t> {
+                    char* end;
+                    errno = 0;
+                    const auto value = std::strtoll(text.c_str(), &end, 10);
+                    if (*end != '\0' || errno != 0
+                        || value < std::numeric_limits<std::int32_t>::min()
+                        || value > std::numeric_limits<std::int32_t>::max()) {
+                        return std::nullopt;
+                    }
+
+                    return static_cast<std::int32_t>(value);
+                }
+            );
+        case DTYPE_FLOAT64:
+            return coerce_array<arrow::DoubleBuilder>(
+                *array,
+                type,
+                dtype,
+                offset,
+                failures,
+                [](const std::string& text) -> std::optional<double> {
+                    char* end;
+                    const double value = std::strtod(text.c_str(), &end);
+                    if (*end != '\0') {
+                        return std::nullopt;
+                    }
+
+                    return value;
+                }
+            );
+        case DTYPE_BOOL:
+            return coerce_array<arrow::BooleanBuilder>(
+                *array,
+                type,
+                dtype,
+                offset,
+                failures,
+                [](std::string text) -> std::optional<bool> {
+                    std::transform(
+                        text.begin(), text.end(), text.begin(), ::tolower
+                    );
+
+                    if (text == "true" || text == "1") {
+                        return true;
+                    }
+
+                    if (text == "false" || text == "0") {
+                        return false;
+                    }
+
+                    return std::nullopt;
+                }
+            );
+        case DTYPE_DATE:
+            return coerce_array<arrow::Date32Builder>(
+                *array,
+                type,
+                dtype,
+                offset,
+                failures,
+                [](const std::string& text) -> std::optional<std::int32_t> {
+                    const auto millis = parseAsArrowTimestamp(text);
+                    if (!millis.has_value()) {
+                        return std::nullopt;
+                    }
+
+                    constexpr std::int64_t millis_per_day = 86400000;
+                    auto days = *millis / millis_per_day;
+                    if (*millis % millis_per_day < 0) {
+                        days -= 1;
+                    }
+
+                    return static_cast<std::int32_t>(days);
+                }
+            );
+        case DTYPE_TIME:
+            return coerce_array<arrow::TimestampBuilder>(
+                *array,
+                type,
+                dtype,
+                offset,
+                failures,
+                [](const std::string& text) {
+                    return parseAsArrowTimestamp(text);
+                }
+            );
+        default:
+            PSP_COMPLAIN_AND_ABORT("Unsupported schema hint");
+            return nullptr;
+    }
+}
+
+void
+ArrowLoader::apply_schema_hints() {
+    if (m_schema_hints.empty()) {
+        return;
+    }
+
+    const auto input_fields = fields();
+    for (std::size_t cidx = 0; cidx < input_fields.size(); ++cidx) {
+        const auto& name = input_fields[cidx]->name();
+        const auto hint = m_schema_hints.find(name);
+        if (hint == m_schema_hints.end()) {
+            continue;
+        }
+
+        const auto type = hint_arrow_type(hint->second);
+        if (input_fields[cidx]->type()->Equals(*type)) {
+            continue;
+        }
+
+        const auto column = m_normalized
+            ? m_normalized->columns[cidx]
+            : m_table->column(static_cast<int>(cidx));
+
+        std::map<std::int64_t, std::string> failures;
+        std::vector<std::shared_ptr<arrow::Array>> chunks;
+        std::int64_t offset = 0;
+        for (const auto& chunk : column->chunks()) {
+            chunks.push_back(
+                hint_array(chunk, hint->second, type, offset, failures)
+            );
+
+            offset += chunk->length();
+        }
+
+        const auto message =
+            "Could not coerce value to " + dtype_to_str(hint->second);
+
+        // An expanded column's values are gathered into rows, so a value is
+        // reported for every row it fills.
+        const std::vector<std::int64_t>* gather = nullptr;
+        if (m_normalized && !m_normalized->gathers[cidx].empty()) {
+            gather = &m_normalized->gathers[cidx];
+        }
+
+        if (gather == nullptr) {
+            for (auto& [row, text] : failures) {
+                m_row_errors.push_back(t_row_error{
+                    static_cast<t_uindex>(row), name, std::move(text), message
+                });
+            }
+        } else if (!failures.empty()) {
+            for (std::size_t row = 0; row < gather->size(); ++row) {
+                const auto it = failures.find((*gather)[row]);
+                if (it != failures.end()) {
+                    m_row_errors.push_back(
+                        t_row_error{row, name, it->second, message}
+                    );
+                }
+            }
+        }
+
+        auto hinted = std::make_shared<arrow::ChunkedArray>(chunks, type);
+        auto field = arrow::field(name, type);
+        if (m_normalized) {
+            m_normalized->fields[cidx] = field;
+            m_normalized->columns[cidx] = hinted;
+        } else {
+            auto result =
+                m_table->SetColumn(static_cast<int>(cidx), field, hinted);
+            if (!result.ok()) {
+                PSP_COMPLAIN_AND_ABORT(result.status().ToString());
+            }
+
+            m_table = *result;
+        }
+    }
+}
+
 std::vector<std::string>
 ArrowLoader::names() const {
     return m_names;
@@ -18,6 +18,8 @@
 #include <arrow/compute/api_vector.h>
 #include <arrow/util/bit_util.h>
 #include <arrow/util/bitmap_ops.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
 #include <algorithm>
 #include <cstdint>
 #include <limits>
@@ -29,8 +31,6 @@
 
 namespace perspective::apachearrow {
 
-const char* const FLATTEN_SEPARATOR = ".";
-
 namespace {
 
     bool
@@ -38,15 +38,23 @@ namespace {
         return id == arrow::Type::LIST || id == arrow::Type::LARGE_LIST;
     }
 
+    /**
+     * Whether `type`, at `depth` (a top-level column is at depth 1), has a
+     * list which expands, i.e. one not inside a struct kept whole.
+     */
     bool
-    type_expands(const arrow::DataType& type) {
+    type_expands(
+        const arrow::DataType& type,
+        const t_struct_flatten& structs,
+        std::uint32_t depth
+    ) {
         if (is_list_id(type.id())) {
             return true;
         }
 
-        if (type.id() == arrow::Type::STRUCT) {
+        if (type.id() == arrow::Type::STRUCT && depth <= structs.max_depth) {
             for (const auto& child : type.fields()) {
-                if (type_expands(*child->type())) {
+                if (type_expands(*child->type(), structs, depth + 1)) {
                     return true;
                 }
             }
@@ -55,6 +63,79 @@ namespace {
         return false;
     }
 
+    /**
+     * Whether `type`, at `depth`, has a struct deeper than `max_depth`.
+     */
+    bool
+    type_too_deep(
+        const arrow::DataType& type,
+        const t_struct_flatten& structs,
+        std::uint32_t depth
+    ) {
+        if (is_list_id(type.id())) {
+            return type_too_deep(
+                *static_cast<const arrow::BaseListType&>(type).value_type(),
+                structs,
+                depth
+            );
+        }
+
+        if (type.id() != arrow::Type::STRUCT) {
+            return false;
+        }
+
+        if (depth > structs.max_depth) {
+            return true;
+        }
+
+        for (const auto& child : type.fields()) {
+            if (type_too_deep(*child->type(), structs, depth + 1)) {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    /**
+     * `type`, at `depth`, with each struct deeper than `max_depth` replaced by
+     * a string -- the type `limit_struct_depth` rewrites a column to.
+     */
+    std::shared_ptr<arrow::DataType>
+    limited_type(
+        const std::shared_ptr<arrow::DataType>& type,
+        const t_struct_flatten& structs,
+        std::uint32_t depth
+    ) {
+        if (is_list_id(type->id())) {
+            const auto& list_type =
+                static_cast<const arrow::BaseListType&>(*type);
+            const auto field = list_type.value_field()->WithType(
+                limited_type(list_type.value_type(), structs, depth)
+            );
+
+            return type->id() == arrow::Type::LIST ? arrow::list(field)
+                                                   : arrow::large_list(field);
+        }
+
+        if (type->id() != arrow::Type::STRUCT) {
+            return type;
+        }
+
+        if (depth > structs.max_depth) {
+            return arrow::utf8();
+        }
+
+        std::vector<std::shared_ptr<arrow::Field>> fields;
+        for (const auto& child : type->fields()) {
+            fields.push_back(
+                child->WithType(limited_type(child->type(), structs, depth + 1))
+            );
+        }
+
+        return arrow::struct_(fields);
+    }
+
     [[noreturn]] void
     abort_with(const std::string& msg) {
         PSP_COMPLAIN_AND_ABORT(msg);
@@ -123,16 +204,243 @@ namespace {
         return arrow::MakeArray(data);
     }
 
+    template <typename ARRAY>
+    const ARRAY&
+    as(const arrow::Array& array) {
+        return static_cast<const ARRAY&>(array);
+    }
+
     /**
-     * Hoist every top-level struct column's children into dotted columns.
-     * Returns whether anything changed.
+     * Write element `i` of `array` as JSON, matching the text the JSON loader
+     * keeps for an object beyond `t_struct_flatten::max_depth`. Types with no
+     * JSON counterpart, e.g. timestamps, are written as their Arrow text.
+     */
+    void
+    write_json(
+        const arrow::Array& array,
+        std::int64_t i,
+        rapidjson::Writer<rapidjson::StringBuffer>& writer
+    ) {
+        if (array.IsNull(i)) {
+            writer.Null();
+            return;
+        }
+
+        switch (array.type_id()) {
+            case arrow::Type::STRUCT: {
+                const auto& parent = as<arrow::StructArray>(array);
+                writer.StartObject();
+                for (int c = 0; c < parent.num_fields(); ++c) {
+                    const auto& name = parent.struct_type()->field(c)->name();
+                    writer.Key(name.c_str(), name.size());
+                    write_json(*parent.field(c), i, writer);
+                }
+
+                writer.EndObject();
+            } break;
+            case arrow::Type::LIST:
+            case arrow::Type::LARGE_LIST: {
+                std::int64_t offset;
+                std::int64_t length;
+                std::shared_ptr<arrow::Array> values;
+                if (array.type_id() == arrow::Type::LIST) {
+                    const auto& list = as<arrow::ListArray>(array);
+                    offset = list.value_offset(i);
+                    length = list.value_length(i);
+                    values = list.values();
+                } else {
+                    const auto& list = as<arrow::LargeListArray>(array);
+                    offset = list.value_offset(i);
+                    length = list.value_length(i);
+                    values = list.values();
+                }
+
+                writer.StartArray();
+                for (std::int64_t k = 0; k < length; ++k) {
+                    write_json(*values, offset + k, writer);
+                }
+
+                writer.EndArray();
+            } break;
+            case arrow::Type::DICTIONARY: {
+                const auto& dict = as<arrow::DictionaryArray>(array);
+                write_json(*dict.dictionary(), dict.GetValueIndex(i), writer);
+            } break;
+            case arrow::Type::BOOL:
+                writer.Bool(as<arrow::BooleanArray>(array).Value(i));
+                break;
+            case arrow::Type::INT8:
+                writer.Int64(as<arrow::Int8Array>(array).Value(i));
+                break;
+            case arrow::Type::INT16:
+                writer.Int64(as<arrow::Int16Array>(array).Value(i));
+                break;
+            case arrow::Type::INT32:
+                writer.Int64(as<arrow::Int32Array>(array).Value(i));
+                break;
+            case arrow::Type::INT64:
+                writer.Int64(as<arrow::Int64Array>(array).Value(i));
+                break;
+            case arrow::Type::UINT8:
+                writer.Uint64(as<arrow::UInt8Array>(array).Value(i));
+                break;
+            case arrow::Type::UINT16:
+                writer.Uint64(as<arrow::UInt16Array>(array).Value(i));
+                break;
+            case arrow::Type::UINT32:
+                writer.Uint64(as<arrow::UInt32Array>(array).Value(i));
+                break;
+            case arrow::Type::UINT64:
+                writer.Uint64(as<arrow::UInt64Array>(array).Value(i));
+                break;
+            case arrow::Type::FLOAT:
+                writer.Double(as<arrow::FloatArray>(array).Value(i));
+                break;
+            case arrow::Type::DOUBLE:
+                writer.Double(as<arrow::DoubleArray>(array).Value(i));
+                break;
+            case arrow::Type::STRING: {
+                const auto view = as<arrow::StringArray>(array).GetView(i);
+                writer.String(view.data(), view.size());
+            } break;
+            case arrow::Type::LARGE_STRING: {
+                const auto view = as<arrow::LargeStringArray>(array).GetView(i);
+                writer.String(view.data(), view.size());
+            } break;
+            default: {
+                const auto text =
+                    unwrap(array.GetScalar(i), "Could not read struct field")
+                        ->ToString();
+                writer.String(text.c_str(), text.size());
+            }
+        }
+    }
+
+    /**
+     * Each value of `array` as its JSON text, or null.
+     */
+    std::shared_ptr<arrow::Array>
+    stringify_array(const arrow::Array& array) {
+        arrow::StringBuilder builder;
+        for (std::int64_t i = 0; i < array.length(); ++i) {
+            arrow::Status status;
+            if (array.IsNull(i)) {
+                status = builder.AppendNull();
+            } else {
+                rapidjson::StringBuffer buffer;
+                rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+                write_json(array, i, writer);
+                status = builder.Append(buffer.GetString(), buffer.GetSize());
+            }
+
+            if (!status.ok()) {
+                abort_with("Could not stringify struct: " + status.ToString());
+            }
+        }
+
+        return unwrap(builder.Finish(), "Could not stringify struct");
+    }
+
+    /**
+     * Rewrite every struct in `array`, at `depth`, which is deeper than
+     * `max_depth` into a string column of its JSON text, so that only structs
+     * to be hoisted remain. Lists do not count towards the depth, as their
+     * elements are hoisted into the same columns.
+     *
+     * A rebuilt struct carries its own nulls in its children instead, which
+     * is what `flatten_structs` would do with them.
+     */
+    std::shared_ptr<arrow::Array>
+    limit_struct_depth(
+        const std::shared_ptr<arrow::Array>& array,
+        const t_struct_flatten& structs,
+        std::uint32_t depth
+    ) {
+        const auto id = array->type_id();
+        if (id == arrow::Type::STRUCT) {
+            if (depth > structs.max_depth) {
+                return stringify_array(*array);
+            }
+
+            const auto parent =
+                std::static_pointer_cast<arrow::StructArray>(array);
+            const auto& fields = parent->struct_type()->fields();
+            std::vector<std::shared_ptr<arrow::Array>> children;
+            std::vector<std::shared_ptr<arrow::Field>> child_fields;
+            bool changed = false;
+            for (int c = 0; c < parent->num_fields(); ++c) {
+                const auto projected = struct_child(parent, c);
+                auto child = limit_struct_depth(projected, structs, depth + 1);
+                changed = changed || child != projected;
+                child_fields.push_back(fields[c]->WithType(child->type()));
+                children.push_back(std::move(child));
+            }
+
+            if (!changed) {
+                return array;
+            }
+
+            return unwrap(
+                arrow::StructArray::Make(children, child_fields),
+                "Could not rebuild struct"
+            );
+        }
+
+        if (!is_list_id(id)) {
+            return array;
+        }
+
+        const auto values = arrow::MakeArray(array->data()->child_data[0]);
+        const auto limited = limit_struct_depth(values, structs, depth);
+        if (limited == values) {
+            return array;
+        }
+
+        auto data = array->data()->Copy();
+        data->type = limited_type(array->type(), structs, depth);
+        data->child_data = {limited->data()};
+        return arrow::MakeArray(data);
+    }
+
+    /**
+     * Apply `limit_struct_depth` to every top-level column which needs it.
+     */
+    void
+    limit_struct_depths(
+        std::vector<std::shared_ptr<arrow::Field>>& fields,
+        std::vector<std::shared_ptr<arrow::ChunkedArray>>& columns,
+        const t_struct_flatten& structs
+    ) {
+        for (std::size_t i = 0; i < fields.size(); ++i) {
+            if (!type_too_deep(*fields[i]->type(), structs, 1)) {
+                continue;
+            }
+
+            std::vector<std::shared_ptr<arrow::Array>> chunks;
+            chunks.reserve(columns[i]->num_chunks());
+            for (const auto& chunk : columns[i]->chunks()) {
+                chunks.push_back(limit_struct_depth(chunk, structs, 1));
+            }
+
+            const auto type = limited_type(fields[i]->type(), structs, 1);
+            fields[i] = fields[i]->WithType(type);
+            columns[i] = std::make_shared<arrow::ChunkedArray>(
+                std::move(chunks), type
+            );
+        }
+    }
+
+    /**
+     * Hoist every top-level struct column's children into columns named by
+     * their paths, joined by `separator`. Returns whether anything changed.
      */
     bool
     flatten_structs(
         std::vector<std::shared_ptr<arrow::Field>>& fields,
         std::vector<std::shared_ptr<arrow::ChunkedArray>>& columns,
         std::vector<std::vector<std::int64_t>>& gathers,
-        std::vector<bool>& per_element
+        std::vector<bool>& per_element,
+        const std::string& separator
     ) {
         bool changed = false;
         for (const auto& field : fields) {
@@ -170,7 +478,7 @@ namespace {
                 }
 
                 out_fields.push_back(arrow::field(
-                    fields[i]->name() + FLATTEN_SEPARATOR + children[c]->name(),
+                    fields[i]->name() + separator + children[c]->name(),
                     children[c]->type()
                 ));
 
@@ -522,7 +830,7 @@ normalize_table_is_noop(const arrow::Table& input, t_list_flatten mode) {
             return false;
         }
 
-        if (mode != LIST_FLATTEN_STRINGIFY && is_list_id(id)) {
+        if (list_flatten_expands(mode) && is_list_id(id)) {
             return false;
         }
     }
@@ -531,13 +839,17 @@ normalize_table_is_noop(const arrow::Table& input, t_list_flatten mode) {
 }
 
 bool
-normalize_table_expands(const arrow::Table& input, t_list_flatten mode) {
-    if (mode == LIST_FLATTEN_STRINGIFY) {
+normalize_table_expands(
+    const arrow::Table& input,
+    t_list_flatten mode,
+    const t_struct_flatten& structs
+) {
+    if (!list_flatten_expands(mode)) {
         return false;
     }
 
     for (const auto& field : input.schema()->fields()) {
-        if (type_expands(*field->type())) {
+        if (type_expands(*field->type(), structs, 1)) {
             return true;
         }
     }
@@ -546,19 +858,28 @@ normalize_table_expands(const arrow::Table& input, t_list_flatten mode) {
 }
 
 t_normalized_table
-normalize_table(std::shared_ptr<arrow::Table> input, t_list_flatten mode) {
+normalize_table(
+    std::shared_ptr<arrow::Table> input,
+    t_list_flatten mode,
+    const t_struct_flatten& structs
+) {
     t_normalized_table out;
     out.fields = input->schema()->fields();
     out.columns = input->columns();
     out.gathers.resize(out.fields.size());
     out.per_element.assign(out.fields.size(), false);
     out.num_rows = input->num_rows();
+    limit_struct_depths(out.fields, out.columns, structs);
     while (true) {
         bool changed = flatten_structs(
-            out.fields, out.columns, out.gathers, out.per_element
+            out.fields,
+            out.columns,
+            out.gathers,
+            out.per_element,
+            structs.separator
         );
 
-        if (mode != LIST_FLATTEN_STRINGIFY
+        if (list_flatten_expands(mode)
             && explode_lists(
                 out.fields,
                 out.columns,
@@ -622,6 +622,9 @@ maybe_str_to_aggtype(const std::string& str) {
     if (str == "gmv") {
         return t_aggtype::AGGTYPE_GMV;
     }
+    if (str == "union") {
+        return t_aggtype::AGGTYPE_UNION;
+    }
 
     return std::nullopt;
 }
@@ -116,6 +116,7 @@ struct t_computed_expression_cache {
     std::vector<std::pair<std::string, t_tscalar>> m_values;
     std::vector<std::shared_ptr<t_column>> m_columns;
     std::vector<t_dtype> m_input_dtypes;
+    std::vector<t_timestamp_unit> m_input_units;
     exprtk::symbol_table<t_tscalar> m_sym_table;
     exprtk::expression<t_tscalar> m_expr;
     std::unique_ptr<t_computed_function_store> m_function_store;
@@ -168,6 +169,9 @@ t_computed_expression::compute(
         cache.m_values.resize(num_input_columns);
         cache.m_columns.resize(num_input_columns);
         cache.m_input_dtypes.resize(num_input_columns);
+        cache.m_input_units.resize(
+            num_input_columns, TIMESTAMP_UNIT_MILLISECOND
+        );
 
         for (t_uindex cidx = 0; cidx < num_input_columns; ++cidx) {
             const std::string& column_id = m_column_ids[cidx].first;
@@ -175,6 +179,10 @@ t_computed_expression::compute(
             t_dtype dtype = source_table->get_column(column_name)->get_dtype();
 
             cache.m_input_dtypes[cidx] = dtype;
+            if (const auto it = m_timestamp_params.find(column_name);
+                dtype == DTYPE_TIME && it != m_timestamp_params.end()) {
+                cache.m_input_units[cidx] = it->second.unit;
+            }
 
             t_tscalar rval;
             rval.clear();
@@ -229,9 +237,19 @@ t_computed_expression::compute(
 
     for (t_uindex ridx = 0; ridx < num_rows; ++ridx) {
         for (t_uindex cidx = 0; cidx < num_input_columns; ++cidx) {
-            cache.m_values[cidx].second.set(
-                cache.m_columns[cidx]->get_scalar(ridx)
-            );
+            auto& input = cache.m_values[cidx].second;
+            input.set(cache.m_columns[cidx]->get_scalar(ridx));
+
+            // Datetime functions all read `t_time` milliseconds.
+            const auto unit = cache.m_input_units[cidx];
+            if (unit != TIMESTAMP_UNIT_MILLISECOND && input.is_valid()
+                && input.get_dtype() == DTYPE_TIME) {
+                input.set(t_time(convert_timestamp(
+                    input.get<t_time>().raw_value(),
+                    unit,
+                    TIMESTAMP_UNIT_MILLISECOND
+                )));
+            }
         }
         cache.m_row_idx = ridx;
 
@@ -274,6 +292,12 @@ t_computed_expression::get_dtype() const {
     return m_dtype;
 }
 
+void
+t_computed_expression::set_timestamp_params(const t_timestamp_map& params) {
+    m_timestamp_params = params;
+    m_cache.reset();
+}
+
 /******************************************************************************
  *
  * t_computed_expression_parser
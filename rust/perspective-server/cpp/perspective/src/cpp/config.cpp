@@ -223,6 +223,7 @@ t_config::setup(
             case AGGTYPE_Q3:
             case AGGTYPE_MEDIAN:
             case AGGTYPE_JOIN:
+            case AGGTYPE_UNION:
             case AGGTYPE_DOMINANT:
             case AGGTYPE_PY_AGG:
             case AGGTYPE_MIN:
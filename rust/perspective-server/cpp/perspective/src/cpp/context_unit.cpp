@@ -81,7 +81,18 @@ t_ctxunit::notify(
     // widening pass (for OTHER contexts on this gnode) are pure no-ops here
     // and must not enter the row delta.
     const t_column* widened_col = existed._get_const_column("psp_widened");
+    const t_column* existed_col = existed._get_const_column("psp_existed");
+
+    // Rows removed from the context are recorded in the diff with the values
+    // they had before this update.
+    std::vector<const t_column*> prev_cols;
+    if (m_diff_enabled) {
+        for (t_index cidx = 0; cidx < get_column_count(); ++cidx) {
+            prev_cols.push_back(prev._get_const_column(m_config.col_at(cidx)));
+        }
+    }
 
+    auto none = mknone();
     bool delete_encountered = false;
 
     for (t_uindex idx = 0; idx < nrecs; ++idx) {
@@ -91,11 +102,35 @@ t_ctxunit::notify(
         std::uint8_t op_ = *(op_col->get_nth<std::uint8_t>(idx));
         t_op op = static_cast<t_op>(op_);
 
+        bool row_existed = *(existed_col->get_nth<bool>(idx));
+        bool widened = *(widened_col->get_nth<bool>(idx));
+
         switch (op) {
             case OP_INSERT: {
+                if (m_diff_enabled && !widened) {
+                    if (row_existed) {
+                        m_diff.update(pkey);
+                    } else {
+                        m_diff.add(pkey);
+                    }
+                }
             } break;
             case OP_DELETE: {
                 delete_encountered = true;
+                if (m_diff_enabled && row_existed) {
+                    std::vector<t_tscalar> row;
+                    row.reserve(prev_cols.size());
+                    for (const t_column* col : prev_cols) {
+                        auto v = col->get_scalar(idx);
+                        if (!v.is_valid()) {
+                            v.set(none);
+                        }
+
+                        row.push_back(v);
+                    }
+
+                    m_diff.remove(pkey, std::move(row));
+                }
             } break;
             default: {
                 PSP_COMPLAIN_AND_ABORT("Unexpected OP");
@@ -104,7 +139,7 @@ t_ctxunit::notify(
 
         // add the pkey for row delta (value read: `get_nth` never returns
         // nullptr in bounds; the column is written for every row)
-        if (!*(widened_col->get_nth<bool>(idx))) {
+        if (!widened) {
             add_delta_pkey(pkey);
         }
     }
@@ -144,6 +179,9 @@ t_ctxunit::notify(const t_data_table& flattened, bool is_registration) {
 
         // Add primary key to track row delta
         add_delta_pkey(pkey);
+        if (m_diff_enabled) {
+            m_diff.add(pkey);
+        }
     }
 }
 
@@ -381,6 +419,25 @@ t_ctxunit::get_column_names() const {
 
 void
 t_ctxunit::reset() {
+    // Every row is removed from the context, so record them in the diff
+    // while they can still be read.
+    t_index nrows = m_diff_enabled ? get_row_count() : 0;
+    if (nrows > 0) {
+        t_index ncols = get_column_count();
+        auto data = get_data(0, nrows, 0, ncols);
+        const t_column* pkey_col =
+            m_gstate->get_table()->_get_const_column("psp_pkey");
+        for (t_index ridx = 0; ridx < nrows; ++ridx) {
+            m_diff.remove(
+                pkey_col->get_scalar(ridx),
+                std::vector<t_tscalar>(
+                    data.begin() + ridx * ncols,
+                    data.begin() + (ridx + 1) * ncols
+                )
+            );
+        }
+    }
+
     m_has_delta = false;
 }
 
@@ -392,6 +449,27 @@ t_ctxunit::get_deltas_enabled() const {
 void
 t_ctxunit::set_deltas_enabled(bool enabled_state) {}
 
+bool
+t_ctxunit::get_diff_enabled() const {
+    return m_diff_enabled;
+}
+
+void
+t_ctxunit::set_diff_enabled(bool enabled_state) {
+    if (!enabled_state) {
+        m_diff.clear();
+    }
+
+    m_diff_enabled = enabled_state;
+}
+
+t_pkey_diff
+t_ctxunit::take_diff() {
+    t_pkey_diff diff = std::move(m_diff);
+    m_diff.clear();
+    return diff;
+}
+
 t_index
 t_ctxunit::sidedness() const {
     return 0;
@@ -145,6 +145,35 @@ t_ctx0::notify(
         msk_curr = filter_table_for_config(curr, m_config);
     }
 
+    // Rows removed from the context are recorded in the diff with the values
+    // they had before this update.
+    std::vector<const t_column*> prev_cols;
+    if (m_diff_enabled) {
+        for (t_index cidx = 0; cidx < get_column_count(); ++cidx) {
+            prev_cols.push_back(prev._get_const_column(m_config.col_at(cidx)));
+        }
+    }
+
+    auto none = mknone();
+    auto diff_remove = [&](const t_tscalar& pkey, t_uindex idx) {
+        if (!m_diff_enabled) {
+            return;
+        }
+
+        std::vector<t_tscalar> row;
+        row.reserve(prev_cols.size());
+        for (const t_column* col : prev_cols) {
+            auto v = col->get_scalar(idx);
+            if (!v.is_valid()) {
+                v.set(none);
+            }
+
+            row.push_back(v);
+        }
+
+        m_diff.remove(pkey, std::move(row));
+    };
+
     for (t_uindex idx = 0; idx < nrecs; ++idx) {
         t_tscalar pkey =
             m_symtable.get_interned_tscalar(pkey_col->get_scalar(idx));
@@ -166,8 +195,12 @@ t_ctx0::notify(
                                 m_config,
                                 pkey
                             );
+                            if (m_diff_enabled) {
+                                m_diff.update(pkey);
+                            }
                         } else {
                             m_traversal->delete_row(pkey);
+                            diff_remove(pkey, idx);
                         }
                     } else {
                         if (filter_curr) {
@@ -177,6 +210,9 @@ t_ctx0::notify(
                                 m_config,
                                 pkey
                             );
+                            if (m_diff_enabled) {
+                                m_diff.add(pkey);
+                            }
                         }
                     }
                 } else {
@@ -187,6 +223,9 @@ t_ctx0::notify(
                             m_config,
                             pkey
                         );
+                        if (m_diff_enabled) {
+                            m_diff.update(pkey);
+                        }
                     } else {
                         m_traversal->add_row(
                             *m_gstate,
@@ -194,12 +233,20 @@ t_ctx0::notify(
                             m_config,
                             pkey
                         );
+                        if (m_diff_enabled) {
+                            m_diff.add(pkey);
+                        }
                     }
                 }
             } break;
             case OP_DELETE: {
                 m_traversal->delete_row(pkey);
                 delete_encountered = true;
+
+                // Only rows which were in the view are removed from it.
+                if (row_existed && (!has_filters || msk_prev.get(idx))) {
+                    diff_remove(pkey, idx);
+                }
             } break;
             default: {
                 PSP_COMPLAIN_AND_ABORT("Unexpected OP");
@@ -262,6 +309,9 @@ t_ctx0::notify(const t_data_table& flattened, bool is_registration) {
             std::uint8_t op_ = *(op_col->get_nth<std::uint8_t>(idx));
             if (static_cast<t_op>(op_) == OP_INSERT) {
                 m_traversal->bulk_load_append(pkey);
+                if (m_diff_enabled) {
+                    m_diff.add(pkey);
+                }
             }
             if (track_deltas) {
                 add_delta_pkey(pkey);
@@ -289,6 +339,9 @@ t_ctx0::notify(const t_data_table& flattened, bool is_registration) {
                             m_config,
                             pkey
                         );
+                        if (m_diff_enabled) {
+                            m_diff.add(pkey);
+                        }
                     }
                 } break;
                 default:
@@ -314,6 +367,9 @@ t_ctx0::notify(const t_data_table& flattened, bool is_registration) {
                 m_traversal->add_row(
                     *m_gstate, *(m_expression_tables->m_master), m_config, pkey
                 );
+                if (m_diff_enabled) {
+                    m_diff.add(pkey);
+                }
             } break;
             default:
                 break;
@@ -471,6 +527,29 @@ t_ctx0::get_data(const std::vector<t_uindex>& rows) const {
     return values;
 }
 
+std::vector<t_tscalar>
+t_ctx0::get_data(const std::vector<t_tscalar>& pkeys) const {
+    t_uindex stride = get_column_count();
+    std::vector<t_tscalar> values(pkeys.size() * stride);
+    std::vector<t_tscalar> out_data(pkeys.size());
+
+    auto none = mknone();
+
+    for (t_uindex cidx = 0; cidx < stride; ++cidx) {
+        read_column_from_gstate(m_config.col_at(cidx), pkeys, out_data);
+        for (t_uindex ridx = 0; ridx < pkeys.size(); ++ridx) {
+            auto v = out_data[ridx];
+            if (!v.is_valid()) {
+                v.set(none);
+            }
+
+            values[ridx * stride + cidx] = v;
+        }
+    }
+
+    return values;
+}
+
 void
 t_ctx0::sort_by() {
     reset_sortby();
@@ -561,6 +640,24 @@ t_ctx0::get_sort_by() const {
 
 void
 t_ctx0::reset(bool reset_expressions) {
+    // Every row is removed from the context, so record them in the diff
+    // while they can still be read.
+    if (m_diff_enabled && m_traversal->size() > 0) {
+        t_index nrows = m_traversal->size();
+        t_index ncols = get_column_count();
+        auto pkeys = m_traversal->get_pkeys(0, nrows);
+        auto data = get_data(0, nrows, 0, ncols);
+        for (t_index ridx = 0; ridx < nrows; ++ridx) {
+            m_diff.remove(
+                pkeys[ridx],
+                std::vector<t_tscalar>(
+                    data.begin() + ridx * ncols,
+                    data.begin() + (ridx + 1) * ncols
+                )
+            );
+        }
+    }
+
     m_traversal->reset();
     m_deltas = std::make_shared<t_zcdeltas>();
     m_has_delta = false;
@@ -961,6 +1058,27 @@ t_ctx0::set_deltas_enabled(bool enabled_state) {
     m_features[CTX_FEAT_DELTA] = enabled_state;
 }
 
+bool
+t_ctx0::get_diff_enabled() const {
+    return m_diff_enabled;
+}
+
+void
+t_ctx0::set_diff_enabled(bool enabled_state) {
+    if (!enabled_state) {
+        m_diff.clear();
+    }
+
+    m_diff_enabled = enabled_state;
+}
+
+t_pkey_diff
+t_ctx0::take_diff() {
+    t_pkey_diff diff = std::move(m_diff);
+    m_diff.clear();
+    return diff;
+}
+
 std::vector<t_stree*>
 t_ctx0::get_trees() {
     return {};
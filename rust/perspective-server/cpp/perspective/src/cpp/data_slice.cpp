@@ -106,6 +106,32 @@ t_data_slice<CTX_T>::get_column_slice(t_uindex cidx) const {
     return column_data;
 }
 
+template <typename CTX_T>
+std::shared_ptr<t_data_slice<CTX_T>>
+t_data_slice<CTX_T>::with_rows(
+    const std::vector<t_tscalar>& slice, t_uindex nrows
+) const {
+    auto out = std::make_shared<t_data_slice<CTX_T>>(
+        m_ctx,
+        0,
+        nrows,
+        m_start_col,
+        m_end_col,
+        0,
+        m_col_offset,
+        slice,
+        m_column_names,
+        m_column_indices
+    );
+
+    auto extents = get_data_extents();
+    out->m_detached_extents = t_get_data_extents{
+        0, static_cast<t_index>(nrows), extents.m_scol, extents.m_ecol
+    };
+
+    return out;
+}
+
 template <typename CTX_T>
 std::vector<t_tscalar>
 t_data_slice<CTX_T>::get_row_path(t_uindex ridx) const {
@@ -177,6 +203,10 @@ t_data_slice<CTX_T>::get_col_offset() const {
 template <typename CTX_T>
 t_get_data_extents
 t_data_slice<CTX_T>::get_data_extents() const {
+    if (m_detached_extents.has_value()) {
+        return *m_detached_extents;
+    }
+
     auto nrows = m_ctx->get_row_count();
     auto ncols = m_ctx->get_column_count();
     t_get_data_extents ext = sanitize_get_data_extents(
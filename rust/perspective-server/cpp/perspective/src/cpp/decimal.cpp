@@ -0,0 +1,210 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/base.h>
+#include <perspective/decimal.h>
+#include <cctype>
+#include <cmath>
+#include <algorithm>
+#include <cstdio>
+
+namespace perspective {
+
+// Exponents beyond this can only produce zero or overflow, and bounding them
+// keeps the shift arithmetic below from overflowing itself.
+static constexpr std::int64_t MAX_DECIMAL_EXPONENT = 4096;
+
+bool
+t_decimal_params::operator==(const t_decimal_params& other) const {
+    return precision == other.precision && scale == other.scale;
+}
+
+bool
+is_exact_decimal(std::int64_t precision, std::int64_t scale) {
+    return precision > 0 && precision <= DECIMAL_MAX_PRECISION && scale >= 0
+        && scale <= precision;
+}
+
+std::int64_t
+decimal_pow10(std::uint32_t exponent) {
+    std::int64_t result = 1;
+    for (std::uint32_t i = 0; i < exponent; ++i) {
+        result *= 10;
+    }
+
+    return result;
+}
+
+std::int64_t
+unscaled_decimal(const t_tscalar& scalar) {
+    switch (scalar.get_dtype()) {
+        case DTYPE_FLOAT32:
+        case DTYPE_FLOAT64:
+            return std::llround(scalar.to_double());
+        default:
+            return scalar.to_int64();
+    }
+}
+
+std::optional<std::int64_t>
+parse_decimal(std::string_view text, const t_decimal_params& params) {
+    const auto is_space = [](char c) {
+        return std::isspace(static_cast<unsigned char>(c)) != 0;
+    };
+
+    while (!text.empty() && is_space(text.front())) {
+        text.remove_prefix(1);
+    }
+
+    while (!text.empty() && is_space(text.back())) {
+        text.remove_suffix(1);
+    }
+
+    std::size_t pos = 0;
+    bool negative = false;
+    if (pos < text.size() && (text[pos] == '-' || text[pos] == '+')) {
+        negative = text[pos] == '-';
+        ++pos;
+    }
+
+    std::string digits;
+    std::int64_t frac_len = 0;
+    bool seen_point = false;
+    bool seen_digit = false;
+    for (; pos < text.size(); ++pos) {
+        char c = text[pos];
+        if (c >= '0' && c <= '9') {
+            seen_digit = true;
+
+            // Leading zeros carry no value, and skipping them keeps `digits`
+            // bounded by the significant digits of the input.
+            if (!(digits.empty() && c == '0')) {
+                digits.push_back(c);
+            }
+
+            if (seen_point) {
+                ++frac_len;
+            }
+        } else if (c == '.' && !seen_point) {
+            seen_point = true;
+        } else {
+            break;
+        }
+    }
+
+    if (!seen_digit) {
+        return std::nullopt;
+    }
+
+    std::int64_t exponent = 0;
+    if (pos < text.size() && (text[pos] == 'e' || text[pos] == 'E')) {
+        ++pos;
+        bool exp_negative = false;
+        if (pos < text.size() && (text[pos] == '-' || text[pos] == '+')) {
+            exp_negative = text[pos] == '-';
+            ++pos;
+        }
+
+        if (pos == text.size()) {
+            return std::nullopt;
+        }
+
+        for (; pos < text.size(); ++pos) {
+            char c = text[pos];
+            if (c < '0' || c > '9') {
+                return std::nullopt;
+            }
+
+            exponent =
+                std::min(exponent * 10 + (c - '0'), MAX_DECIMAL_EXPONENT);
+        }
+
+        if (exp_negative) {
+            exponent = -exponent;
+        }
+    }
+
+    if (pos != text.size()) {
+        return std::nullopt;
+    }
+
+    if (digits.empty()) {
+        return 0;
+    }
+
+    // The unscaled value is `digits * 10^shift`.
+    const std::int64_t shift =
+        exponent - frac_len + static_cast<std::int64_t>(params.scale);
+
+    std::int64_t kept_len = static_cast<std::int64_t>(digits.size()) + shift;
+    if (kept_len > static_cast<std::int64_t>(params.precision)) {
+        return std::nullopt;
+    }
+
+    std::int64_t result = 0;
+    for (std::int64_t i = 0; i < kept_len; ++i) {
+        result *= 10;
+        if (i < static_cast<std::int64_t>(digits.size())) {
+            result += digits[i] - '0';
+        }
+    }
+
+    // Round half away from zero on the first dropped digit.
+    if (shift < 0 && kept_len >= 0
+        && kept_len < static_cast<std::int64_t>(digits.size())
+        && digits[kept_len] >= '5') {
+        result += 1;
+    }
+
+    if (result >= decimal_pow10(params.precision)) {
+        return std::nullopt;
+    }
+
+    return negative ? -result : result;
+}
+
+std::optional<std::int64_t>
+double_to_decimal(double value, const t_decimal_params& params) {
+    if (!std::isfinite(value)) {
+        return std::nullopt;
+    }
+
+    char buf[32];
+    int len = std::snprintf(buf, sizeof(buf), "%.15g", value);
+    if (len <= 0 || len >= static_cast<int>(sizeof(buf))) {
+        return std::nullopt;
+    }
+
+    return parse_decimal(std::string_view(buf, len), params);
+}
+
+std::string
+format_decimal(std::int64_t unscaled, std::uint32_t scale) {
+    // Negate in unsigned space so `INT64_MIN` does not overflow.
+    const bool negative = unscaled < 0;
+    const std::uint64_t magnitude = negative
+        ? ~static_cast<std::uint64_t>(unscaled) + 1
+        : static_cast<std::uint64_t>(unscaled);
+
+    std::string digits = std::to_string(magnitude);
+    if (digits.size() <= scale) {
+        digits.insert(0, scale + 1 - digits.size(), '0');
+    }
+
+    if (scale > 0) {
+        digits.insert(digits.size() - scale, 1, '.');
+    }
+
+    return negative ? "-" + digits : digits;
+}
+
+} // namespace perspective
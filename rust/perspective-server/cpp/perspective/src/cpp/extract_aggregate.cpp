@@ -82,6 +82,7 @@ extract_aggregate(
         case AGGTYPE_UDF_COMBINER:
         case AGGTYPE_UDF_REDUCER:
         case AGGTYPE_JOIN:
+        case AGGTYPE_UNION:
         case AGGTYPE_IDENTITY:
         case AGGTYPE_DISTINCT_COUNT:
         case AGGTYPE_DISTINCT_LEAF:
@@ -186,6 +186,49 @@ t_gnode::remove_input_port(t_uindex port_id) {
     m_input_ports.erase(port_id);
 }
 
+void
+t_gnode::merge_input_port(t_uindex from_port_id, t_uindex to_port_id) {
+    PSP_VERBOSE_ASSERT(
+        m_init, "Cannot `merge_input_port` on an uninited gnode."
+    );
+
+    auto from_port = m_input_ports.find(from_port_id);
+    auto to_port = m_input_ports.find(to_port_id);
+    if (from_port == m_input_ports.end() || to_port == m_input_ports.end()) {
+        PSP_COMPLAIN_AND_ABORT("Cannot merge input port which does not exist");
+    }
+
+    to_port->second->send(from_port->second->get_table());
+    remove_input_port(from_port_id);
+}
+
+void
+t_gnode::adopt(t_gnode& other) {
+    PSP_VERBOSE_ASSERT(m_init, "Cannot `adopt` on an uninited gnode.");
+    while (m_last_input_port_id < other.m_last_input_port_id) {
+        make_input_port();
+    }
+
+    std::vector<t_uindex> removed_ports;
+    for (const auto& [port_id, port] : m_input_ports) {
+        if (port_id != 0 && other.m_input_ports.count(port_id) == 0) {
+            removed_ports.push_back(port_id);
+        }
+    }
+
+    for (const auto port_id : removed_ports) {
+        remove_input_port(port_id);
+    }
+
+    for (const auto& [name, ctxh] : other.m_contexts) {
+        _register_context(
+            name, ctxh.m_ctx_type, reinterpret_cast<std::uintptr_t>(ctxh.m_ctx)
+        );
+    }
+
+    other.m_contexts.clear();
+}
+
 t_value_transition
 t_gnode::calc_transition(
     bool prev_existed,
@@ -978,8 +1021,10 @@ t_gnode::_update_contexts_from_state(std::shared_ptr<t_data_table> tbl) {
                 update_context_from_state<t_ctx0>(ctx, name, tbl, false);
             } break;
             case UNIT_CONTEXT: {
+                // A unit context reads its rows from the already-updated
+                // master table, so it is not reset here - resetting would
+                // record those rows as removed in its diff.
                 auto* ctx = static_cast<t_ctxunit*>(ctxh.m_ctx);
-                ctx->reset();
                 update_context_from_state<t_ctxunit>(ctx, name, tbl, false);
             } break;
             case GROUPED_PKEY_CONTEXT: {
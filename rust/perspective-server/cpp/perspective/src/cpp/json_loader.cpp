@@ -15,12 +15,15 @@
 #include "perspective/base.h"
 #include "perspective/raw_types.h"
 #include "perspective/arrow_csv.h"
+#include "perspective/exception.h"
 #include "rapidjson/document.h"
 #include <rapidjson/stringbuffer.h>
 #include <rapidjson/writer.h>
 #include <algorithm>
 #include <cctype>
+#include <cerrno>
 #include <chrono>
+#include <cmath>
 #include <cstring>
 #include <ctime>
 #include <limits>
@@ -111,10 +114,9 @@ rapidjson_type_to_dtype(const rapidjson::Value& value) {
             return t_dtype::DTYPE_NONE;
         case rapidjson::kArrayType:
             // Only reachable under `stringify`; the expanding modes descend.
-            return t_dtype::DTYPE_STR;
         case rapidjson::kObjectType:
-            PSP_COMPLAIN_AND_ABORT("Unknown JSON type");
-            return t_dtype::DTYPE_NONE;
+            // Only reachable beyond `t_struct_flatten::max_depth`.
+            return t_dtype::DTYPE_STR;
         default:
             PSP_COMPLAIN_AND_ABORT("Unknown JSON type");
             return t_dtype::DTYPE_NONE;
@@ -179,9 +181,9 @@ json_into(const rapidjson::Value& value) {
             case rapidjson::kTrueType:
                 return "true";
             case rapidjson::kObjectType:
-                PSP_COMPLAIN_AND_ABORT("Cannot coerce object to string");
             case rapidjson::kArrayType: {
-                // `stringify` keeps the array as its JSON text.
+                // `stringify` keeps the array as its JSON text, and an object
+                // beyond `t_struct_flatten::max_depth` is kept likewise.
                 rapidjson::StringBuffer buffer;
                 rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
                 value.Accept(writer);
@@ -410,16 +412,24 @@ fill_column_json(
 }
 
 /**
- * The separator joining an object's key to its childrens'. MUST match
- * `apachearrow::FLATTEN_SEPARATOR`, or the same logical record would land in
- * different columns depending on whether it arrived as JSON or as Arrow.
+ * Whether `value`, an object at `depth` (a record's own keys are at depth 1),
+ * is hoisted into its children's columns rather than kept as a leaf. The
+ * separator joining their names must be the same for JSON and Arrow, or the
+ * same logical record would land in different columns depending on its format.
  */
-static const char FLATTEN_SEPARATOR = '.';
+static bool
+descends(
+    const rapidjson::Value& value,
+    const t_struct_flatten& structs,
+    std::uint32_t depth
+) {
+    return value.IsObject() && depth <= structs.max_depth;
+}
 
 /**
- * Visit every scalar beneath `value`, naming it by its dotted path from
- * `prefix`. An object contributes its leaves rather than itself, because
- * Perspective's column model is flat; an empty object contributes nothing.
+ * Visit every scalar beneath `value`, naming it by its path from `prefix`. An
+ * object contributes its leaves rather than itself, because Perspective's
+ * column model is flat; an empty object contributes nothing.
  *
  * `prefix` is grown and restored in place rather than copied per leaf.
  */
@@ -429,29 +439,34 @@ for_each_leaf(
     std::string& prefix,
     const rapidjson::Value& value,
     t_list_flatten mode,
+    const t_struct_flatten& structs,
+    std::uint32_t depth,
     F&& fn,
     bool through_array = false
 ) {
-    if (mode != LIST_FLATTEN_STRINGIFY && value.IsArray()) {
+    if (list_flatten_expands(mode) && value.IsArray()) {
         // Inference only needs one element to learn the leaves' types; any
         // path a later element introduces is grown into by `resolve_column`.
         if (!value.Empty()) {
-            for_each_leaf(prefix, value[0], mode, fn, true);
+            for_each_leaf(prefix, value[0], mode, structs, depth, fn, true);
         }
 
         return;
     }
 
-    if (!value.IsObject()) {
+    if (!descends(value, structs, depth)) {
         fn(static_cast<const std::string&>(prefix), value, through_array);
         return;
     }
 
     const auto len = prefix.size();
     for (const auto& child : value.GetObj()) {
-        prefix += FLATTEN_SEPARATOR;
+        prefix += structs.separator;
         prefix += child.name.GetString();
-        for_each_leaf(prefix, child.value, mode, fn, through_array);
+        for_each_leaf(
+            prefix, child.value, mode, structs, depth + 1, fn, through_array
+        );
+
         prefix.resize(len);
     }
 }
@@ -485,29 +500,35 @@ check_row_count(std::uint64_t rows) {
  * fixpoint the Arrow path reaches by re-running its pass.
  */
 static t_uindex
-leaf_width(const rapidjson::Value& value, t_list_flatten mode) {
-    if (mode == LIST_FLATTEN_STRINGIFY || value.IsNull()) {
+leaf_width(
+    const rapidjson::Value& value,
+    t_list_flatten mode,
+    const t_struct_flatten& structs,
+    std::uint32_t depth
+) {
+    if (!list_flatten_expands(mode) || value.IsNull()) {
         return 1;
     }
 
     if (value.IsArray()) {
         t_uindex total = 0;
         for (const auto& element : value.GetArray()) {
-            total += leaf_width(element, mode);
+            total += leaf_width(element, mode, structs, depth);
         }
 
         // An empty array still occupies a row, carrying a null.
         return std::max<t_uindex>(total, 1);
     }
 
-    if (!value.IsObject()) {
+    if (!descends(value, structs, depth)) {
         return 1;
     }
 
     t_uindex width = 1;
     const char* witness = nullptr;
     for (const auto& child : value.GetObj()) {
-        const auto child_width = leaf_width(child.value, mode);
+        const auto child_width =
+            leaf_width(child.value, mode, structs, depth + 1);
         if (mode == LIST_FLATTEN_CARTESIAN) {
             width *= child_width;
             continue;
@@ -534,14 +555,19 @@ leaf_width(const rapidjson::Value& value, t_list_flatten mode) {
 }
 
 /**
- * Whether `value` needs the descending, expanding path at all. A value that is
- * neither an object nor an expandable array is written straight to the column
- * named by its key, with no path string and no width arithmetic.
+ * Whether `value`, the value of a record's key, needs the descending, expanding
+ * path at all. A value that is neither a hoisted object nor an expandable array
+ * is written straight to the column named by its key, with no path string and
+ * no width arithmetic.
  */
 static bool
-is_nested(const rapidjson::Value& value, t_list_flatten mode) {
-    return value.IsObject()
-        || (mode != LIST_FLATTEN_STRINGIFY && value.IsArray());
+is_nested(
+    const rapidjson::Value& value,
+    t_list_flatten mode,
+    const t_struct_flatten& structs
+) {
+    return descends(value, structs, 1)
+        || (list_flatten_expands(mode) && value.IsArray());
 }
 
 /**
@@ -583,7 +609,7 @@ child_slot(
  * Visit the leaves `value` contributes to output slot `k`, which must be less
  * than `width`.
  *
- * `width` is `leaf_width(value, mode)`, passed in rather than recomputed:
+ * `width` is `leaf_width(value, ...)`, passed in rather than recomputed:
  * every caller already holds it, and recomputing walks the whole subtree once
  * per slot.
  */
@@ -595,9 +621,12 @@ emit_leaves(
     t_uindex k,
     t_uindex width,
     t_list_flatten mode,
+    const t_struct_flatten& structs,
+    std::uint32_t depth,
     F&& fn
 ) {
-    if (mode == LIST_FLATTEN_STRINGIFY || value.IsNull()) {
+    if (value.IsNull()
+        || (!list_flatten_expands(mode) && !descends(value, structs, depth))) {
         fn(std::string_view{prefix}, value);
         return;
     }
@@ -609,15 +638,19 @@ emit_leaves(
         // element `k`, rather than a scan accumulating widths, which would
         // make emitting a whole array quadratic in its length.
         if (width == value.Size()) {
-            emit_leaves(prefix, value[k], 0, 1, mode, fn);
+            emit_leaves(prefix, value[k], 0, 1, mode, structs, depth, fn);
             return;
         }
 
         // Otherwise find the element covering slot `k`, and the slot within it.
         for (const auto& element : value.GetArray()) {
-            const auto element_width = leaf_width(element, mode);
+            const auto element_width =
+                leaf_width(element, mode, structs, depth);
             if (k < element_width) {
-                emit_leaves(prefix, element, k, element_width, mode, fn);
+                emit_leaves(
+                    prefix, element, k, element_width, mode, structs, depth, fn
+                );
+
                 return;
             }
 
@@ -629,7 +662,7 @@ emit_leaves(
         return;
     }
 
-    if (!value.IsObject()) {
+    if (!descends(value, structs, depth)) {
         fn(std::string_view{prefix}, value);
         return;
     }
@@ -637,11 +670,22 @@ emit_leaves(
     t_uindex stride = width;
     const auto len = prefix.size();
     for (const auto& child : value.GetObj()) {
-        const auto child_width = leaf_width(child.value, mode);
+        const auto child_width =
+            leaf_width(child.value, mode, structs, depth + 1);
         const auto child_k = child_slot(k, child_width, stride, mode);
-        prefix += FLATTEN_SEPARATOR;
+        prefix += structs.separator;
         prefix += child.name.GetString();
-        emit_leaves(prefix, child.value, child_k, child_width, mode, fn);
+        emit_leaves(
+            prefix,
+            child.value,
+            child_k,
+            child_width,
+            mode,
+            structs,
+            depth + 1,
+            fn
+        );
+
         prefix.resize(len);
     }
 }
@@ -702,10 +746,12 @@ JsonLoader::init(
     t_json_format format,
     const std::string& index,
     const t_schema* existing,
-    t_list_flatten mode
+    t_list_flatten mode,
+    const t_struct_flatten& structs
 ) {
     m_format = format;
     m_mode = mode;
+    m_struct_flatten = structs;
     if (format == JSON_FORMAT_NDJSON) {
         m_stream = rapidjson::StringStream(data.data());
         m_document.ParseStream<rapidjson::kParseStopWhenDoneFlag>(m_stream);
@@ -768,6 +814,14 @@ JsonLoader::init(
             break;
     }
 
+    for (std::size_t i = 0; i < m_names.size(); ++i) {
+        const auto it = m_schema_hints.find(m_names[i]);
+        if (it != m_schema_hints.end()) {
+            m_types[i] = it->second;
+            m_list_params.erase(it->first);
+        }
+    }
+
     m_expands = !m_per_element.empty();
     if (mode == LIST_FLATTEN_CARTESIAN && m_per_element.size() > 1) {
         // A product repeats every factor against the others' dimensions, so
@@ -822,6 +876,44 @@ JsonLoader::fill_table(
     return 0;
 }
 
+/**
+ * The type of an inferred leaf. Under `LIST_FLATTEN_PRESERVE`, an array of a
+ * single element type is recorded in `lists` as a `LIST` column; one with no
+ * non-null element is, like `null`, untyped until a later value says more.
+ */
+static t_dtype
+infer_leaf_dtype(
+    const std::string& name,
+    const rapidjson::Value& leaf,
+    t_list_flatten mode,
+    t_list_map& lists
+) {
+    if (mode != LIST_FLATTEN_PRESERVE || !leaf.IsArray()) {
+        if (!leaf.IsNull()) {
+            // A scalar after an empty array is a scalar column after all.
+            lists.erase(name);
+        }
+
+        return rapidjson_type_to_dtype(leaf);
+    }
+
+    const auto element = list_element_dtype(leaf);
+    if (!element.has_value()) {
+        // Nested or mixed, so stringified as under `LIST_FLATTEN_STRINGIFY`.
+        lists.erase(name);
+        return DTYPE_STR;
+    }
+
+    if (*element == DTYPE_NONE) {
+        // A list of strings, unless a later array has typed elements.
+        lists.emplace(name, t_list_params{DTYPE_STR});
+        return DTYPE_NONE;
+    }
+
+    lists[name] = t_list_params{*element};
+    return DTYPE_STR;
+}
+
 /**
  * Accumulate types from one record, used once by ndjson and per-record by the
  * row format. `seen` grows with every key encountered; `known` with the keys
@@ -839,7 +931,9 @@ infer_record(
     std::set<std::string>& literal,
     std::set<std::string>& descended,
     std::set<std::string>& per_element,
-    t_list_flatten mode
+    t_list_flatten mode,
+    const t_struct_flatten& structs,
+    t_list_map& lists
 ) {
     std::string path;
     for (const auto& col : record.GetObj()) {
@@ -849,6 +943,8 @@ infer_record(
             path,
             col.value,
             mode,
+            structs,
+            1,
             [&](const auto& name, const auto&, bool through_array) {
                 seen.insert(name);
                 (name.size() == top ? literal : descended).insert(name);
@@ -862,22 +958,29 @@ infer_record(
     // https://github.com/Tencent/rapidjson/issues/1994
     for (const auto& col : record.GetObj()) {
         path.assign(col.name.GetString());
-        for_each_leaf(path, col.value, mode, [&](const auto& name, const auto& leaf, bool) {
-            if (name == index) {
-                is_implicit = false;
-            }
+        for_each_leaf(
+            path,
+            col.value,
+            mode,
+            structs,
+            1,
+            [&](const auto& name, const auto& leaf, bool) {
+                if (name == index) {
+                    is_implicit = false;
+                }
 
-            if (known.count(name) > 0) {
-                return;
-            }
+                if (known.count(name) > 0) {
+                    return;
+                }
 
-            auto dtype = rapidjson_type_to_dtype(leaf);
-            if (dtype != DTYPE_NONE) {
-                known.insert(name);
-                types.push_back(dtype);
-                names.emplace_back(name);
+                auto dtype = infer_leaf_dtype(name, leaf, mode, lists);
+                if (dtype != DTYPE_NONE) {
+                    known.insert(name);
+                    types.push_back(dtype);
+                    names.emplace_back(name);
+                }
             }
-        });
+        );
     }
 }
 
@@ -916,7 +1019,14 @@ JsonLoader::resolve_column(
         return nullptr;
     }
 
-    auto dtype = rapidjson_type_to_dtype(leaf);
+    auto dtype =
+        infer_leaf_dtype(std::string{name}, leaf, m_mode, m_list_params);
+    if (const auto it = m_schema_hints.find(std::string{name});
+        it != m_schema_hints.end()) {
+        dtype = it->second;
+        m_list_params.erase(it->first);
+    }
+
     if (dtype == DTYPE_NONE) {
         // A `null` carries no type; wait for a record that does.
         return nullptr;
@@ -947,7 +1057,9 @@ JsonLoader::infer_rows(const std::string& index) {
             literal,
             descended,
             m_per_element,
-            m_mode
+            m_mode,
+            m_struct_flatten,
+            m_list_params
         );
 
         // Theoretically there can end too early if the first
@@ -980,7 +1092,9 @@ JsonLoader::infer_ndjson(const std::string& index) {
         literal,
         descended,
         m_per_element,
-        m_mode
+        m_mode,
+        m_struct_flatten,
+        m_list_params
     );
 
     check_path_collision(literal, descended);
@@ -1009,8 +1123,7 @@ JsonLoader::infer_cols(const std::string& index) {
         for (const auto& cell : it.value.GetArray()) {
             // Whether this cell contributes the column itself rather than
             // paths beneath it -- the same test `for_each_leaf` applies.
-            const bool is_leaf = !cell.IsObject()
-                && (m_mode == LIST_FLATTEN_STRINGIFY || !cell.IsArray());
+            const bool is_leaf = !is_nested(cell, m_mode, m_struct_flatten);
 
             saw_leaf = saw_leaf || is_leaf;
             path.assign(it.name.GetString());
@@ -1018,6 +1131,8 @@ JsonLoader::infer_cols(const std::string& index) {
                 path,
                 cell,
                 m_mode,
+                m_struct_flatten,
+                1,
                 [&](const auto& name, const auto& v, bool through_array) {
                     if (through_array) {
                         m_per_element.insert(name);
@@ -1027,7 +1142,8 @@ JsonLoader::infer_cols(const std::string& index) {
                         return;
                     }
 
-                    auto dtype = rapidjson_type_to_dtype(v);
+                    auto dtype =
+                        infer_leaf_dtype(name, v, m_mode, m_list_params);
                     if (dtype != DTYPE_NONE) {
                         known.insert(name);
                         m_types.push_back(dtype);
@@ -1056,13 +1172,276 @@ JsonLoader::infer_cols(const std::string& index) {
     }
 }
 
+/**
+ * Write one cell of a `DECIMAL` column, which accepts a JSON number or a
+ * decimal string such as `"12.50"` - the latter is how exact values survive
+ * JSON's binary floats.
+ */
+static void
+fill_decimal_json(
+    const std::shared_ptr<t_column>& col,
+    std::string_view col_name,
+    t_uindex ii,
+    const rapidjson::Value& cell,
+    const t_decimal_params& params,
+    bool is_update
+) {
+    if (cell.IsNull()) {
+        if (is_update) {
+            col->unset(ii);
+        } else {
+            col->clear(ii);
+        }
+
+        return;
+    }
+
+    std::optional<std::int64_t> unscaled;
+    if (cell.IsString()) {
+        unscaled = parse_decimal(
+            std::string_view(cell.GetString(), cell.GetStringLength()), params
+        );
+    } else if (cell.IsInt64()) {
+        unscaled = parse_decimal(std::to_string(cell.GetInt64()), params);
+    } else if (cell.IsUint64()) {
+        unscaled = parse_decimal(std::to_string(cell.GetUint64()), params);
+    } else if (cell.IsNumber()) {
+        unscaled = double_to_decimal(cell.GetDouble(), params);
+    }
+
+    if (!unscaled.has_value()) {
+        std::stringstream ss;
+        ss << "Value at index " << ii << " does not fit DECIMAL("
+           << params.precision << ", " << params.scale << ") column \""
+           << col_name << "\"" << std::endl;
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    col->set_nth<std::int64_t>(ii, *unscaled);
+}
+
+void
+JsonLoader::set_decimal_params(const t_decimal_map& params) {
+    m_decimal_params = params;
+}
+
+/**
+ * Write one cell of a `DTYPE_TIME` column stored in a unit other than
+ * milliseconds. JSON numbers are epoch milliseconds as everywhere else, and
+ * may be fractional; strings keep whatever sub-second digits `unit` resolves.
+ */
+static void
+fill_timestamp_json(
+    const std::shared_ptr<t_column>& col,
+    std::string_view col_name,
+    t_uindex ii,
+    const rapidjson::Value& cell,
+    const t_timestamp_params& params,
+    bool is_update
+) {
+    if (cell.IsNull()) {
+        if (is_update) {
+            col->unset(ii);
+        } else {
+            col->clear(ii);
+        }
+
+        return;
+    }
+
+    std::optional<std::int64_t> value;
+    if (cell.IsString()) {
+        value = parse_timestamp(
+            std::string_view(cell.GetString(), cell.GetStringLength()),
+            params.unit
+        );
+    } else if (cell.IsInt64()) {
+        value = convert_timestamp(
+            cell.GetInt64(), TIMESTAMP_UNIT_MILLISECOND, params.unit
+        );
+    } else if (cell.IsNumber()) {
+        value = millis_to_timestamp(cell.GetDouble(), params.unit);
+    }
+
+    if (!value.has_value()) {
+        std::stringstream ss;
+        ss << "Could not coerce value at index " << ii
+           << " to datetime column \"" << col_name << "\"" << std::endl;
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    col->set_nth<std::int64_t>(ii, *value);
+}
+
+void
+JsonLoader::set_timestamp_params(const t_timestamp_map& params) {
+    m_timestamp_params = params;
+}
+
+/**
+ * Write one cell of a `LIST` column, which accepts a JSON array or a string
+ * holding one, as a `LIST` read from CSV would be.
+ */
+static void
+fill_list_json(
+    const std::shared_ptr<t_column>& col,
+    std::string_view col_name,
+    t_uindex ii,
+    const rapidjson::Value& cell,
+    const t_list_params& params,
+    bool is_update
+) {
+    if (cell.IsNull()) {
+        if (is_update) {
+            col->unset(ii);
+        } else {
+            col->clear(ii);
+        }
+
+        return;
+    }
+
+    const auto encoded = encode_list(cell, params);
+    if (!encoded.has_value()) {
+        std::stringstream ss;
+        ss << "Value at index " << ii << " is not a LIST("
+           << list_element_name(params.element) << ") for column ""
+           << col_name << """ << std::endl;
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    col->set_nth(ii, encoded->c_str());
+}
+
+/**
+ * Write one cell of a column pinned by `t_schema_hints`, which unlike an
+ * inferred column is never promoted. An empty string is null in any but a
+ * string column.
+ *
+ * @return why the value could not be coerced, in which case the cell is left
+ * null.
+ */
+static std::optional<std::string>
+fill_hinted_json(
+    const std::shared_ptr<t_column>& col,
+    t_uindex ii,
+    const rapidjson::Value& cell,
+    bool is_update
+) {
+    const auto dtype = col->get_dtype();
+    const auto set_null = [&]() {
+        if (is_update) {
+            col->unset(ii);
+        } else {
+            col->clear(ii);
+        }
+    };
+
+    if (cell.IsNull()
+        || (cell.IsString() && cell.GetStringLength() == 0
+            && dtype != DTYPE_STR)) {
+        set_null();
+        return std::nullopt;
+    }
+
+    switch (dtype) {
+        case DTYPE_INT32: {
+            std::optional<double> value;
+            if (cell.IsNumber()) {
+                value = cell.GetDouble();
+            } else if (cell.IsString()) {
+                char* end;
+                errno = 0;
+                const auto parsed = std::strtoll(cell.GetString(), &end, 10);
+                if (*end == '\0' && errno == 0) {
+                    value = static_cast<double>(parsed);
+                }
+            }
+
+            if (value.has_value() && std::trunc(*value) == *value
+                && *value >= std::numeric_limits<std::int32_t>::min()
+                && *value <= std::numeric_limits<std::int32_t>::max()) {
+                col->set_nth<std::int32_t>(
+                    ii, static_cast<std::int32_t>(*value)
+                );
+
+                return std::nullopt;
+            }
+        } break;
+        case DTYPE_FLOAT64: {
+            if (cell.IsNumber()) {
+                col->set_nth<double>(ii, cell.GetDouble());
+                return std::nullopt;
+            }
+
+            if (cell.IsString()) {
+                char* end;
+                const double value = std::strtod(cell.GetString(), &end);
+                if (*end == '\0') {
+                    col->set_nth<double>(ii, value);
+                    return std::nullopt;
+                }
+            }
+        } break;
+        default: {
+            // String, boolean, date and datetime coercions are the same as for
+            // an inferred column, which reject what they can't coerce.
+            try {
+                if (!fill_column_json(col, ii, cell, is_update).has_value()) {
+                    return std::nullopt;
+                }
+            } catch (const PerspectiveException&) {
+            }
+        } break;
+    }
+
+    set_null();
+    return "Could not coerce value to " + dtype_to_str(dtype);
+}
+
+/**
+ * The raw text of a JSON value, i.e. a string's own contents, or the JSON text
+ * of anything else.
+ */
+static std::string
+json_raw_text(const rapidjson::Value& value) {
+    if (value.IsString()) {
+        return {value.GetString(), value.GetStringLength()};
+    }
+
+    rapidjson::StringBuffer buffer;
+    rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+    value.Accept(writer);
+    return buffer.GetString();
+}
+
+void
+JsonLoader::set_schema_hints(const t_schema_hints& hints) {
+    m_schema_hints = hints;
+}
+
+const t_row_errors&
+JsonLoader::row_errors() const {
+    return m_row_errors;
+}
+
+const t_list_map&
+JsonLoader::list_params() const {
+    return m_list_params;
+}
+
+void
+JsonLoader::set_list_params(const t_list_map& params) {
+    m_list_params = params;
+}
+
 /**
  * Write one cell, resolving a type conflict the only way each mode can: table
  * creation widens the column and rewrites, while an update cannot change the
  * schema of a live Table and so must reject the value.
  */
-static void
-fill_cell(
+void
+JsonLoader::fill_cell(
     t_data_table& tbl,
     const std::shared_ptr<t_column>& col,
     std::string_view col_name,
@@ -1070,6 +1449,44 @@ fill_cell(
     const rapidjson::Value& cell,
     bool is_update
 ) {
+    if (!m_schema_hints.empty()) {
+        const auto it = m_schema_hints.find(std::string{col_name});
+        if (it != m_schema_hints.end()) {
+            if (auto error = fill_hinted_json(col, ii, cell, is_update)) {
+                m_row_errors.push_back(t_row_error{
+                    ii, it->first, json_raw_text(cell), std::move(*error)
+                });
+            }
+
+            return;
+        }
+    }
+
+    if (!m_decimal_params.empty()) {
+        const auto it = m_decimal_params.find(std::string{col_name});
+        if (it != m_decimal_params.end()) {
+            fill_decimal_json(col, col_name, ii, cell, it->second, is_update);
+            return;
+        }
+    }
+
+    if (!m_list_params.empty() && col->get_dtype() == DTYPE_STR) {
+        const auto it = m_list_params.find(std::string{col_name});
+        if (it != m_list_params.end()) {
+            fill_list_json(col, col_name, ii, cell, it->second, is_update);
+            return;
+        }
+    }
+
+    if (!m_timestamp_params.empty() && col->get_dtype() == DTYPE_TIME) {
+        const auto it = m_timestamp_params.find(std::string{col_name});
+        if (it != m_timestamp_params.end()
+            && it->second.unit != TIMESTAMP_UNIT_MILLISECOND) {
+            fill_timestamp_json(col, col_name, ii, cell, it->second, is_update);
+            return;
+        }
+    }
+
     auto promote = fill_column_json(col, ii, cell, is_update);
     if (!promote) {
         return;
@@ -1117,7 +1534,7 @@ JsonLoader::fill_rows(
         }
 
         for (const auto& it : row.GetObj()) {
-            if (is_nested(it.value, m_mode)) {
+            if (is_nested(it.value, m_mode, m_struct_flatten)) {
                 nested = true;
                 break;
             }
@@ -1155,10 +1572,12 @@ JsonLoader::fill_rows(
             continue;
         }
 
-        const auto width = leaf_width(row, m_mode);
+        const auto width = leaf_width(row, m_mode, m_struct_flatten, 0);
         m_child_widths.clear();
         for (const auto& it : row.GetObj()) {
-            m_child_widths.push_back(leaf_width(it.value, m_mode));
+            m_child_widths.push_back(
+                leaf_width(it.value, m_mode, m_struct_flatten, 1)
+            );
         }
 
         check_row_count(static_cast<std::uint64_t>(ii) + width);
@@ -1194,6 +1613,8 @@ JsonLoader::fill_rows(
                 sub,
                 child_width,
                 m_mode,
+                m_struct_flatten,
+                1,
                 [&](const auto& name, const auto& v) {
                     auto col = resolve_column(tbl, name, v, is_update);
                     if (!col) {
@@ -1273,7 +1694,7 @@ JsonLoader::fill_cols(
         const auto len = static_cast<t_uindex>(cells[c]->Size());
         for (t_uindex r = 0; r < len; ++r) {
             const auto& cell = (*cells[c])[r];
-            if (is_nested(cell, m_mode)) {
+            if (is_nested(cell, m_mode, m_struct_flatten)) {
                 expands = true;
                 break;
             }
@@ -1314,7 +1735,8 @@ JsonLoader::fill_cols(
         const auto len = static_cast<t_uindex>(cells[c]->Size());
         widths[c].assign(nrows, 1);
         for (t_uindex r = 0; r < len; ++r) {
-            widths[c][r] = leaf_width((*cells[c])[r], m_mode);
+            widths[c][r] =
+                leaf_width((*cells[c])[r], m_mode, m_struct_flatten, 1);
         }
     }
 
@@ -1396,6 +1818,8 @@ JsonLoader::fill_cols(
                     sub,
                     widths[c][r],
                     m_mode,
+                    m_struct_flatten,
+                    1,
                     [&](const auto& name, const auto& v) {
                         auto col = resolve_column(tbl, name, v, is_update);
                         if (!col) {
@@ -1443,7 +1867,7 @@ JsonLoader::fill_ndjson(
     while (!is_finished) {
         bool nested = false;
         for (const auto& it : m_document.GetObj()) {
-            if (is_nested(it.value, m_mode)) {
+            if (is_nested(it.value, m_mode, m_struct_flatten)) {
                 nested = true;
                 break;
             }
@@ -1493,13 +1917,16 @@ JsonLoader::fill_ndjson(
             continue;
         }
 
-        const auto width = leaf_width(m_document, m_mode);
+        const auto width =
+            leaf_width(m_document, m_mode, m_struct_flatten, 0);
         check_row_count(static_cast<std::uint64_t>(ii) + width);
         tbl.extend(ii + width);
 
         m_child_widths.clear();
         for (const auto& it : m_document.GetObj()) {
-            m_child_widths.push_back(leaf_width(it.value, m_mode));
+            m_child_widths.push_back(
+                leaf_width(it.value, m_mode, m_struct_flatten, 1)
+            );
         }
 
         for (t_uindex k = 0; k < width; ++k) {
@@ -1529,6 +1956,8 @@ JsonLoader::fill_ndjson(
                 sub,
                 child_width,
                 m_mode,
+                m_struct_flatten,
+                1,
                 [&](const auto& name, const auto& v) {
                 auto col = resolve_column(tbl, name, v, is_update);
                 if (!col) {
@@ -0,0 +1,479 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#include <perspective/base.h>
+#include <perspective/list.h>
+#include <arrow/array.h>
+#include <arrow/builder.h>
+#include <arrow/type.h>
+#include <re2/re2.h>
+#include <cmath>
+#include <set>
+#include <sstream>
+
+namespace perspective {
+
+bool
+t_list_params::operator==(const t_list_params& other) const {
+    return element == other.element;
+}
+
+bool
+is_list_element_dtype(t_dtype dtype) {
+    switch (dtype) {
+        case DTYPE_STR:
+        case DTYPE_INT64:
+        case DTYPE_FLOAT64:
+        case DTYPE_BOOL:
+            return true;
+        default:
+            return false;
+    }
+}
+
+std::optional<t_dtype>
+list_element_dtype(const rapidjson::Value& array) {
+    t_dtype element = DTYPE_NONE;
+    for (const auto& value : array.GetArray()) {
+        t_dtype dtype;
+        if (value.IsNull()) {
+            continue;
+        } else if (value.IsString()) {
+            dtype = DTYPE_STR;
+        } else if (value.IsBool()) {
+            dtype = DTYPE_BOOL;
+        } else if (value.IsInt64()) {
+            dtype = DTYPE_INT64;
+        } else if (value.IsNumber()) {
+            dtype = DTYPE_FLOAT64;
+        } else {
+            return std::nullopt;
+        }
+
+        const auto is_number = [](t_dtype x) {
+            return x == DTYPE_INT64 || x == DTYPE_FLOAT64;
+        };
+
+        if (element == DTYPE_NONE || element == dtype) {
+            element = dtype;
+        } else if (is_number(element) && is_number(dtype)) {
+            element = DTYPE_FLOAT64;
+        } else {
+            return std::nullopt;
+        }
+    }
+
+    return element;
+}
+
+std::optional<t_dtype>
+list_element_dtype(const arrow::DataType& type) {
+    switch (type.id()) {
+        case arrow::Type::STRING:
+        case arrow::Type::LARGE_STRING:
+            return DTYPE_STR;
+        case arrow::Type::BOOL:
+            return DTYPE_BOOL;
+        case arrow::Type::INT8:
+        case arrow::Type::INT16:
+        case arrow::Type::INT32:
+        case arrow::Type::INT64:
+        case arrow::Type::UINT8:
+        case arrow::Type::UINT16:
+        case arrow::Type::UINT32:
+            return DTYPE_INT64;
+        case arrow::Type::FLOAT:
+        case arrow::Type::DOUBLE:
+            return DTYPE_FLOAT64;
+        default:
+            return std::nullopt;
+    }
+}
+
+std::shared_ptr<arrow::DataType>
+list_element_arrow_type(t_dtype element) {
+    switch (element) {
+        case DTYPE_INT64:
+            return arrow::int64();
+        case DTYPE_FLOAT64:
+            return arrow::float64();
+        case DTYPE_BOOL:
+            return arrow::boolean();
+        default:
+            return arrow::utf8();
+    }
+}
+
+std::shared_ptr<arrow::ListBuilder>
+make_list_builder(const t_list_params& params) {
+    auto* pool = arrow::default_memory_pool();
+    std::shared_ptr<arrow::ArrayBuilder> values;
+    switch (params.element) {
+        case DTYPE_INT64:
+            values = std::make_shared<arrow::Int64Builder>(pool);
+            break;
+        case DTYPE_FLOAT64:
+            values = std::make_shared<arrow::DoubleBuilder>(pool);
+            break;
+        case DTYPE_BOOL:
+            values = std::make_shared<arrow::BooleanBuilder>(pool);
+            break;
+        default:
+            values = std::make_shared<arrow::StringBuilder>(pool);
+            break;
+    }
+
+    return std::make_shared<arrow::ListBuilder>(pool, values);
+}
+
+arrow::Status
+append_list(
+    arrow::ListBuilder& builder, const char* text, const t_list_params& params
+) {
+    rapidjson::Document document;
+    document.Parse(text);
+    if (document.HasParseError() || !document.IsArray()) {
+        return arrow::Status::Invalid("Malformed LIST value `", text, "`");
+    }
+
+    ARROW_RETURN_NOT_OK(builder.Append());
+    auto* values = builder.value_builder();
+    for (const auto& element : document.GetArray()) {
+        if (element.IsNull()) {
+            ARROW_RETURN_NOT_OK(values->AppendNull());
+            continue;
+        }
+
+        switch (params.element) {
+            case DTYPE_INT64:
+                ARROW_RETURN_NOT_OK(
+                    static_cast<arrow::Int64Builder*>(values)->Append(
+                        element.GetInt64()
+                    )
+                );
+                break;
+            case DTYPE_FLOAT64:
+                ARROW_RETURN_NOT_OK(
+                    static_cast<arrow::DoubleBuilder*>(values)->Append(
+                        element.GetDouble()
+                    )
+                );
+                break;
+            case DTYPE_BOOL:
+                ARROW_RETURN_NOT_OK(
+                    static_cast<arrow::BooleanBuilder*>(values)->Append(
+                        element.GetBool()
+                    )
+                );
+                break;
+            default:
+                ARROW_RETURN_NOT_OK(
+                    static_cast<arrow::StringBuilder*>(values)->Append(
+                        element.GetString(), element.GetStringLength()
+                    )
+                );
+                break;
+        }
+    }
+
+    return arrow::Status::OK();
+}
+
+const char*
+list_element_name(t_dtype element) {
+    switch (element) {
+        case DTYPE_INT64:
+            return "integer";
+        case DTYPE_FLOAT64:
+            return "float";
+        case DTYPE_BOOL:
+            return "boolean";
+        default:
+            return "string";
+    }
+}
+
+t_list_encoder::t_list_encoder(const t_list_params& params) :
+    m_params(params),
+    m_writer(m_buffer) {}
+
+void
+t_list_encoder::start() {
+    m_buffer.Clear();
+    m_writer.Reset(m_buffer);
+    m_writer.StartArray();
+}
+
+/**
+ * Write `value` as an element of type `element`. Numbers cross between integer
+ * and float lists as long as no precision is lost; nothing else is coerced.
+ */
+static bool
+write_list_element(
+    const rapidjson::Value& value,
+    t_dtype element,
+    rapidjson::Writer<rapidjson::StringBuffer>& writer
+) {
+    if (value.IsNull()) {
+        return writer.Null();
+    }
+
+    switch (element) {
+        case DTYPE_STR:
+            return value.IsString()
+                && writer.String(value.GetString(), value.GetStringLength());
+        case DTYPE_BOOL:
+            return value.IsBool() && writer.Bool(value.GetBool());
+        case DTYPE_INT64: {
+            if (value.IsInt64()) {
+                return writer.Int64(value.GetInt64());
+            }
+
+            if (!value.IsDouble()) {
+                return false;
+            }
+
+            const double x = value.GetDouble();
+            if (std::trunc(x) != x || std::abs(x) >= 9223372036854775808.0) {
+                return false;
+            }
+
+            return writer.Int64(static_cast<std::int64_t>(x));
+        }
+        case DTYPE_FLOAT64: {
+            if (!value.IsNumber()) {
+                return false;
+            }
+
+            // JSON has no NaN, so an Arrow NaN element reads back as null.
+            const double x = value.GetDouble();
+            return std::isfinite(x) ? writer.Double(x) : writer.Null();
+        }
+        default:
+            return false;
+    }
+}
+
+bool
+t_list_encoder::push(const rapidjson::Value& element) {
+    return write_list_element(element, m_params.element, m_writer);
+}
+
+const char*
+t_list_encoder::finish() {
+    m_writer.EndArray();
+    return m_buffer.GetString();
+}
+
+std::optional<std::string>
+encode_list(const rapidjson::Value& value, const t_list_params& params) {
+    if (value.IsString()) {
+        rapidjson::Document parsed;
+        parsed.Parse(value.GetString(), value.GetStringLength());
+        if (parsed.HasParseError() || !parsed.IsArray()) {
+            return std::nullopt;
+        }
+
+        return encode_list(parsed, params);
+    }
+
+    if (!value.IsArray()) {
+        return std::nullopt;
+    }
+
+    t_list_encoder encoder(params);
+    encoder.start();
+    for (const auto& element : value.GetArray()) {
+        if (!encoder.push(element)) {
+            return std::nullopt;
+        }
+    }
+
+    return std::string{encoder.finish()};
+}
+
+template <typename ARRAY>
+static auto
+list_value(const arrow::Array& values, std::int64_t j) {
+    return static_cast<const ARRAY&>(values).Value(j);
+}
+
+/**
+ * Read element `j` of a `LIST` column's `values` as JSON, for
+ * `t_list_encoder`.
+ *
+ * @return false if `values` is not of a type a list element can be read from.
+ */
+static bool
+list_value_to_json(
+    const arrow::Array& values, std::int64_t j, rapidjson::Document& out
+) {
+    if (values.IsNull(j)) {
+        out.SetNull();
+        return true;
+    }
+
+    switch (values.type_id()) {
+        case arrow::Type::BOOL:
+            out.SetBool(list_value<arrow::BooleanArray>(values, j));
+            break;
+        case arrow::Type::INT8:
+            out.SetInt64(list_value<arrow::Int8Array>(values, j));
+            break;
+        case arrow::Type::UINT8:
+            out.SetInt64(list_value<arrow::UInt8Array>(values, j));
+            break;
+        case arrow::Type::INT16:
+            out.SetInt64(list_value<arrow::Int16Array>(values, j));
+            break;
+        case arrow::Type::UINT16:
+            out.SetInt64(list_value<arrow::UInt16Array>(values, j));
+            break;
+        case arrow::Type::INT32:
+            out.SetInt64(list_value<arrow::Int32Array>(values, j));
+            break;
+        case arrow::Type::UINT32:
+            out.SetInt64(list_value<arrow::UInt32Array>(values, j));
+            break;
+        case arrow::Type::INT64:
+            out.SetInt64(list_value<arrow::Int64Array>(values, j));
+            break;
+        case arrow::Type::UINT64:
+            out.SetUint64(list_value<arrow::UInt64Array>(values, j));
+            break;
+        case arrow::Type::FLOAT:
+            out.SetDouble(list_value<arrow::FloatArray>(values, j));
+            break;
+        case arrow::Type::DOUBLE:
+            out.SetDouble(list_value<arrow::DoubleArray>(values, j));
+            break;
+        case arrow::Type::STRING: {
+            const auto view =
+                static_cast<const arrow::StringArray&>(values).GetView(j);
+            out.SetString(view.data(), view.size(), out.GetAllocator());
+        } break;
+        case arrow::Type::LARGE_STRING: {
+            const auto view =
+                static_cast<const arrow::LargeStringArray&>(values).GetView(j);
+            out.SetString(view.data(), view.size(), out.GetAllocator());
+        } break;
+        default:
+            return false;
+    }
+
+    return true;
+}
+
+std::optional<std::string>
+encode_arrow_list(const arrow::Array& values, const t_list_params& params) {
+    t_list_encoder encoder(params);
+    rapidjson::Document element;
+    encoder.start();
+    for (std::int64_t k = 0; k < values.length(); ++k) {
+        if (!list_value_to_json(values, k, element) || !encoder.push(element)) {
+            return std::nullopt;
+        }
+    }
+
+    return std::string{encoder.finish()};
+}
+
+std::optional<std::string>
+encode_list_element(
+    const rapidjson::Value& value, const t_list_params& params
+) {
+    rapidjson::StringBuffer buffer;
+    rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+    if (!write_list_element(value, params.element, writer)) {
+        return std::nullopt;
+    }
+
+    return std::string{buffer.GetString()};
+}
+
+std::string
+list_membership_pattern(const std::vector<std::string>& elements) {
+    if (elements.empty()) {
+        // Matches nothing, as `(?:)` would match the empty list.
+        return "\\b\\B";
+    }
+
+    std::stringstream ss;
+    ss << "[\\[,](?:";
+    for (std::size_t i = 0; i < elements.size(); ++i) {
+        if (i > 0) {
+            ss << "|";
+        }
+
+        ss << RE2::QuoteMeta(elements[i]);
+    }
+
+    ss << ")[,\\]]";
+    return ss.str();
+}
+
+std::string
+union_lists(const std::vector<t_tscalar>& lists) {
+    // A list column's elements are all of one type, so at most one of these
+    // is populated.
+    std::set<bool> bools;
+    std::set<std::int64_t> integers;
+    std::set<double> floats;
+    std::set<std::string> strings;
+    for (const auto& list : lists) {
+        if (!list.is_valid() || !list.is_str()) {
+            continue;
+        }
+
+        rapidjson::Document parsed;
+        parsed.Parse(list.to_string().c_str());
+        if (parsed.HasParseError() || !parsed.IsArray()) {
+            continue;
+        }
+
+        for (const auto& value : parsed.GetArray()) {
+            if (value.IsString()) {
+                strings.emplace(value.GetString(), value.GetStringLength());
+            } else if (value.IsBool()) {
+                bools.insert(value.GetBool());
+            } else if (value.IsInt64()) {
+                integers.insert(value.GetInt64());
+            } else if (value.IsNumber()) {
+                floats.insert(value.GetDouble());
+            }
+        }
+    }
+
+    rapidjson::StringBuffer buffer;
+    rapidjson::Writer<rapidjson::StringBuffer> writer(buffer);
+    writer.StartArray();
+    for (const auto x : bools) {
+        writer.Bool(x);
+    }
+
+    for (const auto x : integers) {
+        writer.Int64(x);
+    }
+
+    for (const auto x : floats) {
+        writer.Double(x);
+    }
+
+    for (const auto& x : strings) {
+        writer.String(x.c_str(), x.size());
+    }
+
+    writer.EndArray();
+    return buffer.GetString();
+}
+
+} // namespace perspective
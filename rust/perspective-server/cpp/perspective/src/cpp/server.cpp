@@ -13,9 +13,12 @@
 #include "google/protobuf/repeated_ptr_field.h"
 #include "google/protobuf/struct.pb.h"
 #include "perspective.pb.h"
+#include "perspective/arrow_decompress.h"
 #include "perspective/base.h"
 #include "perspective/computed_expression.h"
+#include "perspective/decimal.h"
 #include "perspective/exception.h"
+#include "perspective/list.h"
 #include "perspective/pyutils.h"
 #include "perspective/raw_types.h"
 #include "perspective/scalar.h"
@@ -31,6 +34,7 @@
 #include <limits>
 #include <memory>
 #include <optional>
+#include <set>
 #include <perspective/server.h>
 #include <perspective/residency.h>
 #include <perspective/opfs.h>
@@ -66,6 +70,194 @@ tscalar_to_proto(const t_tscalar& scalar, proto::Scalar* out) {
     }
 }
 
+/**
+ * Like `tscalar_to_proto`, for a scalar of a `DECIMAL` column.
+ */
+static void
+decimal_to_proto(
+    const t_tscalar& scalar, const t_decimal_params& params, proto::Scalar* out
+) {
+    if (scalar.is_none() || !scalar.is_valid() || scalar.is_nan()) {
+        out->set_null(::google::protobuf::NullValue::NULL_VALUE);
+    } else {
+        out->set_decimal(
+            format_decimal(unscaled_decimal(scalar), params.scale)
+        );
+    }
+}
+
+static void
+decimal_params_to_proto(
+    const t_decimal_params& params, proto::ColumnTypeParams* out
+) {
+    out->set_precision(params.precision);
+    out->set_scale(params.scale);
+}
+
+static t_timestamp_unit
+time_unit_from_proto(proto::TimeUnit unit) {
+    switch (unit) {
+        case proto::TIME_UNIT_SECOND:
+            return TIMESTAMP_UNIT_SECOND;
+        case proto::TIME_UNIT_MICROSECOND:
+            return TIMESTAMP_UNIT_MICROSECOND;
+        case proto::TIME_UNIT_NANOSECOND:
+            return TIMESTAMP_UNIT_NANOSECOND;
+        default:
+            return TIMESTAMP_UNIT_MILLISECOND;
+    }
+}
+
+static void
+timestamp_params_to_proto(
+    const t_timestamp_params& params, proto::ColumnTypeParams* out
+) {
+    switch (params.unit) {
+        case TIMESTAMP_UNIT_SECOND:
+            out->set_unit(proto::TIME_UNIT_SECOND);
+            break;
+        case TIMESTAMP_UNIT_MILLISECOND:
+            out->set_unit(proto::TIME_UNIT_MILLISECOND);
+            break;
+        case TIMESTAMP_UNIT_MICROSECOND:
+            out->set_unit(proto::TIME_UNIT_MICROSECOND);
+            break;
+        case TIMESTAMP_UNIT_NANOSECOND:
+            out->set_unit(proto::TIME_UNIT_NANOSECOND);
+            break;
+    }
+
+    if (!params.timezone.empty()) {
+        out->set_timezone(params.timezone);
+    }
+}
+
+static proto::ColumnType
+list_element_to_proto(t_dtype element) {
+    switch (element) {
+        case DTYPE_INT64:
+            return proto::ColumnType::INTEGER;
+        case DTYPE_FLOAT64:
+            return proto::ColumnType::FLOAT;
+        case DTYPE_BOOL:
+            return proto::ColumnType::BOOLEAN;
+        default:
+            return proto::ColumnType::STRING;
+    }
+}
+
+/**
+ * The element type of a `LIST` column in a `Schema`, which defaults to
+ * `STRING`.
+ */
+static t_dtype
+list_element_from_proto(
+    const std::string& column, const proto::ColumnTypeParams& params
+) {
+    if (!params.has_element_type()) {
+        return DTYPE_STR;
+    }
+
+    switch (params.element_type()) {
+        case proto::ColumnType::STRING:
+            return DTYPE_STR;
+        case proto::ColumnType::INTEGER:
+            return DTYPE_INT64;
+        case proto::ColumnType::FLOAT:
+            return DTYPE_FLOAT64;
+        case proto::ColumnType::BOOLEAN:
+            return DTYPE_BOOL;
+        default:
+            PSP_COMPLAIN_AND_ABORT(
+                "Column `" + column
+                + "` must be a LIST of string, integer, float or boolean"
+            );
+            return DTYPE_STR;
+    }
+}
+
+static void
+list_params_to_proto(
+    const t_list_params& params, proto::ColumnTypeParams* out
+) {
+    out->set_element_type(list_element_to_proto(params.element));
+}
+
+/**
+ * Like `tscalar_to_proto`, for a scalar of a datetime column with `params`,
+ * which is sent as epoch milliseconds regardless of the column's unit.
+ */
+static void
+timestamp_to_proto(
+    const t_tscalar& scalar,
+    const t_timestamp_params& params,
+    proto::Scalar* out
+) {
+    if (scalar.is_none() || !scalar.is_valid() || scalar.is_nan()) {
+        out->set_null(::google::protobuf::NullValue::NULL_VALUE);
+    } else {
+        out->set_float_(timestamp_to_millis(scalar.to_double(), params.unit));
+    }
+}
+
+static void
+diff_keys_to_proto(
+    const std::vector<std::vector<t_tscalar>>& keys,
+    google::protobuf::RepeatedPtrField<proto::ViewOnUpdateDiff_Key>* out
+) {
+    for (const auto& key : keys) {
+        auto* values = out->Add()->mutable_values();
+        for (const auto& scalar : key) {
+            tscalar_to_proto(scalar, values->Add());
+        }
+    }
+}
+
+static void
+diff_to_proto(const server::t_view_diff& diff, proto::ViewOnUpdateDiff* out) {
+    *out->mutable_added() = *diff.added;
+    *out->mutable_updated() = *diff.updated;
+    *out->mutable_removed() = *diff.removed;
+    diff_keys_to_proto(diff.added_keys, out->mutable_added_keys());
+    diff_keys_to_proto(diff.updated_keys, out->mutable_updated_keys());
+    diff_keys_to_proto(diff.removed_keys, out->mutable_removed_keys());
+}
+
+static void
+row_errors_to_proto(
+    const t_row_errors& errors,
+    google::protobuf::RepeatedPtrField<proto::RowError>* out
+) {
+    for (const auto& error : errors) {
+        auto* row_error = out->Add();
+        row_error->set_row(error.row);
+        row_error->set_column(error.column);
+        row_error->set_value(error.value);
+        row_error->set_message(error.message);
+    }
+}
+
+static void
+ingestion_error_to_proto(
+    const PerspectiveIngestionException& e, proto::ServerError* out
+) {
+    out->set_status_code(proto::StatusCode::INGESTION_ERROR);
+    *out->mutable_message() = std::string(e.what());
+    row_errors_to_proto(e.row_errors(), out->mutable_row_errors());
+}
+
+static t_bad_rows
+bad_rows_from_proto(proto::OnBadRows on_bad_rows) {
+    switch (on_bad_rows) {
+        case proto::ON_BAD_ROWS_SKIP:
+            return BAD_ROWS_SKIP;
+        case proto::ON_BAD_ROWS_FAIL:
+            return BAD_ROWS_FAIL;
+        default:
+            return BAD_ROWS_NULL;
+    }
+}
+
 std::uint32_t server::ProtoServer::m_client_id = 1;
 
 template <>
@@ -550,6 +742,18 @@ ServerResources::delete_view(const std::uint32_t& client_id, const t_id& id) {
     drop_view_on_delete_sub(id);
 }
 
+std::uint32_t
+ServerResources::get_client_id_for_view(const t_id& view_id) {
+    PSP_READ_LOCK(m_write_lock);
+    for (const auto& [client_id, views] : m_client_to_view) {
+        if (std::find(views.begin(), views.end(), view_id) != views.end()) {
+            return client_id;
+        }
+    }
+
+    throw PerspectiveViewNotFoundException();
+}
+
 void
 ServerResources::delete_table(const t_id& id) {
     PSP_WRITE_LOCK(m_write_lock);
@@ -752,6 +956,38 @@ ServerResources::remove_on_hosted_tables_update_sub(
     );
 }
 
+void
+ServerResources::remove_request_subs(
+    std::uint32_t sub_id, std::uint32_t client_id
+) {
+    auto is_cancelled = [sub_id, client_id](const Subscription& sub) {
+        return sub.id == sub_id && sub.client_id == client_id;
+    };
+
+    PSP_WRITE_LOCK(m_write_lock);
+    for (auto* subs_map :
+         {&m_view_on_update_subs,
+          &m_view_on_delete_subs,
+          &m_table_on_delete_subs}) {
+        for (auto it = subs_map->begin(); it != subs_map->end(); ++it) {
+            auto& subs = it.value();
+            subs.erase(
+                std::remove_if(subs.begin(), subs.end(), is_cancelled),
+                subs.end()
+            );
+        }
+    }
+
+    m_on_hosted_tables_update_subs.erase(
+        std::remove_if(
+            m_on_hosted_tables_update_subs.begin(),
+            m_on_hosted_tables_update_subs.end(),
+            is_cancelled
+        ),
+        m_on_hosted_tables_update_subs.end()
+    );
+}
+
 std::vector<std::pair<std::shared_ptr<Table>, const ServerResources::t_id>>
 ServerResources::get_dirty_tables() {
     PSP_READ_LOCK(m_write_lock);
@@ -867,6 +1103,10 @@ ProtoServer::handle_request(
         auto* msg = err->mutable_message();
         *msg = std::string(e.what());
         responses.emplace_back(std::move(resp));
+    } catch (const PerspectiveIngestionException& e) {
+        proto::Response resp;
+        ingestion_error_to_proto(e, resp.mutable_server_error());
+        responses.emplace_back(std::move(resp));
     } catch (const std::exception& e) {
         proto::Response resp;
         auto* err = resp.mutable_server_error()->mutable_message();
@@ -909,6 +1149,26 @@ ProtoServer::handle_request(
     return serialized_responses;
 }
 
+void
+ProtoServer::cancel_request(std::uint32_t client_id, std::uint32_t msg_id) {
+    std::lock_guard<std::mutex> lock(m_cancelled_lock);
+    if (!m_cancelled.emplace(client_id, msg_id).second) {
+        return;
+    }
+
+    m_cancelled_order.emplace_back(client_id, msg_id);
+    if (m_cancelled_order.size() > MAX_CANCELLED) {
+        m_cancelled.erase(m_cancelled_order.front());
+        m_cancelled_order.pop_front();
+    }
+}
+
+bool
+ProtoServer::is_cancelled(std::uint32_t client_id, std::uint32_t msg_id) {
+    std::lock_guard<std::mutex> lock(m_cancelled_lock);
+    return m_cancelled.count({client_id, msg_id}) > 0;
+}
+
 std::vector<ProtoServerResp<std::string>>
 ProtoServer::poll() {
     const auto start = std::chrono::high_resolution_clock::now();
@@ -1028,18 +1288,97 @@ column_type_to_dtype(const proto::ColumnType& t) {
             return t_dtype::DTYPE_FLOAT64;
         case proto::ColumnType::INTEGER:
             return t_dtype::DTYPE_INT32;
+        case proto::ColumnType::DECIMAL:
+            // Stored unscaled, see `t_decimal_params`.
+            return t_dtype::DTYPE_INT64;
         case proto::ColumnType::DATE:
             return t_dtype::DTYPE_DATE;
         case proto::ColumnType::DATETIME:
             return t_dtype::DTYPE_TIME;
         case proto::ColumnType::STRING:
             return t_dtype::DTYPE_STR;
+        case proto::ColumnType::LIST:
+            // Stored as encoded JSON text, see `t_list_params`.
+            return t_dtype::DTYPE_STR;
         default:
             PSP_COMPLAIN_AND_ABORT("Invalid column type");
             return t_dtype::DTYPE_STR;
     }
 }
 
+static char
+csv_char_from_proto(const char* option, const std::string& value) {
+    if (value.size() != 1) {
+        std::stringstream ss;
+        ss << "CSV `" << option << "` must be a single character, found \""
+           << value << "\"";
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    return value[0];
+}
+
+/**
+ * The `t_dtype` of each column named by a `map<string, ColumnType>` option,
+ * which can't name the `DECIMAL` or `LIST` types as these need parameters.
+ */
+template <typename MAP>
+static t_schema_hints
+column_types_from_proto(const char* option, const MAP& types) {
+    t_schema_hints dtypes;
+    for (const auto& [name, type] : types) {
+        if (type == proto::ColumnType::DECIMAL
+            || type == proto::ColumnType::LIST) {
+            std::stringstream ss;
+            ss << "`" << option << "` does not support the type of column \""
+               << name << "\"";
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+
+        dtypes[name] =
+            column_type_to_dtype(static_cast<proto::ColumnType>(type));
+    }
+
+    return dtypes;
+}
+
+static apachearrow::t_csv_options
+csv_options_from_proto(const proto::CsvOptions& options) {
+    apachearrow::t_csv_options csv;
+    if (options.has_delimiter()) {
+        csv.delimiter = csv_char_from_proto("delimiter", options.delimiter());
+    }
+
+    if (options.has_quote()) {
+        csv.quote = csv_char_from_proto("quote", options.quote());
+    }
+
+    if (options.has_escape()) {
+        csv.escape = csv_char_from_proto("escape", options.escape());
+    }
+
+    csv.column_names.assign(
+        options.column_names().begin(), options.column_names().end()
+    );
+
+    csv.null_values.assign(
+        options.null_values().begin(), options.null_values().end()
+    );
+
+    csv.column_types =
+        column_types_from_proto("column_types", options.column_types());
+
+    if (options.has_date_format()) {
+        csv.date_format = options.date_format();
+    }
+
+    if (options.has_datetime_format()) {
+        csv.datetime_format = options.datetime_format();
+    }
+
+    return csv;
+}
+
 struct ValidViewPort {
     std::uint32_t start_row;
     std::uint32_t end_row;
@@ -1104,9 +1443,15 @@ needs_poll(const proto::Request::ClientReqCase proto_case) {
         case ReqCase::kViewToRowsStringReq:
         case ReqCase::kViewToNdjsonStringReq:
         case ReqCase::kViewToArrowReq:
+        case ReqCase::kViewToArrowPageReq:
         case ReqCase::kViewSchemaReq:
         case ReqCase::kViewGetMinMaxReq:
         case ReqCase::kTableRemoveReq:
+        case ReqCase::kTableTransactionReq:
+        case ReqCase::kTableRemoveWhereReq:
+        case ReqCase::kTableAddColumnsReq:
+        case ReqCase::kTableDropColumnsReq:
+        case ReqCase::kTableCastColumnReq:
         case ReqCase::kTableMakeViewReq:
         case ReqCase::kViewOnUpdateReq:
         case ReqCase::kViewCollapseReq:
@@ -1120,6 +1465,8 @@ needs_poll(const proto::Request::ClientReqCase proto_case) {
         case ReqCase::kTableRemoveDeleteReq:
         case ReqCase::kGetHostedTablesReq:
         case ReqCase::kRemoveHostedTablesUpdateReq:
+        case ReqCase::kUnsubscribeReq:
+        case ReqCase::kBatchReq:
         case ReqCase::kTableReplaceReq:
         case ReqCase::kTableDeleteReq:
         case ReqCase::kViewGetConfigReq:
@@ -1149,6 +1496,11 @@ entity_type_is_table(const proto::Request::ClientReqCase proto_case) {
         case ReqCase::kMakeTableReq:
         case ReqCase::kTableOnDeleteReq:
         case ReqCase::kTableRemoveReq:
+        case ReqCase::kTableTransactionReq:
+        case ReqCase::kTableRemoveWhereReq:
+        case ReqCase::kTableAddColumnsReq:
+        case ReqCase::kTableDropColumnsReq:
+        case ReqCase::kTableCastColumnReq:
         case ReqCase::kTableUpdateReq:
         case ReqCase::kTableRemoveDeleteReq:
         case ReqCase::kGetHostedTablesReq:
@@ -1167,6 +1519,7 @@ entity_type_is_table(const proto::Request::ClientReqCase proto_case) {
         case ReqCase::kViewToNdjsonStringReq:
         case ReqCase::kViewToRowsStringReq:
         case ReqCase::kViewToArrowReq:
+        case ReqCase::kViewToArrowPageReq:
         case ReqCase::kViewSchemaReq:
         case ReqCase::kViewGetMinMaxReq:
         case ReqCase::kViewOnUpdateReq:
@@ -1179,6 +1532,8 @@ entity_type_is_table(const proto::Request::ClientReqCase proto_case) {
         case ReqCase::kViewExpressionSchemaReq:
         case ReqCase::kViewRemoveOnUpdateReq:
         case ReqCase::kRemoveHostedTablesUpdateReq:
+        case ReqCase::kUnsubscribeReq:
+        case ReqCase::kBatchReq:
             return false;
         case proto::Request::CLIENT_REQ_NOT_SET:
             throw std::runtime_error("Unhandled request type 2");
@@ -1395,6 +1750,653 @@ coerce_to(const t_dtype dtype, const A& val) {
     }
 }
 
+/**
+ * Rewrite a `from_compressed` payload in place as the equivalent uncompressed
+ * `MakeTableData`, so handlers never see compressed input.
+ */
+static void
+decompress_table_data(proto::MakeTableData& data) {
+    if (data.data_case() != proto::MakeTableData::kFromCompressed) {
+        return;
+    }
+
+    const auto format = data.from_compressed().format();
+    auto payload = apachearrow::decompress(data.from_compressed().data());
+    switch (format) {
+        case proto::MakeTableData::CompressedData::CSV:
+            data.set_from_csv(std::move(payload));
+            break;
+        case proto::MakeTableData::CompressedData::ARROW:
+            data.set_from_arrow(std::move(payload));
+            break;
+        case proto::MakeTableData::CompressedData::ROWS:
+            data.set_from_rows(std::move(payload));
+            break;
+        case proto::MakeTableData::CompressedData::COLS:
+            data.set_from_cols(std::move(payload));
+            break;
+        case proto::MakeTableData::CompressedData::NDJSON:
+            data.set_from_ndjson(std::move(payload));
+            break;
+        default:
+            PSP_COMPLAIN_AND_ABORT("Unknown compressed input format");
+    }
+}
+
+/**
+ * Decompress any `from_compressed` table data carried by `req`.
+ */
+static void
+decompress_request(proto::Request& req) {
+    switch (req.client_req_case()) {
+        case proto::Request::kMakeTableReq:
+            if (req.make_table_req().has_data()) {
+                decompress_table_data(
+                    *req.mutable_make_table_req()->mutable_data()
+                );
+            }
+            break;
+        case proto::Request::kTableUpdateReq:
+            if (req.table_update_req().has_data()) {
+                decompress_table_data(
+                    *req.mutable_table_update_req()->mutable_data()
+                );
+            }
+            break;
+        case proto::Request::kTableReplaceReq:
+            if (req.table_replace_req().has_data()) {
+                decompress_table_data(
+                    *req.mutable_table_replace_req()->mutable_data()
+                );
+            }
+            break;
+        case proto::Request::kTableRemoveReq:
+            if (req.table_remove_req().has_data()) {
+                decompress_table_data(
+                    *req.mutable_table_remove_req()->mutable_data()
+                );
+            }
+            break;
+        case proto::Request::kTableTransactionReq: {
+            auto* ops = req.mutable_table_transaction_req()->mutable_ops();
+            for (auto& op : *ops) {
+                if (op.has_update()) {
+                    decompress_table_data(*op.mutable_update());
+                } else if (op.has_remove()) {
+                    decompress_table_data(*op.mutable_remove());
+                }
+            }
+            break;
+        }
+        default:
+            break;
+    }
+}
+
+/**
+ * Parse a filter argument for a `DECIMAL` column into its unscaled value, so
+ * it compares exactly against the stored `DTYPE_INT64`.
+ */
+static t_tscalar
+parse_decimal_filter(
+    const std::string& column,
+    const proto::Scalar& arg,
+    const t_decimal_params& params
+) {
+    std::optional<std::int64_t> unscaled;
+    switch (arg.scalar_case()) {
+        case proto::Scalar::kFloat:
+            unscaled = double_to_decimal(arg.float_(), params);
+            break;
+        case proto::Scalar::kString:
+            unscaled = parse_decimal(arg.string(), params);
+            break;
+        case proto::Scalar::kDecimal:
+            unscaled = parse_decimal(arg.decimal(), params);
+            break;
+        default:
+            break;
+    }
+
+    if (!unscaled.has_value()) {
+        std::stringstream ss;
+        ss << "Filter value for DECIMAL(" << params.precision << ", "
+           << params.scale << ") column `" << column << "` is not a decimal";
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+
+    t_tscalar a;
+    a.set(*unscaled);
+    return a;
+}
+
+/**
+ * Parse a filter argument for a datetime column stored in a unit other than
+ * milliseconds into that unit. Numbers are epoch milliseconds, as for any
+ * other datetime column.
+ */
+static t_tscalar
+parse_timestamp_filter(
+    const std::string& column,
+    const proto::Scalar& arg,
+    const t_timestamp_params& params
+) {
+    std::optional<std::int64_t> value;
+    switch (arg.scalar_case()) {
+        case proto::Scalar::kFloat:
+            value = millis_to_timestamp(arg.float_(), params.unit);
+            break;
+        case proto::Scalar::kString:
+            value = parse_timestamp(arg.string(), params.unit);
+            break;
+        default:
+            break;
+    }
+
+    if (!value.has_value()) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Filter value for datetime column `" + column
+            + "` is not a datetime"
+        );
+    }
+
+    t_tscalar a;
+    a.set(t_time(*value));
+    return a;
+}
+
+/**
+ * The `matches` pattern for a membership filter (`contains`, `in` and their
+ * negations) on a `LIST` column, matching lists with any of `args` as an
+ * element - see `list_membership_pattern`.
+ */
+static std::string
+parse_list_filter(
+    const std::string& column,
+    const google::protobuf::RepeatedPtrField<proto::Scalar>& args,
+    const t_list_params& params
+) {
+    std::vector<std::string> elements;
+    elements.reserve(args.size());
+    for (const auto& arg : args) {
+        rapidjson::Document value;
+        switch (arg.scalar_case()) {
+            case proto::Scalar::kBool:
+                value.SetBool(arg.bool_());
+                break;
+            case proto::Scalar::kFloat:
+                value.SetDouble(arg.float_());
+                break;
+            case proto::Scalar::kString:
+                // Non-string elements typed as text, e.g. from a filter input.
+                if (params.element == DTYPE_STR
+                    || value.Parse(arg.string().c_str()).HasParseError()) {
+                    value.SetString(
+                        arg.string().c_str(),
+                        arg.string().size(),
+                        value.GetAllocator()
+                    );
+                }
+
+                break;
+            default:
+                continue;
+        }
+
+        auto element = encode_list_element(value, params);
+        if (!element.has_value() || *element == "null") {
+            std::stringstream ss;
+            ss << "Filter value for LIST(" << list_element_name(params.element)
+               << ") column `" << column << "` is not a list element";
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+
+        elements.push_back(std::move(*element));
+    }
+
+    return list_membership_pattern(elements);
+}
+
+/**
+ * Parse the `Filter` terms of a `ViewConfig` (or `TableRemoveWhereReq`) into
+ * scalars of `schema`'s column types. String arguments are interned in
+ * `vocab`, which must outlive the result.
+ */
+static std::vector<
+    std::tuple<std::string, std::string, std::vector<t_tscalar>>>
+parse_filters(
+    const t_schema& schema,
+    const google::protobuf::RepeatedPtrField<proto::ViewConfig_Filter>& filters,
+    t_vocab& vocab,
+    const t_decimal_map& decimals,
+    const t_timestamp_map& timestamps,
+    const t_list_map& lists
+) {
+    std::vector<
+        std::tuple<std::string, std::string, std::vector<t_tscalar>>>
+        filter;
+    filter.reserve(filters.size());
+
+    // Membership filters on `LIST` columns become `matches` filters on their
+    // encoded text.
+    std::vector<std::optional<std::string>> list_patterns;
+    list_patterns.reserve(filters.size());
+    for (const auto& f : filters) {
+        const auto list = lists.find(f.column());
+        const auto& op = f.op();
+        if (list == lists.end()
+            || (op != "contains" && op != "not contains" && op != "in"
+                && op != "not in")) {
+            list_patterns.emplace_back();
+            continue;
+        }
+
+        list_patterns.emplace_back(
+            parse_list_filter(f.column(), f.value(), list->second)
+        );
+
+        vocab.get_interned(*list_patterns.back());
+    }
+
+    for (const auto& f : filters) {
+        for (const auto& arg : f.value()) {
+            switch (arg.scalar_case()) {
+                case proto::Scalar::kString:
+                case proto::Scalar::kDecimal: {
+                    const auto& text =
+                        arg.scalar_case() == proto::Scalar::kString
+                        ? arg.string()
+                        : arg.decimal();
+#ifdef PSP_SSO_SCALAR
+                    if (!t_tscalar::can_store_inplace(text)) {
+                        vocab.get_interned(text);
+                    }
+#else
+                    vocab.get_interned(text);
+#endif
+                    break;
+                }
+                case proto::Scalar::kBool:
+                case proto::Scalar::kFloat:
+                case proto::Scalar::kNull:
+                case proto::Scalar::SCALAR_NOT_SET:
+                    break;
+            }
+        }
+    }
+
+    for (int i = 0; i < filters.size(); ++i) {
+        const auto& f = filters[i];
+        if (list_patterns[i].has_value()) {
+            const auto* text =
+                vocab.unintern_c(vocab.get_interned(*list_patterns[i]));
+
+            t_tscalar pattern;
+            pattern.set(text);
+
+            const bool negated = f.op() == "not contains" || f.op() == "not in";
+            filter.emplace_back(
+                f.column(),
+                negated ? "not matches" : "matches",
+                std::vector<t_tscalar>{pattern}
+            );
+
+            continue;
+        }
+
+        std::vector<t_tscalar> args;
+        args.reserve(f.value().size());
+        const auto decimal = decimals.find(f.column());
+        const auto timestamp = timestamps.find(f.column());
+        const bool is_native_timestamp = timestamp != timestamps.end()
+            && timestamp->second.unit != TIMESTAMP_UNIT_MILLISECOND
+            && schema.has_column(f.column())
+            && schema.get_dtype(f.column()) == DTYPE_TIME;
+
+        for (const auto& arg : f.value()) {
+            t_tscalar a;
+            a.clear();
+            if (decimal != decimals.end()
+                && (arg.scalar_case() == proto::Scalar::kFloat
+                    || arg.scalar_case() == proto::Scalar::kString
+                    || arg.scalar_case() == proto::Scalar::kDecimal)) {
+                args.push_back(
+                    parse_decimal_filter(f.column(), arg, decimal->second)
+                );
+
+                continue;
+            }
+
+            if (is_native_timestamp
+                && (arg.scalar_case() == proto::Scalar::kFloat
+                    || arg.scalar_case() == proto::Scalar::kString)) {
+                args.push_back(
+                    parse_timestamp_filter(f.column(), arg, timestamp->second)
+                );
+
+                continue;
+            }
+
+            switch (arg.scalar_case()) {
+                case proto::Scalar::kBool: {
+                    a.set(arg.bool_());
+                    args.push_back(a);
+                    break;
+                }
+                case proto::Scalar::kFloat: {
+                    a = coerce_to(
+                        schema.get_dtype(f.column()), arg.float_()
+                    );
+
+                    args.push_back(a);
+                    break;
+                }
+                case proto::Scalar::kString:
+                case proto::Scalar::kDecimal: {
+                    if (!schema.has_column(f.column())) {
+                        PSP_COMPLAIN_AND_ABORT(
+                            "Filter column not in schema: " + f.column()
+                        );
+                    }
+
+                    // A decimal operand on a non-`DECIMAL` column coerces
+                    // from its text, like a string.
+                    const auto& text =
+                        arg.scalar_case() == proto::Scalar::kString
+                        ? arg.string()
+                        : arg.decimal();
+#ifdef PSP_SSO_SCALAR
+                    if (!t_tscalar::can_store_inplace(text)) {
+#endif
+                        a = coerce_to(
+                            schema.get_dtype(f.column()),
+                            vocab.unintern_c(vocab.get_interned(text))
+                        );
+#ifdef PSP_SSO_SCALAR
+                    } else {
+
+                        a = coerce_to(
+                            schema.get_dtype(f.column()), text.c_str()
+                        );
+                    }
+#endif
+                    args.push_back(a);
+                    break;
+                }
+                case proto::Scalar::kNull:
+                    a.set(t_none());
+                    args.push_back(a);
+                    break;
+                case proto::Scalar::SCALAR_NOT_SET:
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Filter scalar type not implemented: "
+                        + std::to_string(arg.scalar_case())
+                    )
+                    break;
+            }
+        }
+
+        filter.emplace_back(f.column(), f.op(), args);
+    }
+
+    return filter;
+}
+
+/**
+ * `DECIMAL` columns are stored as unscaled integers, so reject the `View`
+ * features which would read them as plain numbers rather than render a
+ * silently mis-scaled result.
+ */
+static void
+validate_decimal_view(
+    const t_view_config& config, const t_decimal_map& decimals
+) {
+    if (decimals.empty()) {
+        return;
+    }
+
+    for (const auto& name : config.get_column_pivots()) {
+        if (decimals.count(name) > 0) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Cannot `split_by` DECIMAL column `" + name + "`"
+            );
+        }
+    }
+
+    for (const auto& agg : config.get_aggspecs()) {
+        const auto deps = agg.get_input_depnames();
+        if (deps.empty() || decimals.count(deps[0]) == 0) {
+            continue;
+        }
+
+        switch (agg.agg()) {
+            case AGGTYPE_MUL:
+            case AGGTYPE_JOIN:
+            case AGGTYPE_SCALED_DIV:
+            case AGGTYPE_SCALED_ADD:
+            case AGGTYPE_SCALED_MUL:
+            case AGGTYPE_PY_AGG:
+            case AGGTYPE_AND:
+            case AGGTYPE_OR:
+            case AGGTYPE_UDF_COMBINER:
+            case AGGTYPE_UDF_REDUCER:
+            case AGGTYPE_VARIANCE:
+            case AGGTYPE_STANDARD_DEVIATION: {
+                std::stringstream ss;
+                ss << "Aggregate `" << agg.agg_str()
+                   << "` is not supported for DECIMAL column `" << deps[0]
+                   << "`";
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            } break;
+            default:
+                break;
+        }
+    }
+}
+
+/**
+ * Column headers are rendered from raw millisecond `t_time`s, so a datetime
+ * column stored in another unit cannot be a `split_by`.
+ */
+static void
+validate_timestamp_view(
+    const t_view_config& config, const t_timestamp_map& timestamps
+) {
+    for (const auto& name : config.get_column_pivots()) {
+        const auto it = timestamps.find(name);
+        if (it != timestamps.end()
+            && it->second.unit != TIMESTAMP_UNIT_MILLISECOND) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Cannot `split_by` datetime column `" + name + "` with unit `"
+                + timestamp_unit_name(it->second.unit) + "`"
+            );
+        }
+    }
+}
+
+/**
+ * `union` reads its input as encoded `LIST` values, and `LIST` columns only
+ * support the aggregates which don't read their values as scalars.
+ */
+static void
+validate_list_view(const t_view_config& config, const t_list_map& lists) {
+    for (const auto& agg : config.get_aggspecs()) {
+        const auto deps = agg.get_input_depnames();
+        if (deps.empty()) {
+            continue;
+        }
+
+        const bool is_list = lists.count(deps[0]) > 0;
+        if (agg.agg() == AGGTYPE_UNION && !is_list) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Aggregate `union` requires a LIST column, not `" + deps[0]
+                + "`"
+            );
+        }
+
+        if (!is_list) {
+            continue;
+        }
+
+        switch (agg.agg()) {
+            case AGGTYPE_COUNT:
+            case AGGTYPE_DISTINCT_COUNT:
+            case AGGTYPE_UNION:
+            case AGGTYPE_ANY:
+            case AGGTYPE_UNIQUE:
+            case AGGTYPE_DOMINANT:
+            case AGGTYPE_FIRST:
+            case AGGTYPE_LAST_BY_INDEX:
+            case AGGTYPE_LAST_VALUE:
+            case AGGTYPE_IDENTITY:
+            case AGGTYPE_DISTINCT_LEAF:
+                break;
+            default: {
+                std::stringstream ss;
+                ss << "Aggregate `" << agg.agg_str()
+                   << "` is not supported for LIST column `" << deps[0]
+                   << "`";
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            } break;
+        }
+    }
+}
+
+/**
+ * Whether a `View` with `config` reads any of `columns`, and so can't survive
+ * them being dropped or retyped.
+ */
+static bool
+view_config_reads_any(
+    const t_view_config& config, const std::set<std::string>& columns
+) {
+    const auto reads = [&](const std::string& name) {
+        return columns.count(name) > 0;
+    };
+
+    const auto reads_any = [&](const std::vector<std::string>& names) {
+        return std::any_of(names.begin(), names.end(), reads);
+    };
+
+    if (reads_any(config.get_columns()) || reads_any(config.get_row_pivots())
+        || reads_any(config.get_column_pivots())) {
+        return true;
+    }
+
+    for (const auto& fterm : config.get_fterm()) {
+        if (reads(fterm.m_colname)) {
+            return true;
+        }
+    }
+
+    for (const auto& sort : config.get_sortspec()) {
+        if (reads(sort.m_colname)) {
+            return true;
+        }
+    }
+
+    for (const auto& sort : config.get_col_sortspec()) {
+        if (reads(sort.m_colname)) {
+            return true;
+        }
+    }
+
+    for (const auto& aggspec : config.get_aggspecs()) {
+        if (reads_any(aggspec.get_input_depnames())) {
+            return true;
+        }
+    }
+
+    for (const auto& expr : config.get_expressions()) {
+        for (const auto& [column_id, name] : expr->get_column_ids()) {
+            if (reads(name)) {
+                return true;
+            }
+        }
+    }
+
+    for (const auto& window : config.get_windows()) {
+        if (reads(window.m_source) || reads(window.m_order_by)
+            || reads_any(window.m_partition_by)) {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+std::vector<std::string>
+ProtoServer::_alter_table_columns(
+    const ServerResources::t_id& table_id,
+    const std::vector<std::pair<std::string, t_dtype>>& add,
+    const std::vector<std::string>& drop,
+    const std::vector<std::pair<std::string, t_dtype>>& cast,
+    std::vector<ProtoServerResp<Response>>& outs
+) {
+    if (m_join_engine.is_join_table(table_id)
+        || !m_join_engine.get_dependent_join_tables(table_id).empty()) {
+        PSP_COMPLAIN_AND_ABORT(
+            "Cannot change the columns of a join table or its source tables"
+        );
+    }
+
+    auto table = m_resources.get_table(table_id);
+    const auto schema = table->get_schema();
+    std::set<std::string> invalidated{drop.begin(), drop.end()};
+    for (const auto& [name, dtype] : cast) {
+        // A no-op cast leaves every `View` valid.
+        if (!schema.has_column(name) || schema.get_dtype(name) != dtype) {
+            invalidated.insert(name);
+        }
+    }
+
+    // Validate the change before deleting any `View`s, which can't be undone.
+    for (const auto& name : invalidated) {
+        if (!schema.has_column(name) || name == table->get_index()) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Cannot drop or cast column `" + name + "`"
+            );
+        }
+    }
+
+    for (const auto& [name, dtype] : add) {
+        if (schema.has_column(name)) {
+            PSP_COMPLAIN_AND_ABORT("Column `" + name + "` already exists");
+        }
+    }
+
+    // Convert every value before deleting any `View`s, so a failed cast
+    // leaves both the `Table` and its `View`s untouched.
+    auto altered = table->prepare_alter_columns(add, drop, cast);
+    std::vector<std::string> deleted;
+    for (const auto& view_id : m_resources.get_view_ids(table_id)) {
+        const auto view = m_resources.get_view(view_id);
+        if (!view_config_reads_any(*view->get_view_config(), invalidated)) {
+            continue;
+        }
+
+        for (const auto& sub : m_resources.get_view_on_delete_sub(view_id)) {
+            proto::Response resp;
+            resp.mutable_view_on_delete_resp();
+            resp.set_msg_id(sub.id);
+            resp.set_entity_id(view_id);
+            ProtoServerResp<proto::Response> resp2;
+            resp2.data = std::move(resp);
+            resp2.client_id = sub.client_id;
+            outs.emplace_back(std::move(resp2));
+        }
+
+        m_resources.delete_view(
+            m_resources.get_client_id_for_view(view_id), view_id
+        );
+
+        deleted.push_back(view_id);
+    }
+
+    table->apply_altered_columns(std::move(altered));
+    return deleted;
+}
+
 std::vector<ProtoServerResp<ProtoServer::Response>>
 ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
     std::vector<ProtoServerResp<ProtoServer::Response>> proto_resp;
@@ -1412,6 +2414,7 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
         proto_resp.emplace_back(std::move(resp2));
     };
 
+    decompress_request(req);
     if (!m_realtime_mode) {
         handle_process_table(req, proto_resp);
     }
@@ -1545,6 +2548,8 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             (*features->mutable_filter_ops())[proto::ColumnType::DATE] = opts2;
             (*features->mutable_filter_ops())[proto::ColumnType::DATETIME] =
                 opts2;
+            (*features->mutable_filter_ops())[proto::ColumnType::DECIMAL] =
+                opts2;
             (*features->mutable_filter_ops())[proto::ColumnType::INTEGER] =
                 std::move(opts2);
 
@@ -1556,6 +2561,16 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             (*features->mutable_filter_ops())[proto::ColumnType::BOOLEAN] =
                 opts2;
 
+            proto::GetFeaturesResp_ColumnTypeOptions list_filter_opts;
+            list_filter_opts.add_options("contains");
+            list_filter_opts.add_options("not contains");
+            list_filter_opts.add_options("in");
+            list_filter_opts.add_options("not in");
+            list_filter_opts.add_options("is not null");
+            list_filter_opts.add_options("is null");
+            (*features->mutable_filter_ops())[proto::ColumnType::LIST] =
+                std::move(list_filter_opts);
+
             proto::GetFeaturesResp_AggregateOptions string_opts;
             string_opts.add_aggregates()->set_name("count");
             string_opts.add_aggregates()->set_name("any");
@@ -1640,6 +2655,17 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             (*features->mutable_aggregates())[proto::ColumnType::FLOAT] =
                 number_opts;
 
+            proto::GetFeaturesResp_AggregateOptions decimal_opts;
+            for (const auto& agg : number_opts.aggregates()) {
+                if (agg.name() != "mul" && agg.name() != "stddev"
+                    && agg.name() != "var") {
+                    *decimal_opts.add_aggregates() = agg;
+                }
+            }
+
+            (*features->mutable_aggregates())[proto::ColumnType::DECIMAL] =
+                decimal_opts;
+
             proto::GetFeaturesResp_AggregateOptions datetime_opts;
             datetime_opts.add_aggregates()->set_name("count");
             datetime_opts.add_aggregates()->set_name("any");
@@ -1664,6 +2690,20 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             (*features->mutable_aggregates())[proto::ColumnType::DATETIME] =
                 datetime_opts;
 
+            proto::GetFeaturesResp_AggregateOptions list_opts;
+            list_opts.add_aggregates()->set_name("count");
+            list_opts.add_aggregates()->set_name("any");
+            list_opts.add_aggregates()->set_name("distinct count");
+            list_opts.add_aggregates()->set_name("distinct leaf");
+            list_opts.add_aggregates()->set_name("dominant");
+            list_opts.add_aggregates()->set_name("first");
+            list_opts.add_aggregates()->set_name("last");
+            list_opts.add_aggregates()->set_name("last by index");
+            list_opts.add_aggregates()->set_name("union");
+            list_opts.add_aggregates()->set_name("unique");
+            (*features->mutable_aggregates())[proto::ColumnType::LIST] =
+                list_opts;
+
             push_resp(std::move(resp));
             break;
         }
@@ -1706,6 +2746,68 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             push_resp(std::move(resp));
             break;
         }
+        case proto::Request::kBatchReq: {
+            proto::Response resp;
+            auto* batch = resp.mutable_batch_resp();
+            for (auto& sub_req : *req.mutable_batch_req()->mutable_requests()) {
+                const auto sub_msg_id = sub_req.msg_id();
+                const auto sub_entity_id = sub_req.entity_id();
+                std::vector<ProtoServerResp<Response>> sub_resps;
+                proto::Response sub_resp;
+                sub_resp.set_msg_id(sub_msg_id);
+                sub_resp.set_entity_id(sub_entity_id);
+                try {
+                    // Each sub-request is handled exactly as a top-level
+                    // request, including decompressing its table data and
+                    // processing dirty tables first.
+                    sub_resps = _handle_request(client_id, std::move(sub_req));
+                } catch (const PerspectiveViewNotFoundException& e) {
+                    auto* err = sub_resp.mutable_server_error();
+                    err->set_status_code(proto::StatusCode::VIEW_NOT_FOUND);
+                    *err->mutable_message() = std::string(e.what());
+                } catch (const PerspectiveIngestionException& e) {
+                    ingestion_error_to_proto(
+                        e, sub_resp.mutable_server_error()
+                    );
+                } catch (const std::exception& e) {
+                    *sub_resp.mutable_server_error()->mutable_message() =
+                        std::string(e.what());
+                } catch (...) {
+                    *sub_resp.mutable_server_error()->mutable_message() =
+                        "Unknown exception";
+                }
+
+                // The sub-request's own response goes in the `BatchResp`,
+                // anything else (e.g. `on_update` notifications) is sent as
+                // usual.
+                for (auto& r : sub_resps) {
+                    if (r.client_id == client_id
+                        && r.data.msg_id() == sub_msg_id
+                        && sub_resp.client_resp_case()
+                            == proto::Response::CLIENT_RESP_NOT_SET) {
+                        sub_resp = std::move(r.data);
+                    } else {
+                        proto_resp.emplace_back(std::move(r));
+                    }
+                }
+
+                *batch->add_responses() = std::move(sub_resp);
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kUnsubscribeReq: {
+            // Removes the subscriptions the named request created, and
+            // cancels it in case it is still running on another thread.
+            auto sub_id = req.unsubscribe_req().id();
+            m_resources.remove_request_subs(sub_id, client_id);
+            cancel_request(client_id, sub_id);
+            proto::Response resp;
+            resp.mutable_unsubscribe_resp();
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kMakeTableReq: {
             const auto& r = req.make_table_req();
             if (m_resources.has_table(entity_id)) {
@@ -1750,12 +2852,52 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                 case proto::LIST_FLATTEN_STRINGIFY:
                     list_flatten = apachearrow::LIST_FLATTEN_STRINGIFY;
                     break;
+                case proto::LIST_FLATTEN_PRESERVE:
+                    list_flatten = apachearrow::LIST_FLATTEN_PRESERVE;
+                    break;
                 case proto::LIST_FLATTEN_ZIP:
                 default:
                     list_flatten = apachearrow::LIST_FLATTEN_ZIP;
                     break;
             }
 
+            t_struct_flatten struct_flatten;
+            if (r.options().has_struct_flatten()) {
+                const auto& config = r.options().struct_flatten();
+                if (config.has_max_depth()) {
+                    struct_flatten.max_depth = config.max_depth();
+                }
+
+                if (config.has_separator()) {
+                    if (config.separator().empty()) {
+                        PSP_COMPLAIN_AND_ABORT(
+                            "`struct_flatten` separator must not be empty"
+                        );
+                    }
+
+                    struct_flatten.separator = config.separator();
+                }
+            }
+
+            const bool preserve_arrow_types =
+                r.options().preserve_arrow_types();
+
+            apachearrow::t_csv_options csv_options;
+            if (r.options().has_csv()) {
+                csv_options = csv_options_from_proto(r.options().csv());
+            }
+
+            const auto schema_hints = column_types_from_proto(
+                "schema_hints", r.options().schema_hints()
+            );
+
+            std::optional<t_bad_rows> on_bad_rows;
+            if (r.options().has_on_bad_rows()) {
+                on_bad_rows = bad_rows_from_proto(r.options().on_bad_rows());
+            }
+
+            const auto create_bad_rows = on_bad_rows.value_or(BAD_ROWS_NULL);
+
             switch (r.data().data_case()) {
                 case proto::MakeTableData::kFromView: {
                     auto view = m_resources.get_view(r.data().from_view());
@@ -1775,12 +2917,18 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                         dims.end_col
                     );
 
+                    // A `View`'s `LIST` columns are written as Arrow lists,
+                    // which must be read back as lists.
                     table = Table::from_arrow(
                         index,
                         std::move(*arrow),
                         limit,
                         backing_store,
-                        list_flatten
+                        view->list_params().empty()
+                            ? list_flatten
+                            : apachearrow::LIST_FLATTEN_PRESERVE,
+                        struct_flatten,
+                        preserve_arrow_types
                     );
                     break;
                 }
@@ -1793,7 +2941,11 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                         std::move(data),
                         limit,
                         backing_store,
-                        list_flatten
+                        list_flatten,
+                        struct_flatten,
+                        preserve_arrow_types,
+                        schema_hints,
+                        create_bad_rows
                     );
                     break;
                 }
@@ -1801,12 +2953,26 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                     std::string data = r.data().from_csv();
                     { auto _ = std::move(req); }
 
+                    // Under a policy, the CSV's own `column_types` are
+                    // coerced like hints rather than aborting the parse.
+                    auto csv_hints = schema_hints;
+                    if (on_bad_rows.has_value()) {
+                        csv_hints.insert(
+                            csv_options.column_types.begin(),
+                            csv_options.column_types.end()
+                        );
+                    }
+
                     table = Table::from_csv(
                         index,
                         std::move(data),
                         limit,
                         backing_store,
-                        list_flatten
+                        list_flatten,
+                        struct_flatten,
+                        csv_options,
+                        csv_hints,
+                        create_bad_rows
                     );
                     break;
                 }
@@ -1819,7 +2985,10 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                         std::move(data),
                         limit,
                         backing_store,
-                        list_flatten
+                        list_flatten,
+                        struct_flatten,
+                        schema_hints,
+                        create_bad_rows
                     );
                     break;
                 }
@@ -1832,7 +3001,10 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                         std::move(data),
                         limit,
                         backing_store,
-                        list_flatten
+                        list_flatten,
+                        struct_flatten,
+                        schema_hints,
+                        create_bad_rows
                     );
                     break;
                 }
@@ -1845,34 +3017,111 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                         std::move(data),
                         limit,
                         backing_store,
-                        list_flatten
+                        list_flatten,
+                        struct_flatten,
+                        schema_hints,
+                        create_bad_rows
                     );
                     break;
                 }
                 case proto::MakeTableData::kFromSchema: {
                     std::vector<std::string> columns;
                     std::vector<t_dtype> types;
+                    t_decimal_map decimals;
+                    t_timestamp_map timestamps;
+                    t_list_map lists;
                     const auto& schema = r.data().from_schema().schema();
                     for (const auto& it : schema) {
                         columns.push_back(it.name());
                         types.push_back(column_type_to_dtype(it.type()));
+                        if (it.type() == proto::ColumnType::LIST) {
+                            lists[it.name()] = t_list_params{
+                                list_element_from_proto(it.name(), it.params())
+                            };
+
+                            continue;
+                        }
+
+                        if (it.type() == proto::ColumnType::DATETIME
+                            && it.has_params()) {
+                            const t_timestamp_params params{
+                                time_unit_from_proto(it.params().unit()),
+                                it.params().timezone()
+                            };
+
+                            if (params.unit != TIMESTAMP_UNIT_MILLISECOND
+                                || !params.timezone.empty()) {
+                                timestamps[it.name()] = params;
+                            }
+                        }
+
+                        if (it.type() != proto::ColumnType::DECIMAL) {
+                            continue;
+                        }
+
+                        const auto& params = it.params();
+                        const std::uint32_t precision = params.has_precision()
+                            ? params.precision()
+                            : DECIMAL_MAX_PRECISION;
+
+                        if (!params.has_scale()
+                            || !is_exact_decimal(precision, params.scale())) {
+                            std::stringstream ss;
+                            ss << "Column `" << it.name()
+                               << "` must be DECIMAL(precision, scale) with "
+                                  "scale <= precision <= "
+                               << DECIMAL_MAX_PRECISION;
+                            PSP_COMPLAIN_AND_ABORT(ss.str());
+                        }
+
+                        decimals[it.name()] =
+                            t_decimal_params{precision, params.scale()};
                     }
 
+                    // Updates to a `LIST` column must not expand its arrays.
                     t_schema table_schema(columns, types);
                     table = Table::from_schema(
-                        index, table_schema, limit, backing_store
+                        index,
+                        table_schema,
+                        limit,
+                        backing_store,
+                        lists.empty() ? list_flatten
+                                      : apachearrow::LIST_FLATTEN_PRESERVE,
+                        struct_flatten
                     );
+
+                    table->set_decimal_params(decimals);
+                    table->set_timestamp_params(timestamps);
+                    table->set_list_params(lists);
                     break;
                 }
+                case proto::MakeTableData::kFromCompressed:
                 case proto::MakeTableData::DATA_NOT_SET: {
                     PSP_COMPLAIN_AND_ABORT("MakeTableReq malformed");
                     break;
                 }
             }
 
+            if (table->get_decimal_params().count(index) > 0) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Cannot index on DECIMAL column `" + index + "`"
+                );
+            }
+
+            if (table->get_list_params().count(index) > 0) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Cannot index on LIST column `" + index + "`"
+                );
+            }
+
+            table->set_on_bad_rows(on_bad_rows);
             m_resources.host_table(entity_id, table);
             proto::Response resp;
-            resp.mutable_make_table_resp();
+            row_errors_to_proto(
+                table->get_row_errors(),
+                resp.mutable_make_table_resp()->mutable_row_errors()
+            );
+
             push_resp(std::move(resp));
 
             // Notify `on_thsoted_tables_update` listeners
@@ -1989,10 +3238,31 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             auto table_schema = table->get_schema();
             auto columns = table_schema.columns();
             auto types = table_schema.types();
+            const auto& decimals = table->get_decimal_params();
+            const auto& timestamps = table->get_timestamp_params();
+            const auto& lists = table->get_list_params();
             for (std::size_t i = 0; i < table_schema.size(); ++i) {
                 auto* ktp = output_schema->add_schema();
                 ktp->set_name(columns[i]);
-                ktp->set_type(dtype_to_column_type(types[i]));
+                const auto decimal = decimals.find(columns[i]);
+                const auto list = lists.find(columns[i]);
+                if (decimal != decimals.end()) {
+                    ktp->set_type(proto::ColumnType::DECIMAL);
+                    decimal_params_to_proto(
+                        decimal->second, ktp->mutable_params()
+                    );
+                } else if (list != lists.end()) {
+                    ktp->set_type(proto::ColumnType::LIST);
+                    list_params_to_proto(list->second, ktp->mutable_params());
+                } else {
+                    ktp->set_type(dtype_to_column_type(types[i]));
+                    const auto timestamp = timestamps.find(columns[i]);
+                    if (timestamp != timestamps.end()) {
+                        timestamp_params_to_proto(
+                            timestamp->second, ktp->mutable_params()
+                        );
+                    }
+                }
             }
 
             push_resp(std::move(resp));
@@ -2149,6 +3419,258 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             push_resp(std::move(resp));
             break;
         }
+        case proto::Request::kTableRemoveWhereReq: {
+            if (m_join_engine.is_join_table(req.entity_id())) {
+                proto::Response resp;
+                *resp.mutable_server_error()->mutable_message() =
+                    "Cannot update a read-only join table";
+                push_resp(std::move(resp));
+                break;
+            }
+
+            const auto& r = req.table_remove_where_req();
+            auto table = m_resources.get_table(req.entity_id());
+            const auto& schema = table->get_schema();
+            for (const auto& f : r.filter()) {
+                if (!schema.has_column(f.column())) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Filter column not in schema: " + f.column()
+                    );
+                }
+            }
+
+            t_vocab vocab;
+            vocab.init(false);
+            const auto filter = parse_filters(
+                schema,
+                r.filter(),
+                vocab,
+                table->get_decimal_params(),
+                table->get_timestamp_params(),
+                table->get_list_params()
+            );
+            std::vector<t_fterm> fterms;
+            fterms.reserve(filter.size());
+            for (const auto& [column, op_str, args] : filter) {
+                const auto op = str_to_filter_op(op_str);
+                switch (op) {
+                    case FILTER_OP_NOT_IN:
+                    case FILTER_OP_IN:
+                        fterms.emplace_back(column, op, mktscalar(0), args);
+                        break;
+                    default:
+                        fterms.emplace_back(
+                            column,
+                            op,
+                            args.empty() ? mknone() : args[0],
+                            std::vector<t_tscalar>()
+                        );
+                }
+            }
+
+            const auto combiner =
+                r.filter_op() == proto::ViewConfig_FilterReducer_OR
+                ? FILTER_OP_OR
+                : FILTER_OP_AND;
+
+            table->remove_where(fterms, combiner);
+            m_resources.mark_table_dirty(req.entity_id());
+            proto::Response resp;
+            resp.mutable_table_remove_where_resp();
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableAddColumnsReq: {
+            const auto& r = req.table_add_columns_req();
+            std::vector<std::pair<std::string, t_dtype>> add;
+            add.reserve(r.columns_size());
+            for (const auto& col : r.columns()) {
+                if (col.type() == proto::ColumnType::DECIMAL
+                    || col.type() == proto::ColumnType::LIST) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Cannot add " + proto::ColumnType_Name(col.type())
+                        + " column `" + col.name() + "`"
+                    );
+                }
+
+                add.emplace_back(col.name(), column_type_to_dtype(col.type()));
+            }
+
+            _alter_table_columns(req.entity_id(), add, {}, {}, proto_resp);
+            proto::Response resp;
+            resp.mutable_table_add_columns_resp();
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableDropColumnsReq: {
+            const auto& r = req.table_drop_columns_req();
+            std::vector<std::string> drop{
+                r.columns().begin(), r.columns().end()
+            };
+
+            const auto deleted = _alter_table_columns(
+                req.entity_id(), {}, drop, {}, proto_resp
+            );
+
+            proto::Response resp;
+            auto* drop_resp = resp.mutable_table_drop_columns_resp();
+            for (const auto& view_id : deleted) {
+                drop_resp->add_deleted_views(view_id);
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableCastColumnReq: {
+            const auto& r = req.table_cast_column_req();
+            if (r.type() == proto::ColumnType::DECIMAL
+                || r.type() == proto::ColumnType::LIST) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Cannot cast column `" + r.column() + "` to "
+                    + proto::ColumnType_Name(r.type())
+                );
+            }
+
+            const auto deleted = _alter_table_columns(
+                req.entity_id(),
+                {},
+                {},
+                {{r.column(), column_type_to_dtype(r.type())}},
+                proto_resp
+            );
+
+            proto::Response resp;
+            auto* cast_resp = resp.mutable_table_cast_column_resp();
+            for (const auto& view_id : deleted) {
+                cast_resp->add_deleted_views(view_id);
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kTableTransactionReq: {
+            if (m_join_engine.is_join_table(req.entity_id())) {
+                proto::Response resp;
+                *resp.mutable_server_error()->mutable_message() =
+                    "Cannot update a read-only join table";
+                push_resp(std::move(resp));
+                break;
+            }
+
+            const auto& r = req.table_transaction_req();
+            auto table = m_resources.get_table(req.entity_id());
+
+            // Ops before the last `clear` have no effect, so staging starts
+            // from it.
+            std::int64_t last_clear = -1;
+            for (std::int64_t i = 0; i < r.ops_size(); ++i) {
+                if (r.ops(i).has_clear()) {
+                    last_clear = i;
+                }
+            }
+
+            // Stage the ops, in order, on a temporary port, so a failing op
+            // discards those before it rather than leaving them to be
+            // processed. The offset the discarded rows were written at is
+            // rolled back too, as unindexed rows are keyed by it. Once
+            // merged, the whole transaction is processed as one step, which
+            // notifies each `View` once.
+            const auto staging_port = table->make_port();
+            const auto offset = table->get_offset();
+            try {
+                if (last_clear >= 0) {
+                    table->remove_all(staging_port);
+                }
+
+                for (auto i = last_clear + 1; i < r.ops_size(); ++i) {
+                    const auto& op = r.ops(i);
+                    std::optional<t_bad_rows> on_bad_rows;
+                    if (op.has_on_bad_rows()) {
+                        on_bad_rows = bad_rows_from_proto(op.on_bad_rows());
+                    }
+
+                    const auto& data =
+                        op.has_update() ? op.update() : op.remove();
+                    if (op.has_update()) {
+                        switch (data.data_case()) {
+                            case proto::MakeTableData::kFromArrow:
+                                table->update_arrow(
+                                    data.from_arrow(), staging_port, on_bad_rows
+                                );
+                                break;
+                            case proto::MakeTableData::kFromCsv: {
+                                std::optional<apachearrow::t_csv_options>
+                                    csv_options;
+                                if (op.has_csv()) {
+                                    csv_options =
+                                        csv_options_from_proto(op.csv());
+                                }
+
+                                table->update_csv(
+                                    data.from_csv(),
+                                    staging_port,
+                                    csv_options ? &*csv_options : nullptr,
+                                    on_bad_rows
+                                );
+                                break;
+                            }
+                            case proto::MakeTableData::kFromRows:
+                                table->update_rows(
+                                    data.from_rows(), staging_port, on_bad_rows
+                                );
+                                break;
+                            case proto::MakeTableData::kFromCols:
+                                table->update_cols(
+                                    data.from_cols(), staging_port, on_bad_rows
+                                );
+                                break;
+                            case proto::MakeTableData::kFromNdjson:
+                                table->update_ndjson(
+                                    data.from_ndjson(),
+                                    staging_port,
+                                    on_bad_rows
+                                );
+                                break;
+                            default:
+                                PSP_COMPLAIN_AND_ABORT(
+                                    "Transaction update malformed"
+                                );
+                        }
+                    } else if (op.has_remove()) {
+                        switch (data.data_case()) {
+                            case proto::MakeTableData::kFromCols:
+                                table->remove_cols(
+                                    data.from_cols(), staging_port
+                                );
+                                break;
+                            case proto::MakeTableData::kFromRows:
+                                table->remove_rows(
+                                    data.from_rows(), staging_port
+                                );
+                                break;
+                            default:
+                                PSP_COMPLAIN_AND_ABORT(
+                                    "Transaction remove malformed"
+                                );
+                        }
+                    } else {
+                        PSP_COMPLAIN_AND_ABORT("Transaction op malformed");
+                    }
+                }
+
+                table->merge_port(staging_port, r.port_id());
+            } catch (...) {
+                table->remove_port(staging_port);
+                table->set_offset(offset);
+                throw;
+            }
+
+            m_resources.mark_table_dirty(req.entity_id());
+            proto::Response resp;
+            resp.mutable_table_transaction_resp();
+            push_resp(std::move(resp));
+            break;
+        }
         case proto::Request::kTableUpdateReq: {
             if (m_join_engine.is_join_table(req.entity_id())) {
                 proto::Response resp;
@@ -2159,25 +3681,60 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             }
             const auto& r = req.table_update_req();
             auto table = m_resources.get_table(req.entity_id());
+            std::optional<t_bad_rows> on_bad_rows;
+            if (r.has_on_bad_rows()) {
+                on_bad_rows = bad_rows_from_proto(r.on_bad_rows());
+            }
+
+            t_row_errors row_errors;
             switch (r.data().data_case()) {
                 case proto::MakeTableData::kFromArrow: {
-                    table->update_arrow(r.data().from_arrow(), r.port_id());
+                    row_errors = table->update_arrow(
+                        r.data().from_arrow(), r.port_id(), on_bad_rows
+                    );
+
                     break;
                 }
                 case proto::MakeTableData::kFromCsv: {
-                    table->update_csv(r.data().from_csv(), r.port_id());
+                    if (r.has_csv()) {
+                        const auto csv_options =
+                            csv_options_from_proto(r.csv());
+                        row_errors = table->update_csv(
+                            r.data().from_csv(),
+                            r.port_id(),
+                            &csv_options,
+                            on_bad_rows
+                        );
+                    } else {
+                        row_errors = table->update_csv(
+                            r.data().from_csv(),
+                            r.port_id(),
+                            nullptr,
+                            on_bad_rows
+                        );
+                    }
+
                     break;
                 }
                 case proto::MakeTableData::kFromRows: {
-                    table->update_rows(r.data().from_rows(), r.port_id());
+                    row_errors = table->update_rows(
+                        r.data().from_rows(), r.port_id(), on_bad_rows
+                    );
+
                     break;
                 }
                 case proto::MakeTableData::kFromCols: {
-                    table->update_cols(r.data().from_cols(), r.port_id());
+                    row_errors = table->update_cols(
+                        r.data().from_cols(), r.port_id(), on_bad_rows
+                    );
+
                     break;
                 }
                 case proto::MakeTableData::kFromNdjson: {
-                    table->update_ndjson(r.data().from_ndjson(), r.port_id());
+                    row_errors = table->update_ndjson(
+                        r.data().from_ndjson(), r.port_id(), on_bad_rows
+                    );
+
                     break;
                 }
                 case proto::MakeTableData::kFromSchema:
@@ -2190,11 +3747,19 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
 
             m_resources.mark_table_dirty(req.entity_id());
             proto::Response resp;
-            resp.mutable_table_update_resp();
+            auto* update_resp = resp.mutable_table_update_resp();
+            row_errors_to_proto(row_errors, update_resp->mutable_row_errors());
+
             push_resp(std::move(resp));
             break;
         }
         case proto::Request::kTableMakeViewReq: {
+            // A cancelled request gets no response, as the client has stopped
+            // waiting for one.
+            if (is_cancelled(client_id, msg_id)) {
+                break;
+            }
+
             auto table = m_resources.get_table(req.entity_id());
             auto schema = std::make_shared<t_schema>(
                 table->get_gnode()->get_output_schema()
@@ -2250,7 +3815,18 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                 legacy_exprs;
 
             legacy_exprs.resize(1);
+            const auto& decimals = table->get_decimal_params();
             for (const auto& expr : exprs) {
+                for (const auto& [_, column] : expr.column_id_map) {
+                    if (decimals.count(column) > 0) {
+                        PSP_COMPLAIN_AND_ABORT(
+                            "Expression `" + expr.expression_alias
+                            + "` cannot reference DECIMAL column `" + column
+                            + "`"
+                        );
+                    }
+                }
+
                 legacy_exprs[0] = {
                     expr.expression_alias,
                     expr.expression,
@@ -2299,13 +3875,16 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                 auto dtype = computed_expression->get_dtype();
 
                 schema->add_column(expr.expression_alias, dtype);
-                expressions.push_back(std::make_shared<t_computed_expression>(
+                auto expression = std::make_shared<t_computed_expression>(
                     expr.expression_alias,
                     expr.expression,
                     expr.parse_expression_string,
                     column_id_map,
                     dtype
-                ));
+                );
+
+                expression->set_timestamp_params(table->get_timestamp_params());
+                expressions.push_back(expression);
             }
 
             std::vector<t_window_spec> windows;
@@ -2340,6 +3919,30 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                     );
                 }
 
+                if (decimals.count(w.source()) > 0
+                    || (w.has_order_by()
+                        && decimals.count(w.order_by().column()) > 0)) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Window `" + name + "` cannot read a DECIMAL column"
+                    );
+                }
+
+                const auto& timestamps = table->get_timestamp_params();
+                const auto is_native_timestamp = [&](const std::string& c) {
+                    const auto it = timestamps.find(c);
+                    return it != timestamps.end()
+                        && it->second.unit != TIMESTAMP_UNIT_MILLISECOND;
+                };
+
+                if (is_native_timestamp(w.source())
+                    || (w.has_order_by()
+                        && is_native_timestamp(w.order_by().column()))) {
+                    PSP_COMPLAIN_AND_ABORT(
+                        "Window `" + name
+                        + "` cannot read a non-millisecond datetime column"
+                    );
+                }
+
                 // `order_by`/`partition_by` must be real `Table` columns -
                 // the window engine reads them from the gnode master table,
                 // where expression aliases do not exist. An OMITTED
@@ -2539,96 +4142,14 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
 
             t_vocab vocab;
             vocab.init(false);
-            std::vector<
-                std::tuple<std::string, std::string, std::vector<t_tscalar>>>
-                filter;
-            filter.reserve(cfg.filter().size());
-
-            for (const auto& f : cfg.filter()) {
-                for (const auto& arg : f.value()) {
-                    switch (arg.scalar_case()) {
-                        case proto::Scalar::kString: {
-#ifdef PSP_SSO_SCALAR
-                            if (!t_tscalar::can_store_inplace(arg.string())) {
-                                vocab.get_interned(arg.string());
-                            }
-#else
-                            vocab.get_interned(arg.string());
-#endif
-                            break;
-                        }
-                        case proto::Scalar::kBool:
-                        case proto::Scalar::kFloat:
-                        case proto::Scalar::kNull:
-                        case proto::Scalar::SCALAR_NOT_SET:
-                            break;
-                    }
-                }
-            }
-
-            for (const auto& f : cfg.filter()) {
-                std::vector<t_tscalar> args;
-                args.reserve(f.value().size());
-                for (const auto& arg : f.value()) {
-                    t_tscalar a;
-                    a.clear();
-                    switch (arg.scalar_case()) {
-                        case proto::Scalar::kBool: {
-                            a.set(arg.bool_());
-                            args.push_back(a);
-                            break;
-                        }
-                        case proto::Scalar::kFloat: {
-                            a = coerce_to(
-                                schema->get_dtype(f.column()), arg.float_()
-                            );
-
-                            args.push_back(a);
-                            break;
-                        }
-                        case proto::Scalar::kString: {
-                            if (!schema->has_column(f.column())) {
-                                PSP_COMPLAIN_AND_ABORT(
-                                    "Filter column not in schema: " + f.column()
-                                );
-                            }
-
-#ifdef PSP_SSO_SCALAR
-                            if (!t_tscalar::can_store_inplace(arg.string())) {
-#endif
-                                a = coerce_to(
-                                    schema->get_dtype(f.column()),
-                                    vocab.unintern_c(
-                                        vocab.get_interned(arg.string())
-                                    )
-                                );
-#ifdef PSP_SSO_SCALAR
-                            } else {
-
-                                a = coerce_to(
-                                    schema->get_dtype(f.column()),
-                                    arg.string().c_str()
-                                );
-                            }
-#endif
-                            args.push_back(a);
-                            break;
-                        }
-                        case proto::Scalar::kNull:
-                            a.set(t_none());
-                            args.push_back(a);
-                            break;
-                        case proto::Scalar::SCALAR_NOT_SET:
-                            PSP_COMPLAIN_AND_ABORT(
-                                "Filter scalar type not implemented: "
-                                + std::to_string(arg.scalar_case())
-                            )
-                            break;
-                    }
-                }
-
-                filter.emplace_back(f.column(), f.op(), args);
-            }
+            auto filter = parse_filters(
+                *schema,
+                cfg.filter(),
+                vocab,
+                table->get_decimal_params(),
+                table->get_timestamp_params(),
+                table->get_list_params()
+            );
 
             const auto& cols = cfg.columns();
             std::vector<std::string> columns;
@@ -2701,6 +4222,9 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                 split_rollup
             );
             config->init(schema);
+            validate_decimal_view(*config, table->get_decimal_params());
+            validate_timestamp_view(*config, table->get_timestamp_params());
+            validate_list_view(*config, table->get_list_params());
 
             if (cfg.has_group_by_depth()) {
                 config->set_row_pivot_depth(cfg.group_by_depth());
@@ -2726,6 +4250,9 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                 && cfg.expressions().empty() && cfg.windows().empty();
 
             std::shared_ptr<ErasedView> erased_view;
+            if (is_cancelled(client_id, msg_id)) {
+                break;
+            }
 
             if (is_unit_context) {
                 auto ctx =
@@ -2759,6 +4286,11 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                 PSP_COMPLAIN_AND_ABORT("Invalid number of sides");
             }
 
+            // Dropping the unhosted `View` unregisters its context.
+            if (is_cancelled(client_id, msg_id)) {
+                break;
+            }
+
             m_resources.host_view(
                 client_id, r.view_id(), req.entity_id(), erased_view
             );
@@ -2810,9 +4342,37 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             proto::Response resp;
             auto* view_schema =
                 resp.mutable_view_schema_resp()->mutable_schema();
+            auto* view_params =
+                resp.mutable_view_schema_resp()->mutable_params();
             auto schema = view->schema();
+            const auto& decimals = view->decimal_params();
+            const auto& timestamps = view->timestamp_params();
+            const auto& lists = view->list_params();
             for (const auto& [k, v] : schema) {
-                (*view_schema)[k] = dtype_to_column_type(str_to_dtype(v));
+                const auto decimal = decimals.find(k);
+                const auto list = lists.find(k);
+                if (decimal != decimals.end()) {
+                    (*view_schema)[k] = proto::ColumnType::DECIMAL;
+                    decimal_params_to_proto(
+                        decimal->second, &(*view_params)[k]
+                    );
+                } else if (list != lists.end()) {
+                    (*view_schema)[k] = proto::ColumnType::LIST;
+                    list_params_to_proto(list->second, &(*view_params)[k]);
+                } else {
+                    const auto type = dtype_to_column_type(str_to_dtype(v));
+                    (*view_schema)[k] = type;
+
+                    // Only datetime columns report a unit, as the `"mean"`
+                    // of one is written as float milliseconds.
+                    const auto timestamp = timestamps.find(k);
+                    if (timestamp != timestamps.end()
+                        && type == proto::ColumnType::DATETIME) {
+                        timestamp_params_to_proto(
+                            timestamp->second, &(*view_params)[k]
+                        );
+                    }
+                }
             }
 
             push_resp(std::move(resp));
@@ -3339,6 +4899,17 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                 req.entity_id(), sub_id, client_id
             );
 
+            auto subs = m_resources.get_view_on_update_sub(req.entity_id());
+            auto has_diff_sub =
+                std::any_of(subs.begin(), subs.end(), [](const auto& sub) {
+                    return sub.mode
+                        == proto::ViewOnUpdateReq_Mode::ViewOnUpdateReq_Mode_DIFF;
+                });
+
+            if (!has_diff_sub && m_resources.has_view(req.entity_id())) {
+                m_resources.get_view(req.entity_id())->set_diff_enabled(false);
+            }
+
             proto::Response resp;
             resp.mutable_view_remove_on_update_resp();
             push_resp(std::move(resp));
@@ -3359,6 +4930,10 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             break;
         }
         case proto::Request::kViewToArrowReq: {
+            if (is_cancelled(client_id, msg_id)) {
+                break;
+            }
+
             auto view = m_resources.get_view(req.entity_id());
             const auto& r = req.view_to_arrow_req();
             auto config = view->get_view_config();
@@ -3387,6 +4962,53 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
                 legacy_names
             );
 
+            if (is_cancelled(client_id, msg_id)) {
+                break;
+            }
+
+            push_resp(std::move(resp));
+            break;
+        }
+        case proto::Request::kViewToArrowPageReq: {
+            auto view = m_resources.get_view(req.entity_id());
+            const auto& r = req.view_to_arrow_page_req();
+            auto config = view->get_view_config();
+            auto num_hidden = calculate_num_hidden(*view, *config);
+            auto dims = parse_format_options(
+                r.viewport(),
+                view->num_columns(),
+                view->num_rows(),
+                view->sides(),
+                view->get_view_config()->is_column_only(),
+                num_hidden
+            );
+
+            auto end_row = dims.end_row;
+            if (r.page_rows() > 0 && dims.start_row + r.page_rows() < end_row) {
+                end_row = dims.start_row + r.page_rows();
+            }
+
+            proto::Response resp;
+            auto* page = resp.mutable_view_to_arrow_page_resp();
+            bool legacy_names = r.viewport().has_emit_legacy_row_path_names()
+                ? r.viewport().emit_legacy_row_path_names()
+                : true;
+            if (dims.start_row < end_row) {
+                *page->mutable_arrow() = *view->to_arrow(
+                    dims.start_row,
+                    end_row,
+                    dims.start_col,
+                    dims.end_col,
+                    true,
+                    r.compression() == "lz4",
+                    legacy_names
+                );
+            }
+
+            if (end_row < dims.end_row) {
+                page->set_next_start_row(end_row);
+            }
+
             push_resp(std::move(resp));
             break;
         }
@@ -3418,13 +5040,22 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             Subscription sub_info;
             sub_info.id = req.msg_id();
             sub_info.client_id = client_id;
-            m_resources.create_view_on_update_sub(req.entity_id(), sub_info);
-            if (req.view_on_update_req().has_mode()
-                && req.view_on_update_req().mode()
-                    == proto::ViewOnUpdateReq_Mode::ViewOnUpdateReq_Mode_ROW) {
-                auto view = m_resources.get_view(req.entity_id());
+            if (req.view_on_update_req().has_mode()) {
+                sub_info.mode = req.view_on_update_req().mode();
+            }
+
+            // Enabled before subscribing, as views with a `group_by` or
+            // `split_by` reject diff mode.
+            auto view = m_resources.get_view(req.entity_id());
+            if (sub_info.mode
+                == proto::ViewOnUpdateReq_Mode::ViewOnUpdateReq_Mode_ROW) {
                 view->set_deltas_enabled(true);
+            } else if (sub_info.mode
+                       == proto::ViewOnUpdateReq_Mode::ViewOnUpdateReq_Mode_DIFF) {
+                view->set_diff_enabled(true);
             }
+
+            m_resources.create_view_on_update_sub(req.entity_id(), sub_info);
             break;
         }
         case proto::Request::kViewGetMinMaxReq: {
@@ -3433,8 +5064,28 @@ ProtoServer::_handle_request(std::uint32_t client_id, Request&& req) {
             const auto min_max = view->get_min_max(col);
             proto::Response resp;
             auto* pair = resp.mutable_view_get_min_max_resp();
-            tscalar_to_proto(min_max.first, pair->mutable_min());
-            tscalar_to_proto(min_max.second, pair->mutable_max());
+            const auto& decimals = view->decimal_params();
+            if (const auto decimal = decimals.find(col);
+                decimal != decimals.end()) {
+                decimal_to_proto(
+                    min_max.first, decimal->second, pair->mutable_min()
+                );
+                decimal_to_proto(
+                    min_max.second, decimal->second, pair->mutable_max()
+                );
+            } else if (const auto timestamp =
+                           view->timestamp_params().find(col);
+                       timestamp != view->timestamp_params().end()) {
+                timestamp_to_proto(
+                    min_max.first, timestamp->second, pair->mutable_min()
+                );
+                timestamp_to_proto(
+                    min_max.second, timestamp->second, pair->mutable_max()
+                );
+            } else {
+                tscalar_to_proto(min_max.first, pair->mutable_min());
+                tscalar_to_proto(min_max.second, pair->mutable_max());
+            }
             push_resp(std::move(resp));
             break;
         }
@@ -3626,13 +5277,25 @@ ProtoServer::_process_table_unchecked(
 
             auto view = m_resources.get_view(view_id);
             auto subscriptions = m_resources.get_view_on_update_sub(view_id);
+            std::optional<t_view_diff> diff;
+            if (view->get_diff_enabled()) {
+                diff = view->get_diff_as_arrow();
+            }
+
             for (auto& subscription : subscriptions) {
                 Response out;
                 out.set_msg_id(subscription.id);
                 out.set_entity_id(view_id);
                 auto* r = out.mutable_view_on_update_resp();
                 r->set_port_id(port_id);
-                if (view->get_deltas_enabled()) {
+                if (subscription.mode
+                        == proto::ViewOnUpdateReq_Mode::ViewOnUpdateReq_Mode_DIFF
+                    && diff.has_value()) {
+                    diff_to_proto(*diff, r->mutable_diff());
+                } else if (subscription.mode
+                               != proto::ViewOnUpdateReq_Mode::
+                                   ViewOnUpdateReq_Mode_NONE
+                           && view->get_deltas_enabled()) {
                     *r->mutable_delta() = *view->get_row_delta_as_arrow();
                 }
 
@@ -15,6 +15,7 @@
 #include <cmath>
 #include <fstream>
 #include <unordered_map>
+#include <perspective/aggregate.h>
 #include <perspective/base.h>
 #include <perspective/compat.h>
 #include <perspective/extract_aggregate.h>
@@ -29,10 +30,12 @@
 #include <perspective/gnode_state.h>
 #include <perspective/config.h>
 #include <perspective/data_table.h>
+#include <perspective/list.h>
 #include <perspective/filter_utils.h>
 #include <perspective/context_two.h>
 #include <set>
 #include <utility>
+#include <arrow/util/decimal.h>
 
 namespace perspective {
 
@@ -1127,6 +1130,90 @@ t_stree::gen_aggidx() {
     return rval;
 }
 
+/**
+ * Add a `DTYPE_INT64` delta to a sum aggregate as `t_tscalar::add` does, or
+ * return `nullopt` if the delta itself overflowed, as marked by
+ * `t_aggimpl_checked_sum`, or the sum overflows an `std::int64_t`.
+ */
+static std::optional<t_tscalar>
+checked_int64_add(const t_tscalar& sum, const t_tscalar& delta) {
+    if (!delta.is_valid()) {
+        return sum;
+    }
+
+    const auto y = delta.to_int64();
+    if (y == t_aggimpl_checked_sum::MAX || y == t_aggimpl_checked_sum::MIN) {
+        return std::nullopt;
+    }
+
+    if (!sum.is_valid()) {
+        return delta;
+    }
+
+    const auto x = sum.to_int64();
+    if ((y > 0 && x > std::numeric_limits<std::int64_t>::max() - y)
+        || (y < 0 && x < std::numeric_limits<std::int64_t>::min() - y)) {
+        return std::nullopt;
+    }
+
+    t_tscalar rval;
+    rval.set(x + y);
+    return rval;
+}
+
+t_tscalar
+t_stree::exact_int64_sum(
+    t_uindex nidx,
+    t_uindex aggidx,
+    t_uindex colidx,
+    const std::string& colname,
+    const t_gstate& gstate,
+    const t_data_table& expression_master_table,
+    bool abs_values,
+    bool abs_total
+) {
+    std::vector<t_tscalar> values;
+    read_column_from_gstate(
+        gstate, expression_master_table, colname, get_pkeys(nidx), values
+    );
+
+    if (values.empty()) {
+        m_overflowed_sums.erase({aggidx, colidx});
+        return mknone();
+    }
+
+    arrow::Decimal128 sum;
+    for (const auto& v : values) {
+        if (!v.is_valid() || v.is_nan()) {
+            continue;
+        }
+
+        arrow::Decimal128 value(v.coerce_numeric_dtype(DTYPE_INT64).to_int64());
+        if (abs_values) {
+            value.Abs();
+        }
+
+        sum += value;
+    }
+
+    if (abs_total) {
+        sum.Abs();
+    }
+
+    const arrow::Decimal128 max(std::numeric_limits<std::int64_t>::max());
+    const arrow::Decimal128 min(std::numeric_limits<std::int64_t>::min());
+
+    if (sum > max || sum < min) {
+        m_overflowed_sums.insert({aggidx, colidx});
+        return mknone();
+    }
+
+    m_overflowed_sums.erase({aggidx, colidx});
+    t_tscalar rval;
+    rval.set(static_cast<std::int64_t>(sum.low_bits()));
+    return rval;
+}
+
 void
 t_stree::update_agg_table(
     t_uindex nidx,
@@ -1156,9 +1243,27 @@ t_stree::update_agg_table(
                 t_tscalar dst_scalar = dst->get_scalar(dst_ridx);
                 old_value.set(dst_scalar);
 
-                // is_nan returns false for non-float types
-                if (is_expr || old_value.is_nan()) {
+                // An `std::int64_t` sum, e.g. of a `DECIMAL` column, adds
+                // its delta until that overflows, and is then recomputed
+                // exactly, which also accounts for values that cancel.
+                std::optional<t_tscalar> int64_sum;
+                if (dst->get_dtype() == DTYPE_INT64 && !is_expr
+                    && m_overflowed_sums.count({dst_ridx, idx}) == 0) {
+                    int64_sum = checked_int64_add(dst_scalar, src_scalar);
+                }
 
+                if (int64_sum.has_value()) {
+                    new_value.set(*int64_sum);
+                } else if (dst->get_dtype() == DTYPE_INT64) {
+                    new_value.set(exact_int64_sum(
+                        nidx,
+                        dst_ridx,
+                        idx,
+                        spec.get_dependencies()[0].name(),
+                        gstate,
+                        expression_master_table
+                    ));
+                } else if (is_expr || old_value.is_nan()) {
                     // if we previously had a NaN, add can't make it finite
                     // again; recalculate entire sum in case it is now finite
                     auto pkeys = get_pkeys(nidx);
@@ -1440,6 +1545,27 @@ t_stree::update_agg_table(
 
                 dst->set_scalar(dst_ridx, new_value);
             } break;
+            case AGGTYPE_UNION: {
+                old_value.set(dst->get_scalar(dst_ridx));
+                auto pkeys = get_pkeys(nidx);
+
+                new_value.set(
+                    reduce_from_gstate<
+                        std::function<t_tscalar(std::vector<t_tscalar>&)>>(
+                        gstate,
+                        expression_master_table,
+                        spec.get_dependencies()[0].name(),
+                        pkeys,
+                        [this](std::vector<t_tscalar>& values) {
+                            return m_symtable.get_interned_tscalar(
+                                union_lists(values).c_str()
+                            );
+                        }
+                    )
+                );
+
+                dst->set_scalar(dst_ridx, new_value);
+            } break;
             case AGGTYPE_SCALED_DIV: {
                 const t_column* src_1 = info.m_dst[spec.get_agg_one_idx()];
                 const t_column* src_2 = info.m_dst[spec.get_agg_two_idx()];
@@ -1765,6 +1891,20 @@ t_stree::update_agg_table(
             } break;
             case AGGTYPE_SUM_NOT_NULL: {
                 old_value.set(dst->get_scalar(dst_ridx));
+                if (dst->get_dtype() == DTYPE_INT64) {
+                    new_value.set(exact_int64_sum(
+                        nidx,
+                        dst_ridx,
+                        idx,
+                        spec.get_dependencies()[0].name(),
+                        gstate,
+                        expression_master_table
+                    ));
+
+                    dst->set_scalar(dst_ridx, new_value);
+                    break;
+                }
+
                 auto pkeys = get_pkeys(nidx);
 
                 new_value.set(
@@ -1798,6 +1938,21 @@ t_stree::update_agg_table(
             } break;
             case AGGTYPE_SUM_ABS: {
                 old_value.set(dst->get_scalar(dst_ridx));
+                if (dst->get_dtype() == DTYPE_INT64) {
+                    new_value.set(exact_int64_sum(
+                        nidx,
+                        dst_ridx,
+                        idx,
+                        spec.get_dependencies()[0].name(),
+                        gstate,
+                        expression_master_table,
+                        true
+                    ));
+
+                    dst->set_scalar(dst_ridx, new_value);
+                    break;
+                }
+
                 auto pkeys = get_pkeys(nidx);
 
                 new_value.set(
@@ -1827,6 +1982,22 @@ t_stree::update_agg_table(
             } break;
             case AGGTYPE_ABS_SUM: {
                 old_value.set(dst->get_scalar(dst_ridx));
+                if (dst->get_dtype() == DTYPE_INT64) {
+                    new_value.set(exact_int64_sum(
+                        nidx,
+                        dst_ridx,
+                        idx,
+                        spec.get_dependencies()[0].name(),
+                        gstate,
+                        expression_master_table,
+                        false,
+                        true
+                    ));
+
+                    dst->set_scalar(dst_ridx, new_value);
+                    break;
+                }
+
                 auto pkeys = get_pkeys(nidx);
                 new_value.set(
                     reduce_from_gstate<
@@ -2260,11 +2431,29 @@ t_stree::clear_aggregates(const std::vector<t_uindex>& indices) {
         }
     }
 
+    for (auto aggidx : indices) {
+        m_overflowed_sums.erase(
+            m_overflowed_sums.lower_bound({aggidx, 0}),
+            m_overflowed_sums.lower_bound({aggidx + 1, 0})
+        );
+    }
+
     m_agg_freelist.insert(
         std::end(m_agg_freelist), std::begin(indices), std::end(indices)
     );
 }
 
+std::optional<std::string>
+t_stree::overflowed_sum() const {
+    if (m_overflowed_sums.empty()) {
+        return std::nullopt;
+    }
+
+    return m_aggregates->get_schema().m_columns.at(
+        m_overflowed_sums.begin()->second
+    );
+}
+
 void
 t_stree::drop_zero_strands() {
     auto iterators = m_nodes->get<by_nstrands>().equal_range(0);
@@ -2644,6 +2833,7 @@ t_stree::clear_deltas() {
 void
 t_stree::clear() {
     m_nodes->clear();
+    m_overflowed_sums.clear();
     clear_deltas();
 }
 
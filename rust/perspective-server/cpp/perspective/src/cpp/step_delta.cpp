@@ -68,6 +68,98 @@ t_rowdelta::t_rowdelta(
     rows_changed(rows_changed),
     num_rows_changed(num_rows_changed),
     data(data) {}
+
+// t_pkey_diff collects the primary keys changed in a flat context until it
+// is taken by the server
+void
+t_pkey_diff::add(const t_tscalar& pkey) {
+    auto it = m_changes.find(pkey);
+    if (it == m_changes.end()) {
+        m_order.push_back(pkey);
+        m_changes[pkey] = PKEY_DIFF_ADDED;
+    } else if (it->second == PKEY_DIFF_REMOVED) {
+        // Removed and re-inserted, so the row is in the context both before
+        // and after this diff.
+        m_removed_rows.erase(pkey);
+        it.value() = PKEY_DIFF_UPDATED;
+    } else if (it->second == PKEY_DIFF_NONE) {
+        it.value() = PKEY_DIFF_ADDED;
+    }
+}
+
+void
+t_pkey_diff::update(const t_tscalar& pkey) {
+    auto it = m_changes.find(pkey);
+    if (it == m_changes.end()) {
+        m_order.push_back(pkey);
+        m_changes[pkey] = PKEY_DIFF_UPDATED;
+    }
+}
+
+void
+t_pkey_diff::remove(const t_tscalar& pkey, std::vector<t_tscalar> row) {
+    auto it = m_changes.find(pkey);
+    if (it == m_changes.end()) {
+        m_order.push_back(pkey);
+        m_changes[pkey] = PKEY_DIFF_REMOVED;
+        m_removed_rows[pkey] = std::move(row);
+    } else if (it->second == PKEY_DIFF_ADDED) {
+        it.value() = PKEY_DIFF_NONE;
+    } else if (it->second == PKEY_DIFF_UPDATED) {
+        it.value() = PKEY_DIFF_REMOVED;
+        m_removed_rows[pkey] = std::move(row);
+    }
+}
+
+void
+t_pkey_diff::clear() {
+    m_order.clear();
+    m_changes.clear();
+    m_removed_rows.clear();
+}
+
+bool
+t_pkey_diff::empty() const {
+    return m_order.empty();
+}
+
+std::vector<t_tscalar>
+t_pkey_diff::get_pkeys(t_change change) const {
+    std::vector<t_tscalar> pkeys;
+    for (const auto& pkey : m_order) {
+        if (m_changes.at(pkey) == change) {
+            pkeys.push_back(pkey);
+        }
+    }
+
+    return pkeys;
+}
+
+std::vector<t_tscalar>
+t_pkey_diff::get_added() const {
+    return get_pkeys(PKEY_DIFF_ADDED);
+}
+
+std::vector<t_tscalar>
+t_pkey_diff::get_updated() const {
+    return get_pkeys(PKEY_DIFF_UPDATED);
+}
+
+std::vector<t_tscalar>
+t_pkey_diff::get_removed() const {
+    return get_pkeys(PKEY_DIFF_REMOVED);
+}
+
+std::vector<t_tscalar>
+t_pkey_diff::get_removed_data() const {
+    std::vector<t_tscalar> data;
+    for (const auto& pkey : get_removed()) {
+        const auto& row = m_removed_rows.at(pkey);
+        data.insert(data.end(), row.begin(), row.end());
+    }
+
+    return data;
+}
 } // end namespace perspective
 
 namespace std {
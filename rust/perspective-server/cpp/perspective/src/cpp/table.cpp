@@ -15,14 +15,21 @@
 #include "perspective/column.h"
 #include "perspective/computed_expression.h"
 #include "perspective/data_table.h"
+#include "perspective/exception.h"
+#include "perspective/mask.h"
 #include "perspective/raw_types.h"
 #include "perspective/json_loader.h"
 #include "perspective/schema.h"
+#include "perspective/utils.h"
 #include "rapidjson/document.h"
 #include <chrono>
+#include <cmath>
 #include <ctime>
+#include <limits>
+#include <map>
 #include <memory>
 #include <optional>
+#include <set>
 #include <perspective/table.h>
 #include <rapidjson/writer.h>
 #include <sstream>
@@ -42,7 +49,8 @@ Table::Table(
     std::uint32_t limit,
     std::string index,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten
 ) :
     m_init(false),
     m_id(GLOBAL_TABLE_ID++),
@@ -54,7 +62,8 @@ Table::Table(
     m_index(std::move(index)),
     m_gnode_set(false),
     m_backing_store(backing_store),
-    m_list_flatten(list_flatten) {
+    m_list_flatten(list_flatten),
+    m_struct_flatten(struct_flatten) {
 
     validate_columns(m_column_names);
 }
@@ -254,11 +263,25 @@ Table::remove_port(t_uindex port_id) const {
     m_gnode->remove_input_port(port_id);
 }
 
+void
+Table::merge_port(t_uindex from_port_id, t_uindex to_port_id) const {
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    PSP_VERBOSE_ASSERT(
+        m_gnode_set, "Cannot merge input port on a gnode that does not exist."
+    );
+    m_gnode->merge_input_port(from_port_id, to_port_id);
+}
+
 void
 Table::calculate_offset(std::uint32_t row_count) {
     m_offset = m_offset + row_count;
 }
 
+void
+Table::set_offset(std::uint32_t offset) {
+    m_offset = offset;
+}
+
 t_uindex
 Table::get_id() const {
     return m_id;
@@ -322,8 +345,171 @@ Table::set_data_types(const std::vector<t_dtype>& data_types) {
     m_data_types = data_types;
 }
 
+const t_decimal_map&
+Table::get_decimal_params() const {
+    return m_decimal_params;
+}
+
+void
+Table::set_decimal_params(const t_decimal_map& params) {
+    m_decimal_params = params;
+}
+
+const t_timestamp_map&
+Table::get_timestamp_params() const {
+    return m_timestamp_params;
+}
+
+void
+Table::set_timestamp_params(const t_timestamp_map& params) {
+    m_timestamp_params = params;
+}
+
+const t_list_map&
+Table::get_list_params() const {
+    return m_list_params;
+}
+
+void
+Table::set_list_params(const t_list_map& params) {
+    m_list_params = params;
+}
+
+const t_arrow_type_map&
+Table::get_arrow_types() const {
+    return m_arrow_types;
+}
+
+void
+Table::set_arrow_types(const t_arrow_type_map& types) {
+    m_arrow_types = types;
+}
+
+const apachearrow::t_csv_options&
+Table::get_csv_options() const {
+    return m_csv_options;
+}
+
+void
+Table::set_csv_options(const apachearrow::t_csv_options& options) {
+    m_csv_options = options;
+}
+
+const t_row_errors&
+Table::get_row_errors() const {
+    return m_row_errors;
+}
+
+void
+Table::set_row_errors(const t_row_errors& errors) {
+    m_row_errors = errors;
+}
+
+const std::optional<t_bad_rows>&
+Table::get_on_bad_rows() const {
+    return m_on_bad_rows;
+}
+
+void
+Table::set_on_bad_rows(const std::optional<t_bad_rows>& on_bad_rows) {
+    m_on_bad_rows = on_bad_rows;
+}
+
+t_schema_hints
+Table::update_schema_hints() const {
+    t_schema_hints hints;
+    const auto schema = get_schema();
+    const auto& column_names = schema.columns();
+    const auto& data_types = schema.types();
+    for (std::size_t idx = 0; idx < column_names.size(); ++idx) {
+        const auto& name = column_names[idx];
+        if (m_decimal_params.count(name) > 0 || m_list_params.count(name) > 0
+            || m_timestamp_params.count(name) > 0) {
+            continue;
+        }
+
+        switch (data_types[idx]) {
+            case DTYPE_INT32:
+            case DTYPE_FLOAT64:
+            case DTYPE_BOOL:
+            case DTYPE_DATE:
+            case DTYPE_TIME:
+                hints[name] = data_types[idx];
+                break;
+            default:
+                break;
+        }
+    }
+
+    return hints;
+}
+
+/**
+ * Apply `on_bad_rows` to the rows of `data_table` named by `errors`, before it
+ * is written to a `Table`. `BAD_ROWS_FAIL` throws, and `BAD_ROWS_SKIP` drops
+ * the rows, renumbering an implicit index so the rows kept are contiguous
+ * from `offset`.
+ *
+ * @return the number of rows kept.
+ */
+static std::uint32_t
+apply_bad_rows(
+    t_data_table& data_table,
+    const t_row_errors& errors,
+    t_bad_rows on_bad_rows,
+    bool implicit_index,
+    std::uint32_t offset
+) {
+    const auto size = data_table.size();
+    if (errors.empty() || on_bad_rows == BAD_ROWS_NULL) {
+        return size;
+    }
+
+    if (on_bad_rows == BAD_ROWS_FAIL) {
+        throw PerspectiveIngestionException(errors);
+    }
+
+    t_mask mask(size);
+    for (t_uindex ridx = 0; ridx < size; ++ridx) {
+        mask.set(ridx, true);
+    }
+
+    for (const auto& error : errors) {
+        if (error.row < size) {
+            mask.set(error.row, false);
+        }
+    }
+
+    const auto schema = data_table.get_schema();
+    for (const auto& name : schema.columns()) {
+        data_table.set_column(
+            name, data_table.get_const_column(name)->clone(mask)
+        );
+    }
+
+    data_table.set_size(mask.count());
+    if (implicit_index) {
+        for (const auto* name : {"psp_pkey", "psp_okey"}) {
+            if (!schema.has_column(name)) {
+                continue;
+            }
+
+            auto col = data_table.get_column(name);
+            for (std::uint32_t ridx = 0; ridx < mask.count(); ++ridx) {
+                col->set_nth<std::uint32_t>(ridx, ridx + offset);
+            }
+        }
+    }
+
+    return mask.count();
+}
+
 std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>
-schema_to_arrow_map(const t_schema& gnode_output_schema) {
+schema_to_arrow_map(
+    const t_schema& gnode_output_schema,
+    const t_decimal_map& decimals,
+    const t_timestamp_map& timestamps
+) {
     auto map =
         std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>();
 
@@ -356,10 +542,25 @@ schema_to_arrow_map(const t_schema& gnode_output_schema) {
                 map[name] = std::make_shared<arrow::Int32Type>();
                 break;
             case DTYPE_INT64:
-                map[name] = std::make_shared<arrow::Int64Type>();
+                if (const auto it = decimals.find(name); it != decimals.end()) {
+                    map[name] = arrow::decimal128(
+                        static_cast<std::int32_t>(it->second.precision),
+                        static_cast<std::int32_t>(it->second.scale)
+                    );
+                } else {
+                    map[name] = std::make_shared<arrow::Int64Type>();
+                }
                 break;
             case DTYPE_TIME:
-                map[name] = std::make_shared<arrow::TimestampType>();
+                if (const auto it = timestamps.find(name);
+                    it != timestamps.end()) {
+                    map[name] = arrow::timestamp(
+                        to_arrow_time_unit(it->second.unit),
+                        it->second.timezone
+                    );
+                } else {
+                    map[name] = std::make_shared<arrow::TimestampType>();
+                }
                 break;
             case DTYPE_DATE:
                 map[name] = std::make_shared<arrow::Date64Type>();
@@ -375,20 +576,49 @@ schema_to_arrow_map(const t_schema& gnode_output_schema) {
     return map;
 }
 
-void
-Table::update_csv(const std::string_view& data, std::uint32_t port_id) {
-    auto type_map = schema_to_arrow_map(get_gnode()->get_output_schema());
+t_row_errors
+Table::update_csv(
+    const std::string_view& data,
+    std::uint32_t port_id,
+    const apachearrow::t_csv_options* options,
+    std::optional<t_bad_rows> on_bad_rows
+) {
+    auto type_map = schema_to_arrow_map(
+        get_gnode()->get_output_schema(), m_decimal_params, m_timestamp_params
+    );
+
+    if (!on_bad_rows.has_value()) {
+        on_bad_rows = m_on_bad_rows;
+    }
+
     apachearrow::ArrowLoader arrow_loader;
-    arrow_loader.init_csv(data, true, type_map);
+    if (on_bad_rows.has_value()) {
+        arrow_loader.set_schema_hints(update_schema_hints());
+    }
+
+    arrow_loader.init_csv(
+        data, true, type_map, options != nullptr ? *options : m_csv_options
+    );
+    arrow_loader.set_decimal_params(m_decimal_params);
+    arrow_loader.set_timestamp_params(m_timestamp_params);
+    arrow_loader.set_list_params(m_list_params);
     std::uint32_t row_count = 0;
     row_count = arrow_loader.row_count();
     t_data_table data_table(get_schema());
     data_table.init();
     data_table.extend(row_count);
     arrow_loader.fill_table(data_table, get_schema(), m_index, m_offset, true);
+    t_row_errors row_errors = arrow_loader.row_errors();
+    if (on_bad_rows.has_value()) {
+        row_count = apply_bad_rows(
+            data_table, row_errors, *on_bad_rows, m_index.empty(), m_offset
+        );
+    }
+
     process_op_column(data_table, t_op::OP_INSERT);
     calculate_offset(row_count);
     m_pool->send(get_gnode()->get_id(), port_id, data_table);
+    return row_errors;
 }
 
 std::shared_ptr<Table>
@@ -397,13 +627,27 @@ Table::from_csv(
     std::string&& data,
     std::uint32_t limit,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten,
+    const apachearrow::t_csv_options& csv_options,
+    const t_schema_hints& schema_hints,
+    t_bad_rows on_bad_rows
 ) {
-    auto map =
-        std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>();
+    std::vector<std::string> override_names;
+    std::vector<t_dtype> override_types;
+    for (const auto& [name, type] : csv_options.column_types) {
+        override_names.push_back(name);
+        override_types.push_back(type);
+    }
+
+    auto map = schema_to_arrow_map(
+        t_schema(override_names, override_types), {}, {}
+    );
 
     apachearrow::ArrowLoader arrow_loader;
-    arrow_loader.init_csv(data, false, map);
+    arrow_loader.set_schema_hints(schema_hints);
+    arrow_loader.init_csv(data, false, map, csv_options);
+    const t_row_errors row_errors = arrow_loader.row_errors();
 
     // Arrow has materialized the CSV into its own buffers at this point; drop
     // the raw CSV string so it does not live concurrently with the Arrow table
@@ -436,12 +680,30 @@ Table::from_csv(
         loader.fill_table(*data_table, input_schema, index, 0, false);
     }
 
+    row_count = apply_bad_rows(
+        *data_table,
+        row_errors,
+        on_bad_rows,
+        index.empty() && !has_index_column,
+        0
+    );
+
     auto pool = std::make_shared<t_pool>();
     pool->init();
     auto tbl = std::make_shared<Table>(
-        pool, column_names, data_types, limit, index, backing_store, list_flatten
+        pool,
+        column_names,
+        data_types,
+        limit,
+        index,
+        backing_store,
+        list_flatten,
+        struct_flatten
     );
 
+    tbl->set_csv_options(csv_options);
+    tbl->set_row_errors(row_errors);
+
     // `psp_pkey` is guaranteed unique only when the index is implicit (a
     // generated row-number). Explicit indexes or `__INDEX__` columns can
     // contain duplicates, which must be deduplicated via the `flatten()`
@@ -465,7 +727,7 @@ Table::clear() {
 
 
 void
-Table::remove_rows(const std::string_view& data) {
+Table::remove_rows(const std::string_view& data, std::uint32_t port_id) {
     // 1.) Infer schema
     rapidjson::Document document;
     document.Parse(data.data());
@@ -517,11 +779,11 @@ Table::remove_rows(const std::string_view& data) {
     data_table.clone_column("psp_pkey", "psp_okey");
     // calculate_offset(data_table.size());
     process_op_column(data_table, OP_DELETE);
-    m_pool->send(get_gnode()->get_id(), 0, data_table);
+    m_pool->send(get_gnode()->get_id(), port_id, data_table);
 }
 
 void
-Table::remove_cols(const std::string_view& data) {
+Table::remove_cols(const std::string_view& data, std::uint32_t port_id) {
     // 1.) Infer schema
     rapidjson::Document document;
     document.Parse(data.data());
@@ -576,7 +838,365 @@ Table::remove_cols(const std::string_view& data) {
     // calculate_offset(nrows);
     calculate_offset(data_table.size());
     process_op_column(data_table, OP_DELETE);
-    m_pool->send(get_gnode()->get_id(), 0, data_table);
+    m_pool->send(get_gnode()->get_id(), port_id, data_table);
+}
+
+void
+Table::remove_where(
+    const std::vector<t_fterm>& fterms,
+    t_filter_op combiner,
+    std::uint32_t port_id
+) {
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+
+    // An empty `and` filter matches every row, which is never what a caller
+    // of `remove_where` intends - use `clear` for that.
+    if (fterms.empty()) {
+        PSP_COMPLAIN_AND_ABORT("Cannot `remove_where` with an empty filter");
+    }
+
+    const auto master_table = m_gnode->get_pkeyed_table();
+    const auto mask = master_table->filter_cpp(combiner, fterms);
+    if (mask.count() == 0) {
+        return;
+    }
+
+    const auto* pkey_col = master_table->_get_const_column("psp_pkey");
+    std::vector<std::string> column_names{"psp_pkey"};
+    std::vector<t_dtype> data_types{pkey_col->get_dtype()};
+    t_schema schema(column_names, data_types);
+
+    t_data_table data_table(schema);
+    data_table.init();
+    data_table.set_column("psp_pkey", pkey_col->clone(mask));
+    data_table.set_size(mask.count());
+    data_table.clone_column("psp_pkey", "psp_okey");
+    process_op_column(data_table, OP_DELETE);
+    m_pool->send(get_gnode()->get_id(), port_id, data_table);
+}
+
+void
+Table::remove_all(std::uint32_t port_id) {
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    const auto master_table = m_gnode->get_pkeyed_table();
+    if (master_table->size() == 0) {
+        return;
+    }
+
+    const auto* pkey_col = master_table->_get_const_column("psp_pkey");
+    std::vector<std::string> column_names{"psp_pkey"};
+    std::vector<t_dtype> data_types{pkey_col->get_dtype()};
+    t_schema schema(column_names, data_types);
+
+    t_data_table data_table(schema);
+    data_table.init();
+    data_table.set_column("psp_pkey", pkey_col->clone());
+    data_table.set_size(master_table->size());
+    data_table.clone_column("psp_pkey", "psp_okey");
+    process_op_column(data_table, OP_DELETE);
+    m_pool->send(get_gnode()->get_id(), port_id, data_table);
+}
+
+/**
+ * @brief Convert `val` to `dtype` for `Table::prepare_alter_columns`,
+ * returning `false` if `val` has no exact representation in `dtype`, e.g. a
+ * fractional float or an out-of-range integer cast to an integer type. String
+ * results are written to `buf`, which must outlive `out`.
+ */
+static bool
+cast_scalar(
+    const t_tscalar& val, t_dtype dtype, t_tscalar& out, std::string& buf
+) {
+    const auto src = val.get_dtype();
+    out.clear();
+    if (dtype == DTYPE_STR) {
+        buf = val.to_string();
+        out.set(buf.c_str());
+        return true;
+    }
+
+    if (src == DTYPE_STR) {
+        const std::string str = val.to_string();
+        try {
+            switch (dtype) {
+                case DTYPE_BOOL:
+                    if (str != "true" && str != "false") {
+                        return false;
+                    }
+
+                    out.set(str == "true");
+                    return true;
+                case DTYPE_INT32: {
+                    std::size_t pos = 0;
+                    const auto ival = std::stoi(str, &pos);
+                    if (pos != str.size()) {
+                        return false;
+                    }
+
+                    out.set(static_cast<std::int32_t>(ival));
+                    return true;
+                }
+                case DTYPE_INT64: {
+                    std::size_t pos = 0;
+                    const auto ival = std::stoll(str, &pos);
+                    if (pos != str.size()) {
+                        return false;
+                    }
+
+                    out.set(static_cast<std::int64_t>(ival));
+                    return true;
+                }
+                case DTYPE_FLOAT64: {
+                    std::size_t pos = 0;
+                    const auto dval = std::stod(str, &pos);
+                    if (pos != str.size()) {
+                        return false;
+                    }
+
+                    out.set(dval);
+                    return true;
+                }
+                case DTYPE_DATE: {
+                    std::tm tm = {};
+                    if (!parse_all_date_time(tm, str)) {
+                        return false;
+                    }
+
+                    out.set(t_date{
+                        static_cast<std::int16_t>(tm.tm_year + 1900),
+                        static_cast<std::int8_t>(tm.tm_mon),
+                        static_cast<std::int8_t>(tm.tm_mday)
+                    });
+                    return true;
+                }
+                case DTYPE_TIME: {
+                    std::chrono::system_clock::time_point tp;
+                    if (!parse_all_date_time(tp, str)) {
+                        return false;
+                    }
+
+                    out.set(t_time{
+                        std::chrono::duration_cast<std::chrono::milliseconds>(
+                            tp.time_since_epoch()
+                        )
+                            .count()
+                    });
+                    return true;
+                }
+                default:
+                    return false;
+            }
+        } catch (const std::logic_error&) {
+            // `std::invalid_argument` or `std::out_of_range` from `sto*`
+            return false;
+        }
+    }
+
+    if (src == DTYPE_DATE) {
+        if (dtype != DTYPE_TIME) {
+            return false;
+        }
+
+        out.set(t_time{val.get<t_date>().as_epoch_ms()});
+        return true;
+    }
+
+    if (dtype == DTYPE_DATE) {
+        if (src != DTYPE_TIME) {
+            return false;
+        }
+
+        out.set(t_date::from_epoch_ms(val.get<t_time>().raw_value()));
+        return true;
+    }
+
+    // Numeric, boolean and datetime (epoch milliseconds) sources. Integer
+    // targets must hold the value exactly, rather than truncating a float or
+    // wrapping an out-of-range integer.
+    switch (dtype) {
+        case DTYPE_BOOL:
+            out.set(val.to_double() != 0);
+            return true;
+        case DTYPE_INT32:
+        case DTYPE_INT64: {
+            std::int64_t ival;
+            if (src == DTYPE_FLOAT64 || src == DTYPE_FLOAT32) {
+                // 2^63, the first float64 beyond the range of `std::int64_t`.
+                constexpr double int64_bound = 9223372036854775808.0;
+                const auto dval = val.to_double();
+                if (std::trunc(dval) != dval || dval < -int64_bound
+                    || dval >= int64_bound) {
+                    return false;
+                }
+
+                ival = static_cast<std::int64_t>(dval);
+            } else {
+                ival = val.to_int64();
+            }
+
+            if (dtype == DTYPE_INT64) {
+                out.set(ival);
+                return true;
+            }
+
+            if (ival < std::numeric_limits<std::int32_t>::min()
+                || ival > std::numeric_limits<std::int32_t>::max()) {
+                return false;
+            }
+
+            out.set(static_cast<std::int32_t>(ival));
+            return true;
+        }
+        case DTYPE_FLOAT64:
+            out.set(val.to_double());
+            return true;
+        case DTYPE_TIME:
+            out.set(t_time{val.to_int64()});
+            return true;
+        default:
+            return false;
+    }
+}
+
+t_altered_columns
+Table::prepare_alter_columns(
+    const std::vector<std::pair<std::string, t_dtype>>& add,
+    const std::vector<std::string>& drop,
+    const std::vector<std::pair<std::string, t_dtype>>& cast
+) const {
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    PSP_VERBOSE_ASSERT(m_gnode_set, "Cannot alter columns without a gnode.");
+
+    const auto old_table = m_gnode->get_pkeyed_table();
+    const t_schema& old_schema = m_gnode->get_state_input_schema();
+    const auto is_reserved = [&](const std::string& name) {
+        return name == m_index || name.rfind("psp_", 0) == 0;
+    };
+
+    std::map<std::string, t_dtype> casts;
+    for (const auto& [name, dtype] : cast) {
+        if (!old_schema.has_column(name) || is_reserved(name)
+            || m_decimal_params.count(name) > 0
+            || m_timestamp_params.count(name) > 0
+            || m_list_params.count(name) > 0) {
+            PSP_COMPLAIN_AND_ABORT("Cannot cast column `" + name + "`");
+        }
+
+        casts[name] = dtype;
+    }
+
+    std::set<std::string> drops;
+    for (const auto& name : drop) {
+        if (!old_schema.has_column(name) || is_reserved(name)) {
+            PSP_COMPLAIN_AND_ABORT("Cannot drop column `" + name + "`");
+        }
+
+        drops.insert(name);
+    }
+
+    t_schema schema = old_schema.drop(drops);
+    for (const auto& [name, dtype] : casts) {
+        schema.retype_column(name, dtype);
+    }
+
+    for (const auto& [name, dtype] : add) {
+        if (schema.has_column(name) || name.rfind("psp_", 0) == 0) {
+            PSP_COMPLAIN_AND_ABORT("Cannot add column `" + name + "`");
+        }
+
+        schema.add_column(name, dtype);
+    }
+
+    // Build the new state in full before touching `m_gnode`, so a failed
+    // cast leaves the `Table` as it was.
+    const auto nrows = old_table->size();
+    auto data_table = std::make_shared<t_data_table>(schema);
+    data_table->init();
+    data_table->extend(nrows);
+    for (const auto& name : schema.columns()) {
+        if (name == "psp_op") {
+            continue;
+        }
+
+        if (!old_schema.has_column(name)) {
+            auto* col = data_table->_get_column(name);
+            for (t_uindex ii = 0; ii < nrows; ++ii) {
+                col->clear(ii);
+            }
+
+            continue;
+        }
+
+        const auto* old_col = old_table->_get_const_column(name);
+        if (casts.count(name) == 0) {
+            data_table->set_column(name, old_col->clone());
+            continue;
+        }
+
+        const auto dtype = casts.at(name);
+        auto* col = data_table->_get_column(name);
+        t_tscalar out;
+        std::string buf;
+        for (t_uindex ii = 0; ii < nrows; ++ii) {
+            const auto val = old_col->get_scalar(ii);
+            if (!val.is_valid()) {
+                col->clear(ii);
+            } else if (cast_scalar(val, dtype, out, buf)) {
+                col->set_scalar(ii, out);
+            } else {
+                std::stringstream ss;
+                ss << "Cannot cast value `" << val.to_string()
+                   << "` in column `" << name << "` to "
+                   << dtype_to_str(dtype);
+                PSP_COMPLAIN_AND_ABORT(ss.str());
+            }
+        }
+    }
+
+    data_table->get_column("psp_op")->raw_fill<std::uint8_t>(OP_INSERT);
+
+    t_altered_columns altered;
+    altered.m_schema = std::move(schema);
+    altered.m_data_table = std::move(data_table);
+    altered.m_drops = std::move(drops);
+    for (const auto& cast : casts) {
+        altered.m_casts.insert(cast.first);
+    }
+
+    return altered;
+}
+
+void
+Table::apply_altered_columns(t_altered_columns&& altered) {
+    PSP_VERBOSE_ASSERT(m_init, "touching uninited object");
+    PSP_VERBOSE_ASSERT(m_gnode_set, "Cannot alter columns without a gnode.");
+
+    const auto old_gnode = m_gnode;
+    auto new_gnode = make_gnode(altered.m_schema);
+    m_pool->register_gnode(new_gnode.get());
+    if (altered.m_data_table->size() > 0) {
+        new_gnode->init_bulk(altered.m_data_table);
+    }
+
+    new_gnode->adopt(*old_gnode);
+    m_pool->unregister_gnode(old_gnode->get_id());
+    set_gnode(new_gnode);
+
+    for (const auto& name : altered.m_drops) {
+        m_decimal_params.erase(name);
+        m_timestamp_params.erase(name);
+        m_list_params.erase(name);
+        m_arrow_types.erase(name);
+    }
+
+    // A cast column no longer holds values of its source Arrow type.
+    for (const auto& name : altered.m_casts) {
+        m_arrow_types.erase(name);
+    }
+
+    const auto out_schema = get_schema();
+    m_column_names = out_schema.columns();
+    m_data_types = out_schema.types();
 }
 
 std::shared_ptr<Table>
@@ -586,7 +1206,9 @@ Table::from_json_loader(
     std::string&& data,
     std::uint32_t limit,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten,
+    t_bad_rows on_bad_rows
 ) {
     if (const auto repeated = loader.repeated_index(index)) {
         std::stringstream ss;
@@ -604,13 +1226,18 @@ Table::from_json_loader(
     data_table->add_column("psp_pkey", pkey_dtype, true);
     data_table->add_column("psp_okey", pkey_dtype, true);
 
-    const auto nrows = loader.fill_table(*data_table, index, 0, false);
+    auto nrows = loader.fill_table(*data_table, index, 0, false);
 
     // `names`/`types` may have grown during the fill -- an ndjson record can
     // introduce a column -- so the Table's column list is read back from the
     // loader rather than from `schema`. Copy them out before releasing.
     auto column_names = loader.names();
     auto data_types = loader.types();
+    const t_list_map lists = loader.list_params();
+    const t_row_errors row_errors = loader.row_errors();
+    nrows = apply_bad_rows(
+        *data_table, row_errors, on_bad_rows, loader.is_implicit(), 0
+    );
 
     // Drop the parsed document and the source text before the gnode allocates
     // its master table, so the two peaks do not overlap.
@@ -626,26 +1253,43 @@ Table::from_json_loader(
         limit,
         index,
         backing_store,
-        list_flatten
+        list_flatten,
+        struct_flatten
     );
 
+    tbl->set_list_params(lists);
+    tbl->set_row_errors(row_errors);
     tbl->init(*data_table, nrows, t_op::OP_INSERT, 0);
     data_table.reset();
     pool->_process();
     return tbl;
 }
 
-void
+t_row_errors
 Table::update_json(
     const std::string_view& data,
     json::t_json_format format,
-    std::uint32_t port_id
+    std::uint32_t port_id,
+    std::optional<t_bad_rows> on_bad_rows
 ) {
+    if (!on_bad_rows.has_value()) {
+        on_bad_rows = m_on_bad_rows;
+    }
+
     t_schema table_schema = get_schema();
     json::JsonLoader loader;
-    loader.init(data, format, m_index, &table_schema, m_list_flatten);
+    loader.set_decimal_params(m_decimal_params);
+    loader.set_timestamp_params(m_timestamp_params);
+    loader.set_list_params(m_list_params);
+    if (on_bad_rows.has_value()) {
+        loader.set_schema_hints(update_schema_hints());
+    }
+
+    loader.init(
+        data, format, m_index, &table_schema, m_list_flatten, m_struct_flatten
+    );
     if (loader.empty()) {
-        return;
+        return {};
     }
 
     if (const auto repeated = loader.repeated_index(m_index)) {
@@ -663,17 +1307,29 @@ Table::update_json(
         true
     );
 
-    const auto size = loader.fill_table(data_table, m_index, m_offset, true);
+    auto size = loader.fill_table(data_table, m_index, m_offset, true);
+    t_row_errors row_errors = loader.row_errors();
+    if (on_bad_rows.has_value()) {
+        size = apply_bad_rows(
+            data_table, row_errors, *on_bad_rows, m_index.empty(), m_offset
+        );
+    }
+
     data_table.clone_column("psp_pkey", "psp_okey");
     process_op_column(data_table, t_op::OP_INSERT);
     calculate_offset(size);
     m_pool->send(get_gnode()->get_id(), port_id, data_table);
+    return row_errors;
 }
 
 
-void
-Table::update_cols(const std::string_view& data, std::uint32_t port_id) {
-    update_json(data, json::JSON_FORMAT_COLUMNS, port_id);
+t_row_errors
+Table::update_cols(
+    const std::string_view& data,
+    std::uint32_t port_id,
+    std::optional<t_bad_rows> on_bad_rows
+) {
+    return update_json(data, json::JSON_FORMAT_COLUMNS, port_id, on_bad_rows);
 }
 
 std::shared_ptr<Table>
@@ -682,14 +1338,31 @@ Table::from_cols(
     std::string&& data,
     std::uint32_t limit,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten,
+    const t_schema_hints& schema_hints,
+    t_bad_rows on_bad_rows
 ) {
     json::JsonLoader loader;
+    loader.set_schema_hints(schema_hints);
     loader.init(
-        data, json::JSON_FORMAT_COLUMNS, index, nullptr, list_flatten
+        data,
+        json::JSON_FORMAT_COLUMNS,
+        index,
+        nullptr,
+        list_flatten,
+        struct_flatten
     );
+
     return from_json_loader(
-        loader, index, std::move(data), limit, backing_store, list_flatten
+        loader,
+        index,
+        std::move(data),
+        limit,
+        backing_store,
+        list_flatten,
+        struct_flatten,
+        on_bad_rows
     );
 }
 
@@ -699,9 +1372,13 @@ Table::from_cols(
 // document.Accept(writer);
 // std::cout << buffer.GetString() << std::endl;
 
-void
-Table::update_rows(const std::string_view& data, std::uint32_t port_id) {
-    update_json(data, json::JSON_FORMAT_ROWS, port_id);
+t_row_errors
+Table::update_rows(
+    const std::string_view& data,
+    std::uint32_t port_id,
+    std::optional<t_bad_rows> on_bad_rows
+) {
+    return update_json(data, json::JSON_FORMAT_ROWS, port_id, on_bad_rows);
 }
 
 std::shared_ptr<Table>
@@ -710,20 +1387,41 @@ Table::from_rows(
     std::string&& data,
     std::uint32_t limit,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten,
+    const t_schema_hints& schema_hints,
+    t_bad_rows on_bad_rows
 ) {
     json::JsonLoader loader;
+    loader.set_schema_hints(schema_hints);
     loader.init(
-        data, json::JSON_FORMAT_ROWS, index, nullptr, list_flatten
+        data,
+        json::JSON_FORMAT_ROWS,
+        index,
+        nullptr,
+        list_flatten,
+        struct_flatten
     );
+
     return from_json_loader(
-        loader, index, std::move(data), limit, backing_store, list_flatten
+        loader,
+        index,
+        std::move(data),
+        limit,
+        backing_store,
+        list_flatten,
+        struct_flatten,
+        on_bad_rows
     );
 }
 
-void
-Table::update_ndjson(const std::string_view& data, std::uint32_t port_id) {
-    update_json(data, json::JSON_FORMAT_NDJSON, port_id);
+t_row_errors
+Table::update_ndjson(
+    const std::string_view& data,
+    std::uint32_t port_id,
+    std::optional<t_bad_rows> on_bad_rows
+) {
+    return update_json(data, json::JSON_FORMAT_NDJSON, port_id, on_bad_rows);
 }
 
 std::shared_ptr<Table>
@@ -732,14 +1430,31 @@ Table::from_ndjson(
     std::string&& data,
     std::uint32_t limit,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten,
+    const t_schema_hints& schema_hints,
+    t_bad_rows on_bad_rows
 ) {
     json::JsonLoader loader;
+    loader.set_schema_hints(schema_hints);
     loader.init(
-        data, json::JSON_FORMAT_NDJSON, index, nullptr, list_flatten
+        data,
+        json::JSON_FORMAT_NDJSON,
+        index,
+        nullptr,
+        list_flatten,
+        struct_flatten
     );
+
     return from_json_loader(
-        loader, index, std::move(data), limit, backing_store, list_flatten
+        loader,
+        index,
+        std::move(data),
+        limit,
+        backing_store,
+        list_flatten,
+        struct_flatten,
+        on_bad_rows
     );
 }
 
@@ -749,7 +1464,8 @@ Table::from_schema(
     const t_schema& schema,
     std::uint32_t limit,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten
 ) {
     auto pool = std::make_shared<t_pool>();
     pool->init();
@@ -780,7 +1496,8 @@ Table::from_schema(
         limit,
         index,
         backing_store,
-        list_flatten
+        list_flatten,
+        struct_flatten
     );
 
     tbl->init(data_table, 0, t_op::OP_INSERT, 0);
@@ -788,13 +1505,26 @@ Table::from_schema(
     return tbl;
 }
 
-void
-Table::update_arrow(const std::string_view& data, std::uint32_t port_id) {
+t_row_errors
+Table::update_arrow(
+    const std::string_view& data,
+    std::uint32_t port_id,
+    std::optional<t_bad_rows> on_bad_rows
+) {
+    if (!on_bad_rows.has_value()) {
+        on_bad_rows = m_on_bad_rows;
+    }
+
     apachearrow::ArrowLoader arrow_loader;
+    if (on_bad_rows.has_value()) {
+        arrow_loader.set_schema_hints(update_schema_hints());
+    }
+
     arrow_loader.initialize(
         reinterpret_cast<const std::uint8_t*>(data.data()),
         data.size(),
-        m_list_flatten
+        m_list_flatten,
+        m_struct_flatten
     );
 
     if (const auto repeated = arrow_loader.repeated_index(m_index)) {
@@ -804,6 +1534,10 @@ Table::update_arrow(const std::string_view& data, std::uint32_t port_id) {
         PSP_COMPLAIN_AND_ABORT(ss.str());
     }
 
+    arrow_loader.set_decimal_params(m_decimal_params);
+    arrow_loader.set_timestamp_params(m_timestamp_params);
+    arrow_loader.set_list_params(m_list_params);
+
     t_data_table data_table{this->get_schema()};
     data_table.init();
     auto row_count = arrow_loader.row_count();
@@ -811,8 +1545,11 @@ Table::update_arrow(const std::string_view& data, std::uint32_t port_id) {
     auto input_schema = this->get_schema();
 
     auto arrow_names = arrow_loader.names();
-    if (std::find(arrow_names.begin(), arrow_names.end(), "__INDEX__")
-        != arrow_names.end()) {
+    const bool has_index_column =
+        std::find(arrow_names.begin(), arrow_names.end(), "__INDEX__")
+        != arrow_names.end();
+
+    if (has_index_column) {
         if (m_index.empty()) {
             input_schema.add_column("__INDEX__", DTYPE_INT32);
         } else {
@@ -823,10 +1560,21 @@ Table::update_arrow(const std::string_view& data, std::uint32_t port_id) {
     }
 
     arrow_loader.fill_table(data_table, input_schema, m_index, m_offset, true);
+    t_row_errors row_errors = arrow_loader.row_errors();
+    if (on_bad_rows.has_value()) {
+        row_count = apply_bad_rows(
+            data_table,
+            row_errors,
+            *on_bad_rows,
+            m_index.empty() && !has_index_column,
+            m_offset
+        );
+    }
 
     process_op_column(data_table, t_op::OP_INSERT);
     calculate_offset(row_count);
     m_pool->send(get_gnode()->get_id(), port_id, data_table);
+    return row_errors;
 }
 
 std::shared_ptr<Table>
@@ -835,15 +1583,24 @@ Table::from_arrow(
     std::string&& data,
     std::uint32_t limit,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten,
+    bool preserve_arrow_types,
+    const t_schema_hints& schema_hints,
+    t_bad_rows on_bad_rows
 ) {
     apachearrow::ArrowLoader arrow_loader;
+    arrow_loader.set_schema_hints(schema_hints);
+
+    // Only a preserved `Decimal128` is loaded as an exact `DECIMAL` column.
+    arrow_loader.set_exact_decimals(preserve_arrow_types);
 
     // Parse the arrow and get its metadata
     arrow_loader.initialize(
         reinterpret_cast<const std::uint8_t*>(data.data()),
         data.size(),
-        list_flatten
+        list_flatten,
+        struct_flatten
     );
 
     if (const auto repeated = arrow_loader.repeated_index(index)) {
@@ -864,8 +1621,9 @@ Table::from_arrow(
 
     auto implicit_index_it =
         std::find(columns.begin(), columns.end(), "__INDEX__");
+    const bool has_index_column = implicit_index_it != columns.end();
 
-    if (implicit_index_it != columns.end()) {
+    if (has_index_column) {
         auto idx = std::distance(columns.begin(), implicit_index_it);
         // position of the column is at the same index in both
         // vectors
@@ -877,6 +1635,15 @@ Table::from_arrow(
     auto data_table = std::make_unique<t_data_table>(output_schema);
     data_table->init();
 
+    // Read before `arrow_loader` is moved from below.
+    const t_decimal_map decimals = arrow_loader.decimal_params();
+    const t_timestamp_map timestamps = arrow_loader.timestamp_params();
+    const t_list_map lists = arrow_loader.list_params();
+    const t_arrow_type_map arrow_types = preserve_arrow_types
+        ? arrow_loader.arrow_types()
+        : t_arrow_type_map{};
+    const t_row_errors row_errors = arrow_loader.row_errors();
+
     {
         auto _ = std::move(data);
         auto loader = std::move(arrow_loader);
@@ -885,13 +1652,33 @@ Table::from_arrow(
         loader.fill_table(*data_table, input_schema, index, 0, false);
     }
 
+    apply_bad_rows(
+        *data_table,
+        row_errors,
+        on_bad_rows,
+        index.empty() && !has_index_column,
+        0
+    );
+
     // Make Table
     auto pool = std::make_shared<t_pool>();
     pool->init();
     auto table = std::make_shared<Table>(
-        pool, columns, types, limit, index, backing_store, list_flatten
+        pool,
+        columns,
+        types,
+        limit,
+        index,
+        backing_store,
+        list_flatten,
+        struct_flatten
     );
 
+    table->set_decimal_params(decimals);
+    table->set_timestamp_params(timestamps);
+    table->set_list_params(lists);
+    table->set_arrow_types(arrow_types);
+    table->set_row_errors(row_errors);
     table->init(*data_table, data_table->num_rows(), t_op::OP_INSERT, 0);
     data_table.reset();
     pool->_process();
@@ -906,7 +1693,8 @@ Table::make_table(
     const std::string& index,
     const std::string_view& data,
     t_backing_store backing_store,
-    apachearrow::t_list_flatten list_flatten
+    apachearrow::t_list_flatten list_flatten,
+    const t_struct_flatten& struct_flatten
 ) {
     auto pool = std::make_shared<t_pool>();
     pool->init();
@@ -931,7 +1719,14 @@ Table::make_table(
     auto columns = data_table.get_schema().columns();
     auto dtypes = data_table.get_schema().types();
     auto table = std::make_shared<Table>(
-        pool, columns, dtypes, limit, index, backing_store, list_flatten
+        pool,
+        columns,
+        dtypes,
+        limit,
+        index,
+        backing_store,
+        list_flatten,
+        struct_flatten
     );
     table->init(data_table, data_table.num_rows(), t_op::OP_INSERT, 0);
     pool->_process();
@@ -0,0 +1,151 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+#include <perspective/base.h>
+#include <perspective/timestamp.h>
+#include <perspective/arrow_csv.h>
+#include <perspective/utils.h>
+#include <chrono>
+#include <cmath>
+
+namespace perspective {
+
+bool
+t_timestamp_params::operator==(const t_timestamp_params& other) const {
+    return unit == other.unit && timezone == other.timezone;
+}
+
+std::int64_t
+timestamp_units_per_second(t_timestamp_unit unit) {
+    switch (unit) {
+        case TIMESTAMP_UNIT_SECOND:
+            return 1;
+        case TIMESTAMP_UNIT_MILLISECOND:
+            return 1000;
+        case TIMESTAMP_UNIT_MICROSECOND:
+            return 1000000;
+        case TIMESTAMP_UNIT_NANOSECOND:
+            return 1000000000;
+    }
+
+    PSP_COMPLAIN_AND_ABORT("Unknown timestamp unit");
+    return 1000;
+}
+
+std::int64_t
+convert_timestamp(
+    std::int64_t value, t_timestamp_unit from, t_timestamp_unit to
+) {
+    const std::int64_t from_per_second = timestamp_units_per_second(from);
+    const std::int64_t to_per_second = timestamp_units_per_second(to);
+    if (from_per_second == to_per_second) {
+        return value;
+    }
+
+    if (to_per_second > from_per_second) {
+        return value * (to_per_second / from_per_second);
+    }
+
+    // Floor rather than truncate, so pre-epoch instants round to the earlier
+    // tick like their post-epoch counterparts.
+    const std::int64_t factor = from_per_second / to_per_second;
+    std::int64_t result = value / factor;
+    if (value % factor < 0) {
+        --result;
+    }
+
+    return result;
+}
+
+double
+timestamp_to_millis(double value, t_timestamp_unit unit) {
+    return value * 1000.0
+        / static_cast<double>(timestamp_units_per_second(unit));
+}
+
+std::int64_t
+millis_to_timestamp(double millis, t_timestamp_unit unit) {
+    return std::llround(
+        millis * static_cast<double>(timestamp_units_per_second(unit)) / 1000.0
+    );
+}
+
+arrow::TimeUnit::type
+to_arrow_time_unit(t_timestamp_unit unit) {
+    switch (unit) {
+        case TIMESTAMP_UNIT_SECOND:
+            return arrow::TimeUnit::SECOND;
+        case TIMESTAMP_UNIT_MILLISECOND:
+            return arrow::TimeUnit::MILLI;
+        case TIMESTAMP_UNIT_MICROSECOND:
+            return arrow::TimeUnit::MICRO;
+        case TIMESTAMP_UNIT_NANOSECOND:
+            return arrow::TimeUnit::NANO;
+    }
+
+    return arrow::TimeUnit::MILLI;
+}
+
+t_timestamp_unit
+from_arrow_time_unit(arrow::TimeUnit::type unit) {
+    switch (unit) {
+        case arrow::TimeUnit::SECOND:
+            return TIMESTAMP_UNIT_SECOND;
+        case arrow::TimeUnit::MILLI:
+            return TIMESTAMP_UNIT_MILLISECOND;
+        case arrow::TimeUnit::MICRO:
+            return TIMESTAMP_UNIT_MICROSECOND;
+        case arrow::TimeUnit::NANO:
+            return TIMESTAMP_UNIT_NANOSECOND;
+    }
+
+    return TIMESTAMP_UNIT_MILLISECOND;
+}
+
+std::optional<std::int64_t>
+parse_timestamp(std::string_view text, t_timestamp_unit unit) {
+    const std::string input{text};
+    const auto result =
+        apachearrow::parseAsArrowTimestamp(input, to_arrow_time_unit(unit));
+    if (result.has_value()) {
+        return result;
+    }
+
+    // The fallback formats have no fractional seconds to lose.
+    std::chrono::system_clock::time_point tp;
+    if (!parse_all_date_time(tp, input)) {
+        return std::nullopt;
+    }
+
+    const auto millis = std::chrono::duration_cast<std::chrono::milliseconds>(
+        tp.time_since_epoch()
+    );
+
+    return convert_timestamp(millis.count(), TIMESTAMP_UNIT_MILLISECOND, unit);
+}
+
+const char*
+timestamp_unit_name(t_timestamp_unit unit) {
+    switch (unit) {
+        case TIMESTAMP_UNIT_SECOND:
+            return "s";
+        case TIMESTAMP_UNIT_MILLISECOND:
+            return "ms";
+        case TIMESTAMP_UNIT_MICROSECOND:
+            return "us";
+        case TIMESTAMP_UNIT_NANOSECOND:
+            return "ns";
+    }
+
+    return "ms";
+}
+
+} // namespace perspective
@@ -13,11 +13,16 @@
 #include <perspective/first.h>
 #include <perspective/view.h>
 #include <perspective/arrow_writer.h>
+#include <cmath>
+#include <cstring>
+#include <optional>
 #include <sstream>
 #include <utility>
 #include <rapidjson/writer.h>
 #include <rapidjson/stringbuffer.h>
 #include <arrow/csv/writer.h>
+#include <arrow/compute/cast.h>
+#include <arrow/util/key_value_metadata.h>
 #include <perspective/pyutils.h>
 
 namespace perspective {
@@ -85,9 +90,395 @@ View<CTX_T>::View(
     // path for everything
     sides() > 0 ? m_col_offset = 1 : m_col_offset = 0;
 
+    _init_decimal_params();
+    _init_timestamp_params();
+    _init_list_params();
+    _init_arrow_types();
+
     // TODO: add index shifting ability
 }
 
+template <typename CTX_T>
+void
+View<CTX_T>::_init_decimal_params() {
+    const auto& decimals = m_table->get_decimal_params();
+    if (decimals.empty()) {
+        return;
+    }
+
+    for (const auto& name : m_row_pivots) {
+        if (const auto it = decimals.find(name); it != decimals.end()) {
+            m_row_path_decimal_params.insert(*it);
+        }
+    }
+
+    if (sides() == 0) {
+        for (const auto& name : m_columns) {
+            if (const auto it = decimals.find(name); it != decimals.end()) {
+                m_decimal_params.insert(*it);
+            }
+        }
+
+        return;
+    }
+
+    for (const auto& agg : m_aggregates) {
+        const auto deps = agg.get_input_depnames();
+        if (deps.empty()) {
+            continue;
+        }
+
+        const auto it = decimals.find(deps[0]);
+        if (it == decimals.end()) {
+            continue;
+        }
+
+        switch (agg.agg()) {
+            case AGGTYPE_SUM:
+            case AGGTYPE_SUM_ABS:
+            case AGGTYPE_ABS_SUM:
+            case AGGTYPE_SUM_NOT_NULL:
+            case AGGTYPE_GMV:
+            case AGGTYPE_LAST_MINUS_FIRST:
+            case AGGTYPE_HIGH_MINUS_LOW: {
+                m_decimal_params[agg.name()] =
+                    t_decimal_params{DECIMAL_MAX_PRECISION, it->second.scale};
+            } break;
+            case AGGTYPE_ANY:
+            case AGGTYPE_UNIQUE:
+            case AGGTYPE_DOMINANT:
+            case AGGTYPE_Q1:
+            case AGGTYPE_Q3:
+            case AGGTYPE_MEDIAN:
+            case AGGTYPE_FIRST:
+            case AGGTYPE_LAST_BY_INDEX:
+            case AGGTYPE_LAST_VALUE:
+            case AGGTYPE_MAX:
+            case AGGTYPE_MIN:
+            case AGGTYPE_MAX_BY:
+            case AGGTYPE_MIN_BY:
+            case AGGTYPE_HIGH_WATER_MARK:
+            case AGGTYPE_LOW_WATER_MARK:
+            case AGGTYPE_IDENTITY:
+            case AGGTYPE_DISTINCT_LEAF:
+            case AGGTYPE_MEAN:
+            case AGGTYPE_MEAN_BY_COUNT:
+            case AGGTYPE_WEIGHTED_MEAN: {
+                m_decimal_params[agg.name()] = it->second;
+            } break;
+            default:
+                break;
+        }
+    }
+}
+
+template <typename CTX_T>
+void
+View<CTX_T>::_init_timestamp_params() {
+    const auto& timestamps = m_table->get_timestamp_params();
+    if (timestamps.empty()) {
+        return;
+    }
+
+    // Expressions compute in milliseconds, but keep the timezone of the one
+    // datetime column they read, e.g. `bucket("ts", 'h')`.
+    t_timestamp_map expressions;
+    for (const auto& expr : m_expressions) {
+        if (expr->get_dtype() != DTYPE_TIME) {
+            continue;
+        }
+
+        std::optional<std::string> timezone;
+        bool ambiguous = false;
+        for (const auto& [_, column] : expr->get_column_ids()) {
+            const auto it = timestamps.find(column);
+            if (it == timestamps.end() || it->second.timezone.empty()) {
+                continue;
+            }
+
+            if (timezone.has_value() && *timezone != it->second.timezone) {
+                ambiguous = true;
+            }
+
+            timezone = it->second.timezone;
+        }
+
+        if (timezone.has_value() && !ambiguous) {
+            expressions[expr->get_expression_alias()] =
+                t_timestamp_params{TIMESTAMP_UNIT_MILLISECOND, *timezone};
+        }
+    }
+
+    const auto find =
+        [&](const std::string& name) -> const t_timestamp_params* {
+        if (const auto it = timestamps.find(name); it != timestamps.end()) {
+            return &it->second;
+        }
+
+        const auto it = expressions.find(name);
+        return it == expressions.end() ? nullptr : &it->second;
+    };
+
+    for (const auto& name : m_row_pivots) {
+        if (const auto* params = find(name)) {
+            m_row_path_timestamp_params[name] = *params;
+        }
+    }
+
+    if (sides() == 0) {
+        for (const auto& name : m_columns) {
+            if (const auto* params = find(name)) {
+                m_timestamp_params[name] = *params;
+            }
+        }
+
+        return;
+    }
+
+    for (const auto& agg : m_aggregates) {
+        const auto deps = agg.get_input_depnames();
+        if (deps.empty()) {
+            continue;
+        }
+
+        const auto* params = find(deps[0]);
+        if (params == nullptr) {
+            continue;
+        }
+
+        switch (agg.agg()) {
+            case AGGTYPE_ANY:
+            case AGGTYPE_UNIQUE:
+            case AGGTYPE_DOMINANT:
+            case AGGTYPE_Q1:
+            case AGGTYPE_Q3:
+            case AGGTYPE_MEDIAN:
+            case AGGTYPE_FIRST:
+            case AGGTYPE_LAST_BY_INDEX:
+            case AGGTYPE_LAST_VALUE:
+            case AGGTYPE_MAX:
+            case AGGTYPE_MIN:
+            case AGGTYPE_MAX_BY:
+            case AGGTYPE_MIN_BY:
+            case AGGTYPE_HIGH_WATER_MARK:
+            case AGGTYPE_LOW_WATER_MARK:
+            case AGGTYPE_IDENTITY:
+            case AGGTYPE_DISTINCT_LEAF:
+            case AGGTYPE_MEAN:
+            case AGGTYPE_MEAN_BY_COUNT:
+            case AGGTYPE_WEIGHTED_MEAN: {
+                m_timestamp_params[agg.name()] = *params;
+            } break;
+            default:
+                break;
+        }
+    }
+}
+
+template <typename CTX_T>
+const t_timestamp_map&
+View<CTX_T>::timestamp_params() const {
+    return m_timestamp_params;
+}
+
+template <typename CTX_T>
+const t_timestamp_params*
+View<CTX_T>::get_timestamp(const std::string& column) const {
+    const auto it = m_timestamp_params.find(column);
+    return it == m_timestamp_params.end() ? nullptr : &it->second;
+}
+
+template <typename CTX_T>
+const t_timestamp_params*
+View<CTX_T>::get_row_path_timestamp(t_uindex depth) const {
+    if (depth >= m_row_pivots.size()) {
+        return nullptr;
+    }
+
+    const auto it = m_row_path_timestamp_params.find(m_row_pivots[depth]);
+    return it == m_row_path_timestamp_params.end() ? nullptr : &it->second;
+}
+
+template <typename CTX_T>
+void
+View<CTX_T>::_init_list_params() {
+    const auto& lists = m_table->get_list_params();
+    if (lists.empty()) {
+        return;
+    }
+
+    for (const auto& name : m_row_pivots) {
+        if (const auto it = lists.find(name); it != lists.end()) {
+            m_row_path_list_params.insert(*it);
+        }
+    }
+
+    if (sides() == 0) {
+        for (const auto& name : m_columns) {
+            if (const auto it = lists.find(name); it != lists.end()) {
+                m_list_params.insert(*it);
+            }
+        }
+
+        return;
+    }
+
+    for (const auto& agg : m_aggregates) {
+        const auto deps = agg.get_input_depnames();
+        if (deps.empty()) {
+            continue;
+        }
+
+        const auto it = lists.find(deps[0]);
+        if (it == lists.end()) {
+            continue;
+        }
+
+        switch (agg.agg()) {
+            case AGGTYPE_UNION:
+            case AGGTYPE_ANY:
+            case AGGTYPE_UNIQUE:
+            case AGGTYPE_DOMINANT:
+            case AGGTYPE_FIRST:
+            case AGGTYPE_LAST_BY_INDEX:
+            case AGGTYPE_LAST_VALUE:
+            case AGGTYPE_IDENTITY:
+            case AGGTYPE_DISTINCT_LEAF: {
+                m_list_params[agg.name()] = it->second;
+            } break;
+            default:
+                break;
+        }
+    }
+}
+
+template <typename CTX_T>
+const t_list_map&
+View<CTX_T>::list_params() const {
+    return m_list_params;
+}
+
+template <typename CTX_T>
+const t_list_params*
+View<CTX_T>::get_list(const std::string& column) const {
+    const auto it = m_list_params.find(column);
+    return it == m_list_params.end() ? nullptr : &it->second;
+}
+
+template <typename CTX_T>
+const t_list_params*
+View<CTX_T>::get_row_path_list(t_uindex depth) const {
+    if (depth >= m_row_pivots.size()) {
+        return nullptr;
+    }
+
+    const auto it = m_row_path_list_params.find(m_row_pivots[depth]);
+    return it == m_row_path_list_params.end() ? nullptr : &it->second;
+}
+
+template <typename CTX_T>
+void
+View<CTX_T>::_init_arrow_types() {
+    const auto& types = m_table->get_arrow_types();
+    if (types.empty()) {
+        return;
+    }
+
+    for (const auto& name : m_row_pivots) {
+        if (const auto it = types.find(name); it != types.end()) {
+            m_row_path_arrow_types.insert(*it);
+        }
+    }
+
+    if (sides() == 0) {
+        for (const auto& name : m_columns) {
+            if (const auto it = types.find(name); it != types.end()) {
+                m_arrow_types.insert(*it);
+            }
+        }
+
+        return;
+    }
+
+    for (const auto& agg : m_aggregates) {
+        const auto deps = agg.get_input_depnames();
+        if (deps.empty()) {
+            continue;
+        }
+
+        const auto it = types.find(deps[0]);
+        if (it == types.end()) {
+            continue;
+        }
+
+        switch (agg.agg()) {
+            case AGGTYPE_ANY:
+            case AGGTYPE_UNIQUE:
+            case AGGTYPE_DOMINANT:
+            case AGGTYPE_Q1:
+            case AGGTYPE_Q3:
+            case AGGTYPE_MEDIAN:
+            case AGGTYPE_FIRST:
+            case AGGTYPE_LAST_BY_INDEX:
+            case AGGTYPE_LAST_VALUE:
+            case AGGTYPE_MAX:
+            case AGGTYPE_MIN:
+            case AGGTYPE_MAX_BY:
+            case AGGTYPE_MIN_BY:
+            case AGGTYPE_HIGH_WATER_MARK:
+            case AGGTYPE_LOW_WATER_MARK:
+            case AGGTYPE_IDENTITY:
+            case AGGTYPE_DISTINCT_LEAF: {
+                m_arrow_types[agg.name()] = it->second;
+            } break;
+            default:
+                break;
+        }
+    }
+}
+
+template <typename CTX_T>
+const std::shared_ptr<arrow::DataType>*
+View<CTX_T>::get_arrow_type(const std::string& column) const {
+    const auto it = m_arrow_types.find(column);
+    return it == m_arrow_types.end() ? nullptr : &it->second;
+}
+
+template <typename CTX_T>
+const std::shared_ptr<arrow::DataType>*
+View<CTX_T>::get_row_path_arrow_type(t_uindex depth) const {
+    if (depth >= m_row_pivots.size()) {
+        return nullptr;
+    }
+
+    const auto it = m_row_path_arrow_types.find(m_row_pivots[depth]);
+    return it == m_row_path_arrow_types.end() ? nullptr : &it->second;
+}
+
+template <typename CTX_T>
+const t_decimal_map&
+View<CTX_T>::decimal_params() const {
+    return m_decimal_params;
+}
+
+template <typename CTX_T>
+const t_decimal_params*
+View<CTX_T>::get_decimal(const std::string& column) const {
+    const auto it = m_decimal_params.find(column);
+    return it == m_decimal_params.end() ? nullptr : &it->second;
+}
+
+template <typename CTX_T>
+const t_decimal_params*
+View<CTX_T>::get_row_path_decimal(t_uindex depth) const {
+    if (depth >= m_row_pivots.size()) {
+        return nullptr;
+    }
+
+    const auto it = m_row_path_decimal_params.find(m_row_pivots[depth]);
+    return it == m_row_path_decimal_params.end() ? nullptr : &it->second;
+}
+
 template <typename CTX_T>
 View<CTX_T>::~View() {
     auto pool = m_table->get_pool();
@@ -659,11 +1050,38 @@ View<t_ctx0>::get_data(
     return data_slice_ptr;
 }
 
+/**
+ * Abort if a `DTYPE_INT64` sum of `ctx`, e.g. of a `DECIMAL` column, has
+ * overflowed, rather than read it as null.
+ */
+template <typename CTX_T>
+static void
+check_sum_overflow(CTX_T& ctx, const t_decimal_map& decimals) {
+    for (const auto* tree : ctx.get_trees()) {
+        const auto name = tree->overflowed_sum();
+        if (!name.has_value()) {
+            continue;
+        }
+
+        std::stringstream ss;
+        ss << "Sum `" << *name << "` overflows ";
+        if (const auto it = decimals.find(*name); it != decimals.end()) {
+            ss << "DECIMAL(" << it->second.precision << ", "
+               << it->second.scale << ")";
+        } else {
+            ss << "a 64-bit integer";
+        }
+
+        PSP_COMPLAIN_AND_ABORT(ss.str());
+    }
+}
+
 template <>
 std::shared_ptr<t_data_slice<t_ctx1>>
 View<t_ctx1>::get_data(
     t_uindex start_row, t_uindex end_row, t_uindex start_col, t_uindex end_col
 ) const {
+    check_sum_overflow(*m_ctx, m_decimal_params);
     std::vector<t_tscalar> slice =
         m_ctx->get_data(start_row, end_row, start_col, end_col);
     auto col_names = column_names();
@@ -689,6 +1107,7 @@ std::shared_ptr<t_data_slice<t_ctx2>>
 View<t_ctx2>::get_data(
     t_uindex start_row, t_uindex end_row, t_uindex start_col, t_uindex end_col
 ) const {
+    check_sum_overflow(*m_ctx, m_decimal_params);
     std::vector<t_tscalar> slice;
     std::vector<t_uindex> column_indices;
     std::vector<std::vector<t_tscalar>> cols;
@@ -819,6 +1238,49 @@ View<t_ctx2>::get_data(
     return data_slice_ptr;
 }
 
+/**
+ * The Arrow type of a datetime column with `params`, which is milliseconds
+ * without a timezone by default.
+ */
+static std::shared_ptr<arrow::DataType>
+timestamp_arrow_type(const t_timestamp_params* params) {
+    if (params == nullptr) {
+        return arrow::timestamp(arrow::TimeUnit::MILLI);
+    }
+
+    return arrow::timestamp(to_arrow_time_unit(params->unit), params->timezone);
+}
+
+/**
+ * Cast a column to the source Arrow `type` it was read from, and mark `field`
+ * with `PRESERVED_ARROW_TYPE_KEY`. A column whose values do not survive the
+ * cast keeps its Perspective type instead, e.g. the `Float64` values of a
+ * `Decimal128(38, 10)` column which are not exact at that scale.
+ */
+static void
+cast_to_arrow_type(
+    std::shared_ptr<arrow::Field>& field,
+    std::shared_ptr<arrow::Array>& array,
+    const std::shared_ptr<arrow::DataType>& type
+) {
+    if (!array->type()->Equals(*type)) {
+        auto cast = arrow::compute::Cast(
+            *array, type, arrow::compute::CastOptions::Safe()
+        );
+
+        if (!cast.ok()) {
+            return;
+        }
+
+        array = *cast;
+    }
+
+    field = arrow::field(field->name(), type)
+                ->WithMetadata(arrow::key_value_metadata(
+                    {PRESERVED_ARROW_TYPE_KEY}, {type->ToString()}
+                ));
+}
+
 template <typename CTX_T>
 std::shared_ptr<std::string>
 View<CTX_T>::to_arrow(
@@ -900,7 +1362,8 @@ template <typename CTX_T>
 std::pair<std::shared_ptr<arrow::Schema>, std::shared_ptr<arrow::RecordBatch>>
 View<CTX_T>::data_slice_to_batches(
     bool emit_group_by, std::shared_ptr<t_data_slice<CTX_T>> data_slice,
-    bool emit_legacy_row_path_names
+    bool emit_legacy_row_path_names,
+    bool preserve_arrow_types
 ) const {
     // From the data slice, get all the metadata we need
     t_get_data_extents extents = data_slice->get_data_extents();
@@ -957,6 +1420,57 @@ View<CTX_T>::data_slice_to_batches(
                 }
             }
 
+            if (const auto* decimal = get_row_path_decimal(rpidx)) {
+                fields[write_idx] = arrow::field(
+                    row_path_name,
+                    arrow::decimal128(
+                        static_cast<std::int32_t>(decimal->precision),
+                        static_cast<std::int32_t>(decimal->scale)
+                    )
+                );
+
+                vectors[write_idx] = apachearrow::decimal_col_to_array(
+                    extents,
+                    *decimal,
+                    [&, rpidx](t_uindex ridx) {
+                        auto depth = m_ctx->unity_get_row_depth(ridx);
+                        if (rpidx < depth) {
+                            return m_ctx->unity_get_row_path(ridx).at(
+                                (depth - 1) - rpidx
+                            );
+                        }
+                        return mknone();
+                    }
+                );
+
+                write_idx++;
+                continue;
+            }
+
+            if (const auto* list = get_row_path_list(rpidx)) {
+                fields[write_idx] = arrow::field(
+                    row_path_name,
+                    arrow::list(list_element_arrow_type(list->element))
+                );
+
+                vectors[write_idx] = apachearrow::list_col_to_array(
+                    extents,
+                    *list,
+                    [&, rpidx](t_uindex ridx) {
+                        auto depth = m_ctx->unity_get_row_depth(ridx);
+                        if (rpidx < depth) {
+                            return m_ctx->unity_get_row_path(ridx).at(
+                                (depth - 1) - rpidx
+                            );
+                        }
+                        return mknone();
+                    }
+                );
+
+                write_idx++;
+                continue;
+            }
+
             std::shared_ptr<arrow::Array> arr;
             switch (dtype) {
                 case DTYPE_INT8: {
@@ -1126,9 +1640,11 @@ View<CTX_T>::data_slice_to_batches(
                     );
                 } break;
                 case DTYPE_TIME: {
-                    fields[write_idx] = arrow::field(
-                        row_path_name, arrow::timestamp(arrow::TimeUnit::MILLI)
+                    const auto type = timestamp_arrow_type(
+                        get_row_path_timestamp(rpidx)
                     );
+
+                    fields[write_idx] = arrow::field(row_path_name, type);
                     vectors[write_idx] = apachearrow::timestamp_col_to_array(
                         extents,
                         [&, rpidx](t_uindex ridx) {
@@ -1139,7 +1655,8 @@ View<CTX_T>::data_slice_to_batches(
                                 );
                             }
                             return mknone();
-                        }
+                        },
+                        type
                     );
                 } break;
                 case DTYPE_BOOL: {
@@ -1187,6 +1704,13 @@ View<CTX_T>::data_slice_to_batches(
                 }
             }
 
+            if (const auto* type = get_row_path_arrow_type(rpidx);
+                type != nullptr && preserve_arrow_types) {
+                cast_to_arrow_type(
+                    fields[write_idx], vectors[write_idx], *type
+                );
+            }
+
             write_idx++;
         }
     }
@@ -1263,6 +1787,51 @@ View<CTX_T>::data_slice_to_batches(
             name = col_path.at(col_path.size() - 1).to_string();
         }
 
+        if (const auto* decimal =
+                get_decimal(col_path.at(col_path.size() - 1).to_string())) {
+            fields[ccidx] = arrow::field(
+                name,
+                arrow::decimal128(
+                    static_cast<std::int32_t>(decimal->precision),
+                    static_cast<std::int32_t>(decimal->scale)
+                )
+            );
+
+            vectors[ccidx] = apachearrow::decimal_col_to_array(
+                extents,
+                *decimal,
+                [&](t_uindex ridx) {
+                    return slice
+                        [(ridx - extents.m_srow) * stride
+                         + (cidx - extents.m_scol)];
+                }
+            );
+
+            return;
+        }
+
+        if (const auto* list =
+                get_list(col_path.at(col_path.size() - 1).to_string())) {
+            fields[ccidx] = arrow::field(
+                name, arrow::list(list_element_arrow_type(list->element))
+            );
+
+            vectors[ccidx] = apachearrow::list_col_to_array(
+                extents,
+                *list,
+                [&](t_uindex ridx) {
+                    return slice
+                        [(ridx - extents.m_srow) * stride
+                         + (cidx - extents.m_scol)];
+                }
+            );
+
+            return;
+        }
+
+        const auto* timestamp =
+            get_timestamp(col_path.at(col_path.size() - 1).to_string());
+
         std::shared_ptr<arrow::Array> arr;
         switch (dtype) {
             case DTYPE_INT8: {
@@ -1362,9 +1931,19 @@ View<CTX_T>::data_slice_to_batches(
                 vectors[ccidx] = apachearrow::numeric_col_to_array<
                     arrow::DoubleType,
                     double>(extents, [&](t_uindex ridx) {
-                    return slice
+                    auto scalar = slice
                         [(ridx - extents.m_srow) * stride
                          + (cidx - extents.m_scol)];
+
+                    // The `"mean"` of a datetime column is in its unit.
+                    if (timestamp != nullptr && scalar.is_valid()
+                        && scalar.get_dtype() == DTYPE_FLOAT64) {
+                        scalar.set(timestamp_to_millis(
+                            scalar.get<double>(), timestamp->unit
+                        ));
+                    }
+
+                    return scalar;
                 });
             } break;
             case DTYPE_DATE: {
@@ -1377,16 +1956,16 @@ View<CTX_T>::data_slice_to_batches(
                     });
             } break;
             case DTYPE_TIME: {
-                fields[ccidx] = arrow::field(
-                    name, arrow::timestamp(arrow::TimeUnit::MILLI)
-                );
+                const auto type = timestamp_arrow_type(timestamp);
+                fields[ccidx] = arrow::field(name, type);
                 vectors[ccidx] = apachearrow::timestamp_col_to_array(
                     extents,
                     [&](t_uindex ridx) {
                         return slice
                             [(ridx - extents.m_srow) * stride
                              + (cidx - extents.m_scol)];
-                    }
+                    },
+                    type
                 );
             } break;
             case DTYPE_BOOL: {
@@ -1422,6 +2001,12 @@ View<CTX_T>::data_slice_to_batches(
                 PSP_COMPLAIN_AND_ABORT(ss.str());
             }
         }
+
+        if (const auto* type =
+                get_arrow_type(col_path.at(col_path.size() - 1).to_string());
+            type != nullptr && preserve_arrow_types) {
+            cast_to_arrow_type(fields[ccidx], vectors[ccidx], *type);
+        }
     });
     // }
 
@@ -1453,7 +2038,9 @@ View<CTX_T>::data_slice_to_arrow(
     std::pair<
         std::shared_ptr<arrow::Schema>,
         std::shared_ptr<arrow::RecordBatch>>
-        pairs = data_slice_to_batches(emit_group_by, data_slice, emit_legacy_row_path_names);
+        pairs = data_slice_to_batches(
+            emit_group_by, data_slice, emit_legacy_row_path_names, true
+        );
     std::shared_ptr<arrow::RecordBatch> batches = pairs.second;
     std::shared_ptr<arrow::Schema> arrow_schema = pairs.first;
     arrow::Result<std::shared_ptr<arrow::ResizableBuffer>> allocated =
@@ -1488,6 +2075,48 @@ View<CTX_T>::data_slice_to_arrow(
     return std::make_shared<std::string>(buffer->ToString());
 }
 
+/**
+ * The CSV writer cannot write Arrow lists, so `LIST` columns are written as
+ * their JSON text, which is also what a CSV `LIST` column is read from.
+ */
+static std::shared_ptr<arrow::RecordBatch>
+list_columns_to_text(std::shared_ptr<arrow::RecordBatch> batch) {
+    for (int i = 0; i < batch->num_columns(); ++i) {
+        const auto field = batch->schema()->field(i);
+        if (field->type()->id() != arrow::Type::LIST) {
+            continue;
+        }
+
+        const auto& list =
+            static_cast<const arrow::ListArray&>(*batch->column(i));
+        const t_list_params params{
+            list_element_dtype(*list.value_type()).value_or(DTYPE_STR)
+        };
+
+        arrow::StringBuilder builder;
+        for (std::int64_t r = 0; r < list.length(); ++r) {
+            const auto text = list.IsNull(r)
+                ? std::nullopt
+                : encode_arrow_list(*list.value_slice(r), params);
+
+            PSP_CHECK_ARROW_STATUS(
+                text.has_value() ? builder.Append(*text) : builder.AppendNull()
+            );
+        }
+
+        std::shared_ptr<arrow::Array> array;
+        PSP_CHECK_ARROW_STATUS(builder.Finish(&array));
+        auto replaced = batch->SetColumn(
+            i, arrow::field(field->name(), arrow::utf8()), array
+        );
+
+        PSP_CHECK_ARROW_STATUS(replaced.status());
+        batch = *replaced;
+    }
+
+    return batch;
+}
+
 template <typename CTX_T>
 std::shared_ptr<std::string>
 View<CTX_T>::data_slice_to_csv(std::shared_ptr<t_data_slice<CTX_T>> data_slice
@@ -1496,8 +2125,9 @@ View<CTX_T>::data_slice_to_csv(std::shared_ptr<t_data_slice<CTX_T>> data_slice
         std::shared_ptr<arrow::Schema>,
         std::shared_ptr<arrow::RecordBatch>>
         pairs = data_slice_to_batches(true, data_slice);
-    std::shared_ptr<arrow::RecordBatch> batches = pairs.second;
-    std::shared_ptr<arrow::Schema> arrow_schema = pairs.first;
+    std::shared_ptr<arrow::RecordBatch> batches =
+        list_columns_to_text(pairs.second);
+    std::shared_ptr<arrow::Schema> arrow_schema = batches->schema();
     arrow::Result<std::shared_ptr<arrow::ResizableBuffer>> allocated =
         arrow::AllocateResizableBuffer(0);
     if (!allocated.ok()) {
@@ -1808,7 +2438,10 @@ void
 write_scalar(
     t_tscalar scalar,
     bool is_formatted,
-    rapidjson::Writer<rapidjson::StringBuffer>& writer
+    rapidjson::Writer<rapidjson::StringBuffer>& writer,
+    const t_decimal_params* decimal,
+    const t_timestamp_params* timestamp,
+    const t_list_params* list
 ) {
 
     if (!scalar.is_valid()) {
@@ -1816,6 +2449,40 @@ write_scalar(
         return;
     }
 
+    // JSON datetimes are always epoch milliseconds, fractional when the
+    // column's unit is finer.
+    if (timestamp != nullptr && timestamp->unit != TIMESTAMP_UNIT_MILLISECOND
+        && (scalar.get_dtype() == DTYPE_TIME
+            || (scalar.get_dtype() == DTYPE_FLOAT64 && !scalar.is_nan()))) {
+        const double millis =
+            timestamp_to_millis(scalar.to_double(), timestamp->unit);
+        if (is_formatted) {
+            const auto floored = static_cast<std::int64_t>(std::floor(millis));
+            writer.String(mktscalar(t_time(floored)).to_string().c_str());
+        } else {
+            writer.Double(millis);
+        }
+
+        return;
+    }
+
+    // JSON numbers are binary floats, so exact decimals travel as strings.
+    if (decimal != nullptr && scalar.get_dtype() != DTYPE_NONE
+        && !scalar.is_nan()) {
+        writer.String(
+            format_decimal(unscaled_decimal(scalar), decimal->scale).c_str()
+        );
+
+        return;
+    }
+
+    // `LIST` values are stored as canonical JSON arrays, so are written as-is.
+    if (list != nullptr && scalar.get_dtype() == DTYPE_STR && !is_formatted) {
+        const char* text = scalar.get<const char*>();
+        writer.RawValue(text, std::strlen(text), rapidjson::kArrayType);
+        return;
+    }
+
     switch (scalar.get_dtype()) {
         case DTYPE_NONE:
             writer.Null();
@@ -1898,7 +2565,14 @@ View<CTX_T>::write_row_path(
             // Question: Why are the row paths reversed?
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -1937,10 +2611,14 @@ View<CTX_T>::write_column(
     writer.Key(col_path_to_legacy(col_names.at(c)).c_str());
     writer.StartArray();
 
+    const auto& name = col_names.at(c).back().to_string();
+    const auto* decimal = get_decimal(name);
+    const auto* timestamp = get_timestamp(name);
+    const auto* list = get_list(name);
     for (auto r = start_row; r < end_row; ++r) {
         auto scalar = slice->get(r, c);
 
-        write_scalar(scalar, is_formatted, writer);
+        write_scalar(scalar, is_formatted, writer, decimal, timestamp, list);
     }
 
     writer.EndArray();
@@ -2007,10 +2685,19 @@ View<T>::to_rows(
     }
 
     std::vector<std::string> column_names;
+    std::vector<const t_decimal_params*> column_decimals;
+    std::vector<const t_timestamp_params*> column_timestamps;
+    std::vector<const t_list_params*> column_lists;
     for (auto c = start_col; c < end_col; ++c) {
         column_names.emplace_back(
             col_names[c][col_names[c].size() - 1].template get<const char*>()
         );
+
+        column_decimals.push_back(get_decimal(column_names.back()));
+
+        column_timestamps.push_back(get_timestamp(column_names.back()));
+
+        column_lists.push_back(get_list(column_names.back()));
     }
 
     // These columns don't exist as far as the view/table is concerned. They're
@@ -2052,7 +2739,14 @@ View<T>::to_rows(
             for (auto c = start_col; c < end_col; ++c) {
                 writer.Key(column_names[c - start_col].c_str());
                 auto scalar = slice->get(r, c);
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    column_decimals[c - start_col],
+                    column_timestamps[c - start_col],
+                    column_lists[c - start_col]
+                );
             }
 
             writer.EndObject();
@@ -2094,6 +2788,9 @@ View<t_ctx1>::to_rows(
     }
 
     std::vector<std::string> column_names;
+    std::vector<const t_decimal_params*> column_decimals;
+    std::vector<const t_timestamp_params*> column_timestamps;
+    std::vector<const t_list_params*> column_lists;
     for (auto c = start_col + 1; c < end_col; ++c) {
         if (c > columns_length) {
             continue;
@@ -2102,6 +2799,12 @@ View<t_ctx1>::to_rows(
         column_names.emplace_back(
             col_names[c][col_names[c].size() - 1].template get<const char*>()
         );
+
+        column_decimals.push_back(get_decimal(column_names.back()));
+
+        column_timestamps.push_back(get_timestamp(column_names.back()));
+
+        column_lists.push_back(get_list(column_names.back()));
     }
 
     for (auto r = start_row; r < end_row; ++r) {
@@ -2114,7 +2817,14 @@ View<t_ctx1>::to_rows(
         const auto row_path = get_row_path(r);
         for (auto entry = row_path.size(); entry > 0; entry--) {
             const t_tscalar& scalar = row_path[entry - 1];
-            write_scalar(scalar, is_formatted, writer);
+            write_scalar(
+                scalar,
+                is_formatted,
+                writer,
+                get_row_path_decimal(row_path.size() - entry),
+                get_row_path_timestamp(row_path.size() - entry),
+                get_row_path_list(row_path.size() - entry)
+            );
         }
 
         writer.EndArray();
@@ -2124,7 +2834,14 @@ View<t_ctx1>::to_rows(
             writer.StartArray();
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -2150,7 +2867,14 @@ View<t_ctx1>::to_rows(
 
             writer.Key(column_names[c - (start_col + 1)].c_str());
             auto scalar = slice->get(r, c);
-            write_scalar(scalar, is_formatted, writer);
+            write_scalar(
+                scalar,
+                is_formatted,
+                writer,
+                column_decimals[c - (start_col + 1)],
+                column_timestamps[c - (start_col + 1)],
+                column_lists[c - (start_col + 1)]
+            );
         }
 
         writer.EndObject();
@@ -2191,8 +2915,15 @@ View<t_ctx2>::to_rows(
     }
 
     std::vector<std::string> column_names;
+    std::vector<const t_decimal_params*> column_decimals;
+    std::vector<const t_timestamp_params*> column_timestamps;
+    std::vector<const t_list_params*> column_lists;
     for (auto c = start_col + 1; c < end_col; ++c) {
         column_names.emplace_back(col_path_to_legacy(col_names.at(c)));
+        const auto& name = col_names.at(c).back().to_string();
+        column_decimals.push_back(get_decimal(name));
+        column_timestamps.push_back(get_timestamp(name));
+        column_lists.push_back(get_list(name));
     }
 
     bool column_only = is_column_only();
@@ -2207,7 +2938,14 @@ View<t_ctx2>::to_rows(
             writer.StartArray();
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -2218,7 +2956,14 @@ View<t_ctx2>::to_rows(
             writer.StartArray();
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -2247,7 +2992,14 @@ View<t_ctx2>::to_rows(
             }
             writer.Key(column_names[c - (start_col + 1)].c_str());
             auto scalar = slice->get(r, c);
-            write_scalar(scalar, is_formatted, writer);
+            write_scalar(
+                scalar,
+                is_formatted,
+                writer,
+                column_decimals[c - (start_col + 1)],
+                column_timestamps[c - (start_col + 1)],
+                column_lists[c - (start_col + 1)]
+            );
         }
 
         writer.EndObject();
@@ -2334,10 +3086,19 @@ View<T>::to_ndjson(
     }
 
     std::vector<std::string> column_names;
+    std::vector<const t_decimal_params*> column_decimals;
+    std::vector<const t_timestamp_params*> column_timestamps;
+    std::vector<const t_list_params*> column_lists;
     for (auto c = start_col; c < end_col; ++c) {
         column_names.emplace_back(
             col_names[c][col_names[c].size() - 1].template get<const char*>()
         );
+
+        column_decimals.push_back(get_decimal(column_names.back()));
+
+        column_timestamps.push_back(get_timestamp(column_names.back()));
+
+        column_lists.push_back(get_list(column_names.back()));
     }
 
     // These columns don't exist as far as the view/table is concerned. They're
@@ -2386,7 +3147,14 @@ View<T>::to_ndjson(
             for (auto c = start_col; c < end_col; ++c) {
                 writer.Key(column_names[c - start_col].c_str());
                 auto scalar = slice->get(r, c);
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    column_decimals[c - start_col],
+                    column_timestamps[c - start_col],
+                    column_lists[c - start_col]
+                );
             }
 
             writer.EndObject();
@@ -2423,6 +3191,9 @@ View<t_ctx1>::to_ndjson(
     }
 
     std::vector<std::string> column_names;
+    std::vector<const t_decimal_params*> column_decimals;
+    std::vector<const t_timestamp_params*> column_timestamps;
+    std::vector<const t_list_params*> column_lists;
     for (auto c = start_col + 1; c < end_col; ++c) {
         if (c > columns_length) {
             continue;
@@ -2431,6 +3202,12 @@ View<t_ctx1>::to_ndjson(
         column_names.emplace_back(
             col_names[c][col_names[c].size() - 1].template get<const char*>()
         );
+
+        column_decimals.push_back(get_decimal(column_names.back()));
+
+        column_timestamps.push_back(get_timestamp(column_names.back()));
+
+        column_lists.push_back(get_list(column_names.back()));
     }
 
     std::stringstream ndjson;
@@ -2451,7 +3228,14 @@ View<t_ctx1>::to_ndjson(
         const auto row_path = get_row_path(r);
         for (auto entry = row_path.size(); entry > 0; entry--) {
             const t_tscalar& scalar = row_path[entry - 1];
-            write_scalar(scalar, is_formatted, writer);
+            write_scalar(
+                scalar,
+                is_formatted,
+                writer,
+                get_row_path_decimal(row_path.size() - entry),
+                get_row_path_timestamp(row_path.size() - entry),
+                get_row_path_list(row_path.size() - entry)
+            );
         }
 
         writer.EndArray();
@@ -2461,7 +3245,14 @@ View<t_ctx1>::to_ndjson(
             writer.StartArray();
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -2487,7 +3278,14 @@ View<t_ctx1>::to_ndjson(
 
             writer.Key(column_names[c - (start_col + 1)].c_str());
             auto scalar = slice->get(r, c);
-            write_scalar(scalar, is_formatted, writer);
+            write_scalar(
+                scalar,
+                is_formatted,
+                writer,
+                column_decimals[c - (start_col + 1)],
+                column_timestamps[c - (start_col + 1)],
+                column_lists[c - (start_col + 1)]
+            );
         }
 
         writer.EndObject();
@@ -2524,8 +3322,15 @@ View<t_ctx2>::to_ndjson(
     }
 
     std::vector<std::string> column_names;
+    std::vector<const t_decimal_params*> column_decimals;
+    std::vector<const t_timestamp_params*> column_timestamps;
+    std::vector<const t_list_params*> column_lists;
     for (auto c = start_col + 1; c < end_col; ++c) {
         column_names.emplace_back(col_path_to_legacy(col_names.at(c)));
+        const auto& name = col_names.at(c).back().to_string();
+        column_decimals.push_back(get_decimal(name));
+        column_timestamps.push_back(get_timestamp(name));
+        column_lists.push_back(get_list(name));
     }
 
     bool column_only = is_column_only();
@@ -2548,7 +3353,14 @@ View<t_ctx2>::to_ndjson(
             writer.StartArray();
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -2559,7 +3371,14 @@ View<t_ctx2>::to_ndjson(
             writer.StartArray();
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -2588,7 +3407,14 @@ View<t_ctx2>::to_ndjson(
             }
             writer.Key(column_names[c - (start_col + 1)].c_str());
             auto scalar = slice->get(r, c);
-            write_scalar(scalar, is_formatted, writer);
+            write_scalar(
+                scalar,
+                is_formatted,
+                writer,
+                column_decimals[c - (start_col + 1)],
+                column_timestamps[c - (start_col + 1)],
+                column_lists[c - (start_col + 1)]
+            );
         }
 
         writer.EndObject();
@@ -2697,7 +3523,14 @@ View<t_ctx1>::to_columns(
             const auto row_path = m_ctx->get_row_path(r);
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -2770,7 +3603,14 @@ View<t_ctx2>::to_columns(
             const auto row_path = m_ctx->get_row_path(r);
             for (auto entry = row_path.size(); entry > 0; entry--) {
                 const t_tscalar& scalar = row_path[entry - 1];
-                write_scalar(scalar, is_formatted, writer);
+                write_scalar(
+                    scalar,
+                    is_formatted,
+                    writer,
+                    get_row_path_decimal(row_path.size() - entry),
+                    get_row_path_timestamp(row_path.size() - entry),
+                    get_row_path_list(row_path.size() - entry)
+                );
             }
 
             writer.EndArray();
@@ -20,6 +20,7 @@
 #include <perspective/exports.h>
 #include <algorithm>
 #include <functional>
+#include <limits>
 #include <numeric>
 #include <type_traits>
 
@@ -67,6 +68,50 @@ public:
     }
 };
 
+/**
+ * @brief A sum of `std::int64_t` values, e.g. of a `DECIMAL` column, which
+ * sticks at the `std::int64_t` limit it overflows past, so that `t_stree`
+ * recomputes that sum exactly rather than add a wrapped delta.
+ */
+class PERSPECTIVE_EXPORT t_aggimpl_checked_sum
+    : public t_aggimpl<std::int64_t, std::int64_t, std::int64_t> {
+public:
+    static constexpr std::int64_t MAX =
+        std::numeric_limits<std::int64_t>::max();
+    static constexpr std::int64_t MIN =
+        std::numeric_limits<std::int64_t>::min();
+
+    std::int64_t
+    value(std::int64_t rs) { return rs; }
+
+    std::int64_t
+    reduce(const std::int64_t* biter, const std::int64_t* eiter) {
+        return roll_up(biter, eiter);
+    }
+
+    std::int64_t
+    roll_up(const std::int64_t* biter, const std::int64_t* eiter) {
+        std::int64_t value = 0;
+        for (const auto* it = biter; it != eiter; ++it) {
+            if (*it == MAX || *it == MIN) {
+                return *it;
+            }
+
+            if (*it > 0 && value > MAX - *it) {
+                return MAX;
+            }
+
+            if (*it < 0 && value < MIN - *it) {
+                return MIN;
+            }
+
+            value += *it;
+        }
+
+        return value;
+    }
+};
+
 template <typename RAW_DATA_T, typename ROLLING_T, typename RESULT_T>
 class PERSPECTIVE_EXPORT t_aggimpl_mul
     : public t_aggimpl<RAW_DATA_T, ROLLING_T, RESULT_T> {
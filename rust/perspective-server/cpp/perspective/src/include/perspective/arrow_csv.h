@@ -11,25 +11,82 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 #pragma once
+#include <perspective/base.h>
+#include <map>
+#include <optional>
+#include <string>
 #include <unordered_map>
+#include <vector>
 #include <arrow/io/memory.h>
 #include <arrow/table.h>
 
 namespace perspective {
 namespace apachearrow {
 
+    /**
+     * @brief How a CSV is parsed. The defaults are Arrow's, with the first row
+     * naming the columns and every type inferred.
+     */
+    struct t_csv_options {
+        char delimiter = ',';
+        char quote = '"';
+
+        /**
+         * The character which escapes a quote or delimiter, or none to only
+         * allow quotes escaped by doubling.
+         */
+        std::optional<char> escape;
+
+        /**
+         * The names of the columns of a CSV without a header row, whose first
+         * row is then read as data. Empty if the CSV has a header row.
+         */
+        std::vector<std::string> column_names;
+
+        /**
+         * Values read as null in a column of any type, replacing Arrow's
+         * defaults (e.g. `""`, `NA`, `null`) when non-empty.
+         */
+        std::vector<std::string> null_values;
+
+        /**
+         * The type of each named column, overriding inference when a `Table`
+         * is created. Updates are always read as the `Table`'s own schema.
+         */
+        std::map<std::string, t_dtype> column_types;
+
+        /**
+         * `strptime` formats tried before the built-in ones, for `DTYPE_DATE`
+         * and `DTYPE_TIME` columns respectively.
+         */
+        std::optional<std::string> date_format;
+        std::optional<std::string> datetime_format;
+    };
+
     std::optional<int64_t> parseAsArrowTimestamp(const std::string& input);
 
+    /**
+     * @brief Like `parseAsArrowTimestamp`, but returns an epoch offset in
+     * `unit` rather than milliseconds.
+     */
+    std::optional<int64_t> parseAsArrowTimestamp(
+        const std::string& input, arrow::TimeUnit::type unit
+    );
+
     /**
      * @brief Initialize the arrow loader with a CSV.
      *
      * @param ptr
+     * @param schema - the Arrow type of each column whose type is known,
+     * which on update is every column of the `Table`.
+     * @param options
      */
     std::shared_ptr<::arrow::Table> csvToTable(
         const std::string_view& csv,
         bool is_update,
         std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>&
-            schema
+            schema,
+        const t_csv_options& options = t_csv_options{}
     );
 
 } // namespace apachearrow
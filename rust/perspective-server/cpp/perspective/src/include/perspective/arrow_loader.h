@@ -16,6 +16,10 @@
 #include <perspective/date.h>
 #include <perspective/exports.h>
 #include <perspective/data_table.h>
+#include <perspective/decimal.h>
+#include <perspective/list.h>
+#include <perspective/timestamp.h>
+#include <perspective/arrow_types.h>
 #include <perspective/last.h>
 #include <chrono>
 #include <optional>
@@ -26,6 +30,7 @@
 #include <arrow/ipc/reader.h>
 #include <perspective/arrow_csv.h>
 #include <perspective/arrow_normalize.h>
+#include <perspective/schema_hints.h>
 
 namespace perspective {
 namespace apachearrow {
@@ -45,11 +50,13 @@ namespace apachearrow {
          *
          * @param ptr
          * @param mode - how `LIST` columns are ingested.
+         * @param structs - how `STRUCT` columns are hoisted.
          */
         void initialize(
             const std::uint8_t* ptr,
             std::uint32_t,
-            t_list_flatten mode = LIST_FLATTEN_ZIP
+            t_list_flatten mode = LIST_FLATTEN_ZIP,
+            const t_struct_flatten& structs = t_struct_flatten{}
         );
 
         /**
@@ -75,7 +82,8 @@ namespace apachearrow {
             const std::string_view& csv,
             bool is_update,
             std::unordered_map<std::string, std::shared_ptr<arrow::DataType>>&
-                schema
+                schema,
+            const t_csv_options& options = t_csv_options{}
         );
 
         /**
@@ -101,7 +109,93 @@ namespace apachearrow {
         std::vector<t_dtype> types() const;
         std::uint32_t row_count() const;
 
+        /**
+         * @brief Load `Decimal128` columns whose precision fits
+         * `DECIMAL_MAX_PRECISION` as `DECIMAL` columns, rather than as
+         * `DTYPE_FLOAT64`. Must be set before `initialize`.
+         *
+         * @param exact_decimals
+         */
+        void set_exact_decimals(bool exact_decimals);
+
+        /**
+         * @brief The `DECIMAL` columns of this input, i.e. its `Decimal128`
+         * columns whose precision fits `DECIMAL_MAX_PRECISION` when
+         * `set_exact_decimals` is set, which `types()` reports as their
+         * unscaled `DTYPE_INT64`. Any other decimal is loaded as
+         * `DTYPE_FLOAT64` and does not appear here.
+         */
+        const t_decimal_map& decimal_params() const;
+
+        /**
+         * @brief Fill the `DECIMAL` columns of an existing `Table`, whose
+         * values are rescaled from whatever numeric or string type this input
+         * provides for them.
+         *
+         * @param params
+         */
+        void set_decimal_params(const t_decimal_map& params);
+
+        /**
+         * @brief The `Timestamp` columns of this input with a unit other than
+         * milliseconds or with a timezone, which are loaded in their own unit.
+         * Inferred CSV timestamps are always milliseconds without a timezone.
+         */
+        const t_timestamp_map& timestamp_params() const;
+
+        /**
+         * @brief Fill the non-millisecond `DTYPE_TIME` columns of an existing
+         * `Table`, converting input timestamps of any unit to the column's.
+         *
+         * @param params
+         */
+        void set_timestamp_params(const t_timestamp_map& params);
+
+        /**
+         * @brief The `LIST` columns of this input, i.e. its `LIST` and
+         * `LARGE_LIST` columns of a supported element type when initialized
+         * with `LIST_FLATTEN_PRESERVE`, which `types()` reports as
+         * `DTYPE_STR`.
+         */
+        const t_list_map& list_params() const;
+
+        /**
+         * @brief Fill the `LIST` columns of an existing `Table`, whose values
+         * are encoded from either Arrow lists or strings of JSON arrays.
+         *
+         * @param params
+         */
+        void set_list_params(const t_list_map& params);
+
+        /**
+         * @brief The Arrow type of each input column which `types()` reports
+         * as Perspective's own type, i.e. every column without a decimal,
+         * timestamp or list entry above, for `preserve_arrow_types`.
+         */
+        const t_arrow_type_map& arrow_types() const;
+
+        /**
+         * @brief Pin the type of these columns, whose values are coerced
+         * value by value, rather than loaded as their inferred or Arrow type.
+         * Must be set before `initialize` or `init_csv`.
+         *
+         * @param hints
+         */
+        void set_schema_hints(const t_schema_hints& hints);
+
+        /**
+         * @brief The values of hinted columns which could not be coerced, and
+         * were filled as null.
+         */
+        const t_row_errors& row_errors() const;
+
     private:
+        /**
+         * @brief Convert each hinted column to the Arrow type of its hint,
+         * recording the values which can't be converted in `m_row_errors`.
+         */
+        void apply_schema_hints();
+
         /**
          * @brief The post-normalization fields, which for a nested input differ
          * from `m_table`'s.
@@ -118,6 +212,16 @@ namespace apachearrow {
             bool is_update
         );
 
+        /**
+         * @brief Record the type of an input field, which is `DTYPE_INT64`
+         * and a `m_decimal_params` entry for an exact `Decimal128` under
+         * `m_exact_decimals`, and a `m_timestamp_params` entry for a
+         * `Timestamp` which is not plain milliseconds, and a `m_list_params`
+         * entry for a preserved list.
+         * Any other field has its Arrow type recorded in `m_arrow_types`.
+         */
+        void push_field(const std::shared_ptr<arrow::Field>& field);
+
         std::shared_ptr<arrow::Table> m_table;
 
         /**
@@ -130,6 +234,14 @@ namespace apachearrow {
         std::unique_ptr<t_normalized_table> m_normalized;
         std::vector<std::string> m_names;
         std::vector<t_dtype> m_types;
+        t_decimal_map m_decimal_params;
+        t_timestamp_map m_timestamp_params;
+        t_list_map m_list_params;
+        t_arrow_type_map m_arrow_types;
+        t_schema_hints m_schema_hints;
+        t_row_errors m_row_errors;
+        t_list_flatten m_mode{LIST_FLATTEN_ZIP};
+        bool m_exact_decimals{false};
         bool m_expanded{false};
     };
 
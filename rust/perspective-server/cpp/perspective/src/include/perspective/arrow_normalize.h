@@ -25,9 +25,11 @@ namespace apachearrow {
 
     using perspective::t_list_flatten;
     using perspective::LIST_FLATTEN_CARTESIAN;
+    using perspective::LIST_FLATTEN_PRESERVE;
     using perspective::LIST_FLATTEN_STRINGIFY;
     using perspective::LIST_FLATTEN_ZIP;
-    extern const char* const FLATTEN_SEPARATOR;
+    using perspective::list_flatten_expands;
+    using perspective::t_struct_flatten;
 
     /**
      * @brief A table rewritten into Perspective's flat column model, plus the
@@ -53,14 +55,19 @@ namespace apachearrow {
     };
 
     PERSPECTIVE_EXPORT t_normalized_table normalize_table(
-        std::shared_ptr<arrow::Table> input, t_list_flatten mode
+        std::shared_ptr<arrow::Table> input,
+        t_list_flatten mode,
+        const t_struct_flatten& structs
     );
 
     PERSPECTIVE_EXPORT bool
     normalize_table_is_noop(const arrow::Table& input, t_list_flatten mode);
 
-    PERSPECTIVE_EXPORT bool
-    normalize_table_expands(const arrow::Table& input, t_list_flatten mode);
+    PERSPECTIVE_EXPORT bool normalize_table_expands(
+        const arrow::Table& input,
+        t_list_flatten mode,
+        const t_struct_flatten& structs
+    );
 
 } // namespace apachearrow
 } // namespace perspective
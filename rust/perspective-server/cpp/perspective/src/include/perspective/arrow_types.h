@@ -0,0 +1,43 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+#include <perspective/first.h>
+#include <perspective/base.h>
+#include <perspective/last.h>
+#include <arrow/type_fwd.h>
+#include <map>
+#include <memory>
+#include <string>
+
+namespace perspective {
+
+/**
+ * @brief The Arrow type each column of a `Table` created with
+ * `preserve_arrow_types` was read from, by column name.
+ *
+ * Columns are stored in Perspective's own types regardless, e.g. `LargeUtf8`
+ * and `Utf8View` as `DTYPE_STR`, and are only cast back to their source type
+ * when serialized by `View::to_arrow`.
+ */
+using t_arrow_type_map =
+    std::map<std::string, std::shared_ptr<arrow::DataType>>;
+
+/**
+ * @brief The field metadata key of a `View::to_arrow` column which was cast
+ * back to its source type, whose value is that type's `ToString()`. A client
+ * which canonicalizes Arrow types must leave these columns as they are.
+ */
+static constexpr const char* PRESERVED_ARROW_TYPE_KEY =
+    "perspective.preserved_type";
+
+} // namespace perspective
@@ -17,6 +17,8 @@
 #include <perspective/exports.h>
 #include <perspective/scalar.h>
 #include <perspective/data_table.h>
+#include <perspective/decimal.h>
+#include <perspective/list.h>
 #include <perspective/get_data_extents.h>
 #include <perspective/last.h>
 
@@ -317,10 +319,14 @@ namespace apachearrow {
 
     template <typename F>
     std::shared_ptr<arrow::Array>
-    timestamp_col_to_array(t_get_data_extents extents, F f) {
-        // TimestampType requires parameters, so initialize them here
-        std::shared_ptr<arrow::DataType> type =
-            arrow::timestamp(arrow::TimeUnit::MILLI);
+    timestamp_col_to_array(
+        t_get_data_extents extents,
+        F f,
+        // TimestampType requires parameters; the scalars are already in
+        // `type`'s unit.
+        const std::shared_ptr<arrow::DataType>& type =
+            arrow::timestamp(arrow::TimeUnit::MILLI)
+    ) {
         arrow::TimestampBuilder array_builder(
             type, arrow::default_memory_pool()
         );
@@ -352,5 +358,97 @@ namespace apachearrow {
         return array;
     }
 
+    /**
+     * @brief Build an `arrow::Decimal128Array` from a `DECIMAL` column, whose
+     * scalars are unscaled values at `params.scale`.
+     *
+     * @param extents
+     * @param params
+     * @param f
+     * @return std::shared_ptr<arrow::Array>
+     */
+    template <typename F>
+    std::shared_ptr<arrow::Array> decimal_col_to_array(
+        t_get_data_extents extents, const t_decimal_params& params, F f
+    ) {
+        arrow::Decimal128Builder array_builder(
+            arrow::decimal128(
+                static_cast<std::int32_t>(params.precision),
+                static_cast<std::int32_t>(params.scale)
+            ),
+            arrow::default_memory_pool()
+        );
+
+        auto reserve_status =
+            array_builder.Reserve(extents.m_erow - extents.m_srow);
+        if (!reserve_status.ok()) {
+            std::stringstream ss;
+            ss << "Failed to allocate buffer for column: "
+               << reserve_status.message() << "\n";
+            PSP_COMPLAIN_AND_ABORT(ss.str());
+        }
+
+        for (int ridx = extents.m_srow; ridx < extents.m_erow; ++ridx) {
+            t_tscalar scalar = f(ridx);
+            if (scalar.is_valid() && scalar.get_dtype() != DTYPE_NONE
+                && !scalar.is_nan()) {
+                array_builder.UnsafeAppend(
+                    arrow::Decimal128(unscaled_decimal(scalar))
+                );
+            } else {
+                array_builder.UnsafeAppendNull();
+            }
+        }
+
+        std::shared_ptr<arrow::Array> array;
+        arrow::Status status = array_builder.Finish(&array);
+        if (!status.ok()) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Could not serialize decimal column: " + status.message()
+            );
+        }
+        return array;
+    }
+
+    /**
+     * @brief Build an `arrow::ListArray` from a `LIST` column, whose scalars
+     * are the encoded text of each list.
+     *
+     * @param extents
+     * @param params
+     * @param f
+     * @return std::shared_ptr<arrow::Array>
+     */
+    template <typename F>
+    std::shared_ptr<arrow::Array> list_col_to_array(
+        t_get_data_extents extents, const t_list_params& params, F f
+    ) {
+        auto array_builder = make_list_builder(params);
+        for (int ridx = extents.m_srow; ridx < extents.m_erow; ++ridx) {
+            t_tscalar scalar = f(ridx);
+            const auto status =
+                scalar.is_valid() && scalar.get_dtype() == DTYPE_STR
+                ? append_list(
+                      *array_builder, scalar.get<const char*>(), params
+                  )
+                : array_builder->AppendNull();
+
+            if (!status.ok()) {
+                PSP_COMPLAIN_AND_ABORT(
+                    "Could not serialize list column: " + status.message()
+                );
+            }
+        }
+
+        std::shared_ptr<arrow::Array> array;
+        arrow::Status status = array_builder->Finish(&array);
+        if (!status.ok()) {
+            PSP_COMPLAIN_AND_ABORT(
+                "Could not serialize list column: " + status.message()
+            );
+        }
+        return array;
+    }
+
 } // namespace apachearrow
 } // namespace perspective
\ No newline at end of file
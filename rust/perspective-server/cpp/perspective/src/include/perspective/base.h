@@ -300,7 +300,8 @@ enum t_aggtype {
     AGGTYPE_PCT_SUM_GRAND_TOTAL,
     AGGTYPE_VARIANCE,
     AGGTYPE_STANDARD_DEVIATION,
-    AGGTYPE_GMV
+    AGGTYPE_GMV,
+    AGGTYPE_UNION
 };
 
 PERSPECTIVE_EXPORT std::optional<t_aggtype>
@@ -21,6 +21,7 @@
 #include <perspective/rlookup.h>
 #include <perspective/computed_function.h>
 #include <perspective/gnode_state.h>
+#include <perspective/timestamp.h>
 #include <date/date.h>
 #include <tsl/hopscotch_set.h>
 
@@ -179,6 +180,15 @@ public:
     get_column_ids() const;
     t_dtype get_dtype() const;
 
+    /**
+     * @brief The datetime columns of the source `Table` stored in a unit
+     * other than milliseconds, whose values are converted to milliseconds
+     * before they reach the expression.
+     *
+     * @param params
+     */
+    void set_timestamp_params(const t_timestamp_map& params);
+
 private:
     std::string m_expression_alias;
     std::string m_expression_string;
@@ -186,6 +196,7 @@ private:
     t_computed_expression_parser m_computed_expression_parser;
     std::vector<std::pair<std::string, std::string>> m_column_ids;
     t_dtype m_dtype;
+    t_timestamp_map m_timestamp_params;
 
     // Lazily-built compiled-expression cache (see compute()). `mutable` because
     // compute() is const; `unique_ptr` keeps the cache heap-pinned so exprtk's
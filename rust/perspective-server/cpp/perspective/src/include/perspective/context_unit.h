@@ -67,6 +67,17 @@ public:
 
     std::vector<t_tscalar> get_data(const std::vector<t_tscalar>& pkeys) const;
 
+    bool get_diff_enabled() const;
+    void set_diff_enabled(bool enabled_state);
+
+    /**
+     * @brief Returns the rows added, updated and removed since the last call
+     * (or since diffs were enabled), and starts a new diff.
+     *
+     * @return t_pkey_diff
+     */
+    t_pkey_diff take_diff();
+
     // will only work on empty contexts. `is_registration` is `true` when
     // this call is part of `_register_context` (no subscriber yet) and
     // `false` when it is the first update to a previously-empty table.
@@ -165,6 +176,8 @@ private:
 
     t_symtable m_symtable;
     bool m_has_delta;
+    bool m_diff_enabled = false;
+    t_pkey_diff m_diff;
 };
 
 } // end namespace perspective
@@ -44,6 +44,26 @@ public:
 
     const tsl::hopscotch_set<t_tscalar>& get_delta_pkeys() const;
 
+    /**
+     * @brief Returns the data for the rows with the primary keys `pkeys`,
+     * row-major with a stride of `get_column_count()`.
+     *
+     * @param pkeys
+     * @return std::vector<t_tscalar>
+     */
+    std::vector<t_tscalar> get_data(const std::vector<t_tscalar>& pkeys) const;
+
+    bool get_diff_enabled() const;
+    void set_diff_enabled(bool enabled_state);
+
+    /**
+     * @brief Returns the rows added, updated and removed since the last call
+     * (or since diffs were enabled), and starts a new diff.
+     *
+     * @return t_pkey_diff
+     */
+    t_pkey_diff take_diff();
+
     void sort_by();
     std::vector<t_sortspec> get_sort_by() const;
 
@@ -109,6 +129,8 @@ private:
     std::shared_ptr<t_window_engine> m_window_engine;
     t_symtable m_symtable;
     bool m_has_delta;
+    bool m_diff_enabled = false;
+    t_pkey_diff m_diff;
 };
 
 } // end namespace perspective
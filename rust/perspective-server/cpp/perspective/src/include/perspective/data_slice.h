@@ -21,6 +21,7 @@
 #include <perspective/context_zero.h>
 #include <perspective/context_one.h>
 #include <perspective/context_two.h>
+#include <optional>
 
 namespace perspective {
 /**
@@ -106,6 +107,19 @@ public:
 
     std::vector<t_tscalar> get_column_slice(t_uindex cidx) const;
 
+    /**
+     * @brief Returns a new data slice with this slice's columns holding
+     * `slice`, whose extents are detached from the context's current row
+     * count, so that rows since removed from the context can still be
+     * serialized.
+     *
+     * @param slice row-major data with this slice's stride
+     * @param nrows the number of rows in `slice`
+     * @return std::shared_ptr<t_data_slice<CTX_T>>
+     */
+    std::shared_ptr<t_data_slice<CTX_T>>
+    with_rows(const std::vector<t_tscalar>& slice, t_uindex nrows) const;
+
     // Getters
     std::shared_ptr<CTX_T> get_context() const;
     const std::vector<t_tscalar>& get_slice() const;
@@ -140,5 +154,6 @@ private:
     std::vector<t_tscalar> m_slice;
     std::vector<std::vector<t_tscalar>> m_column_names;
     std::vector<t_uindex> m_column_indices;
+    std::optional<t_get_data_extents> m_detached_extents;
 };
 } // end namespace perspective
\ No newline at end of file
@@ -0,0 +1,93 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <perspective/scalar.h>
+#include <cstdint>
+#include <map>
+#include <optional>
+#include <string>
+#include <string_view>
+
+namespace perspective {
+
+/**
+ * @brief The largest `DECIMAL` precision stored exactly. Decimal columns are
+ * held as their unscaled value in a `DTYPE_INT64` column, which holds any 18
+ * digit integer.
+ */
+static constexpr std::uint32_t DECIMAL_MAX_PRECISION = 18;
+
+/**
+ * @brief The precision (total significant digits) and scale (digits after the
+ * decimal point) of a `DECIMAL` column.
+ */
+struct PERSPECTIVE_EXPORT t_decimal_params {
+    std::uint32_t precision;
+    std::uint32_t scale;
+
+    bool operator==(const t_decimal_params& other) const;
+};
+
+/**
+ * @brief The `DECIMAL` columns of a `Table` or `View`, by column name.
+ */
+using t_decimal_map = std::map<std::string, t_decimal_params>;
+
+/**
+ * @brief Whether `DECIMAL(precision, scale)` can be stored exactly.
+ */
+PERSPECTIVE_EXPORT bool
+is_exact_decimal(std::int64_t precision, std::int64_t scale);
+
+/**
+ * @brief Parse decimal text such as `"-12.5"` or `"1e3"` into its unscaled
+ * value at `params.scale`, rounding extra fractional digits half away from
+ * zero.
+ *
+ * @return std::nullopt if `text` is not a number or does not fit in
+ * `params.precision` digits.
+ */
+PERSPECTIVE_EXPORT std::optional<std::int64_t>
+parse_decimal(std::string_view text, const t_decimal_params& params);
+
+/**
+ * @brief Convert a `double` to an unscaled decimal, by way of its 15
+ * significant digit text so that e.g. `0.1` becomes exactly `0.10` at scale 2
+ * rather than the nearest binary fraction.
+ */
+PERSPECTIVE_EXPORT std::optional<std::int64_t>
+double_to_decimal(double value, const t_decimal_params& params);
+
+/**
+ * @brief Format an unscaled decimal as text with exactly `scale` fractional
+ * digits, e.g. `(1250, 2)` becomes `"12.50"`.
+ */
+PERSPECTIVE_EXPORT std::string
+format_decimal(std::int64_t unscaled, std::uint32_t scale);
+
+/**
+ * @brief The unscaled value of a `DECIMAL` column's scalar, which is a
+ * `DTYPE_INT64` for the column itself and for most aggregates over it, but a
+ * `DTYPE_FLOAT64` of unscaled values for the mean aggregates - these round
+ * half away from zero back onto the column's scale.
+ */
+PERSPECTIVE_EXPORT std::int64_t unscaled_decimal(const t_tscalar& scalar);
+
+/**
+ * @brief `10^exponent`, for `exponent <= DECIMAL_MAX_PRECISION`.
+ */
+PERSPECTIVE_EXPORT std::int64_t decimal_pow10(std::uint32_t exponent);
+
+} // namespace perspective
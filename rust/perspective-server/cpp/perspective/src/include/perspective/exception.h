@@ -12,6 +12,8 @@
 
 #pragma once
 #include <perspective/first.h>
+#include <perspective/schema_hints.h>
+#include <sstream>
 
 namespace perspective {
 
@@ -40,4 +42,41 @@ public:
     }
 };
 
+/**
+ * A load rejected by `BAD_ROWS_FAIL`, which carries every value that could
+ * not be coerced.
+ */
+class PERSPECTIVE_EXPORT PerspectiveIngestionException : public std::exception {
+public:
+    explicit PerspectiveIngestionException(t_row_errors errors) :
+        m_errors{std::move(errors)} {
+        std::stringstream ss;
+        ss << m_errors.size() << " value(s) could not be coerced";
+        if (!m_errors.empty()) {
+            const auto& first = m_errors.front();
+            ss << ", first at row " << first.row << " of column \""
+               << first.column << "\": " << first.message << " \""
+               << first.value << "\"";
+        }
+
+        m_message = ss.str();
+    }
+
+    [[nodiscard]]
+    const char*
+    what() const noexcept override {
+        return m_message.c_str();
+    }
+
+    [[nodiscard]]
+    const t_row_errors&
+    row_errors() const noexcept {
+        return m_errors;
+    }
+
+private:
+    t_row_errors m_errors;
+    std::string m_message;
+};
+
 } // namespace perspective
\ No newline at end of file
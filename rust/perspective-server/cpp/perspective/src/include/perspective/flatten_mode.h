@@ -11,13 +11,44 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 #pragma once
+#include <cstdint>
+#include <limits>
+#include <string>
 
 namespace perspective {
 
 enum t_list_flatten {
     LIST_FLATTEN_ZIP = 0,
     LIST_FLATTEN_CARTESIAN = 1,
-    LIST_FLATTEN_STRINGIFY = 2
+    LIST_FLATTEN_STRINGIFY = 2,
+
+    /**
+     * Keep list values whole, as `LIST` columns (see `t_list_params`). Lists
+     * with no supported element type are stringified instead.
+     */
+    LIST_FLATTEN_PRESERVE = 3
+};
+
+/**
+ * @brief Whether `mode` expands lists into rows, rather than keeping each list
+ * as a single value.
+ */
+inline bool
+list_flatten_expands(t_list_flatten mode) {
+    return mode == LIST_FLATTEN_ZIP || mode == LIST_FLATTEN_CARTESIAN;
+}
+
+/**
+ * @brief How nested JSON objects and Arrow `Struct` columns are hoisted into
+ * flat columns, named by the path of their keys joined by `separator`.
+ *
+ * A record's own keys are at depth 1, so `max_depth` is the most separators a
+ * hoisted name can have. Objects nested deeper are kept whole, as their JSON
+ * text in a string column.
+ */
+struct t_struct_flatten {
+    std::string separator = ".";
+    std::uint32_t max_depth = std::numeric_limits<std::uint32_t>::max();
 };
 
 } // namespace perspective
@@ -153,6 +153,26 @@ public:
      */
     void remove_input_port(t_uindex port_id);
 
+    /**
+     * @brief Append the pending data on input port `from_port_id` to input
+     * port `to_port_id`, then remove `from_port_id`. This lets updates be
+     * staged on a temporary port and discarded if a later step fails.
+     *
+     * @param from_port_id
+     * @param to_port_id
+     */
+    void merge_input_port(t_uindex from_port_id, t_uindex to_port_id);
+
+    /**
+     * @brief Take over the registered contexts and input port ids of
+     * `other`, which is being replaced by this gnode (e.g. after a schema
+     * change). Contexts are re-registered, recomputing their state from this
+     * gnode's rows; `other` is left with no contexts.
+     *
+     * @param other
+     */
+    void adopt(t_gnode& other);
+
     /**
      * @brief Given a new context, register it with the gnode, compute and
      * add its expression columns.
@@ -21,6 +21,10 @@
 #include <rapidjson/document.h>
 #include <perspective/schema.h>
 #include <perspective/data_table.h>
+#include <perspective/decimal.h>
+#include <perspective/list.h>
+#include <perspective/schema_hints.h>
+#include <perspective/timestamp.h>
 #include <memory>
 #include <optional>
 #include <set>
@@ -62,7 +66,8 @@ namespace json {
             t_json_format format,
             const std::string& index,
             const t_schema* existing,
-            t_list_flatten mode
+            t_list_flatten mode,
+            const t_struct_flatten& structs
         );
 
         const std::vector<std::string>& names() const;
@@ -77,6 +82,51 @@ namespace json {
 
         void release();
 
+        /**
+         * @brief Parse the values of these `DECIMAL` columns from JSON
+         * numbers or strings into their unscaled `DTYPE_INT64`.
+         *
+         * @param params
+         */
+        void set_decimal_params(const t_decimal_map& params);
+
+        /**
+         * @brief Parse the values of these `DTYPE_TIME` columns from epoch
+         * milliseconds or datetime strings into their own unit.
+         *
+         * @param params
+         */
+        void set_timestamp_params(const t_timestamp_map& params);
+
+        /**
+         * @brief The `LIST` columns inferred from arrays of a single element
+         * type under `LIST_FLATTEN_PRESERVE`.
+         */
+        const t_list_map& list_params() const;
+
+        /**
+         * @brief Encode the values of these `LIST` columns from JSON arrays,
+         * or strings holding one.
+         *
+         * @param params
+         */
+        void set_list_params(const t_list_map& params);
+
+        /**
+         * @brief Pin the type of these columns, whose values are coerced
+         * rather than inferred, or for an update rather than rejected when
+         * they don't match the `Table`. Must be set before `init`.
+         *
+         * @param hints
+         */
+        void set_schema_hints(const t_schema_hints& hints);
+
+        /**
+         * @brief The values of hinted columns which could not be coerced by
+         * `fill_table`, and were filled as null.
+         */
+        const t_row_errors& row_errors() const;
+
         std::uint32_t fill_table(
             t_data_table& tbl,
             const std::string& index,
@@ -92,6 +142,15 @@ namespace json {
             bool is_update
         );
 
+        void fill_cell(
+            t_data_table& tbl,
+            const std::shared_ptr<t_column>& col,
+            std::string_view col_name,
+            t_uindex ii,
+            const rapidjson::Value& cell,
+            bool is_update
+        );
+
         void infer_rows(const std::string& index);
         void infer_cols(const std::string& index);
         void infer_ndjson(const std::string& index);
@@ -109,6 +168,12 @@ namespace json {
         std::vector<std::string> m_names;
         std::vector<t_dtype> m_types;
         t_list_flatten m_mode{LIST_FLATTEN_ZIP};
+        t_struct_flatten m_struct_flatten;
+        t_decimal_map m_decimal_params;
+        t_timestamp_map m_timestamp_params;
+        t_list_map m_list_params;
+        t_schema_hints m_schema_hints;
+        t_row_errors m_row_errors;
 
         std::set<std::string> m_per_element;
         std::vector<t_uindex> m_child_widths;
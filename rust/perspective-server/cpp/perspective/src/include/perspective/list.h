@@ -0,0 +1,171 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+#include <perspective/first.h>
+#include <perspective/base.h>
+#include <perspective/exports.h>
+#include <perspective/scalar.h>
+#include <arrow/type_fwd.h>
+#include <rapidjson/document.h>
+#include <rapidjson/stringbuffer.h>
+#include <rapidjson/writer.h>
+#include <map>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace perspective {
+
+/**
+ * @brief The element type of a `LIST` column, one of `DTYPE_STR`,
+ * `DTYPE_INT64`, `DTYPE_FLOAT64` or `DTYPE_BOOL`.
+ *
+ * `LIST` columns are stored as `DTYPE_STR`, each value the canonical (compact,
+ * element-type normalized) JSON text of its array, e.g. `["a","b"]` or
+ * `[1.0,2.5]`. Canonical text is what makes membership a pattern match - see
+ * `list_membership_pattern`.
+ */
+struct PERSPECTIVE_EXPORT t_list_params {
+    t_dtype element;
+
+    bool operator==(const t_list_params& other) const;
+};
+
+/**
+ * @brief The `LIST` columns of a `Table` or `View`, by column name.
+ */
+using t_list_map = std::map<std::string, t_list_params>;
+
+/**
+ * @brief Whether `dtype` can be the element type of a `LIST` column.
+ */
+PERSPECTIVE_EXPORT bool is_list_element_dtype(t_dtype dtype);
+
+/**
+ * @brief The element type a JSON array's non-null elements share, widening
+ * integers to floats if the two are mixed.
+ *
+ * @return `DTYPE_NONE` if the array has no non-null element to infer from, or
+ * std::nullopt if its elements are nested or of differing kinds.
+ */
+PERSPECTIVE_EXPORT std::optional<t_dtype>
+list_element_dtype(const rapidjson::Value& array);
+
+/**
+ * @brief The element type of an Arrow `LIST` column's values, or std::nullopt
+ * if they are not a supported element type.
+ */
+PERSPECTIVE_EXPORT std::optional<t_dtype>
+list_element_dtype(const arrow::DataType& type);
+
+/**
+ * @brief The Arrow type `LIST` elements of `element` are written as.
+ */
+PERSPECTIVE_EXPORT std::shared_ptr<arrow::DataType>
+list_element_arrow_type(t_dtype element);
+
+/**
+ * @brief A builder of Arrow `LIST` arrays of `params.element`.
+ */
+PERSPECTIVE_EXPORT std::shared_ptr<arrow::ListBuilder>
+make_list_builder(const t_list_params& params);
+
+/**
+ * @brief Append the encoded list `text` to a `make_list_builder` builder.
+ */
+PERSPECTIVE_EXPORT arrow::Status append_list(
+    arrow::ListBuilder& builder, const char* text, const t_list_params& params
+);
+
+/**
+ * @brief Builds the canonical text of `LIST` values, one element at a time.
+ */
+class PERSPECTIVE_EXPORT t_list_encoder {
+public:
+    explicit t_list_encoder(const t_list_params& params);
+
+    /**
+     * @brief Begin a new list, discarding any unfinished one.
+     */
+    void start();
+
+    /**
+     * @brief Append `element`, converted to the list's element type.
+     *
+     * @return false if `element` is not `null` and cannot be represented as
+     * the element type, e.g. a string in an integer list.
+     */
+    bool push(const rapidjson::Value& element);
+
+    /**
+     * @brief Close the list, returning its text, which is valid until the
+     * next `start`.
+     */
+    const char* finish();
+
+private:
+    t_list_params m_params;
+    rapidjson::StringBuffer m_buffer;
+    rapidjson::Writer<rapidjson::StringBuffer> m_writer;
+};
+
+/**
+ * @brief The canonical text of a JSON array (or of a JSON string holding one)
+ * as a list of `params.element`.
+ *
+ * @return std::nullopt if `value` is not an array or has an element which is
+ * not of the element type.
+ */
+PERSPECTIVE_EXPORT std::optional<std::string>
+encode_list(const rapidjson::Value& value, const t_list_params& params);
+
+/**
+ * @brief The canonical text of the Arrow list whose elements are `values`,
+ * e.g. one `value_slice` of a `ListArray`.
+ *
+ * @return std::nullopt if an element is not of the element type.
+ */
+PERSPECTIVE_EXPORT std::optional<std::string>
+encode_arrow_list(const arrow::Array& values, const t_list_params& params);
+
+/**
+ * @brief The canonical text of one element of a list of `params.element`,
+ * as it appears within an encoded list.
+ */
+PERSPECTIVE_EXPORT std::optional<std::string>
+encode_list_element(const rapidjson::Value& value, const t_list_params& params);
+
+/**
+ * @brief An RE2 pattern matching the encoded lists which contain any of the
+ * encoded `elements`.
+ *
+ * An element's text is delimited by `[` or `,` before and `,` or `]` after,
+ * and those never occur unescaped inside a string element, so the match is
+ * exact. No `elements` matches no list.
+ */
+PERSPECTIVE_EXPORT std::string
+list_membership_pattern(const std::vector<std::string>& elements);
+
+/**
+ * @brief The encoded list of the distinct non-null elements of every list in
+ * `lists`, in ascending order.
+ */
+PERSPECTIVE_EXPORT std::string union_lists(const std::vector<t_tscalar>& lists);
+
+/**
+ * @brief The `ColumnType`-style name of a list element type, for messages.
+ */
+PERSPECTIVE_EXPORT const char* list_element_name(t_dtype element);
+
+} // namespace perspective
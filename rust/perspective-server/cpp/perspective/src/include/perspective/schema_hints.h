@@ -0,0 +1,67 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+#include <perspective/first.h>
+#include <perspective/base.h>
+#include <perspective/last.h>
+#include <map>
+#include <string>
+#include <vector>
+
+namespace perspective {
+
+/**
+ * @brief The type of each named column of a new `Table`, which is pinned
+ * rather than inferred from its values. Columns of the input which are not
+ * named here are inferred as usual, and names which are not columns of the
+ * input are ignored.
+ */
+using t_schema_hints = std::map<std::string, t_dtype>;
+
+/**
+ * @brief A value which could not be coerced to its column's type, and so was
+ * handled by the load's `t_bad_rows` policy.
+ */
+struct t_row_error {
+    /**
+     * The row of the loaded data, counted after any `t_list_flatten`
+     * expansion.
+     */
+    t_uindex row;
+    std::string column;
+
+    /**
+     * The raw value, i.e. its CSV text, JSON text or Arrow value.
+     */
+    std::string value;
+    std::string message;
+};
+
+using t_row_errors = std::vector<t_row_error>;
+
+/**
+ * @brief What a load does with a row holding a value which can't be coerced
+ * to its column's type.
+ */
+enum t_bad_rows {
+    // Load the value as null, keeping the rest of the row.
+    BAD_ROWS_NULL,
+
+    // Drop the row.
+    BAD_ROWS_SKIP,
+
+    // Reject the whole load with a `PerspectiveIngestionException`.
+    BAD_ROWS_FAIL
+};
+
+} // namespace perspective
@@ -20,7 +20,12 @@
 #include "perspective/view.h"
 #include "perspective/view_config.h"
 #include <cstdint>
+#include <deque>
 #include <memory>
+#include <mutex>
+#include <optional>
+#include <set>
+#include <type_traits>
 #include <tsl/hopscotch_set.h>
 #include <utility>
 #include <perspective/table.h>
@@ -138,6 +143,20 @@ std::shared_ptr<CTX_T> make_context(
 
 namespace server {
 
+    /**
+     * @brief The rows added, updated and removed from a flat view by an
+     * update, each serialized as Arrow with the primary key of each row in
+     * the same order.
+     */
+    struct t_view_diff {
+        std::shared_ptr<std::string> added;
+        std::shared_ptr<std::string> updated;
+        std::shared_ptr<std::string> removed;
+        std::vector<std::vector<t_tscalar>> added_keys;
+        std::vector<std::vector<t_tscalar>> updated_keys;
+        std::vector<std::vector<t_tscalar>> removed_keys;
+    };
+
     class PERSPECTIVE_EXPORT ErasedView {
     public:
         virtual ~ErasedView() = default;
@@ -240,6 +259,15 @@ namespace server {
         virtual std::pair<t_tscalar, t_tscalar>
         get_min_max(const std::string& col_name) const = 0;
 
+        [[nodiscard]]
+        virtual const t_decimal_map& decimal_params() const = 0;
+
+        [[nodiscard]]
+        virtual const t_timestamp_map& timestamp_params() const = 0;
+
+        [[nodiscard]]
+        virtual const t_list_map& list_params() const = 0;
+
         [[nodiscard]]
         virtual std::shared_ptr<std::string> get_row_delta_as_arrow() const = 0;
 
@@ -247,6 +275,17 @@ namespace server {
         [[nodiscard]]
         virtual bool get_deltas_enabled() const = 0;
 
+        /**
+         * @brief The rows the engine added to, updated in and removed from
+         * the view since the previous call (or since diffs were enabled).
+         */
+        [[nodiscard]]
+        virtual t_view_diff get_diff_as_arrow() = 0;
+
+        virtual void set_diff_enabled(bool enabled_state) = 0;
+        [[nodiscard]]
+        virtual bool get_diff_enabled() const = 0;
+
         virtual t_index collapse(std::int32_t row_idx) = 0;
 
         virtual t_index expand(std::int32_t row_idx) = 0;
@@ -470,6 +509,24 @@ namespace server {
             return m_view->get_min_max(col_name);
         }
 
+        [[nodiscard]]
+        const t_decimal_map&
+        decimal_params() const override {
+            return m_view->decimal_params();
+        }
+
+        [[nodiscard]]
+        const t_timestamp_map&
+        timestamp_params() const override {
+            return m_view->timestamp_params();
+        }
+
+        [[nodiscard]]
+        const t_list_map&
+        list_params() const override {
+            return m_view->list_params();
+        }
+
         [[nodiscard]]
         std::shared_ptr<std::string>
         get_row_delta_as_arrow() const override {
@@ -488,6 +545,53 @@ namespace server {
             return m_view->get_context()->get_deltas_enabled();
         }
 
+        [[nodiscard]]
+        t_view_diff
+        get_diff_as_arrow() override {
+            if constexpr (is_flat) {
+                auto ctx = m_view->get_context();
+                auto pkey_diff = ctx->take_diff();
+                auto added = pkey_diff.get_added();
+                auto updated = pkey_diff.get_updated();
+                auto removed = pkey_diff.get_removed();
+
+                t_view_diff diff;
+                diff.added = rows_to_arrow(ctx->get_data(added), added.size());
+                diff.updated =
+                    rows_to_arrow(ctx->get_data(updated), updated.size());
+                diff.removed = rows_to_arrow(
+                    pkey_diff.get_removed_data(), removed.size()
+                );
+
+                diff.added_keys = pkeys_to_keys(added);
+                diff.updated_keys = pkeys_to_keys(updated);
+                diff.removed_keys = pkeys_to_keys(removed);
+                return diff;
+            } else {
+                PSP_COMPLAIN_AND_ABORT(DIFF_UNSUPPORTED);
+                return {};
+            }
+        }
+
+        void
+        set_diff_enabled(bool enabled_state) override {
+            if constexpr (is_flat) {
+                m_view->get_context()->set_diff_enabled(enabled_state);
+            } else if (enabled_state) {
+                PSP_COMPLAIN_AND_ABORT(DIFF_UNSUPPORTED);
+            }
+        }
+
+        [[nodiscard]]
+        bool
+        get_diff_enabled() const override {
+            if constexpr (is_flat) {
+                return m_view->get_context()->get_diff_enabled();
+            } else {
+                return false;
+            }
+        }
+
         t_index
         collapse(std::int32_t row_idx) override {
             return m_view->collapse(row_idx);
@@ -508,6 +612,33 @@ namespace server {
         }
 
     private:
+        // Diffs are built from the primary keys each update changes, which
+        // only flat views can map to rows.
+        static constexpr bool is_flat = std::is_same_v<CTX_T, t_ctx0>
+            || std::is_same_v<CTX_T, t_ctxunit>;
+
+        static constexpr const char* DIFF_UNSUPPORTED =
+            "`on_update` diff mode is not supported for views with a "
+            "`group_by` or `split_by`";
+
+        std::shared_ptr<std::string>
+        rows_to_arrow(const std::vector<t_tscalar>& data, t_uindex nrows) {
+            auto slice = m_view->get_data(0, 0, 0, m_view->num_columns())
+                             ->with_rows(data, nrows);
+            return m_view->data_slice_to_arrow(slice, false, false);
+        }
+
+        static std::vector<std::vector<t_tscalar>>
+        pkeys_to_keys(const std::vector<t_tscalar>& pkeys) {
+            std::vector<std::vector<t_tscalar>> keys;
+            keys.reserve(pkeys.size());
+            for (const auto& pkey : pkeys) {
+                keys.push_back({pkey});
+            }
+
+            return keys;
+        }
+
         std::shared_ptr<View<CTX_T>> m_view;
     };
 
@@ -531,6 +662,10 @@ namespace server {
     struct Subscription {
         uint32_t id;
         uint32_t client_id;
+
+        // The `ViewOnUpdateReq` mode, if this is a view update subscription
+        // which requested one.
+        std::optional<proto::ViewOnUpdateReq_Mode> mode = std::nullopt;
     };
 
     /**
@@ -557,6 +692,7 @@ namespace server {
         std::shared_ptr<Table> get_table(const t_id& id);
         std::shared_ptr<Table> get_table_for_view(const t_id& view_id);
         t_id get_table_id_for_view(const t_id& view_id);
+        std::uint32_t get_client_id_for_view(const t_id& view_id);
         std::vector<t_id> get_view_ids(const t_id& table_id);
         bool has_view(const t_id& id);
         bool has_table(const t_id& id);
@@ -596,6 +732,11 @@ namespace server {
             std::uint32_t sub_id, std::uint32_t client_id
         );
 
+        // `UnsubscribeReq`, removes every subscription created by a request.
+        void remove_request_subs(
+            std::uint32_t sub_id, std::uint32_t client_id
+        );
+
         void mark_table_dirty(const t_id& id);
         void mark_table_clean(const t_id& id);
         void mark_all_tables_clean();
@@ -677,12 +818,39 @@ namespace server {
             std::vector<ProtoServerResp<Response>>& outs
         );
 
+        /**
+         * @brief Add, drop and retype the columns of a `Table`, first
+         * deleting (and notifying the `on_delete` subscribers of) any `View`
+         * which reads a dropped or retyped column.
+         *
+         * @return The ids of the deleted `View`s.
+         */
+        std::vector<std::string> _alter_table_columns(
+            const ServerResources::t_id& table_id,
+            const std::vector<std::pair<std::string, t_dtype>>& add,
+            const std::vector<std::string>& drop,
+            const std::vector<std::pair<std::string, t_dtype>>& cast,
+            std::vector<ProtoServerResp<Response>>& outs
+        );
+
         void _process_table_unchecked(
             std::shared_ptr<Table>& table,
             const ServerResources::t_id& table_id,
             std::vector<ProtoServerResp<Response>>& outs
         );
 
+        /**
+         * @brief Record that `client_id`'s request `msg_id` was cancelled by
+         * an `UnsubscribeReq`, so that an expensive handler running it (on
+         * another thread) or yet to run it abandons its work.
+         */
+        void cancel_request(std::uint32_t client_id, std::uint32_t msg_id);
+
+        /**
+         * @brief Whether `client_id`'s request `msg_id` has been cancelled.
+         */
+        bool is_cancelled(std::uint32_t client_id, std::uint32_t msg_id);
+
         static std::uint32_t m_client_id;
         bool m_realtime_mode;
         std::atomic<std::chrono::high_resolution_clock::time_point>
@@ -690,6 +858,14 @@ namespace server {
         std::atomic<long long> m_cpu_time;
         ServerResources m_resources;
         JoinEngine m_join_engine;
+
+        // Cancelled `(client_id, msg_id)` pairs, oldest first in
+        // `m_cancelled_order`. Most name a request which already finished,
+        // so only the most recent `MAX_CANCELLED` are kept.
+        static constexpr std::size_t MAX_CANCELLED = 1024;
+        std::mutex m_cancelled_lock;
+        std::set<std::pair<std::uint32_t, std::uint32_t>> m_cancelled;
+        std::deque<std::pair<std::uint32_t, std::uint32_t>> m_cancelled_order;
         t_computed_expression_parser m_computed_expression_parser;
     };
 
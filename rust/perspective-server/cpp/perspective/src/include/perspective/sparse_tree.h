@@ -33,6 +33,8 @@ SUPPRESS_WARNINGS_VC(4503)
 #include <perspective/dense_tree.h>
 #include <vector>
 #include <algorithm>
+#include <optional>
+#include <set>
 #include <deque>
 #include <sstream>
 #include <queue>
@@ -342,6 +344,13 @@ public:
 
     void clear_aggregates(const std::vector<t_uindex>& indices);
 
+    /**
+     * @brief The name of a `DTYPE_INT64` sum aggregate of this tree, e.g. of
+     * a `DECIMAL` column, whose exact value overflowed and reads as null, or
+     * `nullopt` if none has.
+     */
+    std::optional<std::string> overflowed_sum() const;
+
     std::pair<iter_by_idx, bool> insert_node(const t_tnode& node);
     bool has_deltas() const;
     void set_has_deltas(bool v);
@@ -437,6 +446,25 @@ protected:
         const std::function<bool(const t_tscalar&, t_tscalar&)>& fn
     ) const;
 
+    /**
+     * @brief Recompute the `DTYPE_INT64` sum of `colname` over the leaves of
+     * `nidx` in 128 bits, so that only the total must fit, recording the
+     * aggregate at `aggidx` and `colidx` as overflowed if it doesn't.
+     *
+     * @param abs_values - sum the absolute value of each value.
+     * @param abs_total - take the absolute value of the total.
+     */
+    t_tscalar exact_int64_sum(
+        t_uindex nidx,
+        t_uindex aggidx,
+        t_uindex colidx,
+        const std::string& colname,
+        const t_gstate& gstate,
+        const t_data_table& expression_master_table,
+        bool abs_values = false,
+        bool abs_total = false
+    );
+
     template <typename FN_T>
     typename FN_T::result_type reduce_from_gstate(
         const t_gstate& gstate,
@@ -457,6 +485,11 @@ private:
     std::vector<t_aggspec> m_aggspecs;
     t_schema m_schema;
     std::vector<t_uindex> m_agg_freelist;
+
+    // The `DTYPE_INT64` sum aggregates which overflowed, as their
+    // `m_aggregates` row and column indices, which are recomputed exactly
+    // on every update until they fit again.
+    std::set<std::pair<t_uindex, t_uindex>> m_overflowed_sums;
     t_uindex m_cur_aggidx;
     std::set<t_uindex> m_newids;
     std::set<t_uindex> m_newleaves;
@@ -17,6 +17,7 @@
 #include <perspective/exports.h>
 #include <vector>
 #include <tsl/hopscotch_set.h>
+#include <tsl/hopscotch_map.h>
 #include <boost/multi_index_container.hpp>
 #include <boost/multi_index/member.hpp>
 #include <boost/multi_index/hashed_index.hpp>
@@ -120,6 +121,53 @@ struct PERSPECTIVE_EXPORT t_rowdelta {
     std::vector<t_tscalar> data;
 };
 
+/**
+ * @brief The primary keys added to, updated in and removed from a flat
+ * context since the diff was last taken, in the order they first changed.
+ * Removed rows keep the values they had in the context, as these can no
+ * longer be read from the master table.
+ */
+class PERSPECTIVE_EXPORT t_pkey_diff {
+public:
+    void add(const t_tscalar& pkey);
+
+    void update(const t_tscalar& pkey);
+
+    void remove(const t_tscalar& pkey, std::vector<t_tscalar> row);
+
+    void clear();
+
+    bool empty() const;
+
+    std::vector<t_tscalar> get_added() const;
+
+    std::vector<t_tscalar> get_updated() const;
+
+    std::vector<t_tscalar> get_removed() const;
+
+    /**
+     * @brief The values of the rows in `get_removed()`, row-major in the
+     * same order.
+     */
+    std::vector<t_tscalar> get_removed_data() const;
+
+private:
+    enum t_change {
+        PKEY_DIFF_ADDED,
+        PKEY_DIFF_UPDATED,
+        PKEY_DIFF_REMOVED,
+
+        // Added and removed again since the diff was last taken.
+        PKEY_DIFF_NONE
+    };
+
+    std::vector<t_tscalar> get_pkeys(t_change change) const;
+
+    std::vector<t_tscalar> m_order;
+    tsl::hopscotch_map<t_tscalar, t_change> m_changes;
+    tsl::hopscotch_map<t_tscalar, std::vector<t_tscalar>> m_removed_rows;
+};
+
 } // end namespace perspective
 
 namespace std {
@@ -19,11 +19,30 @@
 #include <perspective/gnode.h>
 #include <perspective/pool.h>
 #include <perspective/data_table.h>
+#include <perspective/decimal.h>
+#include <perspective/list.h>
+#include <perspective/timestamp.h>
+#include <perspective/arrow_types.h>
+#include <perspective/arrow_csv.h>
+#include <perspective/schema_hints.h>
 #include <perspective/arrow_normalize.h>
 #include <perspective/json_loader.h>
+#include <set>
 
 namespace perspective {
 
+/**
+ * @brief A validated change to the columns of a `Table`, returned by
+ * `Table::prepare_alter_columns` and applied by
+ * `Table::apply_altered_columns`.
+ */
+struct t_altered_columns {
+    t_schema m_schema;
+    std::shared_ptr<t_data_table> m_data_table;
+    std::set<std::string> m_drops;
+    std::set<std::string> m_casts;
+};
+
 /**
  * @brief the `Table` class encapsulates `t_data_table`, `t_pool` and `t_gnode`,
  * offering a unified public API for consumption by binding languages.
@@ -58,7 +77,8 @@ public:
         std::string index,
         t_backing_store backing_store = BACKING_STORE_MEMORY,
         apachearrow::t_list_flatten list_flatten =
-            apachearrow::LIST_FLATTEN_ZIP
+            apachearrow::LIST_FLATTEN_ZIP,
+        const t_struct_flatten& struct_flatten = t_struct_flatten{}
     );
 
     /**
@@ -194,6 +214,15 @@ public:
      */
     void remove_port(t_uindex port_id) const;
 
+    /**
+     * @brief Move the pending updates and removes on port `from_port_id` to
+     * port `to_port_id`, and remove `from_port_id`.
+     *
+     * @param from_port_id
+     * @param to_port_id
+     */
+    void merge_port(t_uindex from_port_id, t_uindex to_port_id) const;
+
     /**
      * @brief The offset determines where we begin to write data into the Table.
      * Using `m_offset`, `m_limit`, and the length of the dataset, calculate the
@@ -203,6 +232,14 @@ public:
      */
     void calculate_offset(std::uint32_t row_count);
 
+    /**
+     * @brief Restore an offset previously returned by `get_offset()`, e.g.
+     * to roll back rows staged on a port which was then removed.
+     *
+     * @param offset
+     */
+    void set_offset(std::uint32_t offset);
+
     // Getters
     t_uindex get_id() const;
     std::shared_ptr<t_pool> get_pool() const;
@@ -218,14 +255,146 @@ public:
     void set_column_names(const std::vector<std::string>& column_names);
     void set_data_types(const std::vector<t_dtype>& data_types);
 
-    void remove_cols(const std::string_view& data);
-    void remove_rows(const std::string_view& data);
+    /**
+     * @brief The `DECIMAL` columns of this `Table`, which are stored as their
+     * unscaled value in a `DTYPE_INT64` column.
+     */
+    const t_decimal_map& get_decimal_params() const;
+    void set_decimal_params(const t_decimal_map& params);
+
+    /**
+     * @brief The `DTYPE_TIME` columns of this `Table` which are stored in a
+     * unit other than milliseconds, or which carry a timezone.
+     */
+    const t_timestamp_map& get_timestamp_params() const;
+    void set_timestamp_params(const t_timestamp_map& params);
+
+    /**
+     * @brief The `LIST` columns of this `Table`, which are stored as their
+     * encoded JSON text in a `DTYPE_STR` column.
+     */
+    const t_list_map& get_list_params() const;
+    void set_list_params(const t_list_map& params);
+
+    /**
+     * @brief The source Arrow type of each column of a `Table` created from
+     * Arrow with `preserve_arrow_types`, or empty otherwise.
+     */
+    const t_arrow_type_map& get_arrow_types() const;
+    void set_arrow_types(const t_arrow_type_map& types);
 
-    void update_arrow(const std::string_view& data, std::uint32_t port_id);
-    void update_csv(const std::string_view& data, std::uint32_t port_id);
-    void update_rows(const std::string_view& data, std::uint32_t port_id);
-    void update_cols(const std::string_view& data, std::uint32_t port_id);
-    void update_ndjson(const std::string_view& data, std::uint32_t port_id);
+    /**
+     * @brief The `t_csv_options` this `Table` was created with, which
+     * `update_csv` uses when an update does not supply its own.
+     */
+    const apachearrow::t_csv_options& get_csv_options() const;
+    void set_csv_options(const apachearrow::t_csv_options& options);
+
+    /**
+     * @brief The values which could not be coerced to the type of their
+     * column when this `Table` was created, and were handled by its
+     * `t_bad_rows` policy.
+     */
+    const t_row_errors& get_row_errors() const;
+    void set_row_errors(const t_row_errors& errors);
+
+    /**
+     * @brief The `t_bad_rows` policy updates use when they do not supply their
+     * own. When unset, updates are not coerced, and a value which doesn't
+     * match its column rejects the update with a `PerspectiveException`.
+     */
+    const std::optional<t_bad_rows>& get_on_bad_rows() const;
+    void set_on_bad_rows(const std::optional<t_bad_rows>& on_bad_rows);
+
+    void remove_cols(const std::string_view& data, std::uint32_t port_id = 0);
+    void remove_rows(const std::string_view& data, std::uint32_t port_id = 0);
+
+    /**
+     * @brief Remove every row which passes `fterms` combined by `combiner`,
+     * evaluated against the current (processed) state of the `Table`. Works
+     * on both indexed and unindexed `Table`s, as rows are removed by their
+     * `psp_pkey`.
+     *
+     * @param fterms
+     * @param combiner
+     * @param port_id
+     */
+    void remove_where(
+        const std::vector<t_fterm>& fterms,
+        t_filter_op combiner,
+        std::uint32_t port_id = 0
+    );
+
+    /**
+     * @brief Remove every row of the current (processed) state of the
+     * `Table` on `port_id`, so unlike `clear` the removal is applied (and
+     * notifies `View`s) in order with the other data written to that port.
+     *
+     * @param port_id
+     */
+    void remove_all(std::uint32_t port_id = 0);
+
+    /**
+     * @brief Validate a change adding, dropping and retyping columns of a
+     * `Table`, and rebuild its current (processed) rows in the new schema.
+     * Added columns are null-filled, and retyped columns are converted value
+     * by value. Throws if the change is invalid or any value can't be
+     * converted exactly; either way the `Table` itself is not modified.
+     *
+     * @param add
+     * @param drop
+     * @param cast
+     * @return t_altered_columns
+     */
+    t_altered_columns prepare_alter_columns(
+        const std::vector<std::pair<std::string, t_dtype>>& add,
+        const std::vector<std::string>& drop,
+        const std::vector<std::pair<std::string, t_dtype>>& cast
+    ) const;
+
+    /**
+     * @brief Swap in the rows built by `prepare_alter_columns`, by rebuilding
+     * the gnode with the new schema.
+     *
+     * Registered contexts are moved to the new gnode, so the caller must
+     * first delete any `View` which reads a dropped or retyped column.
+     *
+     * @param altered
+     */
+    void apply_altered_columns(t_altered_columns&& altered);
+
+    /**
+     * @brief Each update returns the values which could not be coerced to the
+     * type of their column under `on_bad_rows`, or the `Table`'s own policy
+     * when it is not given. Throws `PerspectiveIngestionException` instead
+     * for `BAD_ROWS_FAIL`, leaving the `Table` unchanged.
+     */
+    t_row_errors update_arrow(
+        const std::string_view& data,
+        std::uint32_t port_id,
+        std::optional<t_bad_rows> on_bad_rows = std::nullopt
+    );
+    t_row_errors update_csv(
+        const std::string_view& data,
+        std::uint32_t port_id,
+        const apachearrow::t_csv_options* options = nullptr,
+        std::optional<t_bad_rows> on_bad_rows = std::nullopt
+    );
+    t_row_errors update_rows(
+        const std::string_view& data,
+        std::uint32_t port_id,
+        std::optional<t_bad_rows> on_bad_rows = std::nullopt
+    );
+    t_row_errors update_cols(
+        const std::string_view& data,
+        std::uint32_t port_id,
+        std::optional<t_bad_rows> on_bad_rows = std::nullopt
+    );
+    t_row_errors update_ndjson(
+        const std::string_view& data,
+        std::uint32_t port_id,
+        std::optional<t_bad_rows> on_bad_rows = std::nullopt
+    );
     // void update_cols(const std::string_view& data) const;
 
     static std::shared_ptr<Table> from_csv(
@@ -234,7 +403,12 @@ public:
         std::uint32_t limit = std::numeric_limits<std::uint32_t>::max(),
         t_backing_store backing_store = BACKING_STORE_MEMORY,
         apachearrow::t_list_flatten list_flatten =
-            apachearrow::LIST_FLATTEN_ZIP
+            apachearrow::LIST_FLATTEN_ZIP,
+        const t_struct_flatten& struct_flatten = t_struct_flatten{},
+        const apachearrow::t_csv_options& csv_options =
+            apachearrow::t_csv_options{},
+        const t_schema_hints& schema_hints = t_schema_hints{},
+        t_bad_rows on_bad_rows = BAD_ROWS_NULL
     );
 
     static std::shared_ptr<Table> from_cols(
@@ -243,7 +417,10 @@ public:
         std::uint32_t limit = std::numeric_limits<std::uint32_t>::max(),
         t_backing_store backing_store = BACKING_STORE_MEMORY,
         apachearrow::t_list_flatten list_flatten =
-            apachearrow::LIST_FLATTEN_ZIP
+            apachearrow::LIST_FLATTEN_ZIP,
+        const t_struct_flatten& struct_flatten = t_struct_flatten{},
+        const t_schema_hints& schema_hints = t_schema_hints{},
+        t_bad_rows on_bad_rows = BAD_ROWS_NULL
     );
 
     static std::shared_ptr<Table> from_rows(
@@ -252,7 +429,10 @@ public:
         std::uint32_t limit = std::numeric_limits<std::uint32_t>::max(),
         t_backing_store backing_store = BACKING_STORE_MEMORY,
         apachearrow::t_list_flatten list_flatten =
-            apachearrow::LIST_FLATTEN_ZIP
+            apachearrow::LIST_FLATTEN_ZIP,
+        const t_struct_flatten& struct_flatten = t_struct_flatten{},
+        const t_schema_hints& schema_hints = t_schema_hints{},
+        t_bad_rows on_bad_rows = BAD_ROWS_NULL
     );
 
     static std::shared_ptr<Table> from_ndjson(
@@ -261,7 +441,10 @@ public:
         std::uint32_t limit = std::numeric_limits<std::uint32_t>::max(),
         t_backing_store backing_store = BACKING_STORE_MEMORY,
         apachearrow::t_list_flatten list_flatten =
-            apachearrow::LIST_FLATTEN_ZIP
+            apachearrow::LIST_FLATTEN_ZIP,
+        const t_struct_flatten& struct_flatten = t_struct_flatten{},
+        const t_schema_hints& schema_hints = t_schema_hints{},
+        t_bad_rows on_bad_rows = BAD_ROWS_NULL
     );
 
     static std::shared_ptr<Table> from_schema(
@@ -270,7 +453,8 @@ public:
         std::uint32_t limit = std::numeric_limits<std::uint32_t>::max(),
         t_backing_store backing_store = BACKING_STORE_MEMORY,
         apachearrow::t_list_flatten list_flatten =
-            apachearrow::LIST_FLATTEN_ZIP
+            apachearrow::LIST_FLATTEN_ZIP,
+        const t_struct_flatten& struct_flatten = t_struct_flatten{}
     );
 
     static std::shared_ptr<Table> from_arrow(
@@ -279,7 +463,11 @@ public:
         std::uint32_t limit = std::numeric_limits<std::uint32_t>::max(),
         t_backing_store backing_store = BACKING_STORE_MEMORY,
         apachearrow::t_list_flatten list_flatten =
-            apachearrow::LIST_FLATTEN_ZIP
+            apachearrow::LIST_FLATTEN_ZIP,
+        const t_struct_flatten& struct_flatten = t_struct_flatten{},
+        bool preserve_arrow_types = false,
+        const t_schema_hints& schema_hints = t_schema_hints{},
+        t_bad_rows on_bad_rows = BAD_ROWS_NULL
     );
 
     static std::shared_ptr<Table> make_table(
@@ -290,7 +478,8 @@ public:
         const std::string_view& data,
         t_backing_store backing_store = BACKING_STORE_MEMORY,
         apachearrow::t_list_flatten list_flatten =
-            apachearrow::LIST_FLATTEN_ZIP
+            apachearrow::LIST_FLATTEN_ZIP,
+        const t_struct_flatten& struct_flatten = t_struct_flatten{}
     );
 
 private:
@@ -304,19 +493,29 @@ private:
         std::string&& data,
         std::uint32_t limit,
         t_backing_store backing_store,
-        apachearrow::t_list_flatten list_flatten
+        apachearrow::t_list_flatten list_flatten,
+        const t_struct_flatten& struct_flatten,
+        t_bad_rows on_bad_rows
     );
 
     /**
      * @brief Apply a JSON payload to this `Table`, shared by the three JSON
      * update formats.
      */
-    void update_json(
+    t_row_errors update_json(
         const std::string_view& data,
         json::t_json_format format,
-        std::uint32_t port_id
+        std::uint32_t port_id,
+        std::optional<t_bad_rows> on_bad_rows
     );
 
+    /**
+     * @brief The columns an update under a `t_bad_rows` policy coerces, i.e.
+     * those whose type a value can fail to match. `DECIMAL`, `LIST` and
+     * non-millisecond `DTYPE_TIME` columns are left to their own loaders.
+     */
+    t_schema_hints update_schema_hints() const;
+
     /**
      * @brief Make sure that the table does not have an explicit index AND an
      * implicit index (with the `__INDEX__` column in data).
@@ -374,6 +573,62 @@ private:
      * different column shape than the schema it is written against.
      */
     const apachearrow::t_list_flatten m_list_flatten;
+
+    /**
+     * @brief How JSON objects and `arrow::Type::STRUCT` columns are hoisted
+     * into columns, with the same INVARIANT as `m_list_flatten`: a streamed
+     * update of nested records must land in the columns its creation did.
+     */
+    const t_struct_flatten m_struct_flatten;
+
+    /**
+     * @brief Precision and scale of each `DECIMAL` column.
+     *
+     * INVARIANT: every key is a `DTYPE_INT64` column of the `Table`, and every
+     * update path must hand this to its loader so input is rescaled rather
+     * than written into the unscaled column as-is.
+     */
+    t_decimal_map m_decimal_params;
+
+    /**
+     * @brief Unit and timezone of each `DTYPE_TIME` column which is not plain
+     * UTC milliseconds.
+     *
+     * INVARIANT: every key is a `DTYPE_TIME` column of the `Table` whose
+     * values are in `unit`; the timezone is display metadata only, values
+     * are always UTC.
+     */
+    t_timestamp_map m_timestamp_params;
+
+    /**
+     * @brief Element type of each `LIST` column.
+     *
+     * INVARIANT: every key is a `DTYPE_STR` column of the `Table` holding
+     * values encoded per `t_list_params`, and every update path must hand
+     * this to its loader so list input is encoded rather than flattened.
+     */
+    t_list_map m_list_params;
+
+    /**
+     * @brief Source Arrow type of each column, which `View::to_arrow` casts
+     * back to.
+     *
+     * INVARIANT: this is fixed at creation; updates of any format are read
+     * into the Perspective type of each column as usual, and only export
+     * consults it.
+     */
+    t_arrow_type_map m_arrow_types;
+
+    /**
+     * @brief How CSV updates are parsed by default.
+     *
+     * `column_types` only applies at creation; updates are always read as the
+     * `Table`'s own schema.
+     */
+    apachearrow::t_csv_options m_csv_options;
+
+    t_row_errors m_row_errors;
+    std::optional<t_bad_rows> m_on_bad_rows;
 };
 
 } // namespace perspective
\ No newline at end of file
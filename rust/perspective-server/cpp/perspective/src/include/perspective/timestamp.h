@@ -0,0 +1,104 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#pragma once
+#include <perspective/first.h>
+#include <perspective/exports.h>
+#include <arrow/type_fwd.h>
+#include <cstdint>
+#include <map>
+#include <optional>
+#include <string>
+#include <string_view>
+
+namespace perspective {
+
+/**
+ * @brief The resolution of a `DTYPE_TIME` column's values. Columns default to
+ * `TIMESTAMP_UNIT_MILLISECOND`, the resolution of `t_time` itself.
+ */
+enum t_timestamp_unit : std::uint8_t {
+    TIMESTAMP_UNIT_SECOND,
+    TIMESTAMP_UNIT_MILLISECOND,
+    TIMESTAMP_UNIT_MICROSECOND,
+    TIMESTAMP_UNIT_NANOSECOND
+};
+
+/**
+ * @brief The unit and IANA timezone (empty for none) of a `DTYPE_TIME`
+ * column. The timezone is metadata for display and export only - values are
+ * always UTC epoch offsets.
+ */
+struct PERSPECTIVE_EXPORT t_timestamp_params {
+    t_timestamp_unit unit;
+    std::string timezone;
+
+    bool operator==(const t_timestamp_params& other) const;
+};
+
+/**
+ * @brief The `DTYPE_TIME` columns of a `Table` or `View` with a unit other
+ * than milliseconds or with a timezone, by column name.
+ */
+using t_timestamp_map = std::map<std::string, t_timestamp_params>;
+
+/**
+ * @brief The number of `unit` ticks in one second.
+ */
+PERSPECTIVE_EXPORT std::int64_t
+timestamp_units_per_second(t_timestamp_unit unit);
+
+/**
+ * @brief Convert an epoch offset between units, flooring toward negative
+ * infinity when `to` is coarser than `from`.
+ */
+PERSPECTIVE_EXPORT std::int64_t convert_timestamp(
+    std::int64_t value, t_timestamp_unit from, t_timestamp_unit to
+);
+
+/**
+ * @brief Convert an epoch offset in `unit` to (possibly fractional)
+ * milliseconds, the unit of JSON output.
+ */
+PERSPECTIVE_EXPORT double
+timestamp_to_millis(double value, t_timestamp_unit unit);
+
+/**
+ * @brief Convert (possibly fractional) epoch milliseconds to `unit`, rounding
+ * to the nearest tick.
+ */
+PERSPECTIVE_EXPORT std::int64_t
+millis_to_timestamp(double millis, t_timestamp_unit unit);
+
+/**
+ * @brief Parse a datetime string to an epoch offset in `unit`, keeping as
+ * many fractional second digits as `unit` resolves.
+ *
+ * @return std::nullopt if `text` is not a recognized datetime.
+ */
+PERSPECTIVE_EXPORT std::optional<std::int64_t>
+parse_timestamp(std::string_view text, t_timestamp_unit unit);
+
+/**
+ * @brief The short name of `unit`, e.g. `"ns"`.
+ */
+PERSPECTIVE_EXPORT const char* timestamp_unit_name(t_timestamp_unit unit);
+
+/**
+ * @brief The Arrow `TimeUnit` of `unit`, and its inverse.
+ */
+PERSPECTIVE_EXPORT arrow::TimeUnit::type
+to_arrow_time_unit(t_timestamp_unit unit);
+PERSPECTIVE_EXPORT t_timestamp_unit
+from_arrow_time_unit(arrow::TimeUnit::type unit);
+
+} // namespace perspective
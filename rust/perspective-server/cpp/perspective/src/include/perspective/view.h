@@ -34,10 +34,20 @@
 
 namespace perspective {
 
+/**
+ * @brief Write `scalar` as JSON. The scalars of a `DECIMAL` column are written
+ * as their exact decimal text, e.g. `"12.50"`, when `decimal` is given, the
+ * scalars of a non-millisecond datetime column as (fractional) epoch
+ * milliseconds when `timestamp` is given, and the scalars of a `LIST` column
+ * as JSON arrays when `list` is given.
+ */
 void write_scalar(
     t_tscalar scalar,
     bool is_formatted,
-    rapidjson::Writer<rapidjson::StringBuffer>& writer
+    rapidjson::Writer<rapidjson::StringBuffer>& writer,
+    const t_decimal_params* decimal = nullptr,
+    const t_timestamp_params* timestamp = nullptr,
+    const t_list_params* list = nullptr
 );
 
 template <typename CTX_T>
@@ -151,6 +161,77 @@ public:
     std::pair<t_tscalar, t_tscalar> get_min_max(const std::string& colname
     ) const;
 
+    /**
+     * @brief The `DECIMAL` columns of this View, by column name. Aggregates
+     * which preserve a decimal column's scale stay `DECIMAL`, while the sums
+     * widen to `DECIMAL_MAX_PRECISION` digits, and reading this View fails if
+     * they overflow.
+     *
+     * @return const t_decimal_map&
+     */
+    const t_decimal_map& decimal_params() const;
+
+    /**
+     * @brief The `DECIMAL` params of `column`, or `nullptr`.
+     */
+    const t_decimal_params* get_decimal(const std::string& column) const;
+
+    /**
+     * @brief The `DECIMAL` params of row path level `depth`, or `nullptr`.
+     */
+    const t_decimal_params* get_row_path_decimal(t_uindex depth) const;
+
+    /**
+     * @brief The datetime columns of this View with a unit other than
+     * milliseconds or with a timezone, by column name. Aggregates which pick
+     * or average their input's values keep its unit and timezone.
+     *
+     * @return const t_timestamp_map&
+     */
+    const t_timestamp_map& timestamp_params() const;
+
+    /**
+     * @brief The datetime params of `column`, or `nullptr`.
+     */
+    const t_timestamp_params* get_timestamp(const std::string& column) const;
+
+    /**
+     * @brief The datetime params of row path level `depth`, or `nullptr`.
+     */
+    const t_timestamp_params* get_row_path_timestamp(t_uindex depth) const;
+
+    /**
+     * @brief The `LIST` columns of this View, by column name. Aggregates which
+     * pick one of their input's lists, and `union`, stay `LIST`.
+     *
+     * @return const t_list_map&
+     */
+    const t_list_map& list_params() const;
+
+    /**
+     * @brief The `LIST` params of `column`, or `nullptr`.
+     */
+    const t_list_params* get_list(const std::string& column) const;
+
+    /**
+     * @brief The `LIST` params of row path level `depth`, or `nullptr`.
+     */
+    const t_list_params* get_row_path_list(t_uindex depth) const;
+
+    /**
+     * @brief The source Arrow type `to_arrow` casts `column` back to, or
+     * `nullptr`. Only aggregates which pick one of their input's values keep
+     * their input's type; e.g. the `mean` of an `Int8` column is a float.
+     */
+    const std::shared_ptr<arrow::DataType>*
+    get_arrow_type(const std::string& column) const;
+
+    /**
+     * @brief The source Arrow type of row path level `depth`, or `nullptr`.
+     */
+    const std::shared_ptr<arrow::DataType>*
+    get_row_path_arrow_type(t_uindex depth) const;
+
     void write_row_path(
         t_uindex start_row,
         t_uindex end_row,
@@ -430,11 +511,36 @@ private:
         std::shared_ptr<arrow::RecordBatch>>
     data_slice_to_batches(
         bool emit_group_by, std::shared_ptr<t_data_slice<CTX_T>> data_slice,
-        bool emit_legacy_row_path_names = true
+        bool emit_legacy_row_path_names = true,
+        bool preserve_arrow_types = false
     ) const;
 
     void _find_hidden_sort(const std::vector<t_sortspec>& sort);
 
+    /**
+     * @brief Derive `m_decimal_params` from the `Table`'s decimal columns and
+     * this View's aggregates.
+     */
+    void _init_decimal_params();
+
+    /**
+     * @brief Derive `m_timestamp_params` from the `Table`'s datetime columns,
+     * this View's expressions and its aggregates.
+     */
+    void _init_timestamp_params();
+
+    /**
+     * @brief Derive `m_list_params` from the `Table`'s list columns and this
+     * View's aggregates.
+     */
+    void _init_list_params();
+
+    /**
+     * @brief Derive `m_arrow_types` from the `Table`'s source Arrow types and
+     * this View's aggregates.
+     */
+    void _init_arrow_types();
+
     std::shared_ptr<Table> m_table;
     std::shared_ptr<CTX_T> m_ctx;
     std::string m_name;
@@ -454,6 +560,15 @@ private:
 
     bool m_split_rollup;
 
+    t_decimal_map m_decimal_params;
+    t_decimal_map m_row_path_decimal_params;
+    t_timestamp_map m_timestamp_params;
+    t_timestamp_map m_row_path_timestamp_params;
+    t_list_map m_list_params;
+    t_list_map m_row_path_list_params;
+    t_arrow_type_map m_arrow_types;
+    t_arrow_type_map m_row_path_arrow_types;
+
     std::shared_ptr<t_view_config> m_view_config;
 };
 } // end namespace perspective
\ No newline at end of file
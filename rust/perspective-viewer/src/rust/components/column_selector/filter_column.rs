@@ -252,8 +252,10 @@ impl Component for FilterColumn {
         });
 
         let type_class = match col_type {
-            Some(ColumnType::Float) | Some(ColumnType::Integer) => "num-filter",
-            Some(ColumnType::String) => "string-filter",
+            Some(ColumnType::Float) | Some(ColumnType::Integer) | Some(ColumnType::Decimal) => {
+                "num-filter"
+            },
+            Some(ColumnType::String) | Some(ColumnType::List) => "string-filter",
             _ => "",
         };
 
@@ -281,6 +283,18 @@ impl Component for FilterColumn {
                     oninput={input}
                 />
             },
+            Some(ColumnType::Decimal) => html! {
+                <input
+                    type="text"
+                    inputmode="decimal"
+                    placeholder="Value"
+                    class="num-filter"
+                    ref={noderef.clone()}
+                    onkeydown={keydown}
+                    value={self.input.clone()}
+                    oninput={input}
+                />
+            },
             Some(ColumnType::String) => html! {
                 <input
                     type="search"
@@ -298,6 +312,18 @@ impl Component for FilterColumn {
                     oninput={input}
                 />
             },
+            Some(ColumnType::List) => html! {
+                <input
+                    type="text"
+                    placeholder="Element"
+                    class="string-filter"
+                    spellcheck="false"
+                    ref={noderef.clone()}
+                    onkeydown={keydown}
+                    value={self.input.clone()}
+                    oninput={input}
+                />
+            },
             Some(ColumnType::Date) => html! {
                 <input
                     type="date"
@@ -489,6 +515,25 @@ impl FilterColumnProps {
                         None
                     }
                 },
+                Some(ColumnType::Decimal) => {
+                    // Sent as text so the engine can compare it exactly.
+                    let val = val.trim();
+                    if val.is_empty() || val.parse::<f64>().is_err() {
+                        None
+                    } else {
+                        Some(FilterTerm::Scalar(Scalar::String(val.to_owned())))
+                    }
+                },
+                Some(ColumnType::List) => {
+                    // A single element, which the engine parses as the list's
+                    // element type.
+                    let val = val.trim();
+                    if val.is_empty() {
+                        None
+                    } else {
+                        Some(FilterTerm::Scalar(Scalar::String(val.to_owned())))
+                    }
+                },
                 Some(ColumnType::Date) => match NaiveDate::parse_from_str(&val, "%Y-%m-%d") {
                     Ok(ref posix) => Some(FilterTerm::Scalar(Scalar::String(format!(
                         "{:0>4}-{:0>2}-{:0>2}",
@@ -161,10 +161,14 @@ pub fn StyleTab(props: &StyleTabProps) -> Html {
                     ControlSpec::DatetimeFormat => {
                         let config: Option<DatetimeColumnStyleConfig> = deser_sub(&raw_config);
                         let enable_time_config = props.ty.unwrap() == ColumnType::Datetime;
+                        let source_time_zone =
+                            props.metadata.get_column_time_zone(&props.column_name);
+
                         html! {
                             <DatetimeColumnStyle
                                 {enable_time_config}
                                 {config}
+                                {source_time_zone}
                                 on_change={on_change.clone()}
                                 keys={keys.clone()}
                             />
@@ -44,6 +44,11 @@ pub struct DatetimeColumnStyleProps {
     #[prop_or_default]
     pub keys: Vec<String>,
 
+    /// The timezone the column was declared with on the `Table`, used in
+    /// place of the browser's timezone when no override is configured.
+    #[prop_or_default]
+    pub source_time_zone: Option<String>,
+
     #[prop_or_default]
     #[derivative(Debug = "ignore")]
     weak_link: WeakScope<DatetimeColumnStyle>,
@@ -57,7 +62,15 @@ impl ModalLink<DatetimeColumnStyle> for DatetimeColumnStyleProps {
 
 impl PartialEq for DatetimeColumnStyleProps {
     fn eq(&self, other: &Self) -> bool {
-        self.enable_time_config == other.enable_time_config && self.config == other.config
+        self.enable_time_config == other.enable_time_config
+            && self.config == other.config
+            && self.source_time_zone == other.source_time_zone
+    }
+}
+
+impl DatetimeColumnStyleProps {
+    fn default_time_zone(&self) -> &String {
+        self.source_time_zone.as_ref().unwrap_or(&*USER_TIMEZONE)
     }
 }
 
@@ -90,7 +103,9 @@ impl Component for DatetimeColumnStyle {
             std::mem::swap(&mut self.config, &mut new_config);
             rerender = true;
         }
-        if old.enable_time_config != ctx.props().enable_time_config {
+        if old.enable_time_config != ctx.props().enable_time_config
+            || old.source_time_zone != ctx.props().source_time_zone
+        {
             rerender = true;
         }
         rerender
@@ -99,7 +114,7 @@ impl Component for DatetimeColumnStyle {
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             DatetimeColumnStyleMsg::TimezoneChanged(val) => {
-                if Some(&*USER_TIMEZONE) != val.as_ref() {
+                if Some(ctx.props().default_time_zone()) != val.as_ref() {
                     *self.config.date_format.time_zone_mut() = val;
                 } else {
                     *self.config.date_format.time_zone_mut() = None;
@@ -129,9 +144,9 @@ impl Component for DatetimeColumnStyle {
                         <SelectValueField<String>
                             label="timezone"
                             values={ALL_TIMEZONES.with(|x| (*x).clone())}
-                            default_value={(*USER_TIMEZONE).clone()}
+                            default_value={ctx.props().default_time_zone().clone()}
                             on_change={ctx.link().callback(DatetimeColumnStyleMsg::TimezoneChanged)}
-                            current_value={self.config.date_format.time_zone().as_ref().unwrap_or(&*USER_TIMEZONE).clone()}
+                            current_value={self.config.date_format.time_zone().as_ref().unwrap_or(ctx.props().default_time_zone()).clone()}
                         />
                     }
                     if let DatetimeFormatType::Simple(config) = &self.config.date_format {
@@ -72,6 +72,7 @@ pub struct CustomDatetimeStyleConfig {
     format: FormatUnit,
 
     /// An IANA time zone name (e.g. `"America/New_York"`); defaults to the
+    /// column's source time zone if the `Table` declares one, otherwise the
     /// browser's local time zone.
     #[serde(default)]
     #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
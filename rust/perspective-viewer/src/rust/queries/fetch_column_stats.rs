@@ -29,6 +29,7 @@ use crate::session::{Session, SessionMetadata};
 fn scalar_to_f64(s: &Scalar) -> Option<f64> {
     match s {
         Scalar::Float(x) => Some(*x),
+        Scalar::Decimal(x) => x.parse().ok(),
         _ => None,
     }
 }
@@ -41,13 +42,13 @@ pub fn min_max_to_abs_max(min: &Scalar, max: &Scalar) -> Option<f64> {
     Some(min.abs().max(max.abs()))
 }
 
-/// `true` if the column's view type is `Integer` or `Float`. Used to
+/// `true` if the column's view type is `Integer`, `Float` or `Decimal`. Used to
 /// skip the `View::get_min_max` round trip for non-numeric columns
 /// (which would either error or return non-`Float` scalars).
 pub fn is_numeric_column(metadata: &SessionMetadata, col_name: &str) -> bool {
     matches!(
         metadata.get_column_view_type(col_name),
-        Some(ColumnType::Integer | ColumnType::Float)
+        Some(ColumnType::Integer | ColumnType::Float | ColumnType::Decimal)
     )
 }
 
@@ -143,6 +143,42 @@ impl Renderer {
             }
         }
 
+        // Datetime columns declared with a source timezone render in that
+        // zone unless the user has picked one explicitly.
+        let metadata = session.metadata().clone();
+        for col in view_config.columns.iter().flatten() {
+            let Some(time_zone) = metadata.get_column_time_zone(col) else {
+                continue;
+            };
+
+            let current = configs.get(col).cloned().unwrap_or_default();
+            let Ok(schema) =
+                self.query_column_config_schema(view_config, session, col, Some(&current))
+            else {
+                continue;
+            };
+
+            if !schema
+                .fields
+                .iter()
+                .any(|f| matches!(f, ControlSpec::DatetimeFormat))
+            {
+                continue;
+            }
+
+            let date_format = configs
+                .entry(col.clone())
+                .or_default()
+                .entry("date_format")
+                .or_insert_with(|| Value::Object(Default::default()));
+
+            if let Value::Object(date_format) = date_format {
+                date_format
+                    .entry("timeZone")
+                    .or_insert(Value::String(time_zone));
+            }
+        }
+
         configs
     }
 
@@ -66,6 +66,7 @@ pub struct SessionMetadataState {
     features: perspective_client::Features,
     column_names: Vec<String>,
     table_schema: HashMap<String, ColumnType>,
+    table_params: HashMap<String, ColumnTypeParams>,
     edit_port: f64,
     view_schema: Option<HashMap<String, ColumnType>>,
     expr_meta: Option<SessionViewExpressionMetadata>,
@@ -78,11 +79,13 @@ impl SessionMetadata {
         let features = table.get_features().await?.clone();
         let column_names = table.columns().await?;
         let table_schema = table.schema().await?;
+        let table_params = table.schema_params().await?;
         let edit_port = table.make_port().await? as f64;
         Ok(Self(Some(SessionMetadataState {
             features,
             column_names,
             table_schema,
+            table_params,
             edit_port,
             ..SessionMetadataState::default()
         })))
@@ -317,6 +320,13 @@ impl SessionMetadata {
         }
     }
 
+    /// The source timezone of a `Table` datetime column, if the column was
+    /// declared with one. Values are always stored as UTC; this is the zone
+    /// the viewer should default to when rendering them.
+    pub fn get_column_time_zone(&self, name: &str) -> Option<String> {
+        self.as_ref()?.table_params.get(name)?.timezone.clone()
+    }
+
     /// Returns the type of a column name relative to the `View`, including
     /// expression columns which were part of the `ViewConfig`.  Types
     /// returned from the `View` incorporate the type transform applied by
@@ -0,0 +1,119 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+
+    use futures::TryStreamExt;
+    use perspective_client::config::ViewConfigUpdate;
+    use perspective_client::{Client, TableInitOptions, UpdateData, View, ViewWindow};
+    use perspective_server::LocalClient;
+
+    /// Loads each page of `view` into its own table, returning the row count
+    /// and `x` column of every page.
+    async fn read_pages(
+        client: &Client,
+        view: &View,
+        window: ViewWindow,
+        chunk_rows: u32,
+    ) -> Result<Vec<(usize, String)>, Box<dyn Error>> {
+        let pages = view
+            .to_arrow_stream(window, chunk_rows)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut results = vec![];
+        for arrow in pages {
+            let table = client
+                .table(UpdateData::Arrow(arrow).into(), TableInitOptions::default())
+                .await?;
+
+            let view = table
+                .view(Some(ViewConfigUpdate {
+                    columns: Some(vec![Some("x".to_owned())]),
+                    ..ViewConfigUpdate::default()
+                }))
+                .await?;
+
+            let columns = view.to_columns_string(ViewWindow::default()).await?;
+            results.push((table.size().await?, columns));
+        }
+
+        Ok(results)
+    }
+
+    #[tokio::test]
+    async fn test_to_arrow_stream_pages() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x\n1\n2\n3\n4\n5\n".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let pages = read_pages(&client, &view, ViewWindow::default(), 2).await?;
+        assert_eq!(pages, vec![
+            (2, r#"{"x":[1,2]}"#.to_owned()),
+            (2, r#"{"x":[3,4]}"#.to_owned()),
+            (1, r#"{"x":[5]}"#.to_owned()),
+        ]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_to_arrow_stream_window() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x\n1\n2\n3\n4\n5\n6\n".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let window = ViewWindow {
+            start_row: Some(1.0),
+            end_row: Some(5.0),
+            ..ViewWindow::default()
+        };
+
+        // A page size dividing the window evenly has no trailing empty page.
+        let pages = read_pages(&client, &view, window, 2).await?;
+        assert_eq!(pages, vec![
+            (2, r#"{"x":[2,3]}"#.to_owned()),
+            (2, r#"{"x":[4,5]}"#.to_owned()),
+        ]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_to_arrow_stream_empty_view() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(UpdateData::Csv("x\n1\n".to_owned()).into(), TableInitOptions::default())
+            .await?;
+
+        table.clear().await?;
+        let view = table.view(None).await?;
+        let pages = read_pages(&client, &view, ViewWindow::default(), 2).await?;
+        assert!(pages.is_empty());
+        Ok(())
+    }
+}
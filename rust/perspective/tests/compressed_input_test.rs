@@ -0,0 +1,181 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use perspective_client::proto::request::ClientReq;
+    use perspective_client::proto::response::ClientResp;
+    use perspective_client::proto::{BatchReq, BatchResp, Request, Response, TableUpdateReq};
+    use perspective_client::{
+        Session, TableInitOptions, TableReadFormat, UpdateData, UpdateOptions,
+    };
+    use perspective_server::LocalClient;
+    use prost::Message;
+
+    fn gzip(data: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(data.as_bytes())?;
+        Ok(encoder.finish()?)
+    }
+
+    #[tokio::test]
+    async fn test_gzip_csv_table_and_zstd_ndjson_update() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let csv = gzip("x,y\n1,a\n2,b")?;
+        assert!(UpdateData::is_compressed(&csv));
+        let table = client
+            .table(
+                UpdateData::Compressed {
+                    format: TableReadFormat::Csv,
+                    data: csv.into(),
+                }
+                .into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let ndjson = zstd::encode_all(r#"{"x": 3, "y": "c"}"#.as_bytes(), 3)?;
+        assert!(UpdateData::is_compressed(&ndjson));
+        table
+            .update(
+                UpdateData::Compressed {
+                    format: TableReadFormat::Ndjson,
+                    data: ndjson.into(),
+                },
+                UpdateOptions::default(),
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let json = view.to_columns_string(Default::default()).await?;
+        assert_eq!(json, r#"{"x":[1,2,3],"y":["a","b","c"]}"#);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_uncompressed_payload_is_rejected() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        assert!(!UpdateData::is_compressed(b"x\n1"));
+        let result = client
+            .table(
+                UpdateData::Compressed {
+                    format: TableReadFormat::Csv,
+                    data: b"x\n1".to_vec().into(),
+                }
+                .into(),
+                TableInitOptions::default(),
+            )
+            .await;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_oversized_declared_size_is_rejected() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+
+        // Forge the gzip `ISIZE` trailer to declare a ~4GB payload.
+        let mut csv = gzip("x\n1")?;
+        let len = csv.len();
+        csv[len - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+        let result = client
+            .table(
+                UpdateData::Compressed {
+                    format: TableReadFormat::Csv,
+                    data: csv.into(),
+                }
+                .into(),
+                TableInitOptions::default(),
+            )
+            .await;
+
+        let message = result.err().ok_or("expected an error")?.to_string();
+        assert!(message.contains("maximum decompressed size"), "{message}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compressed_update_in_batch() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x\n1\n2".to_owned()).into(),
+                TableInitOptions {
+                    name: Some("table".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        let responses: Arc<Mutex<Vec<Response>>> = Arc::default();
+        let session = server
+            .new_session_with_callback({
+                let responses = responses.clone();
+                move |msg| {
+                    let responses = responses.clone();
+                    Box::pin(async move {
+                        responses.lock().unwrap().push(Response::decode(msg)?);
+                        Ok(())
+                    })
+                }
+            })
+            .await;
+
+        let update = UpdateData::Compressed {
+            format: TableReadFormat::Csv,
+            data: gzip("x\n3")?.into(),
+        };
+
+        let batch = Request {
+            msg_id: 1,
+            entity_id: "".to_owned(),
+            client_req: Some(ClientReq::BatchReq(BatchReq {
+                requests: vec![Request {
+                    msg_id: 2,
+                    entity_id: "table".to_owned(),
+                    client_req: Some(ClientReq::TableUpdateReq(TableUpdateReq {
+                        data: Some(update.try_into()?),
+                        ..TableUpdateReq::default()
+                    })),
+                }],
+            })),
+        };
+
+        session.handle_request(&batch.encode_to_vec()).await?;
+        let resp = responses
+            .lock()
+            .unwrap()
+            .iter()
+            .find_map(|resp| match &resp.client_resp {
+                Some(ClientResp::BatchResp(BatchResp { responses })) => Some(responses.clone()),
+                _ => None,
+            })
+            .ok_or("expected a `BatchResp`")?;
+
+        assert!(
+            matches!(resp[0].client_resp, Some(ClientResp::TableUpdateResp(_))),
+            "{:?}",
+            resp[0]
+        );
+
+        assert_eq!(table.size().await?, 3);
+        Ok(())
+    }
+}
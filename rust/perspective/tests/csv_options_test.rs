@@ -0,0 +1,159 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use perspective_client::config::{ColumnType, ViewConfigUpdate};
+    use perspective_client::{
+        CsvOptions, Table, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+    };
+    use perspective_server::LocalClient;
+
+    async fn to_columns(table: &Table, columns: &[&str]) -> Result<String, Box<dyn Error>> {
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(columns.iter().map(|x| Some((*x).to_owned())).collect()),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        Ok(view.to_columns_string(ViewWindow::default()).await?)
+    }
+
+    #[tokio::test]
+    async fn test_csv_options_tsv_quote_and_nulls() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let csv = "x\ty\n1\t'a\tb'\nNA\t-\n";
+        let table = client
+            .table(UpdateData::Csv(csv.to_owned()).into(), TableInitOptions {
+                csv: Some(CsvOptions {
+                    delimiter: Some('\t'),
+                    quote: Some('\''),
+                    null_values: Some(vec!["NA".to_owned(), "-".to_owned()]),
+                    ..CsvOptions::default()
+                }),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        let schema = table.schema().await?;
+        assert_eq!(schema["x"], ColumnType::Integer);
+        assert_eq!(schema["y"], ColumnType::String);
+        assert_eq!(
+            to_columns(&table, &["x", "y"]).await?,
+            r#"{"x":[1,null],"y":["a\tb",null]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_csv_options_headerless_update() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(UpdateData::Csv("1|a\n2|b\n".to_owned()).into(), TableInitOptions {
+                index: Some("x".to_owned()),
+                csv: Some(CsvOptions {
+                    delimiter: Some('|'),
+                    column_names: Some(vec!["x".to_owned(), "y".to_owned()]),
+                    ..CsvOptions::default()
+                }),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        assert_eq!(table.size().await?, 2);
+
+        // Updates are parsed with the options the `Table` was created with.
+        table
+            .update(UpdateData::Csv("2|c\n".to_owned()), UpdateOptions::default())
+            .await?;
+
+        assert_eq!(
+            to_columns(&table, &["x", "y"]).await?,
+            r#"{"x":[1,2],"y":["a","c"]}"#
+        );
+
+        // .. unless the update supplies its own.
+        table
+            .update(UpdateData::Csv("y;x\nd;1\n".to_owned()), UpdateOptions {
+                csv: Some(CsvOptions {
+                    delimiter: Some(';'),
+                    ..CsvOptions::default()
+                }),
+                ..UpdateOptions::default()
+            })
+            .await?;
+
+        assert_eq!(
+            to_columns(&table, &["x", "y"]).await?,
+            r#"{"x":[1,2],"y":["d","c"]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_csv_options_column_types_and_formats() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let csv = "code,day,at\n007,25/12/2024,25/12/2024 10:30\n";
+        let table = client
+            .table(UpdateData::Csv(csv.to_owned()).into(), TableInitOptions {
+                csv: Some(CsvOptions {
+                    column_types: Some(HashMap::from([
+                        ("code".to_owned(), ColumnType::String),
+                        ("day".to_owned(), ColumnType::Date),
+                    ])),
+                    date_format: Some("%d/%m/%Y".to_owned()),
+                    datetime_format: Some("%d/%m/%Y %H:%M".to_owned()),
+                    ..CsvOptions::default()
+                }),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        let schema = table.schema().await?;
+        assert_eq!(schema["code"], ColumnType::String);
+        assert_eq!(schema["day"], ColumnType::Date);
+        assert_eq!(schema["at"], ColumnType::Datetime);
+        assert_eq!(
+            to_columns(&table, &["code", "day", "at"]).await?,
+            r#"{"code":["007"],"day":[1735084800000],"at":[1735122600000]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_csv_options_unsupported_column_type() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let result = client
+            .table(UpdateData::Csv("x\n1.5\n".to_owned()).into(), TableInitOptions {
+                csv: Some(CsvOptions {
+                    column_types: Some(HashMap::from([("x".to_owned(), ColumnType::Decimal)])),
+                    ..CsvOptions::default()
+                }),
+                ..TableInitOptions::default()
+            })
+            .await;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use perspective_client::config::{
+        ColumnType, ColumnTypeParams, Filter, FilterOp, Scalar, ViewConfigUpdate,
+    };
+    use perspective_client::{
+        Client, Table, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+    };
+    use perspective_server::LocalClient;
+
+    async fn make_table(client: &Client) -> Result<Table, Box<dyn Error>> {
+        let table = client
+            .table(
+                TableData::SchemaWithParams(vec![
+                    ("g".to_owned(), ColumnType::String, None),
+                    (
+                        "x".to_owned(),
+                        ColumnType::Decimal,
+                        Some(ColumnTypeParams::decimal(10, 2)),
+                    ),
+                ]),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let rows = r#"[{"g":"a","x":"0.10"},{"g":"a","x":0.2},{"g":"b","x":"1.005"}]"#;
+        table
+            .update(UpdateData::JsonRows(rows.to_owned()), UpdateOptions::default())
+            .await?;
+
+        Ok(table)
+    }
+
+    #[tokio::test]
+    async fn test_decimal_schema_params() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        assert_eq!(table.schema().await?["x"], ColumnType::Decimal);
+        assert_eq!(
+            table.schema_params().await?.get("x"),
+            Some(&ColumnTypeParams::decimal(10, 2))
+        );
+
+        let view = table.view(None).await?;
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, r#"{"g":["a","a","b"],"x":["0.10","0.20","1.01"]}"#);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decimal_sum_is_exact() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                group_by: Some(vec!["g".to_owned()]),
+                columns: Some(vec![Some("x".to_owned())]),
+                aggregates: Some(HashMap::from([("x".to_owned(), "sum".into())])),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        assert_eq!(
+            view.schema_params().await?.get("x"),
+            Some(&ColumnTypeParams::decimal(18, 2))
+        );
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(
+            json,
+            r#"{"__ROW_PATH__":[[],["a"],["b"]],"x":["1.31","0.30","1.01"]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decimal_filter() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(vec![Some("x".to_owned())]),
+                filter: Some(vec![Filter::new(
+                    "x",
+                    FilterOp::Gt,
+                    Scalar::Decimal("0.15".to_owned()),
+                )]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, r#"{"x":["0.20","1.01"]}"#);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decimal_overflow_is_rejected() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let rows = r#"[{"g":"c","x":"123456789.00"}]"#;
+        let result = table
+            .update(UpdateData::JsonRows(rows.to_owned()), UpdateOptions::default())
+            .await;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decimal128_arrow_is_opt_in() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let view = table.view(None).await?;
+        let arrow = view.to_arrow(ViewWindow::default()).await?;
+        let float = client
+            .table(UpdateData::Arrow(arrow.clone()).into(), TableInitOptions::default())
+            .await?;
+
+        assert_eq!(float.schema().await?["x"], ColumnType::Float);
+        let exact = client
+            .table(UpdateData::Arrow(arrow).into(), TableInitOptions {
+                preserve_arrow_types: Some(true),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        assert_eq!(exact.schema().await?["x"], ColumnType::Decimal);
+        assert_eq!(
+            exact.schema_params().await?.get("x"),
+            Some(&ColumnTypeParams::decimal(10, 2))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decimal_sum_overflow_is_reported() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                TableData::SchemaWithParams(vec![
+                    ("g".to_owned(), ColumnType::String, None),
+                    (
+                        "x".to_owned(),
+                        ColumnType::Decimal,
+                        Some(ColumnTypeParams::decimal(18, 0)),
+                    ),
+                ]),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let row = r#"{"g":"a","x":"999999999999999999"}"#;
+        let rows = format!("[{}]", vec![row; 10].join(","));
+        table
+            .update(UpdateData::JsonRows(rows), UpdateOptions::default())
+            .await?;
+
+        let rows = r#"[{"g":"b","x":"1"}]"#;
+        table
+            .update(UpdateData::JsonRows(rows.to_owned()), UpdateOptions::default())
+            .await?;
+
+        let config = |filter| ViewConfigUpdate {
+            group_by: Some(vec!["g".to_owned()]),
+            columns: Some(vec![Some("x".to_owned())]),
+            aggregates: Some(HashMap::from([("x".to_owned(), "sum".into())])),
+            filter,
+            ..ViewConfigUpdate::default()
+        };
+
+        let view = table.view(Some(config(None))).await?;
+        let result = view.to_columns_string(ViewWindow::default()).await;
+        let message = result.err().ok_or("expected an error")?.to_string();
+        assert!(message.contains("overflows DECIMAL(18, 0)"), "{message}");
+
+        // Only the filtered rows are summed.
+        let filter = vec![Filter::new("g", FilterOp::Eq, Scalar::String("b".to_owned()))];
+        let filtered = table.view(Some(config(Some(filter)))).await?;
+        let json = filtered.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, r#"{"__ROW_PATH__":[[],["b"]],"x":["1","1"]}"#);
+
+        // A sum which fits again once values cancel out is exact.
+        let rows = r#"[{"g":"a","x":"-999999999999999999"}]"#;
+        table
+            .update(UpdateData::JsonRows(rows.to_owned()), UpdateOptions::default())
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        let expected = r#"{"__ROW_PATH__":[[],["a"],["b"]],"#.to_owned()
+            + r#""x":["8999999999999999992","8999999999999999991","1"]}"#;
+        assert_eq!(json, expected);
+
+        Ok(())
+    }
+}
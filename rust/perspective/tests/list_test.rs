@@ -0,0 +1,195 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use perspective_client::config::{
+        ColumnType, ColumnTypeParams, Filter, FilterOp, Scalar, ViewConfigUpdate,
+    };
+    use perspective_client::proto::ListFlatten;
+    use perspective_client::{
+        Client, Table, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+    };
+    use perspective_server::LocalClient;
+
+    async fn make_table(client: &Client) -> Result<Table, Box<dyn Error>> {
+        let table = client
+            .table(
+                TableData::SchemaWithParams(vec![
+                    ("g".to_owned(), ColumnType::String, None),
+                    (
+                        "tags".to_owned(),
+                        ColumnType::List,
+                        Some(ColumnTypeParams::list(ColumnType::String)),
+                    ),
+                    (
+                        "n".to_owned(),
+                        ColumnType::List,
+                        Some(ColumnTypeParams::list(ColumnType::Integer)),
+                    ),
+                ]),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let rows = r#"[
+            {"g":"a","tags":["x","y"],"n":[1,2]},
+            {"g":"a","tags":["y","z"],"n":[3]},
+            {"g":"b","tags":[],"n":null}
+        ]"#;
+
+        table
+            .update(UpdateData::JsonRows(rows.to_owned()), UpdateOptions::default())
+            .await?;
+
+        Ok(table)
+    }
+
+    #[tokio::test]
+    async fn test_list_schema_params() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        assert_eq!(table.schema().await?["tags"], ColumnType::List);
+        assert_eq!(
+            table.schema_params().await?.get("n"),
+            Some(&ColumnTypeParams::list(ColumnType::Integer))
+        );
+
+        let view = table.view(None).await?;
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(
+            json,
+            r#"{"g":["a","a","b"],"tags":[["x","y"],["y","z"],[]],"n":[[1,2],[3],null]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_contains_filter() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(vec![Some("n".to_owned())]),
+                filter: Some(vec![Filter::new(
+                    "tags",
+                    FilterOp::Contains,
+                    Scalar::String("y".to_owned()),
+                )]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, r#"{"n":[[1,2],[3]]}"#);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_in_filter() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(vec![Some("tags".to_owned())]),
+                filter: Some(vec![Filter::new("n", FilterOp::In, ["2", "7"])]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, r#"{"tags":[["x","y"]]}"#);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_union_aggregate() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                group_by: Some(vec!["g".to_owned()]),
+                columns: Some(vec![Some("tags".to_owned())]),
+                aggregates: Some(HashMap::from([("tags".to_owned(), "union".into())])),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        assert_eq!(
+            view.schema_params().await?.get("tags"),
+            Some(&ColumnTypeParams::list(ColumnType::String))
+        );
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(
+            json,
+            r#"{"__ROW_PATH__":[[],["a"],["b"]],"tags":[["x","y","z"],["x","y","z"],[]]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_union_rejects_scalars() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let result = table
+            .view(Some(ViewConfigUpdate {
+                group_by: Some(vec!["g".to_owned()]),
+                columns: Some(vec![Some("g".to_owned())]),
+                aggregates: Some(HashMap::from([("g".to_owned(), "union".into())])),
+                ..ViewConfigUpdate::default()
+            }))
+            .await;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_arrow_round_trip() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let source = make_table(&client).await?;
+        let view = source.view(None).await?;
+        let arrow = view.to_arrow(ViewWindow::default()).await?;
+        let table = client
+            .table(UpdateData::Arrow(arrow).into(), TableInitOptions {
+                list_flatten: Some(ListFlatten::Preserve),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        assert_eq!(
+            table.schema_params().await?.get("tags"),
+            Some(&ColumnTypeParams::list(ColumnType::String))
+        );
+
+        let view = table.view(None).await?;
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(
+            json,
+            r#"{"g":["a","a","b"],"tags":[["x","y"],["y","z"],[]],"n":[[1,2],[3],null]}"#
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use perspective_client::config::{ColumnType, ViewConfigUpdate};
+    use perspective_client::{
+        ClientError, OnBadRows, Table, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+    };
+    use perspective_server::LocalClient;
+
+    async fn to_columns(table: &Table, columns: &[&str]) -> Result<String, Box<dyn Error>> {
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(columns.iter().map(|x| Some((*x).to_owned())).collect()),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        Ok(view.to_columns_string(ViewWindow::default()).await?)
+    }
+
+    fn int_hint(column: &str) -> Option<HashMap<String, ColumnType>> {
+        Some(HashMap::from([(column.to_owned(), ColumnType::Integer)]))
+    }
+
+    #[tokio::test]
+    async fn test_on_bad_rows_skip_at_creation() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let json = r#"[{"x":"1","y":"a"},{"x":"two","y":"b"},{"x":"3","y":"c"}]"#;
+        let table = client
+            .table(UpdateData::JsonRows(json.to_owned()).into(), TableInitOptions {
+                schema_hints: int_hint("x"),
+                on_bad_rows: Some(OnBadRows::Skip),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        assert_eq!(table.size().await?, 2);
+        let errors = table.row_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].row, errors[0].value.as_str()), (1, "two"));
+
+        // Skipped rows leave no gap in the implicit index, so later rows append.
+        let errors = table
+            .update(
+                UpdateData::JsonRows(r#"[{"x":4,"y":"d"}]"#.to_owned()),
+                UpdateOptions::default(),
+            )
+            .await?;
+
+        assert!(errors.is_empty());
+        assert_eq!(table.row_errors().len(), 1);
+        assert_eq!(
+            to_columns(&table, &["x", "y"]).await?,
+            r#"{"x":[1,3,4],"y":["a","c","d"]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_on_bad_rows_fail_at_creation() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let result = client
+            .table(UpdateData::Csv("x\n1\nabc\n".to_owned()).into(), TableInitOptions {
+                schema_hints: int_hint("x"),
+                on_bad_rows: Some(OnBadRows::Fail),
+                ..TableInitOptions::default()
+            })
+            .await;
+
+        match result {
+            Err(ClientError::IngestionError { row_errors, .. }) => {
+                assert_eq!(row_errors.len(), 1);
+                assert_eq!(row_errors[0].row, 1);
+                assert_eq!(row_errors[0].column, "x");
+                assert_eq!(row_errors[0].value, "abc");
+            },
+            Err(e) => panic!("Unexpected error {e}"),
+            Ok(_) => panic!("Table created from a bad row"),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_on_bad_rows_null_on_update() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let errors = table
+            .update_with_errors(UpdateData::Csv("x,y\n2,b\nzz,c\n".to_owned()), UpdateOptions {
+                on_bad_rows: Some(OnBadRows::Null),
+                ..UpdateOptions::default()
+            })
+            .await?;
+
+        assert_eq!(
+            to_columns(&table, &["x", "y"]).await?,
+            r#"{"x":[1,2,null],"y":["a","b","c"]}"#
+        );
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].row, 1);
+        assert_eq!(errors[0].column, "x");
+        assert_eq!(errors[0].value, "zz");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_on_bad_rows_skip_on_indexed_csv_update() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(UpdateData::Csv("id,x\n1,10\n".to_owned()).into(), TableInitOptions {
+                index: Some("id".to_owned()),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        let errors = table
+            .update_with_errors(UpdateData::Csv("id,x\n2,20\n3,oops\n".to_owned()), UpdateOptions {
+                on_bad_rows: Some(OnBadRows::Skip),
+                ..UpdateOptions::default()
+            })
+            .await?;
+
+        assert_eq!(
+            to_columns(&table, &["id", "x"]).await?,
+            r#"{"id":[1,2],"x":[10,20]}"#
+        );
+
+        assert_eq!(errors.len(), 1);
+        assert!(table.row_errors().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+    use std::sync::Arc;
+
+    use perspective_client::config::{Filter, FilterOp, Scalar, ViewConfigUpdate};
+    use perspective_client::{
+        Client, OnUpdateDiff, OnUpdateMode, OnUpdateOptions, TableInitOptions, UpdateData,
+        UpdateOptions, View, ViewWindow,
+    };
+    use perspective_server::LocalClient;
+    use tokio::sync::Mutex;
+
+    /// Collects the diff of every update to `view`.
+    async fn on_update_diffs(
+        view: &View,
+    ) -> Result<Arc<Mutex<Vec<OnUpdateDiff>>>, Box<dyn Error>> {
+        let diffs = Arc::new(Mutex::new(vec![]));
+        view.on_update(
+            {
+                let diffs = diffs.clone();
+                move |update| {
+                    let diffs = diffs.clone();
+                    async move { diffs.lock().await.extend(update.diff()) }
+                }
+            },
+            OnUpdateOptions {
+                mode: Some(OnUpdateMode::Diff),
+            },
+        )
+        .await?;
+
+        Ok(diffs)
+    }
+
+    async fn arrow_columns(client: &Client, arrow: Vec<u8>) -> Result<String, Box<dyn Error>> {
+        let table = client
+            .table(UpdateData::Arrow(arrow.into()).into(), TableInitOptions::default())
+            .await?;
+
+        let view = table.view(None).await?;
+        Ok(view.to_columns_string(ViewWindow::default()).await?)
+    }
+
+    fn keys(keys: &[&str]) -> Vec<Vec<Scalar>> {
+        keys.iter()
+            .map(|key| vec![Scalar::String(key.to_string())])
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_on_update_diff() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("id,v\na,1\nb,2\nc,3".to_owned()).into(),
+                TableInitOptions {
+                    index: Some("id".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let diffs = on_update_diffs(&view).await?;
+        table
+            .update(
+                UpdateData::Csv("id,v\nb,20\nd,4".to_owned()),
+                UpdateOptions::default(),
+            )
+            .await?;
+
+        table
+            .remove(UpdateData::JsonRows(r#"[{"id": "c"}]"#.to_owned()))
+            .await?;
+
+        let diffs = diffs.lock().await.clone();
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].added_keys, keys(&["d"]));
+        assert_eq!(diffs[0].updated_keys, keys(&["b"]));
+        assert!(diffs[0].removed_keys.is_empty());
+        assert_eq!(
+            arrow_columns(&client, diffs[0].added.clone()).await?,
+            r#"{"id":["d"],"v":[4]}"#
+        );
+        assert_eq!(
+            arrow_columns(&client, diffs[0].updated.clone()).await?,
+            r#"{"id":["b"],"v":[20]}"#
+        );
+
+        assert!(diffs[1].added_keys.is_empty());
+        assert!(diffs[1].updated_keys.is_empty());
+        assert_eq!(diffs[1].removed_keys, keys(&["c"]));
+        assert_eq!(
+            arrow_columns(&client, diffs[1].removed.clone()).await?,
+            r#"{"id":["c"],"v":[3]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_on_update_diff_filter() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("id,v\na,1\nb,2\nc,3".to_owned()).into(),
+                TableInitOptions {
+                    index: Some("id".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                filter: Some(vec![Filter::new("v", FilterOp::GtEq, Scalar::Float(2.0))]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let diffs = on_update_diffs(&view).await?;
+
+        // `a` enters the filter, `b` leaves it and `e` is never in the view.
+        table
+            .update(
+                UpdateData::Csv("id,v\na,5\nb,0\ne,0".to_owned()),
+                UpdateOptions::default(),
+            )
+            .await?;
+
+        let diffs = diffs.lock().await.clone();
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].added_keys, keys(&["a"]));
+        assert!(diffs[0].updated_keys.is_empty());
+        assert_eq!(diffs[0].removed_keys, keys(&["b"]));
+        assert_eq!(
+            arrow_columns(&client, diffs[0].removed.clone()).await?,
+            r#"{"id":["b"],"v":[2]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_on_update_diff_replace() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("id,v\na,1\nb,2\nc,3".to_owned()).into(),
+                TableInitOptions {
+                    index: Some("id".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let diffs = on_update_diffs(&view).await?;
+        table
+            .replace(UpdateData::Csv("id,v\na,10\nd,4".to_owned()))
+            .await?;
+
+        let diffs = diffs.lock().await.clone();
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].added_keys, keys(&["d"]));
+        assert_eq!(diffs[0].updated_keys, keys(&["a"]));
+        assert_eq!(diffs[0].removed_keys, keys(&["b", "c"]));
+        assert_eq!(
+            arrow_columns(&client, diffs[0].removed.clone()).await?,
+            r#"{"id":["b","c"],"v":[2,3]}"#
+        );
+
+        Ok(())
+    }
+}
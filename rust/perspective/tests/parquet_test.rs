@@ -0,0 +1,110 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+#[cfg(all(feature = "axum-ws", feature = "parquet"))]
+mod internal {
+    use std::error::Error;
+
+    use perspective_client::config::{Filter, FilterOp, Scalar, ViewConfigUpdate};
+    use perspective_client::{TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+    use perspective_server::LocalClient;
+
+    #[tokio::test]
+    async fn test_parquet_round_trip() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let source = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b\n3,c".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let view = source.view(None).await?;
+        let parquet = view
+            .to_parquet(ViewWindow {
+                compression: Some("zstd(3)".to_owned()),
+                ..ViewWindow::default()
+            })
+            .await?;
+
+        let table = client
+            .table(UpdateData::Parquet(parquet.clone()).into(), TableInitOptions::default())
+            .await?;
+
+        assert_eq!(table.schema().await?, source.schema().await?);
+        table
+            .update(UpdateData::Parquet(parquet), UpdateOptions::default())
+            .await?;
+
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(vec![Some("x".to_owned())]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, r#"{"x":[1,2,3,1,2,3]}"#);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parquet_bad_codec() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x\n1".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let result = view
+            .to_parquet(ViewWindow {
+                compression: Some("bogus".to_owned()),
+                ..ViewWindow::default()
+            })
+            .await;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parquet_no_rows() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let source = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let view = source
+            .view(Some(ViewConfigUpdate {
+                filter: Some(vec![Filter::new("x", FilterOp::Gt, Scalar::Float(10.0))]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let parquet = view.to_parquet(ViewWindow::default()).await?;
+        let table = client
+            .table(UpdateData::Parquet(parquet).into(), TableInitOptions::default())
+            .await?;
+
+        assert_eq!(table.schema().await?, source.schema().await?);
+        assert_eq!(table.size().await?, 0);
+        Ok(())
+    }
+}
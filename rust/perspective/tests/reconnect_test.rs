@@ -0,0 +1,254 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+    use std::sync::{Arc, OnceLock};
+
+    use perspective_client::{
+        Client, ClientError, OnUpdateOptions, ReconnectCallback, Session, TableInitOptions,
+        UpdateData, UpdateOptions, ViewWindow,
+    };
+    use perspective_server::{LocalClient, LocalSession, Server};
+    use tokio::sync::{Mutex, RwLock};
+
+    /// A [`Client`]'s connection to a [`Server`] which can be dropped and
+    /// reopened as a new session, like a reconnecting WebSocket.
+    #[derive(Clone)]
+    struct Connection {
+        client: Arc<OnceLock<Client>>,
+        session: Arc<RwLock<Option<LocalSession>>>,
+    }
+
+    impl Connection {
+        fn new() -> Result<(Self, Client), Box<dyn Error>> {
+            let conn = Connection {
+                client: Arc::default(),
+                session: Arc::default(),
+            };
+
+            let client = Client::new_with_callback(None, {
+                let conn = conn.clone();
+                move |bytes: Vec<u8>| {
+                    let conn = conn.clone();
+                    async move {
+                        match conn.session.read().await.as_ref() {
+                            Some(session) => session.handle_request(&bytes).await,
+                            None => Err("Disconnected".into()),
+                        }
+                    }
+                }
+            })?;
+
+            let _ = conn.client.set(client.clone());
+            Ok((conn, client))
+        }
+
+        async fn connect(&self, server: &Server) {
+            let client = self.client.clone();
+            let session = server
+                .new_session_with_callback(move |msg| {
+                    let client = client.get().unwrap().clone();
+                    Box::pin(async move {
+                        client.handle_response(msg).await?;
+                        Ok(())
+                    })
+                })
+                .await;
+
+            *self.session.write().await = Some(session);
+        }
+
+        /// Close the session, which drops its views and subscriptions on the
+        /// server.
+        async fn disconnect(&self) {
+            if let Some(session) = self.session.write().await.take() {
+                session.close().await;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_session() -> Result<(), Box<dyn Error>> {
+        let server = Server::new(None);
+        let host = LocalClient::new(&server);
+        let host_table = host
+            .table(
+                UpdateData::Csv("x\n1\n2".to_owned()).into(),
+                TableInitOptions {
+                    name: Some("superstore".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        let (conn, client) = Connection::new()?;
+        conn.connect(&server).await;
+        client.set_resilient(true);
+        let table = client.open_table("superstore".to_owned()).await?;
+        let view = table.view(None).await?;
+        let updates = Arc::new(Mutex::new(0));
+        view.on_update(
+            {
+                let updates = updates.clone();
+                move |_| {
+                    let updates = updates.clone();
+                    async move { *updates.lock().await += 1 }
+                }
+            },
+            OnUpdateOptions::default(),
+        )
+        .await?;
+
+        // Keep the reconnect callback offered to `on_error` to call it once
+        // the connection has dropped.
+        let reconnect = Arc::new(std::sync::Mutex::new(None::<ReconnectCallback>));
+        client
+            .on_error({
+                let reconnect = reconnect.clone();
+                move |_, callback| {
+                    *reconnect.lock().unwrap() = callback;
+                    async { Ok::<(), ClientError>(()) }
+                }
+            })
+            .await?;
+
+        conn.disconnect().await;
+        client
+            .handle_error(
+                ClientError::Unknown("Disconnected".to_owned()),
+                Some({
+                    let conn = conn.clone();
+                    let server = server.clone();
+                    move || {
+                        let conn = conn.clone();
+                        let server = server.clone();
+                        async move {
+                            conn.connect(&server).await;
+                            Ok(())
+                        }
+                    }
+                }),
+            )
+            .await?;
+
+        let reconnect = reconnect.lock().unwrap().take().unwrap();
+        (*reconnect)().await?;
+
+        // The table, view and subscription work on the new session.
+        assert_eq!(table.size().await?, 2);
+        assert_eq!(
+            view.to_columns_string(ViewWindow::default()).await?,
+            r#"{"x":[1,2]}"#
+        );
+
+        host_table
+            .update(UpdateData::Csv("x\n3".to_owned()), UpdateOptions::default())
+            .await?;
+
+        assert_eq!(*updates.lock().await, 1);
+        assert_eq!(view.num_rows().await?, 3);
+        conn.disconnect().await;
+        host.close().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_recreates_created_tables() -> Result<(), Box<dyn Error>> {
+        let server = Server::new(None);
+        let host = LocalClient::new(&server);
+        host.table(
+            UpdateData::Csv("x\n1".to_owned()).into(),
+            TableInitOptions {
+                name: Some("superstore".to_owned()),
+                ..TableInitOptions::default()
+            },
+        )
+        .await?;
+
+        let (conn, client) = Connection::new()?;
+        conn.connect(&server).await;
+        client.set_resilient(true);
+        let opened = client.open_table("superstore".to_owned()).await?;
+        let table = client
+            .table(
+                UpdateData::Csv("x\n1\n2".to_owned()).into(),
+                TableInitOptions {
+                    name: Some("created".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        table
+            .update(UpdateData::Csv("x\n3".to_owned()), UpdateOptions::default())
+            .await?;
+
+        let view = table.view(None).await?;
+        let reconnect = Arc::new(std::sync::Mutex::new(None::<ReconnectCallback>));
+        client
+            .on_error({
+                let reconnect = reconnect.clone();
+                move |_, callback| {
+                    *reconnect.lock().unwrap() = callback;
+                    async { Ok::<(), ClientError>(()) }
+                }
+            })
+            .await?;
+
+        // Reconnect to a new `Server`, as if the old one had restarted.
+        conn.disconnect().await;
+        let restarted = Server::new(None);
+        client
+            .handle_error(
+                ClientError::Unknown("Disconnected".to_owned()),
+                Some({
+                    let conn = conn.clone();
+                    let restarted = restarted.clone();
+                    move || {
+                        let conn = conn.clone();
+                        let restarted = restarted.clone();
+                        async move {
+                            conn.connect(&restarted).await;
+                            Ok(())
+                        }
+                    }
+                }),
+            )
+            .await?;
+
+        let reconnect = reconnect.lock().unwrap().take().unwrap();
+        let message = (*reconnect)()
+            .await
+            .err()
+            .ok_or("expected an error")?
+            .to_string();
+
+        assert!(
+            message.contains("\"superstore\": not found, and was not created by this client"),
+            "{message}"
+        );
+
+        // The table this client created is recreated with its updates.
+        assert!(opened.size().await.is_err());
+        assert_eq!(table.size().await?, 3);
+        assert_eq!(
+            view.to_columns_string(ViewWindow::default()).await?,
+            r#"{"x":[1,2,3]}"#
+        );
+
+        conn.disconnect().await;
+        host.close().await;
+        Ok(())
+    }
+}
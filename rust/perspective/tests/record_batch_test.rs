@@ -0,0 +1,95 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(all(feature = "axum-ws", feature = "arrow"))]
+mod internal {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use perspective_client::config::ColumnType;
+    use perspective_client::{
+        Client, OnBadRows, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+    };
+    use perspective_server::LocalClient;
+
+    const SECOND_CHUNK_ROW: usize = UpdateData::RECORD_BATCH_CHUNK_ROWS + 1;
+
+    fn hint(column_type: ColumnType) -> Option<HashMap<String, ColumnType>> {
+        Some(HashMap::from([("x".to_owned(), column_type)]))
+    }
+
+    /// A string column `x` long enough to be sent as two chunks, which is
+    /// `"bad"` at each of `bad_rows` and `"1"` elsewhere.
+    async fn batches(client: &Client, bad_rows: &[usize]) -> Result<UpdateData, Box<dyn Error>> {
+        let mut csv = "x\n".to_owned();
+        for row in 0..UpdateData::RECORD_BATCH_CHUNK_ROWS + 2 {
+            csv.push_str(if bad_rows.contains(&row) { "bad\n" } else { "1\n" });
+        }
+
+        let table = client
+            .table(UpdateData::Csv(csv).into(), TableInitOptions {
+                schema_hints: hint(ColumnType::String),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        let view = table.view(None).await?;
+        let batches = view.to_record_batches(ViewWindow::default()).await?;
+        Ok(UpdateData::RecordBatches(batches))
+    }
+
+    #[tokio::test]
+    async fn test_record_batch_row_errors_span_chunks() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let data = batches(&client, &[1, SECOND_CHUNK_ROW]).await?;
+        let table = client
+            .table(data.into(), TableInitOptions {
+                schema_hints: hint(ColumnType::Integer),
+                on_bad_rows: Some(OnBadRows::Null),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        let rows = table.row_errors().iter().map(|x| x.row).collect::<Vec<_>>();
+        assert_eq!(rows, vec![1, SECOND_CHUNK_ROW as u64]);
+
+        let data = batches(&client, &[0, SECOND_CHUNK_ROW]).await?;
+        let errors = table
+            .update_with_errors(data, UpdateOptions::default())
+            .await?;
+        let rows = errors.iter().map(|x| x.row).collect::<Vec<_>>();
+        assert_eq!(rows, vec![0, SECOND_CHUNK_ROW as u64]);
+        assert_eq!(table.row_errors().len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_record_batch_fail_in_later_chunk() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let data = batches(&client, &[SECOND_CHUNK_ROW]).await?;
+        let tables = client.get_hosted_table_names().await?;
+        let result = client
+            .table(data.into(), TableInitOptions {
+                schema_hints: hint(ColumnType::Integer),
+                on_bad_rows: Some(OnBadRows::Fail),
+                ..TableInitOptions::default()
+            })
+            .await;
+
+        let message = result.err().ok_or("expected an error")?.to_string();
+        assert!(message.contains("Ingestion error"), "{message}");
+        assert_eq!(client.get_hosted_table_names().await?, tables);
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+    use std::sync::Arc;
+
+    use perspective_client::config::{Filter, FilterOp, FilterReducer, Scalar};
+    use perspective_client::{OnUpdateOptions, TableInitOptions, UpdateData};
+    use perspective_server::LocalClient;
+    use tokio::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_remove_where_indexed() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("id,status\n1,open\n2,closed\n3,closed".to_owned()).into(),
+                TableInitOptions {
+                    index: Some("id".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let count = Arc::new(Mutex::new(0));
+        let _sub = view
+            .on_update(
+                {
+                    let count = count.clone();
+                    move |_| {
+                        let count = count.clone();
+                        async move { *count.lock().await += 1 }
+                    }
+                },
+                OnUpdateOptions::default(),
+            )
+            .await?;
+
+        let closed = Filter::new("status", FilterOp::Eq, Scalar::String("closed".into()));
+        table.remove_where(vec![closed], FilterReducer::And).await?;
+        assert_eq!(view.num_rows().await?, 1);
+        assert_eq!(*count.lock().await, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_where_unindexed_or() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b\n3,c\n4,d".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let filter = vec![
+            Filter::new("x", FilterOp::Lt, Scalar::Float(2.0)),
+            Filter::new("y", FilterOp::Eq, Scalar::String("d".into())),
+        ];
+
+        table.remove_where(filter, FilterReducer::Or).await?;
+        assert_eq!(table.size().await?, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_where_no_match() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x\n1\n2".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let filter = vec![Filter::new("x", FilterOp::Gt, Scalar::Float(10.0))];
+        table.remove_where(filter, FilterReducer::And).await?;
+        assert_eq!(table.size().await?, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_where_empty_filter() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x\n1\n2".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        assert!(table.remove_where(vec![], FilterReducer::And).await.is_err());
+        assert_eq!(table.size().await?, 2);
+        Ok(())
+    }
+}
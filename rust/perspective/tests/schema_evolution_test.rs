@@ -0,0 +1,136 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use perspective_client::config::{ColumnType, ViewConfigUpdate};
+    use perspective_client::{TableInitOptions, UpdateData, UpdateOptions};
+    use perspective_server::LocalClient;
+
+    fn columns(names: &[&str]) -> ViewConfigUpdate {
+        ViewConfigUpdate {
+            columns: Some(names.iter().map(|x| Some(x.to_string())).collect()),
+            ..ViewConfigUpdate::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_columns_null_fills() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let view = table.view(Some(columns(&["x"]))).await?;
+        table
+            .add_columns(vec![("z".to_owned(), ColumnType::Float)])
+            .await?;
+
+        assert_eq!(table.schema().await?.get("z"), Some(&ColumnType::Float));
+        assert_eq!(view.num_rows().await?, 2);
+
+        table
+            .update(
+                UpdateData::JsonRows(r#"[{"x": 3, "y": "c", "z": 1.5}]"#.to_owned()),
+                UpdateOptions::default(),
+            )
+            .await?;
+
+        let z = table.view(Some(columns(&["z"]))).await?;
+        let json = z.to_json_string(Default::default()).await?;
+        assert_eq!(json, r#"[{"z":null},{"z":null},{"z":1.5}]"#);
+        assert_eq!(view.num_rows().await?, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_drop_columns_deletes_views() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let kept = table.view(Some(columns(&["x"]))).await?;
+        let dropped = table.view(Some(columns(&["y"]))).await?;
+        let deleted = Arc::new(AtomicBool::new(false));
+        dropped
+            .on_delete(Box::new({
+                let deleted = deleted.clone();
+                move || deleted.store(true, Ordering::SeqCst)
+            }))
+            .await?;
+
+        let views = table.drop_columns(vec!["y".to_owned()]).await?;
+        assert_eq!(views, vec![dropped.name.clone()]);
+        assert!(deleted.load(Ordering::SeqCst));
+        assert!(!table.schema().await?.contains_key("y"));
+        assert_eq!(kept.num_rows().await?, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cast_column() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        table.cast_column("x", ColumnType::String).await?;
+        assert_eq!(table.schema().await?.get("x"), Some(&ColumnType::String));
+        assert!(table.cast_column("y", ColumnType::Float).await.is_err());
+        assert_eq!(table.schema().await?.get("y"), Some(&ColumnType::String));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_failed_cast_keeps_views() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1.5,a\n2.5,b".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let view = table.view(Some(columns(&["x"]))).await?;
+        let deleted = Arc::new(AtomicBool::new(false));
+        view.on_delete(Box::new({
+            let deleted = deleted.clone();
+            move || deleted.store(true, Ordering::SeqCst)
+        }))
+        .await?;
+
+        assert!(table.cast_column("x", ColumnType::Integer).await.is_err());
+        assert!(!deleted.load(Ordering::SeqCst));
+        assert_eq!(table.schema().await?.get("x"), Some(&ColumnType::Float));
+        let json = view.to_json_string(Default::default()).await?;
+        assert_eq!(json, r#"[{"x":1.5},{"x":2.5}]"#);
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use perspective_client::config::{ColumnType, ViewConfigUpdate};
+    use perspective_client::{Table, TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+    use perspective_server::LocalClient;
+
+    async fn to_columns(table: &Table, columns: &[&str]) -> Result<String, Box<dyn Error>> {
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(columns.iter().map(|x| Some((*x).to_owned())).collect()),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        Ok(view.to_columns_string(ViewWindow::default()).await?)
+    }
+
+    fn hints(hints: &[(&str, ColumnType)]) -> Option<HashMap<String, ColumnType>> {
+        Some(
+            hints
+                .iter()
+                .map(|(name, dtype)| ((*name).to_owned(), *dtype))
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_schema_hints_csv_partial_override() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let csv = "zip,n\n007,1\n010,2\n";
+        let table = client
+            .table(UpdateData::Csv(csv.to_owned()).into(), TableInitOptions {
+                schema_hints: hints(&[("zip", ColumnType::String)]),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        let schema = table.schema().await?;
+        assert_eq!(schema["zip"], ColumnType::String);
+        assert_eq!(schema["n"], ColumnType::Integer);
+        assert!(table.row_errors().is_empty());
+        assert_eq!(
+            to_columns(&table, &["zip", "n"]).await?,
+            r#"{"zip":["007","010"],"n":[1,2]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_schema_hints_csv_coercion_errors() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let csv = "x,y\n1,a\nabc,b\n3,c\n";
+        let table = client
+            .table(UpdateData::Csv(csv.to_owned()).into(), TableInitOptions {
+                schema_hints: hints(&[("x", ColumnType::Integer)]),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        assert_eq!(table.schema().await?["x"], ColumnType::Integer);
+        assert_eq!(
+            to_columns(&table, &["x", "y"]).await?,
+            r#"{"x":[1,null,3],"y":["a","b","c"]}"#
+        );
+
+        let errors = table.row_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].row, 1);
+        assert_eq!(errors[0].column, "x");
+        assert_eq!(errors[0].value, "abc");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_schema_hints_json_coercion_errors() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let json = r#"[{"x":"1","y":1.5},{"x":"two","y":2.5},{"x":3.5,"y":"3.25"}]"#;
+        let table = client
+            .table(UpdateData::JsonRows(json.to_owned()).into(), TableInitOptions {
+                schema_hints: hints(&[("x", ColumnType::Integer), ("y", ColumnType::Float)]),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        let schema = table.schema().await?;
+        assert_eq!(schema["x"], ColumnType::Integer);
+        assert_eq!(schema["y"], ColumnType::Float);
+        assert_eq!(
+            to_columns(&table, &["x", "y"]).await?,
+            r#"{"x":[1,null,null],"y":[1.5,2.5,3.25]}"#
+        );
+
+        let errors = table.row_errors();
+        let errors = errors
+            .iter()
+            .map(|x| (x.row, x.column.as_str(), x.value.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(errors, vec![(1, "x", "two"), (2, "x", "3.5")]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_schema_hints_ignored_by_update() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(UpdateData::Csv("id,x\n1,01\n".to_owned()).into(), TableInitOptions {
+                index: Some("id".to_owned()),
+                schema_hints: hints(&[("x", ColumnType::String), ("missing", ColumnType::Date)]),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        assert!(!table.schema().await?.contains_key("missing"));
+        table
+            .update(UpdateData::Csv("id,x\n2,02\n".to_owned()), UpdateOptions::default())
+            .await?;
+
+        assert_eq!(
+            to_columns(&table, &["id", "x"]).await?,
+            r#"{"id":[1,2],"x":["01","02"]}"#
+        );
+
+        Ok(())
+    }
+}
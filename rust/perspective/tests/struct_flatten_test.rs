@@ -0,0 +1,114 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+
+    use perspective_client::config::{ColumnType, ViewConfigUpdate};
+    use perspective_client::proto::StructFlatten;
+    use perspective_client::{Table, TableInitOptions, UpdateData, UpdateOptions, ViewWindow};
+    use perspective_server::LocalClient;
+
+    const ROWS: &str = r#"[
+        {"id":1,"user":{"name":"a","geo":{"lat":1.5,"tags":{"x":1}}}},
+        {"id":2,"user":{"name":"b","geo":{"lat":2.5,"tags":{"x":2}}}}
+    ]"#;
+
+    async fn to_columns(table: &Table, columns: &[&str]) -> Result<String, Box<dyn Error>> {
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(columns.iter().map(|x| Some((*x).to_owned())).collect()),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        Ok(view.to_columns_string(ViewWindow::default()).await?)
+    }
+
+    #[tokio::test]
+    async fn test_struct_flatten_default() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(UpdateData::JsonRows(ROWS.to_owned()).into(), TableInitOptions::default())
+            .await?;
+
+        let schema = table.schema().await?;
+        assert_eq!(schema["user.name"], ColumnType::String);
+        assert_eq!(schema["user.geo.lat"], ColumnType::Float);
+        assert_eq!(schema["user.geo.tags.x"], ColumnType::Integer);
+        assert_eq!(
+            to_columns(&table, &["user.name", "user.geo.tags.x"]).await?,
+            r#"{"user.name":["a","b"],"user.geo.tags.x":[1,2]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_struct_flatten_separator_and_max_depth() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(UpdateData::JsonRows(ROWS.to_owned()).into(), TableInitOptions {
+                index: Some("id".to_owned()),
+                struct_flatten: Some(StructFlatten {
+                    max_depth: Some(2),
+                    separator: Some("_".to_owned()),
+                }),
+                ..TableInitOptions::default()
+            })
+            .await?;
+
+        let schema = table.schema().await?;
+        assert_eq!(schema["user_name"], ColumnType::String);
+        assert_eq!(schema["user_geo_lat"], ColumnType::Float);
+        assert_eq!(schema["user_geo_tags"], ColumnType::String);
+        assert!(!schema.contains_key("user_geo_tags_x"));
+        assert_eq!(
+            to_columns(&table, &["user_geo_lat", "user_geo_tags"]).await?,
+            r#"{"user_geo_lat":[1.5,2.5],"user_geo_tags":["{\"x\":1}","{\"x\":2}"]}"#
+        );
+
+        // Updates are flattened with the mapping the `Table` was created with.
+        let update = r#"[{"id":2,"user":{"name":"c","geo":{"lat":3.5,"tags":{"y":3}}}}]"#;
+        table
+            .update(UpdateData::JsonRows(update.to_owned()), UpdateOptions::default())
+            .await?;
+
+        assert_eq!(
+            to_columns(&table, &["user_name", "user_geo_tags"]).await?,
+            r#"{"user_name":["a","c"],"user_geo_tags":["{\"x\":1}","{\"y\":3}"]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_struct_flatten_empty_separator() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let result = client
+            .table(UpdateData::JsonRows(ROWS.to_owned()).into(), TableInitOptions {
+                struct_flatten: Some(StructFlatten {
+                    max_depth: None,
+                    separator: Some("".to_owned()),
+                }),
+                ..TableInitOptions::default()
+            })
+            .await;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+}
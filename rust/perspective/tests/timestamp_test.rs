@@ -0,0 +1,141 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+
+    use perspective_client::config::{
+        ColumnType, ColumnTypeParams, Filter, FilterOp, Scalar, TimeUnit, ViewConfigUpdate,
+    };
+    use perspective_client::{
+        Client, Table, TableData, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+    };
+    use perspective_server::LocalClient;
+
+    fn params() -> ColumnTypeParams {
+        ColumnTypeParams::datetime(TimeUnit::Nanosecond, Some("America/New_York".to_owned()))
+    }
+
+    async fn make_table(client: &Client) -> Result<Table, Box<dyn Error>> {
+        let table = client
+            .table(
+                TableData::SchemaWithParams(vec![
+                    ("g".to_owned(), ColumnType::String, None),
+                    ("t".to_owned(), ColumnType::Datetime, Some(params())),
+                ]),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let rows = r#"[
+            {"g":"a","t":"2024-01-02T03:04:05.0015Z"},
+            {"g":"b","t":"2024-01-02T03:04:06Z"}
+        ]"#;
+
+        table
+            .update(
+                UpdateData::JsonRows(rows.to_owned()),
+                UpdateOptions::default(),
+            )
+            .await?;
+
+        Ok(table)
+    }
+
+    #[tokio::test]
+    async fn test_timestamp_schema_params() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        assert_eq!(table.schema().await?["t"], ColumnType::Datetime);
+        assert_eq!(table.schema_params().await?.get("t"), Some(&params()));
+
+        let view = table.view(None).await?;
+        assert_eq!(view.schema_params().await?.get("t"), Some(&params()));
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(
+            json,
+            r#"{"g":["a","b"],"t":[1704164645001.5,1704164646000]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_timestamp_arrow_round_trip() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let source = make_table(&client).await?;
+        let view = source.view(None).await?;
+        let arrow = view.to_arrow(ViewWindow::default()).await?;
+        let table = client
+            .table(UpdateData::Arrow(arrow).into(), TableInitOptions::default())
+            .await?;
+
+        assert_eq!(table.schema_params().await?.get("t"), Some(&params()));
+        let view = table.view(None).await?;
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(
+            json,
+            r#"{"g":["a","b"],"t":[1704164645001.5,1704164646000]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_timestamp_filter() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let view = table
+            .view(Some(ViewConfigUpdate {
+                columns: Some(vec![Some("g".to_owned())]),
+                filter: Some(vec![Filter::new(
+                    "t",
+                    FilterOp::Gt,
+                    Scalar::String("2024-01-02T03:04:05.0014Z".to_owned()),
+                )]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await?;
+
+        let json = view.to_columns_string(ViewWindow::default()).await?;
+        assert_eq!(json, r#"{"g":["a","b"]}"#);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_timestamp_split_by_is_rejected() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = make_table(&client).await?;
+        let result = table
+            .view(Some(ViewConfigUpdate {
+                split_by: Some(vec!["t".to_owned()]),
+                ..ViewConfigUpdate::default()
+            }))
+            .await;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_datetime_with_params() {
+        assert_eq!(
+            ColumnType::parse_with_params("datetime(ns, America/New_York)").unwrap(),
+            (ColumnType::Datetime, Some(params()))
+        );
+    }
+}
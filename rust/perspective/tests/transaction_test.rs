@@ -0,0 +1,210 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+    use std::sync::Arc;
+
+    use perspective_client::{
+        CsvOptions, OnUpdateOptions, TableInitOptions, UpdateData, UpdateOptions, ViewWindow,
+    };
+    use perspective_server::LocalClient;
+    use tokio::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_transaction_fires_one_on_update() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b\n3,c".to_owned()).into(),
+                TableInitOptions {
+                    name: Some("Table1".to_owned()),
+                    index: Some("x".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let count = Arc::new(Mutex::new(0));
+        let _sub = view
+            .on_update(
+                {
+                    let count = count.clone();
+                    move |_| {
+                        let count = count.clone();
+                        async move { *count.lock().await += 1 }
+                    }
+                },
+                OnUpdateOptions::default(),
+            )
+            .await?;
+
+        let mut tx = table.transaction();
+        tx.update(
+            UpdateData::Csv("x,y\n4,d".to_owned()),
+            UpdateOptions::default(),
+        )?;
+        tx.remove(UpdateData::JsonRows(r#"[{"x": 1}, {"x": 2}]"#.to_owned()))?;
+        tx.update(
+            UpdateData::Csv("x,y\n5,e".to_owned()),
+            UpdateOptions::default(),
+        )?;
+        tx.commit(UpdateOptions::default()).await?;
+
+        assert_eq!(*count.lock().await, 1);
+        assert_eq!(view.num_rows().await?, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transaction_clear() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let mut tx = table.transaction();
+        tx.update(
+            UpdateData::Csv("x,y\n3,c".to_owned()),
+            UpdateOptions::default(),
+        )?;
+        tx.clear();
+        tx.update(
+            UpdateData::Csv("x,y\n4,d".to_owned()),
+            UpdateOptions::default(),
+        )?;
+        tx.commit(UpdateOptions::default()).await?;
+
+        assert_eq!(table.size().await?, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transaction_clear_fires_one_on_update() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+                TableInitOptions {
+                    index: Some("x".to_owned()),
+                    ..TableInitOptions::default()
+                },
+            )
+            .await?;
+
+        let view = table.view(None).await?;
+        let count = Arc::new(Mutex::new(0));
+        let _sub = view
+            .on_update(
+                {
+                    let count = count.clone();
+                    move |_| {
+                        let count = count.clone();
+                        async move { *count.lock().await += 1 }
+                    }
+                },
+                OnUpdateOptions::default(),
+            )
+            .await?;
+
+        // `x = 2` is cleared then re-added without its `y`, so it must not
+        // keep the value it had before the `clear`.
+        let mut tx = table.transaction();
+        tx.update(
+            UpdateData::Csv("x,y\n3,c".to_owned()),
+            UpdateOptions::default(),
+        )?;
+        tx.clear();
+        tx.update(
+            UpdateData::Csv("x,y\n4,d".to_owned()),
+            UpdateOptions::default(),
+        )?;
+        tx.update(
+            UpdateData::JsonRows(r#"[{"x": 2}]"#.to_owned()),
+            UpdateOptions::default(),
+        )?;
+        tx.commit(UpdateOptions::default()).await?;
+
+        assert_eq!(*count.lock().await, 1);
+        assert_eq!(
+            view.to_columns_string(ViewWindow::default()).await?,
+            r#"{"x":[2,4],"y":[null,"d"]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transaction_update_options() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let mut tx = table.transaction();
+        tx.update(UpdateData::Csv("x|y\n2|b".to_owned()), UpdateOptions {
+            csv: Some(CsvOptions {
+                delimiter: Some('|'),
+                ..CsvOptions::default()
+            }),
+            ..UpdateOptions::default()
+        })?;
+
+        tx.update(
+            UpdateData::Csv("x,y\n3,c".to_owned()),
+            UpdateOptions::default(),
+        )?;
+        tx.commit(UpdateOptions::default()).await?;
+
+        let view = table.view(None).await?;
+        assert_eq!(
+            view.to_columns_string(ViewWindow::default()).await?,
+            r#"{"x":[1,2,3],"y":["a","b","c"]}"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transaction_dropped_is_rolled_back() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let client = LocalClient::new(&server);
+        let table = client
+            .table(
+                UpdateData::Csv("x,y\n1,a\n2,b".to_owned()).into(),
+                TableInitOptions::default(),
+            )
+            .await?;
+
+        let mut tx = table.transaction();
+        tx.update(
+            UpdateData::Csv("x,y\n3,c".to_owned()),
+            UpdateOptions::default(),
+        )?;
+        drop(tx);
+
+        assert_eq!(table.size().await?, 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ ██████ ██████ ██████       █      █      █      █      █ █▄  ▀███ █       ┃
+// ┃ ▄▄▄▄▄█ █▄▄▄▄▄ ▄▄▄▄▄█  ▀▀▀▀▀█▀▀▀▀▀ █ ▀▀▀▀▀█ ████████▌▐███ ███▄  ▀█ █ ▀▀▀▀▀ ┃
+// ┃ █▀▀▀▀▀ █▀▀▀▀▀ █▀██▀▀ ▄▄▄▄▄ █ ▄▄▄▄▄█ ▄▄▄▄▄█ ████████▌▐███ █████▄   █ ▄▄▄▄▄ ┃
+// ┃ █      ██████ █  ▀█▄       █ ██████      █      ███▌▐███ ███████▄ █       ┃
+// ┣━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+// ┃ Copyright (c) 2017, the Perspective Authors.                              ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃ This file is part of the Perspective library, distributed under the terms ┃
+// ┃ of the [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0). ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+#[cfg(feature = "axum-ws")]
+mod internal {
+    use std::error::Error;
+    use std::sync::{Arc, Mutex};
+
+    use perspective_client::Session;
+    use perspective_client::proto::request::ClientReq;
+    use perspective_client::proto::response::ClientResp;
+    use perspective_client::proto::{
+        MakeTableData, MakeTableReq, Request, Response, TableMakeViewReq, TableUpdateReq,
+        UnsubscribeReq, ViewDeleteReq, ViewOnUpdateReq, make_table_data,
+    };
+    use perspective_server::LocalSession;
+    use prost::Message;
+
+    async fn send(
+        session: &LocalSession,
+        msg_id: u32,
+        entity_id: &str,
+        req: ClientReq,
+    ) -> Result<(), Box<dyn Error>> {
+        let req = Request {
+            msg_id,
+            entity_id: entity_id.to_owned(),
+            client_req: Some(req),
+        };
+
+        session.handle_request(&req.encode_to_vec()).await?;
+        Ok(())
+    }
+
+    fn csv(csv: &str) -> Option<MakeTableData> {
+        Some(MakeTableData {
+            data: Some(make_table_data::Data::FromCsv(csv.to_owned())),
+        })
+    }
+
+    /// The `msg_id` of every `ViewOnUpdateResp` sent so far.
+    fn on_update_ids(responses: &Mutex<Vec<Response>>) -> Vec<u32> {
+        responses
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|resp| matches!(resp.client_resp, Some(ClientResp::ViewOnUpdateResp(_))))
+            .map(|resp| resp.msg_id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_removes_request_subscriptions() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let responses: Arc<Mutex<Vec<Response>>> = Arc::default();
+        let session = server
+            .new_session_with_callback({
+                let responses = responses.clone();
+                move |msg| {
+                    let responses = responses.clone();
+                    Box::pin(async move {
+                        responses.lock().unwrap().push(Response::decode(msg)?);
+                        Ok(())
+                    })
+                }
+            })
+            .await;
+
+        let make_table = MakeTableReq {
+            data: csv("x\n1"),
+            ..MakeTableReq::default()
+        };
+
+        send(&session, 1, "table", ClientReq::MakeTableReq(make_table)).await?;
+        let make_view = TableMakeViewReq {
+            view_id: "view".to_owned(),
+            config: None,
+        };
+
+        send(&session, 2, "table", ClientReq::TableMakeViewReq(make_view)).await?;
+        let on_update = ViewOnUpdateReq { mode: None };
+        send(&session, 3, "view", ClientReq::ViewOnUpdateReq(on_update)).await?;
+        let update = TableUpdateReq {
+            data: csv("x\n2"),
+            ..TableUpdateReq::default()
+        };
+
+        send(&session, 4, "table", ClientReq::TableUpdateReq(update.clone())).await?;
+        assert_eq!(on_update_ids(&responses), vec![3]);
+
+        // The subscription created by request 3 is removed, but the view it
+        // was registered on is untouched.
+        let unsubscribe = UnsubscribeReq { id: 3 };
+        send(&session, 5, "", ClientReq::UnsubscribeReq(unsubscribe)).await?;
+        send(&session, 6, "table", ClientReq::TableUpdateReq(update)).await?;
+        assert_eq!(on_update_ids(&responses), vec![3]);
+        assert!(responses.lock().unwrap().iter().any(|resp| {
+            resp.msg_id == 5 && matches!(resp.client_resp, Some(ClientResp::UnsubscribeResp(_)))
+        }));
+
+        let on_update = ViewOnUpdateReq { mode: None };
+        send(&session, 7, "view", ClientReq::ViewOnUpdateReq(on_update)).await?;
+        let update = TableUpdateReq {
+            data: csv("x\n3"),
+            ..TableUpdateReq::default()
+        };
+
+        send(&session, 8, "table", ClientReq::TableUpdateReq(update)).await?;
+        assert_eq!(on_update_ids(&responses), vec![3, 7]);
+        session.close().await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_abandons_make_view() -> Result<(), Box<dyn Error>> {
+        let server = perspective::server::Server::new(None);
+        let responses: Arc<Mutex<Vec<Response>>> = Arc::default();
+        let session = server
+            .new_session_with_callback({
+                let responses = responses.clone();
+                move |msg| {
+                    let responses = responses.clone();
+                    Box::pin(async move {
+                        responses.lock().unwrap().push(Response::decode(msg)?);
+                        Ok(())
+                    })
+                }
+            })
+            .await;
+
+        let make_table = MakeTableReq {
+            data: csv("x\n1"),
+            ..MakeTableReq::default()
+        };
+
+        send(&session, 1, "table", ClientReq::MakeTableReq(make_table)).await?;
+
+        // Requests may be handled concurrently, so a cancellation can be
+        // handled before the request it names starts.
+        let unsubscribe = UnsubscribeReq { id: 3 };
+        send(&session, 2, "", ClientReq::UnsubscribeReq(unsubscribe)).await?;
+        let make_view = TableMakeViewReq {
+            view_id: "view".to_owned(),
+            config: None,
+        };
+
+        send(&session, 3, "table", ClientReq::TableMakeViewReq(make_view)).await?;
+        send(&session, 4, "view", ClientReq::ViewDeleteReq(ViewDeleteReq {})).await?;
+        let responses = responses.lock().unwrap();
+        assert!(!responses.iter().any(|resp| resp.msg_id == 3));
+        assert!(responses.iter().any(|resp| {
+            resp.msg_id == 4 && matches!(resp.client_resp, Some(ClientResp::ServerError(_)))
+        }));
+
+        drop(responses);
+        session.close().await;
+        Ok(())
+    }
+}
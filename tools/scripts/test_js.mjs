@@ -158,7 +158,7 @@ if (process.env.PACKAGE) {
         }
 
         execSync(
-            `cargo test ${flags} ${target} -p perspective -p perspective-client`,
+            `cargo test ${flags} ${target} -p perspective -p perspective-client --features perspective-client/derive`,
             { stdio: "inherit" },
         );
     }